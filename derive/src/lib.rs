@@ -0,0 +1,8399 @@
+/*!
+Procedural macro implementation of the `struct_layout` crate.
+
+Do not depend on this crate directly, the generated code refers to the `struct_layout` crate for its runtime support.
+ */
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::vec;
+
+extern crate proc_macro;
+use proc_macro::*;
+
+// A parse error is reported as a compile error at the span of the offending tokens
+type ParseResult<T> = Result<T, (Span, String)>;
+
+// Returns the parse error with the formatted message at the span
+macro_rules! bail {
+	($span:expr, $($fmt:tt)*) => {
+		return Err(($span, format!($($fmt)*)))
+	};
+}
+
+//----------------------------------------------------------------
+// Definitions
+
+#[derive(Clone, Debug)]
+struct KeyValue {
+	ident: Ident,
+	#[allow(dead_code)]
+	punct: Punct,
+	value: Expr,
+}
+
+// Per target values as (cfg predicate, value)
+type CfgArms = Vec<(String, String)>;
+
+#[derive(Clone, Debug)]
+struct ExplicitLayout {
+	size: Expr,
+	align: Expr,
+	// Per target size and alignment
+	size_arms: CfgArms,
+	align_arms: CfgArms,
+	check: Option<String>,
+	base: Option<Type>,
+	copy_from: Option<(Type, Option<Vec<Ident>>)>,
+	// Other views of the same bytes
+	cast_to: Vec<Type>,
+	mirror: Option<Mirror>,
+	impl_trait: Option<(Ident, bool)>,
+	// Trait mirroring all accessors so tests can substitute a fake
+	mockable: Option<Ident>,
+	slice_ext: bool,
+	kind: LayoutKind,
+	packed: bool,
+	modify: bool,
+	offsets: Option<String>,
+	dataview: bool,
+	impl_check: bool,
+	arbitrary_fields: bool,
+	remote: bool,
+	handles: bool,
+	visit: bool,
+	// The check bounds are asserted once per field instead of on every accessor
+	compact: bool,
+	grouped: bool,
+	escape_hatch: bool,
+	field_enum: bool,
+	tracked: bool,
+	parts: bool,
+	// Typestate initializer which sets every field exactly once
+	init: bool,
+	// Checks the stored values of every record in a byte buffer
+	validate_all: bool,
+	// Hash of the declared layout computed while expanding
+	layout_hash: bool,
+	// Writes the bytes of a field found by its offset at runtime
+	apply_patches: bool,
+	// Fields larger than this many bytes get a getter returning a reference by default
+	auto_ref: Option<u128>,
+	// Access to the structure as its bytes
+	bytes: bool,
+	byte_order: bool,
+	random: bool,
+	// Prefix of the extern "C" accessor functions
+	export_c: Option<String>,
+	// Exported to JavaScript, skipping the incompatible fields or rejecting them
+	wasm_bindgen: Option<bool>,
+	// The structure is emitted unchanged and only its layout is checked
+	keep_fields: bool,
+	// Element type of the inner array instead of bytes
+	storage: Option<String>,
+	// Module with the layout constants for tools which do not know the type
+	consts_mod: Option<Ident>,
+	// Visibility of the generated constants, types, traits and modules instead of the visibility of the structure
+	companion_vis: Option<Vis>,
+	// Formats a field chosen by name with Display
+	display_field: bool,
+	// Predicate shown by docs.rs on the generated constants, types, traits and modules
+	doc_cfg: Option<String>,
+	// Prepended and appended to the names of all the accessors of the fields
+	method_prefix: String,
+	method_suffix: String,
+	// Packed structure with a real field for every field for C APIs which take the structure by value
+	ffi_twin: Option<Ident>,
+	// Errors of the arguments, reported together with the errors of the fields
+	errors: Vec<TokenTree>,
+	// The Debug derive prints the offsets of the fields
+	debug_annotated: bool,
+	// Constants with the byte ranges covered by the fields and the gaps
+	coverage: bool,
+	// The getters of plain fields are const fn
+	const_fn: bool,
+	// The repr attribute is given by the user and checked against the layout
+	repr_manual: bool,
+	// Describes the layout as a JSON document for external tools
+	export_json: bool,
+	// The field types may not have interior mutability
+	freeze: bool,
+	// A single field spanning the structure converts to and from the structure
+	transparent: bool,
+	// Reports the parsed layout in a deprecation warning
+	dump: bool,
+	// Appends a table of the fields to the documentation of the structure
+	doc_layout: bool,
+	// The offsets of the fields are given in multiples of this many bytes
+	offset_unit: Option<Expr>,
+	// Sizes above 1 GiB are intended, the tables with an entry per byte are not generated
+	huge: bool,
+	// How the checked accessors report errors, each accessor has its own default
+	on_error: Option<OnError>,
+	// The setters of validated fields panic on invalid values
+	strict_validate: bool,
+	// Asserts that the fields do not overlap
+	strict: bool,
+	// The optional arguments as given
+	arguments: Vec<Ident>,
+	send: bool,
+	sync: bool,
+	portable: bool,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum LayoutKind {
+	Explicit,
+	// Fields default to offset zero and Debug prints the bytes
+	Union,
+	// Fields are placed sequentially
+	Implicit,
+}
+
+#[derive(Clone, Debug)]
+enum Mirror {
+	Plain,
+	// Forward the derives of the structure
+	Derive,
+	DeriveList(TokenStream),
+}
+
+#[derive(Clone, Debug)]
+struct FieldLayout {
+	offset: Expr,
+	stored: Option<Type>,
+	convert: Option<Convert>,
+	method_get: bool,
+	method_set: bool,
+	method_ref: bool,
+	method_mut: bool,
+	method_slice: bool,
+	method_str: bool,
+	method_cstr: bool,
+	method_wstr: bool,
+	wstr_truncate: bool,
+	pstr: Option<Option<Expr>>,
+	dims: Option<(Expr, Expr)>,
+	col_major: bool,
+	count: Option<Expr>,
+	stride: Option<Expr>,
+	tail: Option<Option<Ident>>,
+	len: Option<Expr>,
+	// Byte region without accessors, may be named `_`
+	pad: bool,
+	nested: bool,
+	flatten: bool,
+	variant: Option<Variant>,
+	pad_before: Option<Expr>,
+	align_to: Option<Expr>,
+	method_modify: bool,
+	// Copies between the field and a caller's buffer
+	method_copy_into: bool,
+	method_copy_from: bool,
+	// Fills the bytes of the field with zeroes
+	method_zero: bool,
+	// Copies the bytes of the field by value as a byte array
+	method_bytes_array: bool,
+	// Reads and writes the field across consecutive records in a byte buffer
+	method_column: bool,
+	// Atomic load, store and compare_exchange through shared references
+	method_atomic: bool,
+	// Accessors in little and big endian byte order next to the native accessors
+	method_endian: bool,
+	// Handles to the field which may not be aligned
+	method_unaligned_ref: bool,
+	// Accessors at the offset shifted by a delta given at runtime
+	method_shiftable: bool,
+	// The bytes of the field are left alone when changing the byte order
+	opaque: bool,
+	// The offset is given at runtime
+	dynamic: bool,
+	// The field type may have drop glue
+	allow_drop: bool,
+	// Asserted size of the field type
+	size: Option<Expr>,
+	// Asserted alignment of the offset, at least that of the field type
+	align: Option<Expr>,
+	// Stored as ManuallyDrop of the field type, filled in once the field type is parsed
+	manually_drop: Option<Type>,
+	// Named groups of fields accessed together as a tuple
+	groups: Vec<Ident>,
+	checksum: Option<Checksum>,
+	// Body of an inline structure declared with `inline! { .. }` and the name given with `as = <name>`
+	inline: Option<Group>,
+	inline_name: Option<Ident>,
+	// Path of the function accepting the values of the field
+	validate: Option<Expr>,
+	// Inclusive range the setter clamps values into, or the clamped setter with the raw setter kept
+	clamp: Option<(Expr, Expr)>,
+	method_clamped: bool,
+	// The field is present if any of the cfg predicates match, the stubs are present otherwise
+	optional: Option<Vec<String>>,
+	stubs: bool,
+	// No accessors were specified and all of them were enabled
+	default_methods: bool,
+	// The getter returns a reference, see the auto_ref argument
+	get_ref: bool,
+	// Accessors only present if their cfg predicate matches, they are absent for everything else
+	gated: Vec<(&'static str, String)>,
+	// Predicate under which the field type meets the check bound, gates all the accessors of the field
+	check_cfg: Option<String>,
+	// Integer type exposed by the accessors of a wider stored integer
+	narrow: Option<Type>,
+	// Stored value which is exposed as None
+	sentinel: Option<Expr>,
+	// Range of bits of the stored integer holding the value
+	bits: Option<(u32, u32)>,
+	// The Debug derive prints the byte array as hex digits
+	debug_hex: bool,
+	// The Debug derive only prints the field if the tag field equals the integer literal
+	debug_tag: bool,
+	debug_when: Option<(Ident, Expr)>,
+	// The display_field method leaves out the field
+	display_skip: bool,
+	// Sequence counter incremented after the writes through the update guard
+	seq: bool,
+	// Offset of the field in earlier versions of the layout, accessed by deprecated accessors
+	old_offset: Option<Expr>,
+	// Unit of the offset overriding the unit of the structure, and the offset and unit as declared once the offset is in bytes
+	unit: Option<Expr>,
+	unit_offset: Option<(Expr, Expr)>,
+	// The getter of a bool or char field returns None for invalid values
+	strict: bool,
+	// References to a bool or char field are allowed, the bytes are assumed to hold a valid value
+	assume_valid: bool,
+	// The field is left out of the overlap check of the strict argument
+	overlaps: bool,
+	// The arguments after the offset as given
+	arguments: Vec<Ident>,
+}
+
+// Checksum over a range of bytes of the structure stored in the field
+#[derive(Clone, Debug)]
+struct Checksum {
+	algorithm: Ident,
+	start: Expr,
+	end: Expr,
+	// Every other setter updates the checksum
+	auto: bool,
+}
+
+// Payload selected by the value of a discriminant field
+#[derive(Clone, Debug)]
+struct Variant {
+	field: Ident,
+	value: Expr,
+	default_offset: bool,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum OnError {
+	Panic, Option, Result,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Convert {
+	From, TryFrom, Enum, Sentinel,
+	// Plain bool and char fields read through their integer representation
+	Valid, Strict,
+	// Bitfields mask and shift their range of bits of the stored integer
+	Bits,
+}
+
+#[derive(Clone, Debug)]
+struct Meta {
+	ident: Ident,
+	args: Group,
+}
+
+#[derive(Clone, Debug)]
+struct Attribute {
+	punct: Punct,
+	meta: Group,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum DerivedTrait {
+	Copy, Clone, Debug, Default, Arbitrary, QuickcheckArbitrary, LowerHex, UpperHex,
+	PartialEq, Eq, Hash, PartialOrd, Ord,
+}
+
+#[derive(Clone, Debug)]
+struct Vis(Vec<TokenTree>);
+
+#[derive(Clone, Debug)]
+struct Structure {
+	attrs: Vec<Attribute>,
+	derived: Vec<DerivedTrait>,
+	layout: ExplicitLayout,
+	vis: Vis,
+	stru: Ident,
+	name: Ident,
+	generics: Generics,
+	fields: Vec<Field>,
+	// Fields which are only present in some configurations
+	optional: Vec<Field>,
+}
+
+// Type parameters of the structure with their bounds, and their names
+#[derive(Clone, Debug, Default)]
+struct Generics {
+	params: Vec<TokenTree>,
+	names: Vec<Ident>,
+}
+
+#[derive(Clone, Debug)]
+struct Type(Vec<TokenTree>);
+#[derive(Clone, Debug)]
+struct Expr(TokenStream);
+
+impl std::fmt::Display for Type {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		let stream: TokenStream = self.0.iter().cloned().collect();
+		stream.fmt(f)
+	}
+}
+
+#[derive(Clone, Debug)]
+struct Field {
+	attrs: Vec<Attribute>,
+	layout: FieldLayout,
+	vis: Vis,
+	name: Ident,
+	// The name of the field in the names of its accessors
+	accessor: Ident,
+	ty: Type,
+}
+
+//----------------------------------------------------------------
+// Lookahead queries
+
+fn is_ident(tokens: &[TokenTree]) -> bool {
+	matches!(tokens.first(), Some(TokenTree::Ident(_)))
+}
+fn is_keyword(tokens: &[TokenTree], name: &str) -> bool {
+	match tokens.first() {
+		Some(TokenTree::Ident(ident)) => ident.to_string() == name,
+		_ => false,
+	}
+}
+fn is_punct(tokens: &[TokenTree], chr: char) -> bool {
+	match tokens.first() {
+		Some(TokenTree::Punct(punct)) => punct.as_char() == chr,
+		_ => false,
+	}
+}
+fn is_group(tokens: &[TokenTree], delim: Delimiter) -> bool {
+	match tokens.first() {
+		Some(TokenTree::Group(group)) => group.delimiter() == delim,
+		_ => false,
+	}
+}
+fn is_end(tokens: &[TokenTree]) -> bool {
+	tokens.is_empty()
+}
+
+//----------------------------------------------------------------
+// Parser elements
+
+fn parse_punct(tokens: &mut vec::IntoIter<TokenTree>, punct: char) -> Option<Punct> {
+	if !is_punct(tokens.as_slice(), punct) {
+		return None;
+	}
+	match tokens.next() {
+		Some(TokenTree::Punct(punct)) => Some(punct),
+		_ => unreachable!(),
+	}
+}
+fn parse_ident(tokens: &mut vec::IntoIter<TokenTree>) -> Option<Ident> {
+	match tokens.next() {
+		Some(TokenTree::Ident(ident)) => Some(ident),
+		_ => None,
+	}
+}
+fn parse_keyword(tokens: &mut vec::IntoIter<TokenTree>, keyword: &str) -> Option<Ident> {
+	if !is_keyword(tokens.as_slice(), keyword) {
+		return None;
+	}
+	match tokens.next() {
+		Some(TokenTree::Ident(ident)) => Some(ident),
+		_ => unreachable!(),
+	}
+}
+fn parse_group(tokens: &mut vec::IntoIter<TokenTree>, delim: Delimiter) -> Option<Group> {
+	if !is_group(tokens.as_slice(), delim) {
+		return None;
+	}
+	match tokens.next() {
+		Some(TokenTree::Group(group)) => Some(group),
+		_ => unreachable!(),
+	}
+}
+fn parse_comma(tokens: &mut vec::IntoIter<TokenTree>) -> Option<()> {
+	if is_end(tokens.as_slice()) {
+		Some(())
+	}
+	else if is_punct(tokens.as_slice(), ',') {
+		let _ = tokens.next();
+		Some(())
+	}
+	else {
+		None
+	}
+}
+fn parse_end(tokens: &mut vec::IntoIter<TokenTree>) -> Option<()> {
+	if tokens.len() != 0 {
+		return None;
+	}
+	Some(())
+}
+// $ident $group
+fn parse_meta(tokens: &mut vec::IntoIter<TokenTree>) -> Option<Meta> {
+	let slice = tokens.as_slice();
+	if !(is_ident(&slice[0..]) && is_group(&slice[1..], Delimiter::Parenthesis)) {
+		return None;
+	}
+	let ident = match tokens.next() {
+		Some(TokenTree::Ident(ident)) => ident,
+		_ => unreachable!(),
+	};
+	let args = match tokens.next() {
+		Some(TokenTree::Group(group)) => group,
+		_ => unreachable!(),
+	};
+	Some(Meta { ident, args })
+}
+// $ident = $expr ,
+fn parse_kv(tokens: &mut vec::IntoIter<TokenTree>) -> Option<KeyValue> {
+	let slice = tokens.as_slice();
+	if !(is_ident(&slice[0..]) && is_punct(&slice[1..], '=')) {
+		return None;
+	}
+	let ident = match tokens.next() {
+		Some(TokenTree::Ident(ident)) => ident,
+		_ => unreachable!(),
+	};
+	let punct = match tokens.next() {
+		Some(TokenTree::Punct(punct)) => punct,
+		_ => unreachable!(),
+	};
+	let value = parse_expr(tokens);
+	Some(KeyValue { ident, punct, value })
+}
+// # $group
+fn parse_attr(tokens: &mut vec::IntoIter<TokenTree>) -> Option<Attribute> {
+	if !is_punct(tokens.as_slice(), '#') {
+		return None;
+	}
+	let punct = match tokens.next() {
+		Some(TokenTree::Punct(punct)) => punct,
+		_ => unreachable!(),
+	};
+	let meta = match tokens.next() {
+		Some(TokenTree::Group(group)) => group,
+		_ => unreachable!(),
+	};
+	Some(Attribute { punct, meta })
+}
+// $(# $group)*
+fn parse_attrs(tokens: &mut vec::IntoIter<TokenTree>) -> Vec<Attribute> {
+	let mut attrs = Vec::new();
+	while let Some(attr) = parse_attr(tokens) {
+		attrs.push(attr);
+	}
+	attrs
+}
+// $(pub $($group)?)?
+fn parse_vis(tokens: &mut vec::IntoIter<TokenTree>) -> Vis {
+	let mut vis = Vec::new();
+	if is_keyword(tokens.as_slice(), "pub") {
+		vis.push(tokens.next().unwrap());
+		if is_group(tokens.as_slice(), Delimiter::Parenthesis) {
+			vis.push(tokens.next().unwrap());
+		}
+	}
+	Vis(vis)
+}
+// $($tt)*,
+fn parse_ty(tokens: &mut vec::IntoIter<TokenTree>) -> Type {
+	let mut ty = Vec::new();
+	let mut depth = 0;
+	loop {
+		match tokens.next() {
+			Some(TokenTree::Punct(punct)) => {
+				match punct.as_char() {
+					',' | ';' if depth == 0 => break,
+					'<' => depth += 1,
+					'>' if !is_arrow(ty.last()) => depth -= 1,
+					_ => (),
+				}
+				ty.push(TokenTree::Punct(punct));
+			},
+			Some(tt) => ty.push(tt),
+			None => break,
+		}
+	}
+	Type(ty)
+}
+// The `>` of the `->` of a function pointer type does not close angle brackets
+fn is_arrow(prev: Option<&TokenTree>) -> bool {
+	matches!(prev, Some(TokenTree::Punct(punct)) if punct.as_char() == '-' && punct.spacing() == Spacing::Joint)
+}
+// $($tt)*,
+fn parse_expr(tokens: &mut vec::IntoIter<TokenTree>) -> Expr {
+	let mut expr = Vec::new();
+	loop {
+		match tokens.next() {
+			Some(TokenTree::Punct(punct)) => {
+				if let ',' | ';' = punct.as_char() {
+					break;
+				}
+				expr.push(TokenTree::Punct(punct));
+			},
+			Some(tt) => expr.push(tt),
+			None => break,
+		}
+	}
+	Expr(expr.into_iter().collect())
+}
+
+//----------------------------------------------------------------
+// Parse struct layout attribute
+
+fn parse_explicit_layout(tokens: TokenStream) -> ExplicitLayout {
+	let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+	let mut tokens = tokens.into_iter();
+	let mut errors = Vec::new();
+	// An invalid size or alignment is reported and replaced to continue with the other arguments
+	let mut required = |tokens: &mut vec::IntoIter<TokenTree>, name: &str, fallback: &str| {
+		let span = next_span(tokens.as_slice());
+		let len = tokens.len();
+		match parse_layout_required(tokens, name, span) {
+			Ok(value) => value,
+			Err(err) => {
+				emit_error(&mut errors, err);
+				if tokens.len() == len {
+					skip_argument(tokens);
+				}
+				(Expr(fallback.parse().unwrap()), Vec::new())
+			},
+		}
+	};
+	let (size, size_arms) = required(&mut tokens, "size", "0");
+	let align_span = next_span(tokens.as_slice());
+	let (align, align_arms) = required(&mut tokens, "align", "1");
+	let mut layout = ExplicitLayout { size, align, size_arms, align_arms, check: None, base: None, copy_from: None, cast_to: Vec::new(), mirror: None, impl_trait: None, mockable: None, slice_ext: false, kind: LayoutKind::Explicit, packed: false, modify: false, offsets: None, dataview: false, impl_check: false, arbitrary_fields: false, remote: false, handles: false, visit: false, compact: false, grouped: false, escape_hatch: false, field_enum: false, tracked: false, parts: false, init: false, validate_all: false, layout_hash: false, apply_patches: false, auto_ref: None, bytes: false, byte_order: false, random: false, export_c: None, wasm_bindgen: None, keep_fields: false, storage: None, consts_mod: None, companion_vis: None, doc_cfg: None, method_prefix: String::new(), method_suffix: String::new(), ffi_twin: None, errors, display_field: false, debug_annotated: false, coverage: false, const_fn: false, repr_manual: false, export_json: false, freeze: false, transparent: false, dump: false, doc_layout: false, offset_unit: None, huge: false, on_error: None, strict_validate: false, strict: false, arguments: Vec::new(), send: true, sync: true, portable: true };
+	// Errors of an argument are reported at it and parsing continues with the next argument
+	while tokens.len() > 0 {
+		let span = tokens.as_slice()[0].span();
+		if let TokenTree::Ident(ident) = &tokens.as_slice()[0] {
+			layout.arguments.push(ident.clone());
+		}
+		if let Err(err) = parse_layout_option(&mut tokens, &mut layout, span) {
+			emit_error(&mut layout.errors, err);
+			skip_argument(&mut tokens);
+		}
+	}
+	// Offsets and the size may be constant expressions but `repr(align(..))` only takes an integer literal
+	let literal_align = match layout.align_arms.is_empty() {
+		true => int_literal_value(&layout.align).is_some(),
+		false => layout.align_arms.iter().all(|(_, align)| int_literal(align).is_some()),
+	};
+	if !literal_align && !layout.repr_manual {
+		let message = "parse struct_layout: the align argument must be an integer literal, `repr(align(..))` does not accept constant expressions";
+		emit_error(&mut layout.errors, (align_span, message.to_string()));
+	}
+	layout
+}
+// The tokens of an invalid argument up to its trailing comma
+fn skip_argument(tokens: &mut vec::IntoIter<TokenTree>) {
+	for tt in tokens.by_ref() {
+		if let TokenTree::Punct(punct) = tt {
+			if punct.as_char() == ',' {
+				break;
+			}
+		}
+	}
+}
+// Errors of the structure caused by a layout argument are reported at the argument
+fn argument_span(layout: &ExplicitLayout, name: &str) -> Span {
+	layout.arguments.iter().find(|arg| arg.to_string() == name).map_or_else(Span::call_site, Ident::span)
+}
+// size = $expr or size(cfg($pred) = $expr, ..)
+fn parse_layout_required(tokens: &mut vec::IntoIter<TokenTree>, name: &str, span: Span) -> ParseResult<(Expr, CfgArms)> {
+	if let Some(arms) = parse_layout_arms(tokens, name, span)? {
+		return Ok(arms);
+	}
+	match parse_kv(tokens) {
+		Some(kv) if kv.ident.to_string() == name => Ok((kv.value, Vec::new())),
+		_ => bail!(span, "parse struct_layout: invalid format for {0} argument, expecting `{0} = <usize>`", name),
+	}
+}
+// size(cfg($pred) = $expr, ..) selects the value for the target
+fn parse_layout_arms(tokens: &mut vec::IntoIter<TokenTree>, name: &str, span: Span) -> ParseResult<Option<(Expr, CfgArms)>> {
+	if !(is_keyword(tokens.as_slice(), name) && is_group(&tokens.as_slice()[1..], Delimiter::Parenthesis)) {
+		return Ok(None);
+	}
+	let _ = parse_ident(tokens);
+	let group = parse_group(tokens, Delimiter::Parenthesis).unwrap();
+	if parse_comma(tokens).is_none() {
+		bail!(span, "parse struct_layout: expecting comma after {}", name);
+	}
+	let arms = match parse_cfg_arms(group) {
+		Some(arms) => arms,
+		None => bail!(span, "parse struct_layout: invalid format for {0} argument, expecting `{0}(cfg(<predicate>) = <usize>, ..)`", name),
+	};
+	// The value of the first matching arm, the missing arm is reported by a compile error
+	let fallback = if name == "size" { "0" } else { "1" };
+	Ok(Some((cfg_arms_select(&arms, fallback), arms)))
+}
+// cfg($pred) = $expr, ..
+fn parse_cfg_arms(group: Group) -> Option<CfgArms> {
+	let args: Vec<TokenTree> = group.stream().into_iter().collect();
+	let mut args = args.into_iter();
+	let mut arms = Vec::new();
+	while args.len() > 0 {
+		let pred = match parse_meta(&mut args) {
+			Some(meta) if meta.ident.to_string() == "cfg" && parse_punct(&mut args, '=').is_some() => meta.args.stream().to_string(),
+			_ => return None,
+		};
+		let value = parse_expr(&mut args);
+		if value.0.is_empty() {
+			return None;
+		}
+		arms.push((pred, value.0.to_string()));
+	}
+	if arms.is_empty() {
+		return None;
+	}
+	Some(arms)
+}
+// The value of the first matching arm
+fn cfg_arms_select(arms: &[(String, String)], fallback: &str) -> Expr {
+	let mut select = String::from("{ ");
+	for (pred, value) in arms {
+		select.push_str(&format!("if cfg!({}) {{ {} }} else ", pred, value));
+	}
+	select.push_str(&format!("{{ {} }} }}", fallback));
+	Expr(select.parse().unwrap())
+}
+// Optional arguments following the size and alignment
+fn parse_layout_option(tokens: &mut vec::IntoIter<TokenTree>, layout: &mut ExplicitLayout, span: Span) -> ParseResult<()> {
+	if let Some(kv) = parse_kv(tokens) {
+		// Parsing the value also consumes the trailing comma
+		let name = kv.ident.to_string();
+		match &*name {
+			"offsets" => {
+				let value: Vec<TokenTree> = kv.value.0.into_iter().collect();
+				match &*value {
+					[TokenTree::Literal(lit)] if lit.to_string().starts_with('"') => {
+						layout.offsets = Some(lit.to_string().trim_matches('"').to_string());
+					},
+					_ => bail!(span, "parse struct_layout: invalid format for offsets argument, expecting `offsets = \"<path>\"`"),
+				}
+			},
+			"method_prefix" | "method_suffix" => {
+				let value: Vec<TokenTree> = kv.value.0.into_iter().collect();
+				let affix = match &*value {
+					[TokenTree::Literal(lit)] => string_literal_value(&lit.to_string()),
+					_ => None,
+				};
+				match affix {
+					Some(affix) if is_accessor_affix(&affix, name == "method_prefix") => {
+						if name == "method_prefix" { layout.method_prefix = affix } else { layout.method_suffix = affix }
+					},
+					_ => bail!(span, "parse struct_layout: invalid format for {0} argument, expecting `{0} = \"<identifier characters>\"`", name),
+				}
+			},
+			"send" | "sync" | "portable" => {
+				let value = match &*kv.value.0.to_string() {
+					"true" => true,
+					"false" => false,
+					_ => bail!(span, "parse struct_layout: invalid format for {0} argument, expecting `{0} = true` or `{0} = false`", name),
+				};
+				match &*name {
+					"send" => layout.send = value,
+					"sync" => layout.sync = value,
+					_ => layout.portable = value,
+				}
+			},
+			"on_error" => {
+				layout.on_error = Some(match &*kv.value.0.to_string() {
+					"panic" => OnError::Panic,
+					"option" => OnError::Option,
+					"result" => OnError::Result,
+					_ => bail!(span, "parse struct_layout: invalid format for on_error argument, expecting `on_error = panic`, `on_error = option` or `on_error = result`"),
+				});
+			},
+			"repr" => {
+				layout.repr_manual = match &*kv.value.0.to_string() {
+					"manual" => true,
+					_ => bail!(span, "parse struct_layout: invalid format for repr argument, expecting `repr = manual`"),
+				};
+			},
+			"debug" => {
+				layout.debug_annotated = match &*kv.value.0.to_string() {
+					"annotated" => true,
+					_ => bail!(span, "parse struct_layout: invalid format for debug argument, expecting `debug = annotated`"),
+				};
+			},
+			"offset_unit" => {
+				if kv.value.0.is_empty() {
+					bail!(span, "parse struct_layout: invalid format for offset_unit argument, expecting `offset_unit = <usize>`");
+				}
+				layout.offset_unit = Some(kv.value);
+			},
+			"consts_mod" => {
+				let value: Vec<TokenTree> = kv.value.0.into_iter().collect();
+				match &*value {
+					[TokenTree::Ident(ident)] => layout.consts_mod = Some(ident.clone()),
+					_ => bail!(span, "parse struct_layout: invalid format for consts_mod argument, expecting `consts_mod = <name>`"),
+				}
+			},
+			"ffi_twin" => {
+				let value: Vec<TokenTree> = kv.value.0.into_iter().collect();
+				match &*value {
+					[TokenTree::Ident(ident)] => layout.ffi_twin = Some(ident.clone()),
+					_ => bail!(span, "parse struct_layout: invalid format for ffi_twin argument, expecting `ffi_twin = <name>`"),
+				}
+			},
+			"companion_vis" => {
+				let mut value = kv.value.0.into_iter().collect::<Vec<_>>().into_iter();
+				let vis = parse_vis(&mut value);
+				if vis.0.is_empty() || value.len() != 0 {
+					bail!(span, "parse struct_layout: invalid format for companion_vis argument, expecting `companion_vis = pub` or `companion_vis = pub(<path>)`");
+				}
+				layout.companion_vis = Some(vis);
+			},
+			"storage" => {
+				let value = kv.value.0.to_string();
+				layout.storage = Some(match &*value {
+					// Atomics permit mutation through shared references, eg. by the atomic_ordered accessors
+					"AtomicU8" | "AtomicU16" | "AtomicU32" | "AtomicU64" => format!("::core::sync::atomic::{}", value),
+					_ if is_int(&value) && value != "usize" && value != "isize" => value,
+					_ => bail!(span, "parse struct_layout: invalid format for storage argument, expecting `storage = <integer type>` or `storage = <atomic integer type>` of a fixed size"),
+				});
+			},
+			"arbitrary" => {
+				layout.arbitrary_fields = match &*kv.value.0.to_string() {
+					"bytes" => false,
+					"fields" => true,
+					_ => bail!(span, "parse struct_layout: invalid format for arbitrary argument, expecting `arbitrary = bytes` or `arbitrary = fields`"),
+				};
+			},
+			_ => return Err(unknown_layout_argument(span, &name)),
+		}
+		return Ok(());
+	}
+	if is_ident(tokens.as_slice()) && !is_group(&tokens.as_slice()[1..], Delimiter::Parenthesis) {
+		let ident = parse_ident(tokens).unwrap();
+		let name = ident.to_string();
+		if parse_comma(tokens).is_none() {
+			bail!(span, "parse struct_layout: expecting comma after {}", name);
+		}
+		match &*name {
+			"mirror" => layout.mirror = Some(Mirror::Plain),
+			"slice_ext" => layout.slice_ext = true,
+			"modify" => layout.modify = true,
+			"packed" => layout.packed = true,
+			"dataview" => layout.dataview = true,
+			"remote" => layout.remote = true,
+			"handles" => layout.handles = true,
+			"visit" => layout.visit = true,
+			"display_field" => layout.display_field = true,
+			"compact" => layout.compact = true,
+			"grouped" => layout.grouped = true,
+			"escape_hatch" => layout.escape_hatch = true,
+			"parts" => layout.parts = true,
+			"init" => layout.init = true,
+			"validate_all" => layout.validate_all = true,
+			"layout_hash" => layout.layout_hash = true,
+			"apply_patches" => layout.apply_patches = true,
+			"bytes" => layout.bytes = true,
+			"byte_order" => layout.byte_order = true,
+			"keep_fields" => layout.keep_fields = true,
+			"strict_validate" => layout.strict_validate = true,
+			"strict" => layout.strict = true,
+			"coverage" => layout.coverage = true,
+			"export_json" => layout.export_json = true,
+			"transparent" => layout.transparent = true,
+			"dump" => layout.dump = true,
+			"doc_layout" => layout.doc_layout = true,
+			"huge" => layout.huge = true,
+			"freeze" => layout.freeze = true,
+			"const_fn" => layout.const_fn = true,
+			"random" => {
+				if !cfg!(feature = "rand") {
+					bail!(span, "parse struct_layout: the random argument requires the `rand` feature");
+				}
+				layout.random = true;
+			},
+			"wasm_bindgen" => layout.wasm_bindgen = Some(parse_layout_wasm_bindgen(None, span)?),
+			"field_enum" => layout.field_enum = true,
+			// The dirty fields are reported with the field enum
+			"tracked" => {
+				layout.field_enum = true;
+				layout.tracked = true;
+			},
+			_ => return Err(unknown_layout_argument(span, &name)),
+		}
+		return Ok(());
+	}
+	let meta = match parse_meta(tokens) {
+		Some(meta) => meta,
+		None => bail!(span, "parse struct_layout: unexpected additional tokens found"),
+	};
+	let name = meta.ident.to_string();
+	if parse_comma(tokens).is_none() {
+		bail!(span, "parse struct_layout: expecting comma after {}", name);
+	}
+	match &*name {
+		"check" => {
+			let mut args: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+			// check(Trait, impl) also implements the trait for the structure
+			if args.len() >= 3 && is_keyword(&args[args.len() - 1..], "impl") && is_punct(&args[args.len() - 2..], ',') {
+				args.truncate(args.len() - 2);
+				layout.impl_check = true;
+			}
+			let check = args.into_iter().collect::<TokenStream>().to_string();
+			if layout.impl_check && check.contains('+') {
+				bail!(span, "parse struct_layout: invalid format for check argument, expecting a single trait with `check(PodTrait, impl)`");
+			}
+			layout.check = Some(check);
+		},
+		"base" => {
+			let args: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+			if args.is_empty() {
+				bail!(span, "parse struct_layout: invalid format for base argument, expecting `base(<type>)`");
+			}
+			layout.base = Some(Type(args));
+		},
+		"copy_from" => layout.copy_from = Some(parse_layout_copy_from(meta.args, span)?),
+		"cast_to" => {
+			let args: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+			match args.last() {
+				Some(TokenTree::Ident(_)) => layout.cast_to.push(Type(args)),
+				_ => bail!(span, "parse struct_layout: invalid format for cast_to argument, expecting `cast_to(<type>)`"),
+			}
+		},
+		"export_c" => {
+			let args: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+			let mut args = args.into_iter();
+			let value: Vec<TokenTree> = match parse_kv(&mut args) {
+				Some(kv) if kv.ident.to_string() == "prefix" && args.len() == 0 => kv.value.0.into_iter().collect(),
+				_ => Vec::new(),
+			};
+			layout.export_c = match &*value {
+				[TokenTree::Literal(lit)] if lit.to_string().starts_with('"') => Some(lit.to_string().trim_matches('"').to_string()),
+				_ => bail!(span, "parse struct_layout: invalid format for export_c argument, expecting `export_c(prefix = \"<prefix>\")`"),
+			};
+		},
+		"wasm_bindgen" => layout.wasm_bindgen = Some(parse_layout_wasm_bindgen(Some(meta.args), span)?),
+		"impl_trait" | "define_trait" => {
+			let args: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+			let ident = match &*args {
+				[TokenTree::Ident(ident)] => ident.clone(),
+				_ => bail!(span, "parse struct_layout: invalid format for {0} argument, expecting `{0}(<trait>)`", name),
+			};
+			layout.impl_trait = Some((ident, name == "define_trait"));
+		},
+		"mockable" => {
+			let args: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+			layout.mockable = match &*args {
+				[TokenTree::Ident(ident)] => Some(ident.clone()),
+				_ => bail!(span, "parse struct_layout: invalid format for mockable argument, expecting `mockable(<trait>)`"),
+			};
+		},
+		"doc_cfg" => {
+			let pred = meta.args.stream().to_string();
+			if pred.is_empty() {
+				bail!(span, "parse struct_layout: invalid format for doc_cfg argument, expecting `doc_cfg(<predicate>)`");
+			}
+			layout.doc_cfg = Some(pred);
+		},
+		"auto_ref" => {
+			let args: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+			let mut args = args.into_iter();
+			layout.auto_ref = match parse_kv(&mut args) {
+				Some(kv) if kv.ident.to_string() == "threshold" && args.len() == 0 => int_literal_value(&kv.value),
+				_ => None,
+			};
+			if layout.auto_ref.is_none() {
+				bail!(span, "parse struct_layout: invalid format for auto_ref argument, expecting `auto_ref(threshold = <integer literal>)`");
+			}
+		},
+		"mirror" => {
+			let args: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+			let mut args = args.into_iter();
+			layout.mirror = match parse_meta(&mut args) {
+				Some(meta) if meta.ident.to_string() == "derive" && args.len() == 0 => Some(Mirror::DeriveList(meta.args.stream())),
+				_ if args.as_slice().len() == 1 && is_keyword(args.as_slice(), "derive") => Some(Mirror::Derive),
+				_ => bail!(span, "parse struct_layout: invalid format for mirror argument, expecting `mirror`, `mirror(derive)` or `mirror(derive(..))`"),
+			};
+		},
+		_ => return Err(unknown_layout_argument(span, &name)),
+	}
+	Ok(())
+}
+// The optional arguments of the layout attributes in any of their forms
+const LAYOUT_ARGUMENTS: &[&str] = &[
+	"offsets", "offset_unit", "send", "sync", "portable", "on_error", "repr", "debug", "consts_mod", "companion_vis", "storage", "arbitrary", "method_prefix", "method_suffix", "ffi_twin",
+	"mirror", "slice_ext", "modify", "packed", "dataview", "remote", "handles", "visit", "display_field", "compact", "grouped", "escape_hatch",
+	"parts", "byte_order", "keep_fields", "strict_validate", "strict", "coverage", "export_json", "freeze", "transparent", "dump", "doc_layout", "huge", "const_fn", "random", "wasm_bindgen", "field_enum", "tracked", "init", "validate_all", "layout_hash", "apply_patches", "auto_ref", "bytes",
+	"check", "base", "copy_from", "cast_to", "export_c", "impl_trait", "define_trait", "mockable", "doc_cfg",
+];
+fn unknown_layout_argument(span: Span, name: &str) -> (Span, String) {
+	if LAYOUT_ARGUMENTS.contains(&name) {
+		return (span, format!("parse struct_layout: invalid format for {} argument", name));
+	}
+	let expecting = LAYOUT_ARGUMENTS.iter().map(|arg| format!("`{}`", arg)).collect::<Vec<_>>().join(", ");
+	match suggest_name(name, LAYOUT_ARGUMENTS) {
+		Some(suggestion) => (span, format!("parse struct_layout: unknown argument `{}`, did you mean `{}`? expecting one of {}", name, suggestion, expecting)),
+		None => (span, format!("parse struct_layout: unknown argument `{}`, expecting one of {}", name, expecting)),
+	}
+}
+// The arguments of the field attribute after the offset
+const FIELD_ARGUMENTS: &[&str] = &[
+	"get", "set", "ref", "mut", "modify", "copy_into", "copy_from", "zero", "bytes_array", "column", "atomic_ordered", "endian", "unaligned_ref",
+	"shiftable", "slice", "str", "cstr", "wstr", "pstr", "dims", "col_major", "count", "stride", "tail", "len", "pad", "nested", "flatten",
+	"pad_before", "align_to", "checksum", "allow_drop", "manually_drop", "size", "align", "opaque", "as", "validate", "clamp", "clamped", "stubs",
+	"stored", "narrow", "sentinel", "bits", "debug", "debug_tag", "debug_when", "display", "seq", "old_offset", "unit", "check_cfg", "convert", "enum",
+	"strict", "assume_valid", "overlaps",
+];
+fn unknown_field_argument(span: Span, name: &str) -> (Span, String) {
+	let expecting = FIELD_ARGUMENTS.iter().map(|arg| format!("`{}`", arg)).collect::<Vec<_>>().join(", ");
+	match suggest_name(name, FIELD_ARGUMENTS) {
+		Some(suggestion) => (span, format!("parse field_layout: unknown argument `{}`, did you mean `{}`? expecting one of {}", name, suggestion, expecting)),
+		None => (span, format!("parse field_layout: unknown argument `{}`, expecting one of {}", name, expecting)),
+	}
+}
+// The closest candidate within a third of the length of the name
+fn suggest_name<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+	let max = usize::max(name.len(), 3) / 3;
+	candidates.iter()
+		.map(|&candidate| (edit_distance(name, candidate), candidate))
+		.filter(|&(distance, _)| distance <= max)
+		.min_by_key(|&(distance, _)| distance)
+		.map(|(_, candidate)| candidate)
+}
+// Insertions, deletions, substitutions and transpositions of adjacent characters
+fn edit_distance(a: &str, b: &str) -> usize {
+	let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+	let mut rows = vec![(0..=b.len()).collect::<Vec<usize>>()];
+	for i in 1..=a.len() {
+		let mut row = vec![i; b.len() + 1];
+		for j in 1..=b.len() {
+			let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+			row[j] = usize::min(usize::min(rows[i - 1][j] + 1, row[j - 1] + 1), rows[i - 1][j - 1] + cost);
+			if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+				row[j] = usize::min(row[j], rows[i - 2][j - 2] + 1);
+			}
+		}
+		rows.push(row);
+	}
+	rows[a.len()][b.len()]
+}
+// wasm_bindgen or wasm_bindgen(skip)
+fn parse_layout_wasm_bindgen(args: Option<Group>, span: Span) -> ParseResult<bool> {
+	if !cfg!(feature = "wasm_bindgen") {
+		bail!(span, "parse struct_layout: the wasm_bindgen argument requires the `wasm_bindgen` feature");
+	}
+	match args.map(|args| args.stream().to_string()).as_deref() {
+		None => Ok(false),
+		Some("skip") => Ok(true),
+		_ => bail!(span, "parse struct_layout: invalid format for wasm_bindgen argument, expecting `wasm_bindgen` or `wasm_bindgen(skip)`"),
+	}
+}
+// copy_from($ty) or copy_from($ty { $($field),* })
+fn parse_layout_copy_from(args: Group, span: Span) -> ParseResult<(Type, Option<Vec<Ident>>)> {
+	let mut ty: Vec<TokenTree> = args.stream().into_iter().collect();
+	let names = match ty.last() {
+		Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => {
+			let mut names = Vec::new();
+			let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+			let mut tokens = tokens.into_iter();
+			while tokens.len() > 0 {
+				match parse_ident(&mut tokens) {
+					Some(ident) => names.push(ident),
+					None => bail!(span, "parse struct_layout: invalid format for copy_from argument, expecting a comma separated list of field names in braces"),
+				}
+				if parse_comma(&mut tokens).is_none() {
+					bail!(span, "parse struct_layout: invalid format for copy_from argument, expecting a comma separated list of field names in braces");
+				}
+			}
+			ty.pop();
+			Some(names)
+		},
+		_ => None,
+	};
+	if ty.is_empty() {
+		bail!(span, "parse struct_layout: invalid format for copy_from argument, expecting `copy_from(<type>)`");
+	}
+	Ok((Type(ty), names))
+}
+
+//----------------------------------------------------------------
+// Parse struct fields
+
+// Errors of a field are reported at its name and parsing continues with the next field
+fn parse_fields(tokens: TokenStream, kind: LayoutKind, errors: &mut Vec<TokenTree>) -> Vec<Field> {
+	let mut fields = Vec::new();
+	for chunk in split_fields(tokens) {
+		let span = field_name_span(&chunk);
+		// Errors of the field attribute are reported at the offending argument
+		match parse_field(&mut chunk.into_iter(), kind, span) {
+			Ok(field) => fields.push(field),
+			Err(err) => emit_error(errors, err),
+		}
+	}
+	fields
+}
+// The tokens of every field up to its trailing comma, commas in generic arguments of the type are skipped
+fn split_fields(tokens: TokenStream) -> Vec<Vec<TokenTree>> {
+	let mut chunks = Vec::new();
+	let mut chunk = Vec::new();
+	let mut depth = 0;
+	for tt in tokens {
+		if let TokenTree::Punct(punct) = &tt {
+			match punct.as_char() {
+				',' if depth == 0 => {
+					chunk.push(tt);
+					chunks.push(std::mem::take(&mut chunk));
+					continue;
+				},
+				'<' => depth += 1,
+				'>' if !is_arrow(chunk.last()) => depth -= 1,
+				_ => (),
+			}
+		}
+		chunk.push(tt);
+	}
+	if !chunk.is_empty() {
+		chunks.push(chunk);
+	}
+	chunks
+}
+// The name of a field is the identifier before its colon
+fn field_name_span(tokens: &[TokenTree]) -> Span {
+	for i in 0..tokens.len() {
+		if let (TokenTree::Ident(ident), true, false) = (&tokens[i], is_punct(&tokens[i + 1..], ':'), is_punct(tokens.get(i + 2..).unwrap_or(&[]), ':')) {
+			return ident.span();
+		}
+	}
+	tokens.first().map(|tt| tt.span()).unwrap_or_else(Span::call_site)
+}
+fn parse_field(tokens: &mut vec::IntoIter<TokenTree>, kind: LayoutKind, span: Span) -> ParseResult<Field> {
+	let mut attrs = parse_attrs(tokens);
+	let groups = parse_field_groups(&mut attrs, span)?;
+	let layout = match parse_field_attrs(&mut attrs, kind, span)? {
+		Some(layout) => layout,
+		None if kind != LayoutKind::Explicit => parse_field_layout(&mut Vec::new().into_iter(), kind, span)?,
+		None => bail!(span, "parse field: every field must have a `#[field(..)]` attribute"),
+	};
+	let vis = parse_vis(tokens);
+	let name = match parse_ident(tokens) {
+		Some(ident) => ident,
+		None => bail!(span, "parse field: expecting field identifier not found"),
+	};
+	if parse_punct(tokens, ':').is_none() {
+		bail!(span, "parse field: colon must follow field identifier");
+	}
+	let ty = parse_ty(tokens);
+	check_field_ty(&name, &ty)?;
+	let mut layout = layout;
+	layout.groups = groups;
+	// The inline structure is named after parsing the fields
+	match &*ty.0 {
+		[TokenTree::Ident(ident), TokenTree::Punct(punct), TokenTree::Group(group)] if ident.to_string() == "inline" && punct.as_char() == '!' && group.delimiter() == Delimiter::Brace => {
+			layout.inline = Some(group.clone());
+			layout.nested = true;
+		},
+		_ if layout.inline_name.is_some() => bail!(span, "parse field: the as argument of field `{}` requires an inline structure `inline! {{ .. }}`", name),
+		_ => (),
+	}
+	if layout.clamp.is_some() && !is_int(&ty.to_string()) && !is_float(&ty.to_string()) {
+		bail!(span, "parse field: the clamp argument of field `{}` requires an integer or float type", name);
+	}
+	if layout.manually_drop.is_some() {
+		layout.manually_drop = Some(Type(format!("::core::mem::ManuallyDrop<{}>", ty).parse::<TokenStream>().unwrap().into_iter().collect()));
+	}
+	let mut ty = ty;
+	if let Some(narrow) = &layout.narrow {
+		// The field is declared with its stored type and exposed as the narrow type
+		let stored = layout.stored.as_mut().unwrap();
+		if stored.0.is_empty() {
+			*stored = ty;
+		}
+		if !is_int(&stored.to_string()) || !is_int(&narrow.to_string()) {
+			bail!(span, "parse field: the narrow argument of field `{}` requires integer types", name);
+		}
+		ty = narrow.clone();
+	}
+	if let Some((start, end)) = layout.bits {
+		// Single bits are usually flags in a byte
+		let stored = layout.stored.as_mut().unwrap();
+		if stored.0.is_empty() {
+			*stored = if ty.to_string() == "bool" { Type("u8".parse::<TokenStream>().unwrap().into_iter().collect()) } else { ty.clone() };
+		}
+		if !is_int(&stored.to_string()) || !(is_int(&ty.to_string()) || ty.to_string() == "bool") {
+			bail!(span, "parse field: the bits argument of field `{}` requires an integer or bool field stored in an integer, found `{}` stored in `{}`", name, ty, stored);
+		}
+		if ty.to_string() == "bool" && end - start != 1 {
+			bail!(span, "parse field: the bits argument of bool field `{}` must be a single bit, found `{}..{}`", name, start, end);
+		}
+	}
+	if layout.sentinel.is_some() {
+		layout.stored = Some(ty.clone());
+		ty = Type(format!("::core::option::Option<{}>", ty).parse::<TokenStream>().unwrap().into_iter().collect());
+	}
+	check_field_validity(&name, &ty, &mut layout)?;
+	if layout.debug_hex && !is_byte_array(&ty) {
+		bail!(span, "parse field: the debug = hex_bytes argument of field `{}` requires a byte array", name);
+	}
+	if layout.method_atomic && atomic_ty(&ty).is_none() {
+		bail!(span, "parse field: the atomic_ordered accessors of field `{}` require an integer type of at most 64 bits or bool, found `{}`", name, ty);
+	}
+	if layout.method_endian && !is_int(&ty.to_string()) {
+		bail!(span, "parse field: the endian = both accessors of field `{}` require an integer type, found `{}`", name, ty);
+	}
+	if layout.method_bytes_array && layout.size.is_none() && primitive_size(layout.stored.as_ref().unwrap_or(&ty)).is_none() {
+		bail!(span, "parse field: the bytes_array accessors of field `{}` require a primitive type or its size `size = <usize>`", name);
+	}
+	Ok(Field { attrs, layout, vis, accessor: name.clone(), name, ty })
+}
+// Offset relative to the offset or the end of an earlier field, references to other fields are reported at the name
+fn field_relative_offset(name: &Ident, fields: &[Field], by_name: &HashMap<String, usize>, errors: &mut Vec<TokenTree>, i: usize, ident: &Ident, after: bool) -> String {
+	let mut error = |message: String| {
+		emit_text_spanned(errors, &format!("::core::compile_error!({:?});", message), ident.span());
+		String::from("0")
+	};
+	let target = match by_name.get(&ident.to_string()) {
+		Some(&target) if target < i => &fields[target],
+		Some(_) => return error(format!("struct_layout: the offset of field `{}` refers to field `{}` which is not declared before it", fields[i].name, ident)),
+		None => return error(format!("struct_layout: the offset of field `{}` refers to field `{}` which is not found", fields[i].name, ident)),
+	};
+	if target.layout.dynamic || target.layout.optional.is_some() || (after && (target.layout.variant.is_some() || target.layout.tail.is_some())) {
+		return error(format!("struct_layout: the offset of field `{}` refers to field `{}` whose {} is not known", fields[i].name, ident, if after { "extent" } else { "offset" }));
+	}
+	match after {
+		true => format!("{}::{} + {}", name, field_offset_const(target), field_extent(target).0),
+		false => format!("{}::{}", name, field_offset_const(target)),
+	}
+}
+// The bytes of a bool or char field may hold an invalid value, reading them as the field type is undefined behavior
+fn check_field_validity(name: &Ident, ty: &Type, layout: &mut FieldLayout) -> ParseResult<()> {
+	let repr = match &*ty.to_string() {
+		"bool" => "u8",
+		"char" => "u32",
+		_ => {
+			if layout.strict || layout.assume_valid {
+				bail!(name.span(), "parse field: the strict and assume_valid arguments of field `{}` require a bool or char type", name);
+			}
+			return Ok(());
+		},
+	};
+	if layout.assume_valid {
+		return Ok(());
+	}
+	let plain = layout.stored.is_none() && layout.manually_drop.is_none() && is_single_value_layout(layout)
+		&& !layout.pad && !layout.nested && !layout.dynamic && !layout.allow_drop && layout.optional.is_none();
+	if !plain {
+		if layout.strict {
+			bail!(name.span(), "parse field: the strict argument of field `{}` is only supported on bool and char fields of a single value", name);
+		}
+		return Ok(());
+	}
+	if layout.method_atomic || layout.method_unaligned_ref || layout.method_copy_into || layout.method_copy_from || (!layout.default_methods && (layout.method_ref || layout.method_mut)) {
+		bail!(name.span(), "parse field: the `ref`, `mut`, `unaligned_ref`, `copy_into`, `copy_from` and `atomic_ordered` accessors of field `{}` can observe invalid values of type `{}`, acknowledge this with the assume_valid argument", name, ty);
+	}
+	// Only the accessors by value are enabled by default
+	layout.method_ref = false;
+	layout.method_mut = false;
+	layout.gated.retain(|&(method, _)| method == "get" || method == "set");
+	layout.stored = Some(Type(repr.parse::<TokenStream>().unwrap().into_iter().collect()));
+	layout.convert = Some(if layout.strict { Convert::Strict } else { Convert::Valid });
+	Ok(())
+}
+// The bytes of the structure cannot hold borrowed values, references read from them may dangle
+fn check_field_ty(name: &Ident, ty: &Type) -> ParseResult<()> {
+	if is_punct(&ty.0, '&') {
+		bail!(name.span(), "parse field: field `{}` has reference type `{}`, references cannot be stored in an explicit layout as their lifetime cannot be tracked", name, ty);
+	}
+	for (i, tt) in ty.0.iter().enumerate() {
+		if let TokenTree::Punct(punct) = tt {
+			if punct.as_char() == '\'' && !is_keyword(&ty.0[i + 1..], "static") {
+				bail!(name.span(), "parse field: field `{}` has borrowed type `{}`, types with lifetimes cannot be stored in an explicit layout as their lifetime cannot be tracked", name, ty);
+			}
+		}
+	}
+	Ok(())
+}
+// The `#[group(<name>, ..)]` attributes of a field
+fn parse_field_groups(attrs: &mut Vec<Attribute>, span: Span) -> ParseResult<Vec<Ident>> {
+	let mut groups = Vec::new();
+	for attr in std::mem::take(attrs) {
+		let tokens: Vec<TokenTree> = attr.meta.stream().into_iter().collect();
+		if !is_keyword(&tokens, "group") {
+			attrs.push(attr);
+			continue;
+		}
+		let mut tokens = tokens.into_iter();
+		let meta = match parse_meta(&mut tokens) {
+			Some(meta) => meta,
+			None => bail!(span, "parse field: invalid group attribute syntax, expecting `#[group(<name>, ..)]`"),
+		};
+		if parse_end(&mut tokens).is_none() {
+			bail!(span, "parse field: found extra tokens after group attribute");
+		}
+		let tokens: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+		let mut tokens = tokens.into_iter();
+		while tokens.len() > 0 {
+			match parse_ident(&mut tokens) {
+				Some(ident) => groups.push(ident),
+				None => bail!(span, "parse field: expecting group name in group attribute"),
+			}
+			if parse_comma(&mut tokens).is_none() {
+				bail!(span, "parse field: expecting comma after group name");
+			}
+		}
+	}
+	Ok(groups)
+}
+fn parse_field_attrs(attrs: &mut Vec<Attribute>, kind: LayoutKind, span: Span) -> ParseResult<Option<FieldLayout>> {
+	let mut result = None;
+	for attr in std::mem::take(attrs) {
+		let tokens: Vec<TokenTree> = attr.meta.stream().into_iter().collect();
+		let mut tokens = tokens.into_iter();
+		match tokens.as_slice().first() {
+			Some(TokenTree::Ident(ident)) => {
+				match &*ident.to_string() {
+					"field" => {
+						let meta = match parse_meta(&mut tokens) {
+							Some(meta) => meta,
+							None => bail!(span, "parse field: invalid field attribute syntax, expecting `#[field(..)]`"),
+						};
+						if parse_end(&mut tokens).is_none() {
+							bail!(span, "parse field: found extra tokens after field attribute");
+						}
+						let tokens: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+						let mut tokens = tokens.into_iter();
+						result = Some(parse_field_layout(&mut tokens, kind, span)?);
+					},
+					"variant" => {
+						let meta = match parse_meta(&mut tokens) {
+							Some(meta) => meta,
+							None => bail!(span, "parse field: invalid variant attribute syntax, expecting `#[variant(<field> = <value>)]`"),
+						};
+						if parse_end(&mut tokens).is_none() {
+							bail!(span, "parse field: found extra tokens after variant attribute");
+						}
+						let tokens: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+						let mut tokens = tokens.into_iter();
+						result = Some(parse_field_variant(&mut tokens, span)?);
+					},
+					"doc" => attrs.push(attr),
+					s => bail!(ident.span(), "parse field: unsupported attribute `{}`", s),
+				}
+			},
+			Some(_) => bail!(span, "parse field: unknown attribute syntax"),
+			None => (),
+		}
+	}
+	Ok(result)
+}
+fn parse_field_layout(tokens: &mut vec::IntoIter<TokenTree>, kind: LayoutKind, span: Span) -> ParseResult<FieldLayout> {
+	// Errors of an argument are reported at it
+	let at = tokens.as_slice().first().map_or(span, TokenTree::span);
+	let has_offset = is_keyword(tokens.as_slice(), "offset");
+	if has_offset && kind == LayoutKind::Implicit {
+		bail!(at, "parse field_layout: fields of an implicit layout cannot specify an offset");
+	}
+	let dynamic = kind == LayoutKind::Explicit && is_keyword(tokens.as_slice(), "dynamic");
+	if dynamic {
+		let _ = parse_ident(tokens);
+		if parse_comma(tokens).is_none() {
+			bail!(at, "parse field_layout: expecting comma after dynamic");
+		}
+	}
+	let offset = match if has_offset { parse_kv(tokens) } else { None } {
+		Some(kv) => kv.value,
+		None if dynamic => Expr("0".parse().unwrap()),
+		// Fields of unions overlap at the start unless specified otherwise
+		// The offsets of implicit layouts are assigned after parsing the fields
+		None if kind != LayoutKind::Explicit => Expr("0".parse().unwrap()),
+		None => match tokens.as_slice().first() {
+			Some(TokenTree::Ident(ident)) if suggest_name(&ident.to_string(), &["offset"]).is_some() => {
+				bail!(at, "parse field_layout: unknown argument `{}`, did you mean `offset`? expecting `offset = <usize>` first", ident)
+			},
+			_ => bail!(at, "parse field_layout: invalid format for offset argument, expecting `offset = <usize>`"),
+		},
+	};
+	let mut offset = offset;
+	let mut optional = None;
+	let tts: Vec<TokenTree> = offset.0.clone().into_iter().collect();
+	if let [TokenTree::Ident(ident), TokenTree::Group(group)] = &*tts {
+		if ident.to_string() == "optional" && group.delimiter() == Delimiter::Parenthesis {
+			let arms = match parse_cfg_arms(group.clone()) {
+				Some(arms) => arms,
+				None => bail!(at, "parse field_layout: invalid format for optional offset, expecting `offset = optional(cfg(<predicate>) = <usize>, ..)`"),
+			};
+			offset = cfg_arms_select(&arms, "0");
+			optional = Some(arms.into_iter().map(|(pred, _)| pred).collect::<Vec<_>>());
+		}
+	}
+	let mut stubs = false;
+	let mut stored = None;
+	let mut convert = None;
+	let mut method_get = false;
+	let mut method_set = false;
+	let mut method_ref = false;
+	let mut method_mut = false;
+	let mut method_slice = false;
+	let mut method_str = false;
+	let mut method_cstr = false;
+	let mut method_wstr = false;
+	let mut wstr_truncate = false;
+	let mut pstr = None;
+	let mut dims = None;
+	let mut col_major = false;
+	let mut count = None;
+	let mut stride = None;
+	let mut tail = None;
+	let mut len = None;
+	let mut pad = false;
+	let mut nested = false;
+	let mut flatten = false;
+	let mut pad_before = None;
+	let mut align_to = None;
+	let mut method_modify = false;
+	let mut method_copy_into = false;
+	let mut method_copy_from = false;
+	let mut method_zero = false;
+	let mut method_bytes_array = false;
+	let mut method_column = false;
+	let mut method_atomic = false;
+	let mut method_endian = false;
+	let mut method_unaligned_ref = false;
+	let mut method_shiftable = false;
+	let mut opaque = false;
+	let mut checksum = None;
+	let mut inline_name = None;
+	let mut validate = None;
+	let mut clamp = None;
+	let mut method_clamped = false;
+	let mut align = None;
+	let mut allow_drop = false;
+	let mut size = None;
+	let mut manually_drop = None;
+	let mut gated = Vec::new();
+	let mut narrow = None;
+	let mut check_cfg = None;
+	let mut sentinel = None;
+	let mut bits = None;
+	let mut debug_hex = false;
+	let mut debug_tag = false;
+	let mut debug_when = None;
+	let mut display_skip = false;
+	let mut seq = false;
+	let mut old_offset = None;
+	let mut unit = None;
+	let mut strict = false;
+	let mut assume_valid = false;
+	let mut overlaps = false;
+	let mut arguments = Vec::new();
+	while tokens.len() > 0 {
+		let at = tokens.as_slice()[0].span();
+		let ident = match parse_ident(tokens) {
+			Some(ident) => ident,
+			None => bail!(at, "parse field_layout: expecting an identifier"),
+		};
+		arguments.push(ident.clone());
+		let method = ident.to_string();
+		match &*method {
+			"get" => { method_get = true; parse_field_accessor_cfg(tokens, "get", &mut gated, at)?; },
+			"set" => { method_set = true; parse_field_accessor_cfg(tokens, "set", &mut gated, at)?; },
+			"ref" => { method_ref = true; parse_field_accessor_cfg(tokens, "ref", &mut gated, at)?; },
+			"mut" => { method_mut = true; parse_field_accessor_cfg(tokens, "mut", &mut gated, at)?; },
+			"slice" => method_slice = true,
+			"modify" => method_modify = true,
+			"copy_into" => method_copy_into = true,
+			"copy_from" => method_copy_from = true,
+			"zero" => method_zero = true,
+			"bytes_array" => method_bytes_array = true,
+			"column" => method_column = true,
+			"atomic_ordered" => method_atomic = true,
+			"unaligned_ref" => method_unaligned_ref = true,
+			"shiftable" => method_shiftable = true,
+			"opaque" => opaque = true,
+			"clamped" => method_clamped = true,
+			"stubs" => stubs = true,
+			"strict" => strict = true,
+			"assume_valid" => assume_valid = true,
+			"overlaps" => overlaps = true,
+			"as" => {
+				inline_name = match (parse_punct(tokens, '='), parse_ident(tokens)) {
+					(Some(_), Some(ident)) => Some(ident),
+					_ => bail!(at, "parse field_layout: invalid format for as argument, expecting `as = <name>`"),
+				};
+			},
+			"checksum" => {
+				let group = match parse_group(tokens, Delimiter::Parenthesis) {
+					Some(group) => group,
+					None => bail!(at, "parse field_layout: invalid format for checksum argument, expecting `checksum(<algorithm>, range = <start>..<end>)`"),
+				};
+				checksum = Some(parse_field_checksum(group, at)?);
+			},
+			"str" => method_str = true,
+			"cstr" => method_cstr = true,
+			"pstr" => {
+				pstr = Some(None);
+				if let Some(group) = parse_group(tokens, Delimiter::Parenthesis) {
+					let args: Vec<TokenTree> = group.stream().into_iter().collect();
+					let mut args = args.into_iter();
+					match parse_kv(&mut args) {
+						Some(kv) if kv.ident.to_string() == "cap" && !kv.value.0.is_empty() => pstr = Some(Some(kv.value)),
+						_ => bail!(at, "parse field_layout: invalid format for pstr argument, expecting `pstr` or `pstr(cap = <usize>)`"),
+					}
+				}
+			},
+			"dims" => {
+				let group = match parse_group(tokens, Delimiter::Parenthesis) {
+					Some(group) => group,
+					None => bail!(at, "parse field_layout: invalid format for dims argument, expecting `dims(<rows>, <cols>)`"),
+				};
+				let args: Vec<TokenTree> = group.stream().into_iter().collect();
+				let mut args = args.into_iter();
+				let rows = parse_expr(&mut args);
+				let cols = parse_expr(&mut args);
+				if rows.0.is_empty() || cols.0.is_empty() || args.len() != 0 {
+					bail!(at, "parse field_layout: invalid format for dims argument, expecting `dims(<rows>, <cols>)`");
+				}
+				dims = Some((rows, cols));
+			},
+			"col_major" => col_major = true,
+			"allow_drop" => allow_drop = true,
+			"manually_drop" => manually_drop = Some(Type(Vec::new())),
+			"nested" => nested = true,
+			"flatten" => {
+				nested = true;
+				flatten = true;
+			},
+			"tail" => {
+				tail = Some(None);
+				if let Some(group) = parse_group(tokens, Delimiter::Parenthesis) {
+					let args: Vec<TokenTree> = group.stream().into_iter().collect();
+					let mut args = args.into_iter();
+					let len = match parse_kv(&mut args) {
+						Some(kv) if kv.ident.to_string() == "len" && kv.value.0.clone().into_iter().count() == 1 => kv.value,
+						_ => bail!(at, "parse field_layout: invalid format for tail argument, expecting `tail` or `tail(len = <field>)`"),
+					};
+					match len.0.into_iter().next() {
+						Some(TokenTree::Ident(ident)) => tail = Some(Some(ident)),
+						_ => bail!(at, "parse field_layout: invalid format for tail argument, expecting `tail` or `tail(len = <field>)`"),
+					}
+				}
+			},
+			"len" => {
+				len = Some(parse_field_layout_value(tokens, "len", at)?);
+				continue;
+			},
+			"pad" => {
+				len = Some(parse_field_layout_value(tokens, "pad", at)?);
+				pad = true;
+				continue;
+			},
+			"count" => {
+				count = Some(parse_field_layout_value(tokens, "count", at)?);
+				continue;
+			},
+			"stride" => {
+				stride = Some(parse_field_layout_value(tokens, "stride", at)?);
+				continue;
+			},
+			"size" => {
+				size = Some(parse_field_layout_value(tokens, "size", at)?);
+				continue;
+			},
+			"align" => {
+				align = Some(parse_field_layout_value(tokens, "align", at)?);
+				continue;
+			},
+			"validate" => {
+				if parse_punct(tokens, '=').is_none() {
+					bail!(at, "parse field_layout: invalid format for validate argument, expecting `validate = <path>`");
+				}
+				let path = parse_expr(tokens);
+				if path.0.is_empty() {
+					bail!(at, "parse field_layout: invalid format for validate argument, expecting `validate = <path>`");
+				}
+				validate = Some(path);
+				continue;
+			},
+			"clamp" => {
+				if parse_punct(tokens, '=').is_none() {
+					bail!(at, "parse field_layout: invalid format for clamp argument, expecting `clamp = <min>..=<max>`");
+				}
+				let range: Vec<TokenTree> = parse_expr(tokens).0.into_iter().collect();
+				let split = (0..range.len().saturating_sub(2)).find(|&i| is_punct(&range[i..], '.') && is_punct(&range[i + 1..], '.') && is_punct(&range[i + 2..], '='));
+				clamp = match split {
+					Some(i) if i > 0 && i + 3 < range.len() => Some((Expr(range[..i].iter().cloned().collect()), Expr(range[i + 3..].iter().cloned().collect()))),
+					_ => bail!(at, "parse field_layout: invalid format for clamp argument, expecting `clamp = <min>..=<max>`"),
+				};
+				continue;
+			},
+			"old_offset" | "unit" => {
+				let value = Some(parse_field_layout_value(tokens, &method, at)?);
+				if method == "old_offset" { old_offset = value } else { unit = value }
+				continue;
+			},
+			"pad_before" | "align_to" => {
+				if kind != LayoutKind::Implicit {
+					bail!(at, "parse field_layout: the {} argument is only supported by the implicit attribute", method);
+				}
+				let value = Some(parse_field_layout_value(tokens, &method, at)?);
+				if method == "pad_before" { pad_before = value } else { align_to = value }
+				continue;
+			},
+			"wstr" => {
+				method_wstr = true;
+				if let Some(group) = parse_group(tokens, Delimiter::Parenthesis) {
+					match &*group.stream().to_string() {
+						"truncate" => wstr_truncate = true,
+						_ => bail!(at, "parse field_layout: invalid format for wstr argument, expecting `wstr` or `wstr(truncate)`"),
+					}
+				}
+			},
+			"stored" => {
+				if parse_punct(tokens, '=').is_none() {
+					bail!(at, "parse field_layout: invalid format for stored argument, expecting `stored = <type>`");
+				}
+				// Parsing the type also consumes the trailing comma
+				stored = Some(parse_ty(tokens));
+				continue;
+			},
+			"narrow" => {
+				if parse_punct(tokens, '=').is_none() {
+					bail!(at, "parse field_layout: invalid format for narrow argument, expecting `narrow = <integer type>`");
+				}
+				// Parsing the type also consumes the trailing comma
+				narrow = Some(parse_ty(tokens));
+				continue;
+			},
+			"debug_tag" => debug_tag = true,
+			"seq" => seq = true,
+			"check_cfg" => {
+				check_cfg = match parse_group(tokens, Delimiter::Parenthesis) {
+					Some(group) if !group.stream().is_empty() => Some(group.stream().to_string()),
+					_ => bail!(at, "parse field_layout: invalid format for check_cfg argument, expecting `check_cfg(<predicate>)`"),
+				};
+			},
+			"debug_when" => {
+				let group = match parse_group(tokens, Delimiter::Parenthesis) {
+					Some(group) => group,
+					None => bail!(at, "parse field_layout: invalid format for debug_when argument, expecting `debug_when(<field> = <integer>)`"),
+				};
+				let condition: Vec<TokenTree> = group.stream().into_iter().collect();
+				debug_when = match parse_kv(&mut condition.into_iter()) {
+					Some(kv) if int_literal(kv.value.0.to_string().trim_start_matches("- ")).is_some() => Some((kv.ident, kv.value)),
+					_ => bail!(at, "parse field_layout: invalid format for debug_when argument, expecting `debug_when(<field> = <integer>)`"),
+				};
+			},
+			"endian" => {
+				method_endian = match (parse_punct(tokens, '='), parse_ident(tokens)) {
+					(Some(_), Some(ident)) if ident.to_string() == "both" => true,
+					_ => bail!(at, "parse field_layout: invalid format for endian argument, expecting `endian = both`"),
+				};
+			},
+			"debug" => {
+				debug_hex = match (parse_punct(tokens, '='), parse_ident(tokens)) {
+					(Some(_), Some(ident)) if ident.to_string() == "hex_bytes" => true,
+					_ => bail!(at, "parse field_layout: invalid format for debug argument, expecting `debug = hex_bytes`"),
+				};
+			},
+			"display" => {
+				display_skip = match (parse_punct(tokens, '='), parse_ident(tokens)) {
+					(Some(_), Some(ident)) if ident.to_string() == "skip" => true,
+					_ => bail!(at, "parse field_layout: invalid format for display argument, expecting `display = skip`"),
+				};
+			},
+			"bits" => {
+				bits = Some(parse_field_bits(tokens, at)?);
+				continue;
+			},
+			"sentinel" => {
+				if parse_punct(tokens, '=').is_none() {
+					bail!(at, "parse field_layout: invalid format for sentinel argument, expecting `sentinel = <value>`");
+				}
+				let value = parse_expr(tokens);
+				if value.0.is_empty() {
+					bail!(at, "parse field_layout: invalid format for sentinel argument, expecting `sentinel = <value>`");
+				}
+				sentinel = Some(value);
+				continue;
+			},
+			"convert" => {
+				if parse_punct(tokens, '=').is_none() {
+					bail!(at, "parse field_layout: invalid format for convert argument, expecting `convert = From` or `convert = TryFrom`");
+				}
+				convert = match parse_ident(tokens).map(|ident| ident.to_string()).as_deref() {
+					Some("From") => Some(Convert::From),
+					Some("TryFrom") => Some(Convert::TryFrom),
+					_ => bail!(at, "parse field_layout: invalid format for convert argument, expecting `convert = From` or `convert = TryFrom`"),
+				};
+			},
+			"enum" => {
+				let group = match parse_group(tokens, Delimiter::Parenthesis) {
+					Some(group) => group,
+					None => bail!(at, "parse field_layout: invalid format for enum argument, expecting `enum(<repr>)`"),
+				};
+				let repr: Vec<TokenTree> = group.stream().into_iter().collect();
+				stored = Some(parse_ty(&mut repr.into_iter()));
+				convert = Some(Convert::Enum);
+			},
+			_ => return Err(unknown_field_argument(at, &method)),
+		}
+		if parse_comma(tokens).is_none() {
+			bail!(at, "parse field_layout: expecting comma after {}", method);
+		}
+	}
+	// Combinations of arguments are reported at the field
+	if convert.is_some() && stored.is_none() {
+		bail!(span, "parse field_layout: the convert argument requires a stored type, expecting `stored = <type>`");
+	}
+	if narrow.is_some() {
+		if convert.is_some() {
+			bail!(span, "parse field_layout: the narrow and convert arguments cannot be combined");
+		}
+		// Narrowing is a fallible conversion, the stored type defaults to the field type once it is parsed
+		convert = Some(Convert::TryFrom);
+		if stored.is_none() {
+			stored = Some(Type(Vec::new()));
+		}
+	}
+	if bits.is_some() {
+		if method_ref || method_mut {
+			bail!(span, "parse field_layout: the `ref` and `mut` accessors are not supported on bitfields, a reference cannot point to a range of bits");
+		}
+		if convert.is_some() || narrow.is_some() || sentinel.is_some() {
+			bail!(span, "parse field_layout: the bits argument cannot be combined with a conversion, narrow or sentinel");
+		}
+		if method_column || method_shiftable || old_offset.is_some() {
+			bail!(span, "parse field_layout: the `column` and `shiftable` accessors and the old_offset argument are not supported on bitfields");
+		}
+		// The stored integer defaults to the field type once it is parsed
+		convert = Some(Convert::Bits);
+		if stored.is_none() {
+			stored = Some(Type(Vec::new()));
+		}
+	}
+	if sentinel.is_some() {
+		if convert.is_some() || stored.is_some() {
+			bail!(span, "parse field_layout: the sentinel argument cannot be combined with a stored type or conversion");
+		}
+		// The field is declared with its stored type and exposed as an Option once it is parsed
+		convert = Some(Convert::Sentinel);
+		stored = Some(Type(Vec::new()));
+	}
+	if stored.is_some() {
+		if method_ref || method_mut || method_slice || method_str || method_cstr || method_wstr || pstr.is_some() {
+			bail!(span, "parse field_layout: `ref`, `mut`, `slice`, `str`, `cstr` and `wstr` accessors are not supported on fields with a stored type");
+		}
+		// Stored fields are only accessible by value
+		if !method_get && !method_set {
+			method_get = true;
+			method_set = true;
+		}
+	}
+	if method_str && pstr.is_some() {
+		bail!(span, "parse field_layout: the `str` and `pstr` accessors are mutually exclusive");
+	}
+	if stride.is_some() && count.is_none() {
+		bail!(span, "parse field_layout: the stride argument requires the count argument");
+	}
+	if count.is_some() && (dims.is_some() || stored.is_some() || method_slice || method_str || method_cstr || method_wstr) {
+		bail!(span, "parse field_layout: only `get`, `set`, `ref` and `mut` accessors are supported on fields with a count");
+	}
+	if tail.is_some() {
+		if count.is_some() || dims.is_some() || stored.is_some() || method_get || method_set || method_slice || method_str || method_cstr || method_wstr {
+			bail!(span, "parse field_layout: only `ref` and `mut` accessors are supported on tail fields");
+		}
+		if !method_ref && !method_mut {
+			method_ref = true;
+			method_mut = true;
+		}
+	}
+	if len.is_some() && (tail.is_some() || count.is_some() || dims.is_some() || stored.is_some() || method_slice || method_str || method_cstr || method_wstr) {
+		bail!(span, "parse field_layout: only `get`, `set`, `ref` and `mut` accessors are supported on byte region fields");
+	}
+	if nested && (stored.is_some() || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || method_slice || method_str || method_cstr || method_wstr || pstr.is_some()) {
+		bail!(span, "parse field_layout: only `get`, `set`, `ref` and `mut` accessors are supported on nested fields");
+	}
+	if flatten && !method_get && (method_set || method_ref || method_mut) {
+		bail!(span, "parse field_layout: flattened fields require the `get` accessor");
+	}
+	if col_major && dims.is_none() {
+		bail!(span, "parse field_layout: the col_major argument requires the dims argument");
+	}
+	if dims.is_some() {
+		if stored.is_some() || method_ref || method_mut || method_slice || method_str || method_cstr || method_wstr {
+			bail!(span, "parse field_layout: only `get` and `set` accessors are supported on fields with dims");
+		}
+		if !method_get && !method_set {
+			method_get = true;
+			method_set = true;
+		}
+	}
+	if dynamic {
+		if stored.is_some() || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || nested || method_ref || method_mut || method_modify || method_slice || method_str || method_cstr || method_wstr || pstr.is_some() {
+			bail!(span, "parse field_layout: only `get` and `set` accessors are supported on dynamic fields");
+		}
+		if !method_get && !method_set {
+			method_get = true;
+			method_set = true;
+		}
+	}
+	if manually_drop.is_some() {
+		if allow_drop || method_ref || method_mut || method_modify || stored.is_some() || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || nested || dynamic || method_slice || method_str || method_cstr || method_wstr || pstr.is_some() {
+			bail!(span, "parse field_layout: only `get` and `set` accessors are supported on fields with manually_drop");
+		}
+		if !method_get && !method_set {
+			method_get = true;
+			method_set = true;
+		}
+	}
+	if allow_drop {
+		if method_get || method_modify || stored.is_some() || dims.is_some() || count.is_some() || dynamic {
+			bail!(span, "parse field_layout: only `set`, `ref` and `mut` accessors are supported on fields with allow_drop");
+		}
+		if !method_set && !method_ref && !method_mut {
+			method_set = true;
+			method_ref = true;
+			method_mut = true;
+		}
+	}
+	if method_modify {
+		method_get = true;
+		method_set = true;
+	}
+	if method_modify && (count.is_some() || dims.is_some() || tail.is_some() || len.is_some() || strict || matches!(convert, Some(Convert::TryFrom) | Some(Convert::Enum))) {
+		bail!(span, "parse field_layout: the modify accessor is only supported on fields with infallible get and set accessors");
+	}
+	if checksum.is_some() && (stored.is_some() || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || dynamic || allow_drop || manually_drop.is_some() || nested) {
+		bail!(span, "parse field_layout: the checksum argument is only supported on integer fields");
+	}
+	if method_unaligned_ref && (stored.is_some() || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || dynamic || allow_drop || manually_drop.is_some()) {
+		bail!(span, "parse field_layout: the unaligned_ref accessor is only supported on fields of a single value");
+	}
+	if method_shiftable && (dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || pad || dynamic || allow_drop || manually_drop.is_some() || validate.is_some() || clamp.is_some()) {
+		bail!(span, "parse field_layout: the shiftable accessors are only supported on fields of a single value without validate or clamp");
+	}
+	if old_offset.is_some() && (dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || pad || dynamic || allow_drop || manually_drop.is_some() || optional.is_some()) {
+		bail!(span, "parse field_layout: the old_offset argument is only supported on fields of a single value");
+	}
+	if (method_copy_into || method_copy_from) && (stored.is_some() || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || dynamic || allow_drop || manually_drop.is_some()) {
+		bail!(span, "parse field_layout: the copy_into and copy_from accessors are only supported on fields of a single value");
+	}
+	if method_bytes_array && (dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || dynamic || allow_drop || manually_drop.is_some()) {
+		bail!(span, "parse field_layout: the bytes_array accessors are only supported on fields of a single value");
+	}
+	if method_column && (dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || dynamic || allow_drop || manually_drop.is_some()) {
+		bail!(span, "parse field_layout: the column accessors are only supported on fields of a single value");
+	}
+	if method_atomic && (stored.is_some() || convert.is_some() || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || dynamic || manually_drop.is_some()) {
+		bail!(span, "parse field_layout: the atomic_ordered accessors are only supported on integer and bool fields of a single value");
+	}
+	if method_endian && (stored.is_some() || convert.is_some() || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || dynamic || manually_drop.is_some()) {
+		bail!(span, "parse field_layout: the endian = both accessors are only supported on integer fields of a single value");
+	}
+	if method_zero && (dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || dynamic || allow_drop || manually_drop.is_some()) {
+		bail!(span, "parse field_layout: the zero accessor is only supported on fields of a single value without drop glue");
+	}
+	if pad && (method_get || method_set || method_ref || method_mut || method_modify || method_slice) {
+		bail!(span, "parse field_layout: pad regions do not have accessors");
+	}
+	if validate.is_some() && (pad || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || dynamic || manually_drop.is_some()) {
+		bail!(span, "parse field_layout: the validate argument is only supported on fields of a single value");
+	}
+	// If no methods are specified, enable all of them
+	let default_methods = !pad && !method_get && !method_set && !method_ref && !method_mut;
+	if default_methods {
+		method_get = true;
+		method_set = true;
+		method_ref = true;
+		method_mut = true;
+	}
+	if validate.is_some() && !method_set {
+		bail!(span, "parse field_layout: the validate argument requires the set accessor");
+	}
+	if stubs && optional.is_none() {
+		bail!(span, "parse field_layout: the stubs argument requires an optional offset `offset = optional(..)`");
+	}
+	if optional.is_some() && (stored.is_some() || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || pad || nested || checksum.is_some() || allow_drop || manually_drop.is_some()
+		|| method_slice || method_str || method_cstr || method_wstr || pstr.is_some() || method_modify || method_copy_into || method_copy_from || method_zero || method_bytes_array || method_column || method_atomic || method_endian || method_unaligned_ref || method_shiftable || validate.is_some() || clamp.is_some()) {
+		bail!(span, "parse field_layout: fields with an optional offset only support the get, set, ref and mut accessors on a single value");
+	}
+	if strict && assume_valid {
+		bail!(span, "parse field_layout: the strict and assume_valid arguments cannot be combined");
+	}
+	if method_clamped && clamp.is_none() {
+		bail!(span, "parse field_layout: the clamped accessor requires the clamp argument");
+	}
+	if clamp.is_some() {
+		if pad || dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || dynamic || stored.is_some() || manually_drop.is_some() {
+			bail!(span, "parse field_layout: the clamp argument is only supported on integer and float fields of a single value");
+		}
+		if validate.is_some() {
+			bail!(span, "parse field_layout: the clamp and validate arguments cannot be combined");
+		}
+		if !method_set && !method_clamped {
+			bail!(span, "parse field_layout: the clamp argument requires the set or clamped accessor");
+		}
+	}
+	if let Some(pred) = &check_cfg {
+		if method_slice || method_str || method_cstr || method_wstr || pstr.is_some() || method_copy_into || method_copy_from || method_zero || method_bytes_array || method_column || method_atomic || method_endian || method_unaligned_ref || method_shiftable || method_clamped || checksum.is_some() {
+			bail!(span, "parse field_layout: the check_cfg argument only supports the get, set, ref and mut accessors");
+		}
+		if !gated.is_empty() {
+			bail!(span, "parse field_layout: the check_cfg argument cannot be combined with accessors with a cfg");
+		}
+		// Every accessor has the bound of the check trait
+		let methods = [("get", method_get), ("set", method_set), ("ref", method_ref), ("mut", method_mut)];
+		gated.extend(methods.iter().filter(|&&(_, enabled)| enabled).map(|&(method, _)| (method, pred.clone())));
+	}
+	if !gated.is_empty() {
+		if dims.is_some() || count.is_some() || tail.is_some() || len.is_some() || nested || dynamic || allow_drop || manually_drop.is_some() || optional.is_some() || method_modify || validate.is_some() || clamp.is_some() {
+			bail!(span, "parse field_layout: accessors with a cfg are only supported on fields of a single value without modify, validate or clamp");
+		}
+		// The gated accessors are emitted in their own impl blocks
+		for &(method, _) in &gated {
+			match method {
+				"get" => method_get = false,
+				"set" => method_set = false,
+				"ref" => method_ref = false,
+				_ => method_mut = false,
+			}
+		}
+	}
+	Ok(FieldLayout { offset, stored, convert, method_get, method_set, method_ref, method_mut, method_slice, method_str, method_cstr, method_wstr, wstr_truncate, pstr, dims, col_major, count, stride, tail, len, pad, nested, flatten, variant: None, pad_before, align_to, method_modify, method_copy_into, method_copy_from, method_zero, method_bytes_array, method_column, method_atomic, method_endian, method_unaligned_ref, method_shiftable, opaque, dynamic, allow_drop, size, align, manually_drop, groups: Vec::new(), checksum, inline: None, inline_name, validate, clamp, method_clamped, optional, stubs, default_methods, get_ref: false, gated, check_cfg, narrow, sentinel, bits, debug_hex, debug_tag, debug_when, display_skip, seq, old_offset, unit, unit_offset: None, strict, assume_valid, overlaps, arguments })
+}
+// set(feature = "name") or set(cfg(<predicate>))
+fn parse_field_accessor_cfg(tokens: &mut vec::IntoIter<TokenTree>, method: &'static str, gated: &mut Vec<(&'static str, String)>, span: Span) -> ParseResult<()> {
+	let group = match parse_group(tokens, Delimiter::Parenthesis) {
+		Some(group) => group,
+		None => return Ok(()),
+	};
+	let args: Vec<TokenTree> = group.stream().into_iter().collect();
+	let mut args = args.into_iter();
+	if let Some(kv) = parse_kv(&mut args) {
+		let value: Vec<TokenTree> = kv.value.0.into_iter().collect();
+		if let ("feature", [TokenTree::Literal(lit)], 0) = (&*kv.ident.to_string(), &*value, args.len()) {
+			if lit.to_string().starts_with('"') {
+				gated.push((method, format!("feature = {}", lit)));
+				return Ok(());
+			}
+		}
+	}
+	else if let Some(meta) = parse_meta(&mut args) {
+		if meta.ident.to_string() == "cfg" && args.len() == 0 {
+			gated.push((method, meta.args.stream().to_string()));
+			return Ok(());
+		}
+	}
+	bail!(span, "parse field_layout: invalid format for {0} accessor, expecting `{0}(feature = \"<name>\")` or `{0}(cfg(<predicate>))`", method);
+}
+// $algorithm, range = $expr..$expr, auto
+fn parse_field_checksum(group: Group, span: Span) -> ParseResult<Checksum> {
+	let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+	let mut tokens = tokens.into_iter();
+	let algorithm = match parse_ident(&mut tokens) {
+		Some(ident) if ident.to_string() == "crc32" || ident.to_string() == "sum" => ident,
+		_ => bail!(span, "parse field_layout: invalid checksum algorithm, expecting `crc32` or `sum`"),
+	};
+	if parse_comma(&mut tokens).is_none() {
+		bail!(span, "parse field_layout: expecting comma after checksum algorithm");
+	}
+	let range: Vec<TokenTree> = match parse_kv(&mut tokens) {
+		Some(kv) if kv.ident.to_string() == "range" => kv.value.0.into_iter().collect(),
+		_ => bail!(span, "parse field_layout: invalid format for checksum argument, expecting `checksum(<algorithm>, range = <start>..<end>)`"),
+	};
+	let split = (0..range.len().saturating_sub(1)).find(|&i| is_punct(&range[i..], '.') && is_punct(&range[i + 1..], '.'));
+	let (start, end) = match split {
+		Some(i) if i > 0 && i + 2 < range.len() => (Expr(range[..i].iter().cloned().collect()), Expr(range[i + 2..].iter().cloned().collect())),
+		_ => bail!(span, "parse field_layout: invalid format for checksum range, expecting `range = <start>..<end>`"),
+	};
+	let auto = match parse_ident(&mut tokens) {
+		Some(ident) if ident.to_string() == "auto" => true,
+		Some(_) => bail!(span, "parse field_layout: expecting `auto` after the checksum range"),
+		None => false,
+	};
+	if parse_end(&mut tokens).is_none() {
+		bail!(span, "parse field_layout: found extra tokens after checksum argument");
+	}
+	Ok(Checksum { algorithm, start, end, auto })
+}
+// $field = $expr, offset = $expr
+fn parse_field_variant(tokens: &mut vec::IntoIter<TokenTree>, span: Span) -> ParseResult<FieldLayout> {
+	let (field, value) = match parse_kv(tokens) {
+		Some(kv) if !kv.value.0.is_empty() => (kv.ident, kv.value),
+		_ => bail!(span, "parse field: invalid format for variant attribute, expecting `#[variant(<field> = <value>)]`"),
+	};
+	let default_offset = !is_keyword(tokens.as_slice(), "offset");
+	// The payload is by default placed right after the discriminant
+	let mut layout = parse_field_layout(tokens, LayoutKind::Union, span)?;
+	if layout.stored.is_some() || !is_single_value_layout(&layout) || layout.method_slice || layout.method_str || layout.method_cstr || layout.method_wstr || layout.pstr.is_some() || layout.method_copy_into || layout.method_copy_from || layout.method_zero || layout.method_unaligned_ref || layout.method_shiftable || layout.old_offset.is_some() || layout.validate.is_some() || layout.clamp.is_some() {
+		bail!(span, "parse field: only the offset argument is supported on variant fields");
+	}
+	layout.variant = Some(Variant { field, value, default_offset });
+	Ok(layout)
+}
+// = $expr ,
+fn parse_field_layout_value(tokens: &mut vec::IntoIter<TokenTree>, name: &str, span: Span) -> ParseResult<Expr> {
+	if parse_punct(tokens, '=').is_none() {
+		bail!(span, "parse field_layout: invalid format for {0} argument, expecting `{0} = <usize>`", name);
+	}
+	let value = parse_expr(tokens);
+	if value.0.is_empty() {
+		bail!(span, "parse field_layout: invalid format for {0} argument, expecting `{0} = <usize>`", name);
+	}
+	Ok(value)
+}
+
+// = $start..$end , or = $bit ,
+fn parse_field_bits(tokens: &mut vec::IntoIter<TokenTree>, span: Span) -> ParseResult<(u32, u32)> {
+	let error = "parse field_layout: invalid format for bits argument, expecting `bits = <start>..<end>` or `bits = <bit>` with integer literals";
+	if parse_punct(tokens, '=').is_none() {
+		bail!(span, "{}", error);
+	}
+	let range: Vec<TokenTree> = parse_expr(tokens).0.into_iter().collect();
+	let literal = |tokens: &[TokenTree]| match tokens {
+		[TokenTree::Literal(lit)] => int_literal(&lit.to_string()).filter(|&value| value <= 128).map(|value| value as u32),
+		_ => None,
+	};
+	let split = (0..range.len().saturating_sub(1)).find(|&i| is_punct(&range[i..], '.') && is_punct(&range[i + 1..], '.'));
+	let (start, end) = match split {
+		None => match literal(&range) {
+			Some(bit) => (bit, bit.saturating_add(1)),
+			None => bail!(span, "{}", error),
+		},
+		Some(i) if is_punct(&range[i + 2..], '=') => match (literal(&range[..i]), literal(&range[i + 3..])) {
+			(Some(start), Some(last)) => (start, last.saturating_add(1)),
+			_ => bail!(span, "{}", error),
+		},
+		Some(i) => match (literal(&range[..i]), literal(&range[i + 2..])) {
+			(Some(start), Some(end)) => (start, end),
+			_ => bail!(span, "{}", error),
+		},
+	};
+	if start >= end {
+		bail!(span, "parse field_layout: the bits argument has an empty range `{}..{}`", start, end);
+	}
+	Ok((start, end))
+}
+
+//----------------------------------------------------------------
+// Stored type reinterpretation
+
+// Size in bytes of primitive types which are identical on every target
+fn primitive_size(ty: &Type) -> Option<usize> {
+	let size = match &*ty.to_string() {
+		"u8" | "i8" | "bool" => 1,
+		"u16" | "i16" => 2,
+		"u32" | "i32" | "f32" | "char" => 4,
+		"u64" | "i64" | "f64" => 8,
+		"u128" | "i128" => 16,
+		_ => return None,
+	};
+	Some(size)
+}
+// The atomic type with the same layout as the field type
+fn atomic_ty(ty: &Type) -> Option<&'static str> {
+	let atomic = match &*ty.to_string() {
+		"u8" => "AtomicU8", "i8" => "AtomicI8", "u16" => "AtomicU16", "i16" => "AtomicI16",
+		"u32" => "AtomicU32", "i32" => "AtomicI32", "u64" => "AtomicU64", "i64" => "AtomicI64",
+		"usize" => "AtomicUsize", "isize" => "AtomicIsize", "bool" => "AtomicBool",
+		_ => return None,
+	};
+	Some(atomic)
+}
+fn is_float(ty: &str) -> bool {
+	matches!(ty, "f32" | "f64")
+}
+// The value of an expression which is a single integer literal
+fn int_literal_value(expr: &Expr) -> Option<u128> {
+	let tokens: Vec<TokenTree> = expr.0.clone().into_iter().collect();
+	match &*tokens {
+		[TokenTree::Literal(lit)] => int_literal(&lit.to_string()),
+		_ => None,
+	}
+}
+// The value of an integer literal, with underscores, a radix prefix or a type suffix
+fn int_literal(text: &str) -> Option<u128> {
+	let text = text.replace('_', "");
+	let (digits, radix) = match text.get(..2) {
+		Some("0x") => (&text[2..], 16),
+		Some("0o") => (&text[2..], 8),
+		Some("0b") => (&text[2..], 2),
+		_ => (&text[..], 10),
+	};
+	let end = match radix {
+		16 => digits.find(['u', 'i']).unwrap_or(digits.len()),
+		_ => digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len()),
+	};
+	if end != digits.len() && !is_int(&digits[end..]) {
+		return None;
+	}
+	u128::from_str_radix(&digits[..end], radix).ok()
+}
+fn is_int(ty: &str) -> bool {
+	matches!(ty, "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize")
+}
+fn is_ptr(ty: &Type) -> bool {
+	is_punct(&ty.0, '*')
+}
+// Types whose size depends on the target, including arrays of them
+fn is_pointer_sized(ty: &Type) -> bool {
+	if let Some((elem, _)) = array_ty(ty) {
+		return is_pointer_sized(&elem);
+	}
+	match &*ty.to_string() {
+		"usize" | "isize" => true,
+		_ => is_ptr(ty),
+	}
+}
+// Unsigned integer type with the same bits as the float type
+fn float_bits(ty: &str) -> &'static str {
+	if ty == "f32" { "u32" } else { "u64" }
+}
+fn check_field_stored(field: &Field) -> ParseResult<()> {
+	let stored = match &field.layout.stored {
+		Some(stored) => stored,
+		None => return Ok(()),
+	};
+	// Conversions through From and TryFrom may change the size
+	if field.layout.convert.is_some() {
+		return Ok(());
+	}
+	if let (Some(stored_size), Some(field_size)) = (primitive_size(stored), primitive_size(&field.ty)) {
+		if stored_size != field_size {
+			bail!(field.name.span(), "parse field: stored type `{}` of field `{}` is {} bytes but the field type `{}` is {} bytes",
+				stored, field.name, stored_size, field.ty, field_size);
+		}
+	}
+	Ok(())
+}
+// Converts the `value` from the stored type to the field type
+fn stored_get_expr(field: &Field, stored: &Type) -> String {
+	let (exposed, stored_s) = (field.ty.to_string(), stored.to_string());
+	match field.layout.convert {
+		Some(Convert::TryFrom) => return format!("<{} as ::core::convert::TryFrom<{}>>::try_from(value)", exposed, stored_s),
+		Some(Convert::Enum) => return format!("<{} as ::core::convert::TryFrom<{}>>::try_from(value).ok()", exposed, stored_s),
+		Some(Convert::From) => return format!("<{} as ::core::convert::From<{}>>::from(value)", exposed, stored_s),
+		Some(Convert::Sentinel) => return format!("{{ const SENTINEL: {} = {}; if value == SENTINEL {{ ::core::option::Option::None }} else {{ ::core::option::Option::Some(value) }} }}", stored_s, field.layout.sentinel.as_ref().unwrap().0),
+		Some(Convert::Valid) if exposed == "bool" => return "value != 0".to_string(),
+		Some(Convert::Valid) => return "::core::char::from_u32(value).unwrap_or(::core::char::REPLACEMENT_CHARACTER)".to_string(),
+		Some(Convert::Strict) if exposed == "bool" => return "match value { 0 => ::core::option::Option::Some(false), 1 => ::core::option::Option::Some(true), _ => ::core::option::Option::None }".to_string(),
+		Some(Convert::Strict) => return "::core::char::from_u32(value)".to_string(),
+		Some(Convert::Bits) => {
+			// Shifts the range to the top and back down, the arithmetic shift sign extends signed fields
+			let (start, end) = field.layout.bits.unwrap();
+			return match &*exposed {
+				"bool" => format!("(value as u128) >> {} & 1 != 0", start),
+				_ => format!("((value as {}) << (128 - {1}) >> (128 - {1} + {2})) as {3}", if exposed.starts_with('i') { "i128" } else { "u128" }, end, start, exposed),
+			};
+		},
+		None => (),
+	}
+	if is_float(&exposed) && is_int(&stored_s) {
+		format!("{}::from_bits(value as {})", exposed, float_bits(&exposed))
+	}
+	else if is_int(&exposed) && is_float(&stored_s) {
+		format!("value.to_bits() as {}", exposed)
+	}
+	else if is_int(&exposed) && is_int(&stored_s) {
+		format!("value as {}", exposed)
+	}
+	else if is_ptr(&field.ty) && is_int(&stored_s) {
+		format!("value as usize as {}", exposed)
+	}
+	else {
+		format!("<{} as ::core::convert::From<{}>>::from(value)", exposed, stored_s)
+	}
+}
+// Converts the `value` from the field type to the stored type
+fn stored_set_expr(field: &Field, stored: &Type) -> String {
+	let (exposed, stored_s) = (field.ty.to_string(), stored.to_string());
+	match field.layout.convert {
+		Some(Convert::Enum) | Some(Convert::Valid) | Some(Convert::Strict) => return format!("value as {}", stored_s),
+		Some(Convert::Sentinel) => return format!("{{ const SENTINEL: {} = {}; match value {{ ::core::option::Option::Some(value) => value, ::core::option::Option::None => SENTINEL }} }}", stored_s, field.layout.sentinel.as_ref().unwrap().0),
+		// Merges the bits into the `old` stored value, the value is truncated to the width of the range
+		Some(Convert::Bits) => {
+			let (start, end) = field.layout.bits.unwrap();
+			return format!("{{ let mask = !0u128 >> (128 - {1} + {0}) << {0}; ((old as u128 & !mask) | ((value as u128) << {0} & mask)) as {2} }}", start, end, stored_s);
+		},
+		Some(_) => return format!("::core::convert::Into::<{}>::into(value)", stored_s),
+		None => (),
+	}
+	if is_float(&exposed) && is_int(&stored_s) {
+		format!("value.to_bits() as {}", stored_s)
+	}
+	else if is_int(&exposed) && is_float(&stored_s) {
+		format!("{}::from_bits(value as {})", stored_s, float_bits(&stored_s))
+	}
+	else if is_int(&exposed) && is_int(&stored_s) {
+		format!("value as {}", stored_s)
+	}
+	else if is_ptr(&field.ty) && is_int(&stored_s) {
+		format!("value as usize as {}", stored_s)
+	}
+	else {
+		format!("::core::convert::Into::<{}>::into(value)", stored_s)
+	}
+}
+
+//----------------------------------------------------------------
+// Array field types
+
+// Splits an array type `[T; N]` into its element type and length
+fn array_ty(ty: &Type) -> Option<(Type, Expr)> {
+	let group = match &ty.0[..] {
+		[TokenTree::Group(group)] if group.delimiter() == Delimiter::Bracket => group,
+		_ => return None,
+	};
+	let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+	let mut tokens = tokens.into_iter();
+	let elem = parse_ty(&mut tokens);
+	let len = parse_expr(&mut tokens);
+	// Slices have no length
+	if len.0.is_empty() {
+		return None;
+	}
+	Some((elem, len))
+}
+
+fn is_byte_array(ty: &Type) -> bool {
+	is_array_of(ty, "u8")
+}
+fn is_array_of(ty: &Type, elem_ty: &str) -> bool {
+	match array_ty(ty) {
+		Some((elem, _)) => elem.to_string() == elem_ty,
+		None => false,
+	}
+}
+
+//----------------------------------------------------------------
+// Parse structure
+
+// The offset in units as bytes, literals are multiplied while expanding
+fn unit_offset_bytes(name: &Ident, offset: &Expr, unit: &Expr) -> ParseResult<Expr> {
+	if let (Some(offset), Some(unit)) = (int_literal_value(offset), int_literal_value(unit)) {
+		return match offset.checked_mul(unit) {
+			Some(bytes) => Ok(Expr(format!("{:#x}", bytes).parse().unwrap())),
+			None => bail!(name.span(), "parse field: the offset of field `{}` overflows when converted from units of {} bytes", name, unit),
+		};
+	}
+	let bytes = format!("(match usize::checked_mul({}, {}) {{
+		::core::option::Option::Some(bytes) => bytes,
+		::core::option::Option::None => panic!(\"struct_layout: the offset of field `{}` overflows when converted to bytes\"),
+	}})", offset.0, unit.0, name);
+	Ok(Expr(bytes.parse().unwrap()))
+}
+// The errors of the layout arguments and the structure are reported together
+fn parse_structure(tokens: TokenStream, mut layout: ExplicitLayout) -> Result<Structure, Vec<TokenTree>> {
+	let mut errors = std::mem::take(&mut layout.errors);
+	match parse_structure_item(tokens, layout, &mut errors) {
+		Ok(stru) if errors.is_empty() => Ok(stru),
+		Ok(_) => Err(errors),
+		Err(err) => {
+			emit_error(&mut errors, err);
+			Err(errors)
+		},
+	}
+}
+// Errors which do not prevent checking the rest of the structure are collected in `errors`
+fn parse_structure_item(tokens: TokenStream, layout: ExplicitLayout, errors: &mut Vec<TokenTree>) -> ParseResult<Structure> {
+	let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+	let mut tokens = tokens.into_iter();
+	let mut attrs = parse_attrs(&mut tokens);
+	let derived = match parse_structure_attrs(&mut attrs, layout.repr_manual) {
+		Ok(derived) => derived,
+		Err(err) => {
+			emit_error(errors, err);
+			Vec::new()
+		},
+	};
+	let vis = parse_vis(&mut tokens);
+	let stru = match parse_keyword(&mut tokens, "struct") {
+		Some(ident) => ident,
+		None => bail!(next_span(tokens.as_slice()), "parse struct: struct layout is only allowed on structs"),
+	};
+	let name = match parse_ident(&mut tokens) {
+		Some(ident) => ident,
+		None => bail!(stru.span(), "parse struct: struct name identifier not found"),
+	};
+	let generics = parse_generics(&mut tokens)?;
+	if is_keyword(tokens.as_slice(), "where") {
+		bail!(next_span(tokens.as_slice()), "parse struct: where clause not supported, declare the bounds with the generic parameters");
+	}
+	let group = match parse_group(&mut tokens, Delimiter::Brace) {
+		Some(group) => group,
+		None => bail!(name.span(), "parse struct: tuple syntax not supported, struct layout requires braces to declare the fields"),
+	};
+	let mut fields = parse_fields(group.stream(), layout.kind, errors);
+	// The generated code is suppressed, it would only add errors about the missing fields
+	if !errors.is_empty() {
+		return Ok(Structure { attrs, derived, layout, vis, stru, name, generics, fields: Vec::new(), optional: Vec::new() });
+	}
+	// Packed layouts cannot hand out references, fields only get the get and set accessors by default
+	if layout.packed {
+		for field in &mut fields {
+			if field.layout.default_methods {
+				field.layout.method_ref = false;
+				field.layout.method_mut = false;
+			}
+		}
+	}
+	// Large fields are read by reference unless their accessors are given explicitly
+	if let Some(threshold) = layout.auto_ref {
+		if layout.packed {
+			bail!(argument_span(&layout, "auto_ref"), "parse struct_layout: the auto_ref argument is not supported on packed layouts, they cannot hand out references");
+		}
+		for field in &mut fields {
+			let plain = field.layout.default_methods && field.layout.stored.is_none() && field.layout.manually_drop.is_none() && is_single_value(field);
+			if plain && matches!(field_literal_size(field), Some(size) if size > threshold) {
+				field.layout.get_ref = true;
+			}
+		}
+	}
+	// Pad regions named with a leading underscore may repeat their name, they are numbered in declaration order
+	let mut pads = 0;
+	for field in &mut fields {
+		if field.layout.pad && field.name.to_string().starts_with('_') {
+			field.name = Ident::new(&format!("__pad{}", pads), field.name.span());
+			pads += 1;
+		}
+		field.accessor = Ident::new(&accessor_name(&layout, &field.name), field.name.span());
+	}
+	for field in &mut fields {
+		if field.layout.inline.is_some() {
+			let inline_name = match &field.layout.inline_name {
+				Some(ident) => ident.clone(),
+				None => Ident::new(&format!("{}{}", name, camel_case(&field.name.to_string())), field.name.span()),
+			};
+			field.ty = Type(vec![TokenTree::Ident(inline_name)]);
+		}
+	}
+	// Offsets in units are converted to bytes, offsets relative to other fields are already in bytes
+	if layout.offset_unit.is_some() && layout.kind == LayoutKind::Implicit {
+		bail!(argument_span(&layout, "offset_unit"), "parse struct: the offset_unit argument is not supported by the implicit attribute");
+	}
+	let names: Vec<String> = fields.iter().map(|field| field.name.to_string()).collect();
+	for field in &mut fields {
+		let unit = match field.layout.unit.as_ref().or(layout.offset_unit.as_ref()) {
+			Some(unit) if int_literal_value(unit) != Some(1) => unit.clone(),
+			_ => continue,
+		};
+		let offset: Vec<TokenTree> = field.layout.offset.0.clone().into_iter().collect();
+		let relative = match offset.first() {
+			Some(TokenTree::Ident(ident)) => ident.to_string() == "after" || names.contains(&ident.to_string()) && !is_punct(&offset[1..], ':'),
+			_ => false,
+		};
+		if relative {
+			bail!(field.name.span(), "parse field: the offset of field `{}` is relative to another field and in bytes, give it `unit = 1`", field.name);
+		}
+		if field.layout.dynamic || field.layout.offset.0.to_string() == "extern" || field.layout.variant.is_some() && field.layout.variant.as_ref().unwrap().default_offset {
+			continue;
+		}
+		field.layout.unit_offset = Some((field.layout.offset.clone(), unit.clone()));
+		field.layout.offset = unit_offset_bytes(&field.name, &field.layout.offset, &unit)?;
+		if let Some(old) = &field.layout.old_offset {
+			field.layout.old_offset = Some(unit_offset_bytes(&field.name, old, &unit)?);
+		}
+	}
+	if let Some(path) = &layout.offsets {
+		let offsets = load_offsets(path, argument_span(&layout, "offsets"))?;
+		for field in &mut fields {
+			if field.layout.offset.0.to_string() == "extern" {
+				match offsets.get(&field.name.to_string()) {
+					Some(value) => field.layout.offset = Expr(value.parse().unwrap()),
+					None => bail!(field.name.span(), "parse field: offset of field `{}` not found in `{}`", field.name, offsets_path(path)),
+				}
+			}
+		}
+	}
+	// Fields are looked up by name, machine generated structures may have thousands of them
+	let by_name: HashMap<String, usize> = fields.iter().enumerate().rev().map(|(i, field)| (field.name.to_string(), i)).collect();
+	// Offsets following the previous field or relative to an earlier field
+	for i in 0..fields.len() {
+		let offset: Vec<TokenTree> = fields[i].layout.offset.0.clone().into_iter().collect();
+		match (offset.first(), offset.get(1)) {
+			(Some(TokenTree::Ident(ident)), Some(TokenTree::Group(group))) if ident.to_string() == "after" && group.delimiter() == Delimiter::Parenthesis => {
+				let reference = match &*group.stream().into_iter().collect::<Vec<_>>() {
+					[TokenTree::Ident(reference)] => reference.clone(),
+					_ => bail!(fields[i].name.span(), "parse field: invalid format for the offset of field `{}`, expecting `after(<field>)`", fields[i].name),
+				};
+				let base = field_relative_offset(&name, &fields, &by_name, errors, i, &reference, true);
+				let rest: TokenStream = offset[2..].iter().cloned().collect();
+				fields[i].layout.offset = Expr(format!("{} {}", base, rest).parse().unwrap());
+				continue;
+			},
+			(Some(TokenTree::Ident(ident)), _) if ident.to_string() == "after" => (),
+			// A leading field name stands for the offset of that field, other names are constants
+			(Some(TokenTree::Ident(ident)), None) | (Some(TokenTree::Ident(ident)), Some(TokenTree::Punct(_))) if by_name.contains_key(&ident.to_string()) && !is_punct(&offset[1..], ':') => {
+				let base = field_relative_offset(&name, &fields, &by_name, errors, i, ident, false);
+				let rest: TokenStream = offset[1..].iter().cloned().collect();
+				fields[i].layout.offset = Expr(format!("{} {}", base, rest).parse().unwrap());
+				continue;
+			},
+			_ => continue,
+		}
+		let prev = match i.checked_sub(1).map(|i| &fields[i]) {
+			Some(prev) => prev,
+			None => bail!(fields[i].name.span(), "parse field: the first field `{}` cannot be placed after a previous field", fields[i].name),
+		};
+		if prev.layout.dynamic || prev.layout.variant.is_some() || prev.layout.tail.is_some() || prev.layout.optional.is_some() {
+			bail!(fields[i].name.span(), "parse field: field `{}` cannot be placed after field `{}` whose extent is not known", fields[i].name, prev.name);
+		}
+		let rest: TokenStream = offset[1..].iter().cloned().collect();
+		let offset = format!("{}::{} + {} {}", name, field_offset_const(prev), field_extent(prev).0, rest);
+		fields[i].layout.offset = Expr(offset.parse().unwrap());
+	}
+	for i in 0..fields.len() {
+		let variant = match &fields[i].layout.variant {
+			Some(variant) => variant.clone(),
+			None => continue,
+		};
+		let discr = match by_name.get(&variant.field.to_string()).map(|&i| &fields[i]) {
+			Some(discr) if discr.layout.method_get && discr.layout.method_set && is_single_value(discr) && discr.layout.optional.is_none() => discr,
+			Some(_) => bail!(variant.field.span(), "parse field: discriminant field `{}` of variant `{}` requires the get and set accessors", variant.field, fields[i].name),
+			None => bail!(variant.field.span(), "parse field: discriminant field `{}` of variant `{}` not found", variant.field, fields[i].name),
+		};
+		if variant.default_offset {
+			let offset = format!("({}) + ::core::mem::size_of::<{}>()", discr.layout.offset.0, field_storage_ty(discr));
+			fields[i].layout.offset = Expr(offset.parse().unwrap());
+		}
+	}
+	if layout.kind == LayoutKind::Implicit {
+		for (i, field) in fields.iter().enumerate() {
+			if field.layout.variant.is_some() {
+				bail!(field.name.span(), "parse field: variant fields are not supported by the implicit attribute");
+			}
+			if field.layout.tail.is_some() && i + 1 != fields.len() {
+				bail!(field.name.span(), "parse field: tail field `{}` must be the last field of an implicit layout", field.name);
+			}
+		}
+	}
+	// Every field is checked on its own, their errors are reported together
+	for field in &fields {
+		if let Err(err) = check_structure_field(&layout, field) {
+			emit_error(errors, err);
+		}
+	}
+	if let Some(field) = fields.iter().filter(|field| field.layout.seq).nth(1) {
+		bail!(field.name.span(), "parse struct: only one field can be the sequence field");
+	}
+	if let Some((_, Some(names))) = &layout.copy_from {
+		for name in names {
+			match by_name.get(&name.to_string()).map(|&i| &fields[i]) {
+				Some(field) if field.layout.method_set && is_single_value(field) && field.layout.optional.is_none() => (),
+				Some(_) => bail!(name.span(), "parse struct: copy_from field `{}` requires the set accessor", name),
+				None => bail!(name.span(), "parse struct: copy_from field `{}` not found", name),
+			}
+		}
+	}
+	if let (true, Some(field)) = (layout.freeze, fields.iter().find(|field| field.layout.method_atomic)) {
+		bail!(field.name.span(), "parse struct: the freeze argument cannot be combined with the atomic_ordered accessors of field `{}`", field.name);
+	}
+	for field in &fields {
+		let (tag, _) = match &field.layout.debug_when {
+			Some(condition) => condition,
+			None => continue,
+		};
+		match by_name.get(&tag.to_string()).map(|&i| &fields[i]) {
+			Some(tag) if tag.layout.debug_tag && tag.layout.method_get && is_single_value(tag) && tag.layout.optional.is_none() && is_int(&tag.ty.to_string()) && is_infallible(tag) => (),
+			Some(_) => bail!(tag.span(), "parse struct: the debug_when tag `{}` of field `{}` must be an integer field with the debug_tag argument and a get accessor", tag, field.name),
+			None => bail!(tag.span(), "parse struct: the debug_when tag `{}` of field `{}` not found", tag, field.name),
+		}
+	}
+	if layout.transparent {
+		let plain = |field: &Field| is_single_value(field) && field.layout.stored.is_none() && field.layout.convert.is_none() && field.layout.validate.is_none()
+			&& field.layout.clamp.is_none() && field.layout.narrow.is_none() && field.layout.sentinel.is_none() && field.layout.optional.is_none() && !field.layout.pad;
+		match &*fields {
+			[field] if plain(field) => (),
+			[field] => bail!(field.name.span(), "parse struct: the transparent argument requires a plain field stored as its own type, field `{}` is converted, optional or not a single value", field.name),
+			_ => bail!(argument_span(&layout, "transparent"), "parse struct: the transparent argument requires exactly one field at offset 0 spanning the whole structure, found {} fields", fields.len()),
+		}
+	}
+	if let Some(size) = int_literal_value(&layout.size).filter(|&size| size > i64::MAX as u128) {
+		bail!(argument_span(&layout, "size"), "parse struct_layout: the size of `{}` is {} bytes, which exceeds isize::MAX, the largest size of a Rust object", name, size);
+	}
+	if layout.coverage && fields.iter().any(|field| field.layout.dynamic) {
+		bail!(argument_span(&layout, "coverage"), "parse struct_layout: the coverage argument is not supported on structures with dynamic fields");
+	}
+	if is_atomic_storage(&layout) {
+		// Copying the atomics would not be atomic
+		if layout.byte_order {
+			bail!(argument_span(&layout, "byte_order"), "parse struct_layout: the byte_order argument copies the structure, which is not supported with atomic storage");
+		}
+		if let Some(derived) = derived.iter().find(|derived| matches!(derived, DerivedTrait::Copy | DerivedTrait::Clone)) {
+			bail!(argument_span(&layout, "storage"), "parse struct_layout: deriving {:?} is not supported with atomic storage", derived);
+		}
+	}
+	if layout.coverage && layout.huge {
+		bail!(argument_span(&layout, "coverage"), "parse struct_layout: the coverage argument is not supported on huge structures");
+	}
+	if layout.strict && layout.kind == LayoutKind::Union {
+		bail!(argument_span(&layout, "strict"), "parse struct: the strict argument asserts that the fields do not overlap, the fields of a union layout always do");
+	}
+	if layout.ffi_twin.is_some() {
+		if layout.kind == LayoutKind::Union {
+			bail!(argument_span(&layout, "ffi_twin"), "parse struct: the ffi_twin argument requires fields which do not overlap, union layouts are not supported");
+		}
+		for field in &fields {
+			let layout = &field.layout;
+			if layout.variant.is_some() || layout.dynamic || layout.tail.is_some() || layout.optional.is_some() || layout.stride.is_some() || layout.allow_drop || layout.manually_drop.is_some() || layout.bits.is_some() {
+				bail!(field.name.span(), "parse struct: the ffi_twin argument requires a fixed place for every field, field `{}` is a variant, dynamic, tail, optional, strided, dropped or bit field", field.name);
+			}
+		}
+	}
+	if !generics.names.is_empty() {
+		check_generic_structure(&layout, &derived, &name, &fields)?;
+	}
+	check_field_names(fields.iter().map(|field| &field.name))?;
+	// Optional fields only have their own accessors
+	let (optional, fields) = fields.into_iter().partition(|field| field.layout.optional.is_some());
+	let stru = Structure { attrs, derived, layout, vis, stru, name, generics, fields, optional };
+	check_structure_bytes(&stru)?;
+	Ok(stru)
+}
+// The constants of the fields are named after the uppercase field name, fields which only differ by case would share them
+fn check_field_names<'a>(names: impl Iterator<Item = &'a Ident>) -> ParseResult<()> {
+	let mut seen: Vec<&Ident> = Vec::new();
+	for name in names {
+		let upper = name.to_string().to_uppercase();
+		if let Some(other) = seen.iter().find(|other| other.to_string().to_uppercase() == upper) {
+			bail!(name.span(), "parse field: the field `{}` only differs by case from the field `{}`, their constants such as `{}_OFFSET` would collide", name, other, upper);
+		}
+		seen.push(name);
+	}
+	Ok(())
+}
+// The generated code which creates instances from zeroed or arbitrary bytes without unsafe code requires fields for which these are valid
+fn check_structure_bytes(stru: &Structure) -> ParseResult<()> {
+	const ZEROABLE: &str = "a structure for which zeroed bytes are valid, every field must be an integer, float, bool, char, raw pointer or an array of these, or the structure must declare `check(::struct_layout::Pod)`";
+	const POD: &str = "a structure for which any bytes are valid, every field must be an integer, float or an array of these, or the structure must declare `check(::struct_layout::Pod)`";
+	let layout = &stru.layout;
+	let (zeroable, pod) = (struct_zeroable(stru), struct_pod(stru));
+	for (name, enabled) in [("random", layout.random), ("parts", layout.parts), ("mirror", layout.mirror.is_some())] {
+		if enabled && !zeroable {
+			bail!(argument_span(layout, name), "parse struct: the {} argument requires {}", name, ZEROABLE);
+		}
+	}
+	if layout.dataview && !pod {
+		bail!(argument_span(layout, "dataview"), "parse struct: the dataview argument requires {}", POD);
+	}
+	// The getters and references of the extension trait read the fields from any bytes of the slice
+	if layout.slice_ext {
+		let reads = |field: &Field| field.layout.method_get || field.layout.method_ref || field.layout.method_mut;
+		if let Some(field) = stru.fields.iter().find(|field| is_single_value(field) && reads(field) && !is_pod_field(field) && !is_checked_pod(stru, field)) {
+			bail!(argument_span(layout, "slice_ext"), "parse struct: the slice_ext argument reads field `{}` from any bytes, which requires an integer, float or an array of these, or the structure must declare `check(::struct_layout::Pod)`", field.name);
+		}
+	}
+	// The column iterator reads the field from any bytes of the buffer
+	if let Some(field) = stru.fields.iter().find(|field| field.layout.method_column && !is_pod_field(field) && !is_checked_pod(stru, field)) {
+		let span = field.layout.arguments.iter().find(|arg| arg.to_string() == "column").map_or_else(|| field.name.span(), Ident::span);
+		bail!(span, "parse field: the column accessor reads field `{}` from any bytes, which requires an integer, float or an array of these, or the structure must declare `check(::struct_layout::Pod)`", field.name);
+	}
+	for derived in &stru.derived {
+		match derived {
+			DerivedTrait::QuickcheckArbitrary if !zeroable => bail!(stru.name.span(), "derive attribute: deriving {:?} requires {}", derived, ZEROABLE),
+			// The fields are set to their default value after zeroing the structure
+			DerivedTrait::Default if !zeroable => if let Some(field) = stru.fields.iter().chain(&stru.optional).find(|field| !is_checked_pod(stru, field) && !is_zeroable_field(field) && !is_default_set(stru, field)) {
+				bail!(field.name.span(), "derive attribute: deriving Default requires zeroed bytes to be a valid value of field `{}` or a set accessor without allow_drop to overwrite it", field.name);
+			},
+			DerivedTrait::Arbitrary if layout.arbitrary_fields && !zeroable => bail!(argument_span(layout, "arbitrary"), "derive attribute: deriving Arbitrary requires {}", ZEROABLE),
+			DerivedTrait::Arbitrary if !layout.arbitrary_fields && !pod => bail!(stru.name.span(), "derive attribute: deriving Arbitrary from the bytes requires {}, use `arbitrary = fields` to generate the fields instead", POD),
+			// The copies would both own the value of the field
+			DerivedTrait::Copy | DerivedTrait::Clone => if let Some(field) = stru.fields.iter().chain(&stru.optional).find(|field| field.layout.allow_drop) {
+				bail!(field.name.span(), "derive attribute: deriving {:?} is not supported with the allow_drop field `{}`, the copies would both own its value", derived, field.name);
+			},
+			_ => (),
+		}
+	}
+	Ok(())
+}
+// The field arguments which depend on the type of the field or the layout arguments
+fn check_structure_field(layout: &ExplicitLayout, field: &Field) -> ParseResult<()> {
+	check_field_stored(field)?;
+	if layout.portable && field.layout.size.is_none() && is_pointer_sized(field_storage_ty(field)) {
+		bail!(field.name.span(), "parse field: the size of field `{0}` of type `{1}` depends on the pointer width of the target, which changes the layout when cross compiling. \
+			Use a fixed size type such as `u64` or `u32`, assert the size with `#[field(.., size = <usize>)]` or acknowledge with the `portable = false` argument", field.name, field_storage_ty(field));
+	}
+	if field.layout.len.is_some() && field.ty.to_string() != "()" {
+		bail!(field.name.span(), "parse field: byte region field `{}` with a len argument must have the unit type `()`", field.name);
+	}
+	if field.layout.flatten && !(field.ty.0.len() == 1 && is_ident(&field.ty.0)) {
+		bail!(field.name.span(), "parse field: flattened field `{}` requires the name of an explicit structure in scope", field.name);
+	}
+	if field.layout.method_slice && array_ty(&field.ty).is_none() {
+		bail!(field.name.span(), "parse field: slice accessor of field `{}` requires an array type `[T; N]`", field.name);
+	}
+	if field.layout.method_str && !is_byte_array(&field.ty) {
+		bail!(field.name.span(), "parse field: str accessor of field `{}` requires a byte array type `[u8; N]`", field.name);
+	}
+	if field.layout.pstr.is_some() && !is_byte_array(&field.ty) {
+		bail!(field.name.span(), "parse field: pstr accessor of field `{}` requires a byte array type `[u8; N]`", field.name);
+	}
+	if field.layout.method_cstr && !is_byte_array(&field.ty) {
+		bail!(field.name.span(), "parse field: cstr accessor of field `{}` requires a byte array type `[u8; N]`", field.name);
+	}
+	if field.layout.method_wstr && !is_array_of(&field.ty, "u16") {
+		bail!(field.name.span(), "parse field: wstr accessor of field `{}` requires a code unit array type `[u16; N]`", field.name);
+	}
+	let get_set = is_single_value(field) && field.layout.method_get && field.layout.method_set;
+	if field.layout.optional.is_some() && !field.layout.groups.is_empty() {
+		bail!(field.name.span(), "parse field: optional field `{}` cannot be grouped", field.name);
+	}
+	if !field.layout.groups.is_empty() && !get_set {
+		bail!(field.name.span(), "parse field: grouped field `{}` requires the get and set accessors", field.name);
+	}
+	if layout.parts && !field.layout.pad && !(get_set && is_infallible(field)) {
+		bail!(field.name.span(), "parse field: the parts argument requires infallible get and set accessors, field `{}` does not have them", field.name);
+	}
+	if layout.init && !field.layout.pad && !(is_single_value(field) && field.layout.method_set && field.layout.optional.is_none()) {
+		bail!(field.name.span(), "parse field: the init argument requires the set accessor on a single value, field `{}` does not have it", field.name);
+	}
+	if field.layout.seq && !(get_set && field.layout.stored.is_none() && field.layout.optional.is_none() && is_int(&field.ty.to_string())) {
+		bail!(field.name.span(), "parse field: the sequence field `{}` must be an integer with the get and set accessors", field.name);
+	}
+	Ok(())
+}
+// Type parameters with their bounds, lifetimes and const parameters are not supported
+fn parse_generics(tokens: &mut vec::IntoIter<TokenTree>) -> ParseResult<Generics> {
+	let mut generics = Generics::default();
+	if !is_punct(tokens.as_slice(), '<') {
+		return Ok(generics);
+	}
+	let open = next_span(tokens.as_slice());
+	let _ = tokens.next();
+	let mut param = Vec::new();
+	let mut depth = 0;
+	loop {
+		let tt = match tokens.next() {
+			Some(tt) => tt,
+			None => bail!(open, "parse struct: unterminated generic parameters"),
+		};
+		match &tt {
+			// The arrow of a function type in a bound is not a closing bracket
+			TokenTree::Punct(punct) if punct.as_char() == '>' && !matches!(param.last(), Some(TokenTree::Punct(prev)) if prev.as_char() == '-') => {
+				if depth == 0 {
+					push_generic_param(&mut generics, param)?;
+					return Ok(generics);
+				}
+				depth -= 1;
+			},
+			TokenTree::Punct(punct) if punct.as_char() == '<' => depth += 1,
+			TokenTree::Punct(punct) if punct.as_char() == ',' && depth == 0 => {
+				push_generic_param(&mut generics, std::mem::take(&mut param))?;
+				continue;
+			},
+			_ => (),
+		}
+		param.push(tt);
+	}
+}
+fn push_generic_param(generics: &mut Generics, param: Vec<TokenTree>) -> ParseResult<()> {
+	let span = next_span(&param);
+	let name = match param.first() {
+		// Trailing comma
+		None => return Ok(()),
+		Some(TokenTree::Ident(ident)) if ident.to_string() == "const" => bail!(span, "parse struct: const generic parameters are not supported"),
+		Some(TokenTree::Ident(ident)) => ident.clone(),
+		Some(TokenTree::Punct(punct)) if punct.as_char() == '\'' => bail!(span, "parse struct: lifetime parameters are not supported, the fields are stored as bytes"),
+		Some(_) => bail!(span, "parse struct: expecting a type parameter"),
+	};
+	if param.iter().any(|tt| matches!(tt, TokenTree::Punct(punct) if punct.as_char() == '=')) {
+		bail!(span, "parse struct: defaults of type parameters are not supported");
+	}
+	if !generics.params.is_empty() {
+		emit_punct(&mut generics.params, ',');
+	}
+	generics.params.extend(param);
+	generics.names.push(name);
+	Ok(())
+}
+// The accessors of other arguments declare items which cannot refer to the type parameters
+const GENERIC_LAYOUT_ARGUMENTS: &[&str] = &["check", "packed", "bytes", "auto_ref", "send", "sync", "portable", "method_prefix", "method_suffix"];
+const GENERIC_FIELD_ARGUMENTS: &[&str] = &["get", "set", "ref", "mut", "pad"];
+fn check_generic_structure(layout: &ExplicitLayout, derived: &[DerivedTrait], name: &Ident, fields: &[Field]) -> ParseResult<()> {
+	if layout.kind != LayoutKind::Explicit {
+		bail!(name.span(), "parse struct: generic parameters are only supported by the explicit attribute");
+	}
+	for arg in &layout.arguments {
+		if !GENERIC_LAYOUT_ARGUMENTS.contains(&&*arg.to_string()) {
+			bail!(arg.span(), "parse struct_layout: the {} argument is not supported on generic structures", arg);
+		}
+	}
+	for derive in derived {
+		if let DerivedTrait::Arbitrary | DerivedTrait::QuickcheckArbitrary = derive {
+			bail!(name.span(), "parse struct: the {:?} derive is not supported on generic structures", derive);
+		}
+	}
+	for field in fields {
+		let layout = &field.layout;
+		if let Some(arg) = layout.arguments.iter().find(|arg| !GENERIC_FIELD_ARGUMENTS.contains(&&*arg.to_string())) {
+			bail!(arg.span(), "parse field_layout: the {} argument is not supported on generic structures", arg);
+		}
+		if layout.variant.is_some() || layout.dynamic || layout.optional.is_some() || !layout.groups.is_empty() || layout.inline.is_some() {
+			bail!(field.name.span(), "parse field: field `{}` of a generic structure must be a single value at a fixed offset", field.name);
+		}
+	}
+	Ok(())
+}
+// The offsets file is relative to the manifest directory
+fn offsets_path(path: &str) -> String {
+	match std::env::var("CARGO_MANIFEST_DIR") {
+		Ok(dir) => std::path::Path::new(&dir).join(path).to_string_lossy().into_owned(),
+		Err(_) => path.to_string(),
+	}
+}
+// Reads the flat table of offsets from a TOML or JSON file
+fn load_offsets(path: &str, span: Span) -> ParseResult<HashMap<String, String>> {
+	let full_path = offsets_path(path);
+	let text = match std::fs::read_to_string(&full_path) {
+		Ok(text) => text,
+		Err(err) => bail!(span, "parse struct_layout: cannot read offsets file `{}`: {}", full_path, err),
+	};
+	let mut offsets = HashMap::new();
+	for line in text.lines() {
+		let line = line.split('#').next().unwrap().trim();
+		if line.starts_with('[') {
+			continue;
+		}
+		for entry in line.split(',') {
+			let entry = entry.trim_matches(|c: char| c.is_whitespace() || c == '{' || c == '}');
+			if entry.is_empty() {
+				continue;
+			}
+			let (key, value) = match entry.find(['=', ':']) {
+				Some(i) => (entry[..i].trim().trim_matches('"'), entry[i + 1..].trim()),
+				None => bail!(span, "parse struct_layout: invalid entry `{}` in offsets file `{}`", entry, full_path),
+			};
+			let digits = value.replace('_', "");
+			let valid = match digits.strip_prefix("0x") {
+				Some(hex) => usize::from_str_radix(hex, 16).is_ok(),
+				None => digits.parse::<usize>().is_ok(),
+			};
+			if !valid {
+				bail!(span, "parse struct_layout: invalid offset `{}` of `{}` in offsets file `{}`", value, key, full_path);
+			}
+			// The first entry of a name wins
+			offsets.entry(key.to_string()).or_insert(digits);
+		}
+	}
+	Ok(offsets)
+}
+fn parse_structure_attrs(attrs: &mut Vec<Attribute>, repr_manual: bool) -> ParseResult<Vec<DerivedTrait>> {
+	let mut result = Vec::new();
+	for attr in std::mem::take(attrs) {
+		let tokens: Vec<TokenTree> = attr.meta.stream().into_iter().collect();
+		let mut tokens = tokens.into_iter();
+		let span = next_span(tokens.as_slice());
+		match tokens.as_slice().first() {
+			Some(TokenTree::Ident(ident)) => {
+				match &*ident.to_string() {
+					"derive" => {
+						let meta = match parse_meta(&mut tokens) {
+							Some(meta) => meta,
+							None => bail!(span, "parse struct: invalid derive syntax, expecting `#[derive(..)]`"),
+						};
+						if parse_end(&mut tokens).is_none() {
+							bail!(span, "parse struct: found extra tokens after derive attribute");
+						}
+						let tokens: Vec<TokenTree> = meta.args.stream().into_iter().collect();
+						let mut tokens = tokens.into_iter();
+						parse_structure_derive(&mut tokens, &mut result)?;
+					},
+					"doc" => attrs.push(attr),
+					// The repr is given by the user with `repr = manual`
+					"repr" | "cfg_attr" if repr_manual => attrs.push(attr),
+					s => bail!(span, "parse struct: unsupported attribute `{}`", s),
+				}
+			},
+			Some(_) => bail!(span, "parse struct: unknown attribute syntax"),
+			None => (),
+		}
+	}
+	canonical_derives(&mut result);
+	Ok(result)
+}
+// The derives are emitted alphabetically and only once regardless of how they are listed
+fn canonical_derives(derived: &mut Vec<DerivedTrait>) {
+	derived.sort_by_key(|derive| format!("{:?}", derive));
+	derived.dedup();
+}
+fn parse_structure_derive(tokens: &mut vec::IntoIter<TokenTree>, derived: &mut Vec<DerivedTrait>) -> ParseResult<()> {
+	while tokens.len() > 0 {
+		let span = tokens.as_slice()[0].span();
+		let ident = match parse_ident(tokens) {
+			Some(ident) => ident,
+			None => bail!(span, "derive attribute: expecting list of comma separated identifiers"),
+		};
+		let tr = ident.to_string();
+		match &*tr {
+			"Copy" => derived.push(DerivedTrait::Copy),
+			"Clone" => derived.push(DerivedTrait::Clone),
+			"Debug" => derived.push(DerivedTrait::Debug),
+			"Default" => derived.push(DerivedTrait::Default),
+			"Arbitrary" => {
+				if !cfg!(feature = "arbitrary") {
+					bail!(span, "derive attribute: Arbitrary requires the `arbitrary` feature");
+				}
+				derived.push(DerivedTrait::Arbitrary);
+			},
+			"LowerHex" => derived.push(DerivedTrait::LowerHex),
+			"UpperHex" => derived.push(DerivedTrait::UpperHex),
+			"PartialEq" => derived.push(DerivedTrait::PartialEq),
+			"Eq" => derived.push(DerivedTrait::Eq),
+			"Hash" => derived.push(DerivedTrait::Hash),
+			"PartialOrd" => derived.push(DerivedTrait::PartialOrd),
+			"Ord" => derived.push(DerivedTrait::Ord),
+			"QuickcheckArbitrary" => {
+				if !cfg!(feature = "quickcheck") {
+					bail!(span, "derive attribute: QuickcheckArbitrary requires the `quickcheck` feature");
+				}
+				derived.push(DerivedTrait::QuickcheckArbitrary);
+			},
+			s => bail!(span, "derive attribute: unsupported trait: `{}`", s),
+		}
+		if parse_comma(tokens).is_none() {
+			bail!(span, "derive attribute: expecting comma after {}", tr);
+		}
+	}
+	Ok(())
+}
+
+//----------------------------------------------------------------
+
+/// Explicit field layout attribute.
+///
+/// For more information, see the crate-level documentation.
+#[proc_macro_attribute]
+pub fn explicit(attributes: TokenStream, input: TokenStream) -> TokenStream {
+	let _expansion = Expansion;
+	let layout = parse_explicit_layout(attributes);
+	if layout.keep_fields && layout.errors.is_empty() {
+		return emit_result(emit_kept_structure(input, &layout));
+	}
+	expand_structure(input, layout)
+}
+// Parses the structure and emits its code, or the errors found while parsing
+fn expand_structure(input: TokenStream, layout: ExplicitLayout) -> TokenStream {
+	match parse_structure(input, layout) {
+		Ok(stru) => emit_structure(stru),
+		Err(errors) => errors.into_iter().collect(),
+	}
+}
+
+/// Expands a layout attribute and returns the generated code as a string literal.
+///
+/// The input is the attribute with its arguments followed by the item, eg. `explicit(size = 4, align = 4) struct Foo { .. }`.
+/// Used by the snapshot tests of the crate, the output is formatted by `snapshot_string` so it does not depend on the compiler.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __expand(input: TokenStream) -> TokenStream {
+	TokenTree::Literal(Literal::string(&snapshot_string(expand_fixture(input)))).into()
+}
+
+/// Expands a layout attribute and returns its compile errors as a string literal.
+///
+/// Every error is on its own line prefixed by the source text it is reported at, eg. `offest: parse field_layout: ..`.
+/// Used by the diagnostics tests of the crate, the input is the same as for `__expand`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __diagnostics(input: TokenStream) -> TokenStream {
+	fn collect(stream: TokenStream, out: &mut String) {
+		let tokens: Vec<TokenTree> = stream.into_iter().collect();
+		for i in 0..tokens.len() {
+			match (&tokens[i], tokens.get(i + 1), tokens.get(i + 2)) {
+				(TokenTree::Ident(ident), Some(TokenTree::Punct(bang)), Some(TokenTree::Group(group))) if ident.to_string() == "compile_error" && bang.as_char() == '!' => {
+					let literal = group.stream().to_string();
+					let message = literal[1..literal.len() - 1].replace("\\\"", "\"").replace("\\\\", "\\");
+					let at = ident.span().source_text().unwrap_or_else(|| String::from("<unknown>"));
+					out.push_str(&format!("{}: {}\n", at, message));
+				},
+				(TokenTree::Group(group), _, _) => collect(group.stream(), out),
+				_ => (),
+			}
+		}
+	}
+	let mut out = String::new();
+	collect(expand_fixture(input), &mut out);
+	TokenTree::Literal(Literal::string(&out)).into()
+}
+// Expands the fixture `attr(args) item` of the __expand and __diagnostics macros
+fn expand_fixture(input: TokenStream) -> TokenStream {
+	let tokens: Vec<TokenTree> = input.into_iter().collect();
+	let mut tokens = tokens.into_iter();
+	let (attr, args) = match (parse_ident(&mut tokens), parse_group(&mut tokens, Delimiter::Parenthesis)) {
+		(Some(attr), Some(args)) => (attr, args.stream()),
+		_ => return emit_result(Err((Span::call_site(), String::from("parse __expand: expecting a layout attribute with its arguments followed by the item")))),
+	};
+	let item: TokenStream = tokens.collect();
+	match &*attr.to_string() {
+		"explicit" => explicit(args, item),
+		"implicit" => implicit(args, item),
+		"union_layout" => union_layout(args, item),
+		_ => emit_result(Err((attr.span(), format!("parse __expand: expecting `explicit`, `implicit` or `union_layout`, found `{}`", attr)))),
+	}
+}
+// Prints the tokens with a statement or item per line, the spacing does not depend on the compiler
+fn snapshot_string(stream: TokenStream) -> String {
+	struct Printer {
+		out: String,
+		line: String,
+		depth: usize,
+		joint: bool,
+		// A closing brace ends the line unless it is followed by punctuation, eg. `};`
+		closed: bool,
+	}
+	impl Printer {
+		fn word(&mut self, text: &str) {
+			if self.closed && !matches!(text, ";" | "," | "." | ")" | "]") {
+				self.newline();
+			}
+			self.closed = false;
+			if !self.joint && !self.line.is_empty() && !self.line.ends_with(['(', '[']) {
+				self.line.push(' ');
+			}
+			self.line.push_str(text);
+			self.joint = false;
+		}
+		fn newline(&mut self) {
+			if !self.line.is_empty() {
+				for _ in 0..self.depth {
+					self.out.push('\t');
+				}
+				self.out.push_str(&self.line);
+				self.out.push('\n');
+				self.line.clear();
+			}
+			self.joint = false;
+		}
+		fn write(&mut self, stream: TokenStream, braced: bool) {
+			for tt in stream {
+				match tt {
+					TokenTree::Group(group) => match group.delimiter() {
+						Delimiter::Brace => {
+							self.word("{");
+							self.newline();
+							self.depth += 1;
+							self.write(group.stream(), true);
+							self.newline();
+							self.depth -= 1;
+							self.word("}");
+							self.closed = true;
+						},
+						Delimiter::None => self.write(group.stream(), braced),
+						delim => {
+							let (open, close) = if delim == Delimiter::Parenthesis { ("(", ')') } else { ("[", ']') };
+							self.word(open);
+							self.write(group.stream(), false);
+							self.closed = false;
+							self.line.push(close);
+							self.joint = false;
+						},
+					},
+					TokenTree::Punct(punct) => {
+						self.word(&punct.as_char().to_string());
+						self.joint = punct.spacing() == Spacing::Joint;
+						if punct.as_char() == ';' && braced {
+							self.newline();
+						}
+					},
+					tt => self.word(&tt.to_string()),
+				}
+			}
+		}
+	}
+	let mut printer = Printer { out: String::new(), line: String::new(), depth: 0, joint: false, closed: false };
+	printer.write(stream, true);
+	printer.newline();
+	printer.out
+}
+
+/// Implicit field layout attribute.
+///
+/// The fields are placed sequentially in declaration order, aligned to the alignment of their type.
+///
+/// For more information, see the crate-level documentation.
+#[proc_macro_attribute]
+pub fn implicit(attributes: TokenStream, input: TokenStream) -> TokenStream {
+	let _expansion = Expansion;
+	implicit_structure(attributes, input)
+}
+fn implicit_structure(attributes: TokenStream, input: TokenStream) -> TokenStream {
+	// The size is computed from the fields, the arguments keep their spans for errors
+	let mut args: Vec<TokenTree> = "size = 0,".parse::<TokenStream>().unwrap().into_iter().collect();
+	args.extend(attributes);
+	let mut layout = parse_explicit_layout(args.into_iter().collect());
+	layout.kind = LayoutKind::Implicit;
+	let mut stru = match parse_structure(input, layout) {
+		Ok(stru) => stru,
+		Err(errors) => return errors.into_iter().collect(),
+	};
+	let layout_const = implicit_layout_const(&stru);
+	let mut code = Vec::new();
+	emit_text(&mut code, &implicit_layout_item(&stru));
+	for (i, field) in stru.fields.iter_mut().enumerate() {
+		field.layout.offset = Expr(format!("{}.0[{}]", layout_const, i).parse().unwrap());
+	}
+	stru.layout.size = Expr(format!("{}.1", layout_const).parse().unwrap());
+	code.extend(emit_structure(stru));
+	code.into_iter().collect()
+}
+fn implicit_layout_const(stru: &Structure) -> String {
+	format!("__struct_layout_implicit_{}", stru.name)
+}
+// The offsets of the fields and the size of an implicit layout
+fn implicit_layout_item(stru: &Structure) -> String {
+	let mut fields = String::new();
+	for field in &stru.fields {
+		let zero = Expr("0".parse().unwrap());
+		let (size, align) = field_extent(field);
+		fields.push_str(&format!("({}, {}, {}, {}),", size, align,
+			field.layout.pad_before.as_ref().unwrap_or(&zero).0, field.layout.align_to.as_ref().unwrap_or(&zero).0));
+	}
+	format!("#[doc(hidden)] #[allow(non_upper_case_globals)] const {}: ([usize; {}], usize) = ::struct_layout::__implicit_layout([{}], {}, {});",
+		implicit_layout_const(stru), stru.fields.len(), fields, stru.layout.packed, stru.layout.align.0)
+}
+/// Verifies the layout of a regular structure.
+///
+/// The size and alignment are given by a `#[layout(size = <usize>, align = <usize>)]` attribute and the offsets by `#[field(offset = <usize>)]` attributes on the fields.
+/// The structure is left unchanged and its layout is statically asserted to match.
+#[proc_macro_derive(ExplicitLayout, attributes(layout, field))]
+pub fn derive_explicit_layout(input: TokenStream) -> TokenStream {
+	let _expansion = Expansion;
+	emit_result(derive_explicit_layout_item(input))
+}
+fn derive_explicit_layout_item(input: TokenStream) -> ParseResult<TokenStream> {
+	let tokens: Vec<TokenTree> = input.into_iter().collect();
+	let mut tokens = tokens.into_iter();
+	let attrs = parse_attrs(&mut tokens);
+	let mut layout = None;
+	for attr in &attrs {
+		let meta: Vec<TokenTree> = attr.meta.stream().into_iter().collect();
+		let mut meta = meta.into_iter();
+		if is_keyword(meta.as_slice(), "layout") {
+			let span = next_span(meta.as_slice());
+			match parse_meta(&mut meta) {
+				Some(meta) => layout = Some(parse_explicit_layout(meta.args.stream())),
+				None => bail!(span, "parse struct: invalid layout attribute syntax, expecting `#[layout(size = <usize>, align = <usize>)]`"),
+			}
+		}
+	}
+	let _ = parse_vis(&mut tokens);
+	let stru = match parse_keyword(&mut tokens, "struct") {
+		Some(ident) => ident,
+		None => bail!(next_span(tokens.as_slice()), "parse struct: the ExplicitLayout derive is only allowed on structs"),
+	};
+	let name = match parse_ident(&mut tokens) {
+		Some(ident) => ident,
+		None => bail!(stru.span(), "parse struct: struct name identifier not found"),
+	};
+	let layout = match layout {
+		Some(layout) => layout,
+		None => bail!(name.span(), "parse struct: the ExplicitLayout derive requires a `#[layout(size = <usize>, align = <usize>)]` attribute"),
+	};
+	if !layout.errors.is_empty() {
+		return Ok(layout.errors.into_iter().collect());
+	}
+	if is_punct(tokens.as_slice(), '<') {
+		bail!(next_span(tokens.as_slice()), "parse struct: generic parameters not supported");
+	}
+	let group = match parse_group(&mut tokens, Delimiter::Brace) {
+		Some(group) => group,
+		None => bail!(name.span(), "parse struct: tuple syntax not supported, the ExplicitLayout derive requires braces to declare the fields"),
+	};
+	Ok(verify_layout(&name, &layout, &group, &mut Vec::new())?.parse().unwrap())
+}
+// Asserts the size, alignment and annotated field offsets of a regular structure and adds a constant for every annotated offset
+// The fields without their field attributes are collected in `kept`
+fn verify_layout(name: &Ident, layout: &ExplicitLayout, group: &Group, kept: &mut Vec<TokenTree>) -> ParseResult<String> {
+	// One assertion per check so the error tells which one failed
+	let mut checks = format!("const _: () = assert!(::core::mem::size_of::<{0}>() == {1}, {2:?});
+		const _: () = assert!(::core::mem::align_of::<{0}>() == {3}, {4:?});",
+		name, layout.size.0, format!("the size of `{}` is not {}", name, layout.size.0), layout.align.0, format!("the alignment of `{}` is not {}", name, layout.align.0));
+	let mut consts = String::new();
+	let fields: Vec<TokenTree> = group.stream().into_iter().collect();
+	let mut fields = fields.into_iter();
+	let mut names = Vec::new();
+	while fields.len() > 0 {
+		let mut attrs = parse_attrs(&mut fields);
+		// Other attributes are left alone as the structure is not modified
+		let mut field_attrs: Vec<Attribute> = Vec::new();
+		attrs.retain(|attr| {
+			let keep = !is_keyword(&attr.meta.stream().into_iter().collect::<Vec<_>>(), "field");
+			if !keep {
+				field_attrs.push(attr.clone());
+			}
+			keep
+		});
+		emit_attrs(kept, &attrs);
+		let vis = parse_vis(&mut fields);
+		emit_vis(kept, &vis);
+		let field = match parse_ident(&mut fields) {
+			Some(ident) => ident,
+			None => bail!(next_span(fields.as_slice()), "parse field: expecting field identifier not found"),
+		};
+		if parse_punct(&mut fields, ':').is_none() {
+			bail!(field.span(), "parse field: colon must follow field identifier");
+		}
+		let field_layout = parse_field_attrs(&mut field_attrs, LayoutKind::Explicit, field.span())?;
+		let ty = parse_ty(&mut fields);
+		kept.push(TokenTree::Ident(field.clone()));
+		names.push(field.clone());
+		emit_punct(kept, ':');
+		emit_ty(kept, &ty);
+		emit_punct(kept, ',');
+		if let Some(field_layout) = field_layout {
+			let offset = &field_layout.offset.0;
+			checks.push_str(&format!("const _: () = assert!(::core::mem::offset_of!({}, {}) == {}, {:?});", name, field, offset, format!("field `{}` of `{}` is not at offset {}", field, name, offset)));
+			if let Some(size) = &field_layout.size {
+				checks.push_str(&format!("const _: () = assert!(::core::mem::size_of::<{}>() == {}, {:?});", ty, size.0, format!("field `{}` of `{}` is not {} bytes", field, name, size.0)));
+			}
+			let vis: TokenStream = layout.companion_vis.as_ref().unwrap_or(&vis).0.iter().cloned().collect();
+			consts.push_str(&format!("{} const {}_OFFSET: usize = {};", vis, field.to_string().to_uppercase(), offset));
+		}
+	}
+	check_field_names(names.iter())?;
+	Ok(format!("
+		{}
+		impl {} {{ {} }}
+		unsafe impl ::struct_layout::ExplicitLayout for {1} {{ const SIZE: usize = {}; const ALIGN: usize = {}; }}",
+		checks, name, consts, layout.size.0, layout.align.0))
+}
+/// Default arguments for the layout attributes in a module.
+///
+/// Every `#[struct_layout::explicit]`, `#[struct_layout::implicit]` and `#[struct_layout::union_layout]` attribute in the module inherits the arguments it does not specify.
+#[proc_macro_attribute]
+pub fn defaults(attributes: TokenStream, input: TokenStream) -> TokenStream {
+	let _expansion = Expansion;
+	let defaults = split_args(attributes);
+	let tokens: Vec<TokenTree> = input.into_iter().collect();
+	let is_mod = tokens.iter().any(|tt| match tt { TokenTree::Ident(ident) => ident.to_string() == "mod", _ => false });
+	if !is_mod || !matches!(tokens.last(), Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace) {
+		return emit_result(Err((next_span(&tokens), String::from("parse defaults: the defaults attribute is only allowed on inline modules"))));
+	}
+	splice_defaults(tokens, &defaults).into_iter().collect()
+}
+// Splits arguments on the commas at the top level
+fn split_args(tokens: TokenStream) -> Vec<Vec<TokenTree>> {
+	let mut args = vec![Vec::new()];
+	for tt in tokens {
+		match &tt {
+			TokenTree::Punct(punct) if punct.as_char() == ',' => args.push(Vec::new()),
+			_ => args.last_mut().unwrap().push(tt),
+		}
+	}
+	args.retain(|arg| !arg.is_empty());
+	args
+}
+fn arg_key(arg: &[TokenTree]) -> String {
+	match arg.first() {
+		Some(TokenTree::Ident(ident)) => ident.to_string(),
+		_ => String::new(),
+	}
+}
+fn splice_defaults(tokens: Vec<TokenTree>, defaults: &[Vec<TokenTree>]) -> Vec<TokenTree> {
+	let mut result = Vec::new();
+	let mut tokens = tokens.into_iter().peekable();
+	while let Some(tt) = tokens.next() {
+		match tt {
+			// Rewrite the arguments of the layout attributes
+			TokenTree::Punct(punct) if punct.as_char() == '#' => {
+				result.push(TokenTree::Punct(punct));
+				if let Some(TokenTree::Group(group)) = tokens.peek() {
+					if group.delimiter() == Delimiter::Bracket {
+						let group = group.clone();
+						tokens.next();
+						let mut inner = Group::new(Delimiter::Bracket, splice_attribute(group.stream(), defaults));
+						inner.set_span(group.span());
+						result.push(TokenTree::Group(inner));
+					}
+				}
+			},
+			// Recurse into the module bodies and nested modules
+			TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+				let stream = splice_defaults(group.stream().into_iter().collect(), defaults);
+				let mut inner = Group::new(Delimiter::Brace, stream.into_iter().collect());
+				inner.set_span(group.span());
+				result.push(TokenTree::Group(inner));
+			},
+			tt => result.push(tt),
+		}
+	}
+	result
+}
+fn splice_attribute(meta: TokenStream, defaults: &[Vec<TokenTree>]) -> TokenStream {
+	let tokens: Vec<TokenTree> = meta.clone().into_iter().collect();
+	let path: Vec<String> = tokens.iter().map(|tt| tt.to_string()).collect();
+	let path: Vec<&str> = path.iter().map(|s| &**s).collect();
+	let (args, rest) = match &path[..] {
+		[":", ":", "struct_layout", ":", ":", "explicit", ..] | [":", ":", "struct_layout", ":", ":", "implicit", ..] | [":", ":", "struct_layout", ":", ":", "union_layout", ..] => (tokens.get(6), 6),
+		["struct_layout", ":", ":", "explicit", ..] | ["struct_layout", ":", ":", "implicit", ..] | ["struct_layout", ":", ":", "union_layout", ..] => (tokens.get(4), 4),
+		_ => return meta,
+	};
+	let args = match args {
+		Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => split_args(group.stream()),
+		None => Vec::new(),
+		_ => return meta,
+	};
+	// The size and alignment arguments must come first
+	let mut merged: Vec<&Vec<TokenTree>> = Vec::new();
+	for key in &["size", "align"] {
+		if let Some(arg) = args.iter().chain(defaults.iter()).find(|arg| arg_key(arg) == *key) {
+			merged.push(arg);
+		}
+	}
+	merged.extend(args.iter().filter(|arg| !["size", "align"].contains(&&*arg_key(arg))));
+	merged.extend(defaults.iter().filter(|arg| !["size", "align"].contains(&&*arg_key(arg)) && !args.iter().any(|other| arg_key(other) == arg_key(arg))));
+	let mut stream: Vec<TokenTree> = tokens[..rest].to_vec();
+	stream.push(TokenTree::Group(Group::new(Delimiter::Parenthesis, {
+		let mut args = Vec::new();
+		for arg in merged {
+			args.extend(arg.iter().cloned());
+			emit_punct(&mut args, ',');
+		}
+		args.into_iter().collect()
+	})));
+	stream.into_iter().collect()
+}
+// Size and alignment expressions of the region occupied by the field
+fn field_extent(field: &Field) -> (String, String) {
+	let ty = field_storage_ty(field);
+	let (size, align) = (format!("::core::mem::size_of::<{}>()", ty), format!("::core::mem::align_of::<{}>()", ty));
+	let layout = &field.layout;
+	if let Some((rows, cols)) = &layout.dims {
+		(format!("({}) * ({}) * {}", rows.0, cols.0, size), align)
+	}
+	else if let (Some(count), Some(stride)) = (&layout.count, &layout.stride) {
+		(format!("(({}) - 1) * ({}) + {}", count.0, stride.0, size), align)
+	}
+	else if let Some(count) = &layout.count {
+		(format!("({}) * {}", count.0, size), align)
+	}
+	else if layout.tail.is_some() {
+		("0".to_string(), align)
+	}
+	else if let Some(len) = &layout.len {
+		(len.0.to_string(), "1".to_string())
+	}
+	else {
+		(size, align)
+	}
+}
+
+/// Union layout attribute.
+///
+/// Same as the explicit attribute except the fields are overlapping views of the structure starting at offset zero by default.
+///
+/// For more information, see the crate-level documentation.
+#[proc_macro_attribute]
+pub fn union_layout(attributes: TokenStream, input: TokenStream) -> TokenStream {
+	let _expansion = Expansion;
+	let mut layout = parse_explicit_layout(attributes);
+	layout.kind = LayoutKind::Union;
+	expand_structure(input, layout)
+}
+
+/// Declares an explicit layout from a C struct definition.
+///
+/// The string literal holds the definition, optionally preceded by attributes and a visibility and followed by the arguments of the explicit attribute.
+///
+/// For more information, see the crate-level documentation.
+#[proc_macro]
+pub fn from_c(input: TokenStream) -> TokenStream {
+	let _expansion = Expansion;
+	let tokens: Vec<TokenTree> = input.into_iter().collect();
+	let mut tokens = tokens.into_iter();
+	let attrs = parse_attrs(&mut tokens);
+	let vis = parse_vis(&mut tokens);
+	let span = next_span(tokens.as_slice());
+	let lit = match tokens.next() {
+		Some(TokenTree::Literal(lit)) => lit,
+		_ => return emit_result(Err((span, String::from("parse from_c: expecting a string literal with the C struct definition")))),
+	};
+	let text = match string_literal_value(&lit.to_string()) {
+		Some(text) => text,
+		None => return emit_result(Err((span, String::from("parse from_c: expecting a string literal with the C struct definition")))),
+	};
+	let c = match parse_c_struct(&text) {
+		Ok(c) => c,
+		// Points at the string literal as its contents have no spans of their own
+		Err(err) => return emit_result(Err((lit.span(), format!("from_c: {} at line {}, column {} of the definition", err.message, err.line, err.col)))),
+	};
+	// The size and alignment default to those of the C struct
+	let _ = parse_comma(&mut tokens);
+	let mut layout_arg = |name: &str| match tokens.as_slice() {
+		[TokenTree::Ident(ident), ..] if ident.to_string() == name => parse_kv(&mut tokens).map(|kv| kv.value.0.to_string()),
+		_ => None,
+	};
+	let size = layout_arg("size").unwrap_or_else(|| c.size.to_string());
+	let align = layout_arg("align").unwrap_or_else(|| c.align.to_string());
+	let rest: TokenStream = tokens.collect();
+	let layout = parse_explicit_layout(format!("size = {}, align = {}, {}", size, align, rest).parse().unwrap());
+	let vis_s: TokenStream = vis.0.iter().cloned().collect();
+	let fields: String = c.fields.iter().map(|field| format!("#[field(offset = {:#x})] {} {}: {},", field.offset, vis_s, field.name, field.ty)).collect();
+	let mut input = Vec::new();
+	emit_attrs(&mut input, &attrs);
+	emit_vis(&mut input, &vis);
+	emit_text(&mut input, &format!("struct {} {{ {} }}", c.name, fields));
+	expand_structure(input.into_iter().collect(), layout)
+}
+
+/// Dispatches a byte buffer to a handler by its length.
+///
+/// Every arm names an explicit structure and the handler called with the structure created from the bytes if the length equals its size.
+/// The `_` arm is called with the bytes otherwise.
+///
+/// For more information, see the crate-level documentation.
+#[proc_macro]
+pub fn dispatch_by_size(input: TokenStream) -> TokenStream {
+	let _expansion = Expansion;
+	emit_result(dispatch_by_size_expr(input))
+}
+fn dispatch_by_size_expr(input: TokenStream) -> ParseResult<TokenStream> {
+	let tokens: Vec<TokenTree> = input.into_iter().collect();
+	let mut tokens = tokens.into_iter();
+	let buf = parse_expr(&mut tokens);
+	let span = next_span(tokens.as_slice());
+	let arms = match parse_group(&mut tokens, Delimiter::Brace) {
+		Some(group) if tokens.len() == 0 => group,
+		_ => bail!(span, "parse dispatch_by_size: expecting a buffer followed by braced arms `<type> => <handler>` and a last arm `_ => <fallback>`"),
+	};
+	let span = arms.span_close();
+	let arms: Vec<TokenTree> = arms.stream().into_iter().collect();
+	let mut arms = arms.into_iter();
+	let mut types = Vec::new();
+	let mut handlers = Vec::new();
+	let mut fallback = None;
+	while arms.len() > 0 {
+		let at = next_span(arms.as_slice());
+		// The type is everything up to the fat arrow
+		let mut ty = Vec::new();
+		loop {
+			match arms.next() {
+				Some(TokenTree::Punct(punct)) if punct.as_char() == '=' && is_punct(arms.as_slice(), '>') => {
+					let _ = arms.next();
+					break;
+				},
+				Some(tt) => ty.push(tt),
+				None => bail!(at, "parse dispatch_by_size: expecting `=>` after `{}`", Type(ty)),
+			}
+		}
+		let handler = parse_expr(&mut arms);
+		if handler.0.is_empty() {
+			bail!(at, "parse dispatch_by_size: expecting a handler after `{} =>`", Type(ty));
+		}
+		match &*ty {
+			[TokenTree::Ident(ident)] if ident.to_string() == "_" => fallback = Some(handler),
+			_ if fallback.is_some() => bail!(at, "parse dispatch_by_size: the `_` arm must be the last arm"),
+			_ => {
+				types.push(Type(ty));
+				handlers.push(handler);
+			},
+		}
+	}
+	let fallback = match fallback {
+		Some(fallback) => fallback,
+		None => bail!(span, "parse dispatch_by_size: expecting a fallback arm `_ => <fallback>` called with the bytes of any other length"),
+	};
+	let mut code = String::from("{");
+	// Sizes are only known to the compiler, the arms are checked pairwise
+	for i in 0..types.len() {
+		for j in i + 1..types.len() {
+			code.push_str(&format!("const _: () = ::core::assert!(<{0} as ::struct_layout::ExplicitLayout>::SIZE != <{1} as ::struct_layout::ExplicitLayout>::SIZE,
+				\"dispatch_by_size: `{0}` and `{1}` have the same size\");", types[i], types[j]));
+		}
+	}
+	code.push_str(&format!("let bytes: &[u8] = ::core::convert::AsRef::<[u8]>::as_ref(&{}); match bytes.len() {{", buf.0));
+	for (ty, handler) in types.iter().zip(&handlers) {
+		code.push_str(&format!("<{0} as ::struct_layout::ExplicitLayout>::SIZE => ({1})(<{0}>::from_bytes(match ::core::convert::TryInto::try_into(bytes) {{
+			::core::result::Result::Ok(bytes) => bytes,
+			::core::result::Result::Err(_) => ::core::unreachable!(),
+		}})),", ty, handler.0));
+	}
+	code.push_str(&format!("_ => ({})(bytes), }} }}", fallback.0));
+	Ok(code.parse().unwrap())
+}
+
+/// Asserts the layout of a structure declared elsewhere.
+///
+/// The type is followed by a colon, the optional `size` and `align` arguments and the fields in braces as `name: Type @ offset`.
+///
+/// For more information, see the crate-level documentation.
+#[proc_macro]
+pub fn check_layout(input: TokenStream) -> TokenStream {
+	let _expansion = Expansion;
+	emit_result(check_layout_item(input))
+}
+fn check_layout_item(input: TokenStream) -> ParseResult<TokenStream> {
+	// Collects the tokens up to a punct outside of angle brackets, a colon does not stop at path separators
+	fn parse_until(tokens: &mut vec::IntoIter<TokenTree>, stop: char) -> Option<Type> {
+		let mut ty: Vec<TokenTree> = Vec::new();
+		let mut depth = 0;
+		loop {
+			let tt = tokens.next()?;
+			if let TokenTree::Punct(punct) = &tt {
+				let joined = match ty.last() {
+					Some(TokenTree::Punct(prev)) => prev.as_char() == ':' && prev.spacing() == Spacing::Joint,
+					_ => false,
+				};
+				match punct.as_char() {
+					chr if chr == stop && depth == 0 && !(stop == ':' && (joined || punct.spacing() == Spacing::Joint)) => return Some(Type(ty)),
+					'<' => depth += 1,
+					'>' if !is_arrow(ty.last()) => depth -= 1,
+					_ => (),
+				}
+			}
+			ty.push(tt);
+		}
+	}
+	let tokens: Vec<TokenTree> = input.into_iter().collect();
+	let span = next_span(&tokens);
+	let mut tokens = tokens.into_iter();
+	let ty = match parse_until(&mut tokens, ':') {
+		Some(ty) if !ty.0.is_empty() => ty,
+		_ => bail!(span, "parse check_layout: expecting a type followed by `:`"),
+	};
+	let ty_s = type_string(&ty);
+	let mut asserts = String::new();
+	let mut fields = None;
+	let mut checked = (false, false);
+	while tokens.len() > 0 {
+		let at = next_span(tokens.as_slice());
+		if let Some(kv) = parse_kv(&mut tokens) {
+			let what = match &*kv.ident.to_string() {
+				"size" if !checked.0 => { checked.0 = true; "size" },
+				"align" if !checked.1 => { checked.1 = true; "align" },
+				"size" | "align" => bail!(kv.ident.span(), "parse check_layout: duplicate `{}` argument", kv.ident),
+				_ => bail!(kv.ident.span(), "parse check_layout: unknown argument `{}`, expecting `size`, `align` or `fields`", kv.ident),
+			};
+			asserts.push_str(&format!("::core::assert!(::core::mem::{0}_of::<{1}>() == ({2}), {3:?});", what, ty, kv.value.0,
+				format!("check_layout: the {} of `{}` is not `{}`", what, ty_s, type_string(&Type(kv.value.0.clone().into_iter().collect())))));
+			continue;
+		}
+		if parse_keyword(&mut tokens, "fields").is_some() && fields.is_none() {
+			match parse_group(&mut tokens, Delimiter::Brace) {
+				Some(group) => fields = Some(group),
+				None => bail!(at, "parse check_layout: expecting the fields in braces after `fields`"),
+			}
+			if parse_comma(&mut tokens).is_none() {
+				bail!(at, "parse check_layout: expecting a comma after the fields");
+			}
+			continue;
+		}
+		bail!(at, "parse check_layout: expecting `size = <expr>`, `align = <expr>` or `fields` followed by braced `<name>: <type> @ <offset>` fields");
+	}
+	let fields: Vec<TokenTree> = fields.map(|group| group.stream().into_iter().collect()).unwrap_or_default();
+	let mut fields = fields.into_iter();
+	let mut items = Vec::new();
+	while fields.len() > 0 {
+		let at = next_span(fields.as_slice());
+		let name = match (parse_ident(&mut fields), parse_punct(&mut fields, ':')) {
+			(Some(name), Some(_)) => name,
+			_ => bail!(at, "parse check_layout: expecting a field as `<name>: <type> @ <offset>`"),
+		};
+		let field_ty = match parse_until(&mut fields, '@') {
+			Some(field_ty) if !field_ty.0.is_empty() => field_ty,
+			_ => bail!(name.span(), "parse check_layout: expecting the type of field `{}` followed by `@ <offset>`", name),
+		};
+		let offset = parse_expr(&mut fields);
+		if offset.0.is_empty() {
+			bail!(name.span(), "parse check_layout: expecting the offset of field `{}` after `@`", name);
+		}
+		// The type of the field is checked through a raw pointer which also works for packed structures, errors point at the field name
+		emit_text_spanned(&mut items, &format!("::core::assert!(::core::mem::offset_of!({0}, {1}) == ({2}), {3:?});", ty, name, offset.0,
+			format!("check_layout: field `{}` of `{}` is not at offset `{}`", name, ty_s, type_string(&Type(offset.0.clone().into_iter().collect())))), name.span());
+		emit_text_spanned(&mut items, &format!("#[allow(dead_code)] fn {1}(value: &{0}) -> *const {2} {{ ::core::ptr::addr_of!(value.{1}) }}", ty, name, field_ty), name.span());
+	}
+	let mut code = Vec::new();
+	emit_static(&mut code, "const _: () =");
+	emit_group_f(&mut code, Delimiter::Brace, |body| {
+		emit_text(body, &asserts);
+		body.extend(items);
+	});
+	emit_static(&mut code, ";");
+	Ok(code.into_iter().collect())
+}
+
+fn emit_structure(stru: Structure) -> TokenStream {
+	// Emit the code
+	let mut code: Vec<TokenTree> = Vec::new();
+	emit_attrs(&mut code, &stru.attrs);
+	if stru.layout.doc_layout {
+		emit_doc_layout(&mut code, &stru);
+	}
+	if stru.layout.repr_manual {
+		// Checked against the layout below
+	}
+	else if stru.layout.align_arms.is_empty() {
+		emit_text(&mut code, &format!("#[repr(C, align({}))]", stru.layout.align.0));
+	}
+	else {
+		for (pred, align) in &stru.layout.align_arms {
+			emit_text(&mut code, &format!("#[cfg_attr({}, repr(C, align({})))]", pred, align));
+		}
+	}
+	if stru.layout.wasm_bindgen.is_some() {
+		emit_static(&mut code, "#[cfg_attr(target_arch = \"wasm32\", ::wasm_bindgen::prelude::wasm_bindgen)]");
+	}
+	emit_vis(&mut code, &stru.vis);
+	code.push(TokenTree::Ident(stru.stru.clone()));
+	code.push(TokenTree::Ident(stru.name.clone()));
+	if !stru.generics.names.is_empty() {
+		emit_punct(&mut code, '<');
+		code.extend(stru.generics.params.iter().cloned());
+		emit_punct(&mut code, '>');
+	}
+	let storage = storage_array(&stru.layout);
+	match marker_ty(&stru) {
+		Some(marker) => emit_text(&mut code, &format!("({}, {});", storage, marker)),
+		None => emit_text(&mut code, &format!("({});", storage)),
+	}
+	if !stru.layout.send && stru.layout.sync {
+		// The marker opts out of both, only the pointer semantics are not thread safe
+		emit_text(&mut code, &format!("unsafe impl{} ::core::marker::Sync for {} {{}}", impl_generics(&stru), self_ty(&stru)));
+	}
+	let mut items = ItemNames::default();
+	let mut errors = Vec::new();
+	let origin = format!("the structure `{}`", stru.name);
+	emit_impl_f(&mut code, &stru, |body| {
+		if stru.layout.bytes {
+			emit_from_bytes(body, &stru);
+			emit_as_bytes(body, &stru);
+		}
+		emit_constructors(body, &stru);
+		emit_static(body, "/// Size of the structure in bytes.");
+		emit_static(body, "#[allow(dead_code)]");
+		emit_companion_vis(body, &stru);
+		emit_text(body, &format!("const SIZE: usize = {};", stru.layout.size.0));
+		emit_static(body, "/// Alignment of the structure in bytes.");
+		emit_static(body, "#[allow(dead_code)]");
+		emit_companion_vis(body, &stru);
+		emit_text(body, &format!("const ALIGN: usize = {};", stru.layout.align.0));
+		if !stru.generics.names.is_empty() {
+			let blocks: String = stru.fields.iter().map(|field| field_asserts(&stru, field)).collect();
+			let asserts = format!("#[doc(hidden)] const __STRUCT_LAYOUT_ASSERTS: () = {{ use ::core::mem; {} }};", blocks);
+			body.extend(inline_field_items(asserts.parse::<TokenStream>().unwrap().into_iter().collect()));
+		}
+		items.register(body, &origin, stru.name.span());
+		for field in &stru.fields {
+			let start = body.len();
+			emit_field(body, &stru, field);
+			if !stru.generics.names.is_empty() {
+				let accessors = body.split_off(start);
+				body.extend(inline_field_items(accessors));
+			}
+			items.register(&body[start..], &format!("field `{}`", field.name), field.name.span());
+		}
+		for field in &stru.optional {
+			let start = body.len();
+			emit_static(body, "#[doc(hidden)]");
+			emit_vis(body, &field.vis);
+			emit_text(body, &format!("const {}: usize = {};", field_offset_const(field), field.layout.offset.0));
+			emit_offset_const(body, &stru, field);
+			items.register(&body[start..], &format!("field `{}`", field.name), field.name.span());
+		}
+		let start = body.len();
+		if stru.layout.visit {
+			emit_visit_fields(body, &stru);
+		}
+		if stru.layout.display_field {
+			emit_display_field(body, &stru);
+		}
+		if stru.layout.export_json {
+			emit_export_json(body, &stru);
+		}
+		if stru.layout.apply_patches {
+			emit_apply_patch(body, &stru);
+		}
+		// The offsets of dynamic fields are not known at compile time
+		// The table with an entry per byte is too large to evaluate for huge structures
+		// The table is a constant item which cannot depend on the type parameters
+		if !(stru.fields.iter().any(|field| field.layout.dynamic) || stru.layout.huge || !stru.generics.names.is_empty()) {
+			emit_clear_padding(body, &stru);
+			if stru.layout.coverage {
+				emit_coverage(body, &stru);
+			}
+		}
+		if stru.layout.modify {
+			// Modifies a copy of the whole structure and stores it back at once
+			emit_static(body, "/// Modifies a copy of the structure and stores it back at once.");
+			emit_vis(body, &stru.vis);
+			emit_static(body, "fn modify_all<F: FnOnce(&mut Self)>(&mut self, f: F) -> &mut Self {
+				let mut copy = unsafe { ::core::ptr::read(self) };
+				f(&mut copy);
+				unsafe { ::core::ptr::write(self, copy); }
+				self
+			}");
+		}
+		if stru.layout.escape_hatch {
+			// Access any type at any offset, bypassing the check trait
+			emit_static(body, "/// Reads a value of any type at the given offset.
+				///
+				/// # Safety
+				///
+				/// The value must fit in the structure and its bytes must be a valid value of the type.");
+			emit_vis(body, &stru.vis);
+			emit_static(body, "unsafe fn read_at<T: ::core::marker::Copy>(&self, offset: usize) -> T {
+					debug_assert!(offset <= ::core::mem::size_of::<Self>() && ::core::mem::size_of::<Self>() - offset >= ::core::mem::size_of::<T>(), \"read_at out of bounds\");
+					unsafe { ::core::ptr::read_unaligned((self as *const Self as *const u8).add(offset) as *const T) }
+				}");
+			emit_static(body, "/// Writes a value of any type at the given offset.
+				///
+				/// # Safety
+				///
+				/// The value must fit in the structure and must not break the invariants of the fields it overwrites.");
+			emit_vis(body, &stru.vis);
+			emit_static(body, "unsafe fn write_at<T: ::core::marker::Copy>(&mut self, offset: usize, value: T) {
+					debug_assert!(offset <= ::core::mem::size_of::<Self>() && ::core::mem::size_of::<Self>() - offset >= ::core::mem::size_of::<T>(), \"write_at out of bounds\");
+					unsafe { ::core::ptr::write_unaligned((self as *mut Self as *mut u8).add(offset) as *mut T, value); }
+				}");
+		}
+		items.register(&body[start..], &origin, stru.name.span());
+	});
+	emit_text(&mut code, &format!("unsafe impl{} ::struct_layout::ExplicitLayout for {} {{ const SIZE: usize = {}; const ALIGN: usize = {}; }}",
+		impl_generics(&stru), self_ty(&stru), stru.layout.size.0, stru.layout.align.0));
+	// The structure is stored as plain bytes, unless it is mutated through shared references
+	if !stru.fields.iter().any(|field| field.layout.method_atomic) {
+		emit_text(&mut code, &format!("unsafe impl{} ::struct_layout::Freeze for {} {{}}", impl_generics(&stru), self_ty(&stru)));
+	}
+	// The asserts of generic structures are evaluated by the accessors for the given type parameters
+	if stru.generics.names.is_empty() {
+		emit_field_asserts(&mut code, &stru);
+	}
+	if stru.layout.repr_manual {
+		emit_text(&mut code, &format!("const _: () = {{
+			assert!(::core::mem::align_of::<{0}>() == {1}, \"struct_layout: the repr of `{0}` does not match the alignment of the layout\");
+			assert!(::core::mem::size_of::<{0}>() == usize::div_ceil({2}, {1}) * ({1}), \"struct_layout: the repr of `{0}` does not match the size of the layout\");
+		}};", stru.name, stru.layout.align.0, stru.layout.size.0));
+	}
+	// Pointer offsets into the structure may not exceed isize::MAX
+	emit_text(&mut code, &format!("const _: () = assert!(({}) <= isize::MAX as usize, \"struct_layout: the size of `{}` exceeds isize::MAX\");", stru.layout.size.0, stru.name));
+	if stru.layout.strict {
+		emit_strict_asserts(&mut code, &stru);
+	}
+	// Literal sizes are also checked while expanding, the array of the structure would fail first with a less helpful error
+	if let Some(size) = int_literal_value(&stru.layout.size) {
+		if size > 1 << 30 && !stru.layout.huge {
+			emit_text(&mut code, &format!("impl {0} {{
+				#[doc(hidden)]
+				#[deprecated(note = \"struct_layout: the size of `{0}` is larger than 1 GiB, add the `huge` argument if this is intended\")]
+				const __STRUCT_LAYOUT_HUGE: () = ();
+			}}
+			const _: () = {0}::__STRUCT_LAYOUT_HUGE;", stru.name));
+		}
+	}
+	if let Some(storage) = &stru.layout.storage {
+		emit_text(&mut code, &format!("const _: () = assert!(({}) % ::core::mem::size_of::<{}>() == 0, \"struct_layout: the size of `{}` is not a multiple of its storage element\");", stru.layout.size.0, storage, stru.name));
+	}
+	for field in &stru.optional {
+		items.track(&mut code, &stru, &format!("field `{}`", field.name), field.name.span(), |code| emit_optional_field(code, &stru, field));
+	}
+	for field in &stru.fields {
+		for (method, pred) in &field.layout.gated {
+			items.track(&mut code, &stru, &format!("field `{}`", field.name), field.name.span(), |code| {
+				emit_text(code, &format!("#[cfg({})]", pred));
+				emit_text(code, &doc_cfg_attr(pred));
+				emit_impl_f(code, &stru, |body| match *method {
+					"get" => emit_field_get(body, &stru, field),
+					"set" => emit_field_set(body, &stru, field),
+					"ref" => emit_field_ref(body, &stru, field),
+					_ => emit_field_mut(body, &stru, field),
+				});
+			});
+		}
+	}
+	emit_target_arms_check(&mut code, &stru, "size", &stru.layout.size_arms);
+	emit_target_arms_check(&mut code, &stru, "align", &stru.layout.align_arms);
+	emit_flatten_shim(&mut code, &stru);
+	emit_dynamic_offsets(&mut code, &stru);
+	if let (true, Some(check)) = (stru.layout.impl_check, &stru.layout.check) {
+		// The trait must be an unsafe marker trait without methods
+		emit_text(&mut code, &format!("unsafe impl {} for {} {{}}", check, stru.name));
+		emit_no_trailing_padding(&mut code, &stru);
+	}
+	if stru.layout.dataview {
+		// The structure is a byte array, sound as long as there is no trailing padding
+		emit_text(&mut code, &format!("unsafe impl ::dataview::Pod for {} {{}}", stru.name));
+		emit_no_trailing_padding(&mut code, &stru);
+		items.track(&mut code, &stru, "the `dataview` argument", argument_span(&stru.layout, "dataview"), |code| emit_slice_cast(code, &stru));
+	}
+	if let Some(path) = &stru.layout.offsets {
+		// Recompile when the offsets file changes
+		emit_text(&mut code, &format!("const _: &[u8] = include_bytes!({:?});", offsets_path(path)));
+	}
+	// The optional companions follow the derives
+	emit_derives(&mut code, &stru);
+	if let Some(base) = &stru.layout.base {
+		items.track(&mut code, &stru, "the `base` argument", argument_span(&stru.layout, "base"), |code| emit_base(code, &stru, base));
+	}
+	if let Some((other, names)) = &stru.layout.copy_from {
+		items.track(&mut code, &stru, "the `copy_from` argument", argument_span(&stru.layout, "copy_from"), |code| emit_copy_from(code, &stru, other, names.as_deref()));
+	}
+	if let Some(prefix) = &stru.layout.export_c {
+		emit_export_c(&mut code, &stru, prefix, &mut errors);
+	}
+	if let Some(skip) = stru.layout.wasm_bindgen {
+		items.track(&mut code, &stru, "the `wasm_bindgen` argument", argument_span(&stru.layout, "wasm_bindgen"), |code| emit_wasm_bindgen(code, &stru, skip, &mut errors));
+	}
+	if let Some(module) = &stru.layout.consts_mod {
+		emit_consts_mod(&mut code, &stru, module);
+	}
+	for other in &stru.layout.cast_to {
+		items.track(&mut code, &stru, "the `cast_to` argument", argument_span(&stru.layout, "cast_to"), |code| emit_cast_to(code, &stru, other));
+	}
+	if let Some(mirror) = &stru.layout.mirror {
+		emit_mirror(&mut code, &stru, mirror);
+	}
+	if let Some(twin) = &stru.layout.ffi_twin {
+		emit_ffi_twin(&mut code, &stru, twin);
+	}
+	emit_groups(&mut code, &stru, &mut items);
+	if stru.layout.byte_order {
+		items.track(&mut code, &stru, "the `byte_order` argument", argument_span(&stru.layout, "byte_order"), |code| emit_byte_order(code, &stru, &mut errors));
+	}
+	if stru.layout.random {
+		items.track(&mut code, &stru, "the `random` argument", argument_span(&stru.layout, "random"), |code| emit_random(code, &stru));
+	}
+	if stru.layout.parts {
+		items.track(&mut code, &stru, "the `parts` argument", argument_span(&stru.layout, "parts"), |code| emit_parts(code, &stru));
+	}
+	if stru.layout.transparent {
+		emit_transparent(&mut code, &stru);
+	}
+	if stru.layout.dump {
+		items.track(&mut code, &stru, "the `dump` argument", argument_span(&stru.layout, "dump"), |code| emit_dump(code, &stru));
+	}
+	if stru.layout.field_enum {
+		items.track(&mut code, &stru, "the `field_enum` argument", argument_span(&stru.layout, "field_enum"), |code| emit_field_enum(code, &stru));
+	}
+	if stru.layout.tracked {
+		emit_tracked(&mut code, &stru);
+	}
+	if stru.layout.init {
+		items.track(&mut code, &stru, "the `init` argument", argument_span(&stru.layout, "init"), |code| emit_init(code, &stru));
+	}
+	if stru.layout.layout_hash {
+		items.track(&mut code, &stru, "the `layout_hash` argument", argument_span(&stru.layout, "layout_hash"), |code| {
+			emit_impl_f(code, &stru, |body| {
+				emit_static(body, "/// FNV-1a hash of the name, size, alignment and the name, offset and type of every field, not cryptographic.");
+				emit_companion_vis(body, &stru);
+				emit_text(body, &format!("const LAYOUT_HASH: u64 = {:#018x};", layout_hash(&stru)));
+			});
+		});
+	}
+	if stru.layout.validate_all {
+		items.track(&mut code, &stru, "the `validate_all` argument", argument_span(&stru.layout, "validate_all"), |code| emit_validate_all(code, &stru));
+	}
+	if let Some(seq) = stru.fields.iter().find(|field| field.layout.seq) {
+		items.track(&mut code, &stru, &format!("field `{}`", seq.name), seq.name.span(), |code| emit_update_guard(code, &stru, seq));
+	}
+	if let Some((tr, define)) = &stru.layout.impl_trait {
+		emit_impl_trait(&mut code, &stru, tr, *define);
+	}
+	if let Some(tr) = &stru.layout.mockable {
+		emit_mockable(&mut code, &stru, tr);
+	}
+	if stru.layout.slice_ext {
+		emit_slice_ext(&mut code, &stru);
+	}
+	for field in &stru.fields {
+		if let Some(body) = &field.layout.inline {
+			emit_inline_structure(&mut code, &stru, field, body);
+		}
+	}
+	// Colliding names are reported at their declarations instead of as duplicate definitions in the generated code
+	errors.extend(items.errors);
+	if !errors.is_empty() {
+		return errors.into_iter().collect();
+	}
+	code.into_iter().collect()
+}
+
+// Names of the items in the inherent impls of the structure and where they come from
+#[derive(Default)]
+struct ItemNames {
+	names: HashMap<String, (String, Span)>,
+	errors: Vec<TokenTree>,
+}
+impl ItemNames {
+	// Records the functions and constants declared in the tokens of an impl body
+	fn register(&mut self, tokens: &[TokenTree], origin: &str, span: Span) {
+		let mut own = Vec::new();
+		for i in 1..tokens.len() {
+			let name = match (&tokens[i - 1], &tokens[i]) {
+				(TokenTree::Ident(keyword), TokenTree::Ident(name)) if matches!(&*keyword.to_string(), "fn" | "const") && !matches!(&*name.to_string(), "fn" | "_") => name.to_string(),
+				_ => continue,
+			};
+			// Raw pointer types in where clauses, eg. `*const u8: Copy`
+			if i >= 2 && is_punct(&tokens[i - 2..], '*') {
+				continue;
+			}
+			// The same origin may declare an item under exclusive cfgs, eg. the stubs of optional fields
+			if own.contains(&name) {
+				continue;
+			}
+			match self.names.get(&name) {
+				Some((other, other_span)) => {
+					let message = format!("struct_layout: `{}` generated for {} collides with `{}` generated for {}", name, origin, name, other);
+					emit_text_spanned(&mut self.errors, &format!("::core::compile_error!({:?});", message), span);
+					let message = format!("struct_layout: `{}` generated for {} collides with `{}` generated for {}", name, other, name, origin);
+					emit_text_spanned(&mut self.errors, &format!("::core::compile_error!({:?});", message), *other_span);
+				},
+				None => {
+					self.names.insert(name.clone(), (origin.to_string(), span));
+				},
+			}
+			own.push(name);
+		}
+	}
+	// Records the items of the inherent impls of the structure emitted by the callback
+	fn track(&mut self, code: &mut Vec<TokenTree>, stru: &Structure, origin: &str, span: Span, f: impl FnOnce(&mut Vec<TokenTree>)) {
+		let start = code.len();
+		f(code);
+		let mut own = Vec::new();
+		for i in start + 2..code.len() {
+			match (&code[i - 2], &code[i - 1], &code[i]) {
+				(TokenTree::Ident(keyword), TokenTree::Ident(name), TokenTree::Group(group))
+					if keyword.to_string() == "impl" && name.to_string() == stru.name.to_string() && group.delimiter() == Delimiter::Brace =>
+				{
+					own.extend(group.stream());
+				},
+				_ => (),
+			}
+		}
+		self.register(&own, origin, span);
+	}
+}
+
+// The accessors and asserts of an optional field only exist if any of its predicates match
+fn emit_optional_field(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	let preds = field.layout.optional.as_ref().unwrap().join(", ");
+	emit_text(code, &format!("#[cfg(any({}))] const _: () = {{ use ::core::mem; {} }};", preds, field_asserts(stru, field)));
+	emit_text(code, &format!("#[cfg(any({}))]", preds));
+	emit_text(code, &doc_cfg_attr(&format!("any({})", preds)));
+	emit_impl_f(code, stru, |body| {
+		if !field.layout.stubs {
+			if field.layout.method_get {
+				emit_field_get(body, stru, field);
+			}
+			if field.layout.method_set {
+				emit_field_set(body, stru, field);
+			}
+			if field.layout.method_ref {
+				emit_field_ref(body, stru, field);
+			}
+			if field.layout.method_mut {
+				emit_field_mut(body, stru, field);
+			}
+			return;
+		}
+		emit_optional_stubs(body, stru, field, true);
+	});
+	if field.layout.stubs {
+		emit_text(code, &format!("#[cfg(not(any({})))]", preds));
+		emit_impl_f(code, stru, |body| emit_optional_stubs(body, stru, field, false));
+	}
+}
+// Accessors with the same signature whether the field is present or not
+fn emit_optional_stubs(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, present: bool) {
+	if field.layout.method_get {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}(&self) -> ::core::option::Option<{}>", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			if present {
+				emit_field_prelude(body, stru, field);
+				emit_static(body, "Some(unsafe { ptr::read_unaligned((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) })");
+			}
+			else {
+				emit_static(body, "None");
+			}
+		});
+	}
+	if field.layout.method_set {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn set_{}(&mut self, {}: {}) -> bool", field.accessor, if present { "value" } else { "_value" }, field.ty));
+		emit_field_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			if present {
+				emit_field_prelude(body, stru, field);
+				emit_field_store(body, stru, field);
+				emit_static(body, "true");
+			}
+			else {
+				emit_static(body, "false");
+			}
+		});
+	}
+	if field.layout.method_ref {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_ref(&self) -> ::core::option::Option<&{}>", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			if present {
+				emit_field_prelude_aligned(body, stru, field);
+				emit_static(body, "Some(unsafe { &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) })");
+			}
+			else {
+				emit_static(body, "None");
+			}
+		});
+	}
+	if field.layout.method_mut {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_mut(&mut self) -> ::core::option::Option<&mut {}>", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			if present {
+				emit_field_prelude_aligned(body, stru, field);
+				emit_static(body, "Some(unsafe { &mut *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) })");
+			}
+			else {
+				emit_static(body, "None");
+			}
+		});
+	}
+}
+
+// Inline structures are explicit structures inheriting the alignment, check and derives of the outer structure
+fn emit_inline_structure(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, body: &Group) {
+	let align = if stru.layout.align_arms.is_empty() { stru.layout.align.0.to_string() } else { String::from("1") };
+	let mut args = format!("size = 0, align = {},", align);
+	if let Some(check) = &stru.layout.check {
+		args.push_str(&format!("check({}),", check));
+	}
+	if !stru.layout.portable {
+		args.push_str("portable = false,");
+	}
+	if stru.layout.byte_order {
+		args.push_str("byte_order,");
+	}
+	// The accessors of the outer structure copy the inline structure
+	let mut derives = vec![String::from("Copy"), String::from("Clone")];
+	for derive in &stru.derived {
+		let derive = format!("{:?}", derive);
+		if !derives.contains(&derive) {
+			derives.push(derive);
+		}
+	}
+	let mut tokens = Vec::new();
+	emit_text(&mut tokens, &format!("#[derive({})]", derives.join(", ")));
+	if field.layout.inline_name.is_none() {
+		emit_static(&mut tokens, "#[doc(hidden)]");
+	}
+	emit_vis(&mut tokens, &stru.vis);
+	emit_ident(&mut tokens, "struct");
+	emit_ty(&mut tokens, &field.ty);
+	tokens.push(TokenTree::Group(body.clone()));
+	let mut inline = match parse_structure(tokens.into_iter().collect(), parse_explicit_layout(args.parse().unwrap())) {
+		Ok(inline) => inline,
+		Err(errors) => {
+			code.extend(errors);
+			return;
+		},
+	};
+	// Without an asserted size the structure ends at the end of its last field rounded up to its alignment
+	inline.layout.size = match &field.layout.size {
+		Some(size) => size.clone(),
+		None => {
+			let mut size = String::from("{ let mut size: usize = 0;");
+			for inner in &inline.fields {
+				let (extent, _) = field_extent(inner);
+				size.push_str(&format!("let end = ({}) + {}; if end > size {{ size = end; }}", inner.layout.offset.0, extent));
+			}
+			size.push_str(&format!("size.div_ceil({0}) * ({0}) }}", align));
+			Expr(size.parse().unwrap())
+		},
+	};
+	code.extend(emit_structure(inline));
+}
+
+// Emits an existing structure unchanged except for its field attributes and checks its layout against them
+fn emit_kept_structure(input: TokenStream, layout: &ExplicitLayout) -> ParseResult<TokenStream> {
+	let tokens: Vec<TokenTree> = input.into_iter().collect();
+	let mut tokens = tokens.into_iter();
+	let mut code = Vec::new();
+	let attrs = parse_attrs(&mut tokens);
+	emit_attrs(&mut code, &attrs);
+	let vis = parse_vis(&mut tokens);
+	emit_vis(&mut code, &vis);
+	let stru = match parse_keyword(&mut tokens, "struct") {
+		Some(ident) => ident,
+		None => bail!(next_span(tokens.as_slice()), "parse struct: struct layout is only allowed on structs"),
+	};
+	code.push(TokenTree::Ident(stru.clone()));
+	let name = match parse_ident(&mut tokens) {
+		Some(ident) => ident,
+		None => bail!(stru.span(), "parse struct: struct name identifier not found"),
+	};
+	code.push(TokenTree::Ident(name.clone()));
+	if is_punct(tokens.as_slice(), '<') {
+		bail!(next_span(tokens.as_slice()), "parse struct: generic parameters not supported");
+	}
+	let group = match parse_group(&mut tokens, Delimiter::Brace) {
+		Some(group) => group,
+		None => bail!(name.span(), "parse struct: tuple syntax not supported, the keep_fields argument requires braces to declare the fields"),
+	};
+	let mut fields = Vec::new();
+	let verify = verify_layout(&name, layout, &group, &mut fields)?;
+	code.push(TokenTree::Group(Group::new(Delimiter::Brace, fields.into_iter().collect())));
+	code.extend(tokens);
+	emit_text(&mut code, &verify);
+	Ok(code.into_iter().collect())
+}
+
+// The size is a multiple of the alignment, every size is for an alignment of one
+fn emit_no_trailing_padding(code: &mut Vec<TokenTree>, stru: &Structure) {
+	if int_literal_value(&stru.layout.align) != Some(1) {
+		emit_text(code, &format!("const _: [(); ({0} % {1} == 0) as usize - 1] = [];", stru.layout.size.0, stru.layout.align.0));
+	}
+}
+// Fails to compile if none of the per target arms match
+fn emit_target_arms_check(code: &mut Vec<TokenTree>, stru: &Structure, name: &str, arms: &[(String, String)]) {
+	if arms.is_empty() {
+		return;
+	}
+	let preds: Vec<&str> = arms.iter().map(|(pred, _)| &**pred).collect();
+	emit_text(code, &format!("#[cfg(not(any({})))] compile_error!(\"struct_layout: no {} of `{}` matches the target\");", preds.join(", "), name, stru.name));
+}
+// Marker member opting out of the Send and Sync auto traits
+fn marker_ty(stru: &Structure) -> Option<String> {
+	let marker = match (stru.layout.send, stru.layout.sync) {
+		(true, true) => None,
+		(true, false) => Some("::core::cell::Cell<()>"),
+		(false, _) => Some("*const ()"),
+	};
+	// The type parameters are only used by the accessors
+	let mut params: Vec<String> = marker.into_iter().map(String::from).collect();
+	params.extend(stru.generics.names.iter().map(Ident::to_string));
+	match &*params {
+		[] => None,
+		[param] => Some(format!("::core::marker::PhantomData<{}>", param)),
+		_ => Some(format!("::core::marker::PhantomData<({})>", params.join(", "))),
+	}
+}
+// The generic parameters of impls of the structure and the structure as a type and in paths
+fn impl_generics(stru: &Structure) -> String {
+	match stru.generics.names.is_empty() {
+		true => String::new(),
+		false => format!("<{}>", stru.generics.params.iter().cloned().collect::<TokenStream>()),
+	}
+}
+fn self_ty(stru: &Structure) -> String {
+	match stru.generics.names.is_empty() {
+		true => stru.name.to_string(),
+		false => format!("{}<{}>", stru.name, stru.generics.names.iter().map(Ident::to_string).collect::<Vec<_>>().join(", ")),
+	}
+}
+fn type_path(stru: &Structure) -> String {
+	match stru.generics.names.is_empty() {
+		true => stru.name.to_string(),
+		false => format!("{}::<{}>", stru.name, stru.generics.names.iter().map(Ident::to_string).collect::<Vec<_>>().join(", ")),
+	}
+}
+// Type of the inner array holding the bytes of the structure
+fn storage_array(layout: &ExplicitLayout) -> String {
+	match &layout.storage {
+		Some(storage) => format!("[{0}; ({1}) / ::core::mem::size_of::<{0}>()]", storage, layout.size.0),
+		None => format!("[u8; {}]", layout.size.0),
+	}
+}
+fn is_atomic_storage(layout: &ExplicitLayout) -> bool {
+	matches!(&layout.storage, Some(storage) if storage.starts_with("::core::sync::atomic::"))
+}
+// Reference to the bytes of the inner array
+fn storage_bytes(layout: &ExplicitLayout, place: &str, mutable: bool) -> String {
+	let (m, ptr) = if mutable { ("mut ", "mut") } else { ("", "const") };
+	match &layout.storage {
+		Some(_) => format!("unsafe {{ &{0}*(&{0}{1} as *{2} _ as *{2} [u8; {3}]) }}", m, place, ptr, layout.size.0),
+		None => format!("&{}{}", m, place),
+	}
+}
+// Wraps the bytes, eg. included with `include_bytes!`, in const contexts
+// Any bytes are only a valid instance if every field is Pod, otherwise the caller must ensure it
+fn emit_from_bytes(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let storage = match &stru.layout.storage {
+		Some(_) => format!("unsafe {{ ::core::mem::transmute::<[u8; {}], {}>(bytes) }}", stru.layout.size.0, storage_array(&stru.layout)),
+		None => String::from("bytes"),
+	};
+	let instance = match marker_ty(stru) {
+		Some(_) => format!("{}({}, ::core::marker::PhantomData)", stru.name, storage),
+		None => format!("{}({})", stru.name, storage),
+	};
+	let unsafety = if struct_pod(stru) { "" } else { "unsafe" };
+	emit_static(code, "/// Creates the structure from its bytes.");
+	if !unsafety.is_empty() {
+		emit_static(code, "///\n/// # Safety\n///\n/// The bytes must hold a valid value of every field.");
+	}
+	emit_static(code, "#[allow(dead_code)]");
+	emit_vis(code, &stru.vis);
+	emit_text(code, &format!("const {} fn from_bytes(bytes: [u8; {}]) -> Self {{ {} }}", unsafety, stru.layout.size.0, instance));
+}
+// Reading the bytes is always sound, writing them is as unsafe as from_bytes
+fn emit_as_bytes(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let size = &stru.layout.size.0;
+	let unsafety = if struct_pod(stru) { "" } else { "unsafe" };
+	emit_static(code, "/// Returns the bytes of the structure.");
+	emit_vis(code, &stru.vis);
+	emit_text(code, &format!("fn as_bytes(&self) -> &[u8; {}] {{ {} }}", size, storage_bytes(&stru.layout, "self.0", false)));
+	emit_static(code, "/// Returns the bytes of the structure for writing.");
+	if !unsafety.is_empty() {
+		emit_static(code, "///\n/// # Safety\n///\n/// The bytes must still hold a valid value of every field when the borrow ends.");
+	}
+	emit_vis(code, &stru.vis);
+	emit_text(code, &format!("{} fn as_bytes_mut(&mut self) -> &mut [u8; {}] {{ {} }}", unsafety, size, storage_bytes(&stru.layout, "self.0", true)));
+	emit_static(code, "/// Copies the structure from the start of the bytes, `None` if there are fewer bytes than the size of the structure.");
+	if !unsafety.is_empty() {
+		emit_static(code, "///\n/// # Safety\n///\n/// The bytes must hold a valid value of every field.");
+	}
+	emit_vis(code, &stru.vis);
+	let from_bytes = if unsafety.is_empty() { "Self::from_bytes(array)" } else { "unsafe { Self::from_bytes(array) }" };
+	emit_text(code, &format!("{} fn read_from(bytes: &[u8]) -> ::core::option::Option<Self> {{
+		const SIZE: usize = {};
+		match bytes.get(..SIZE) {{
+			::core::option::Option::Some(bytes) => {{
+				let mut array = [0u8; SIZE];
+				array.copy_from_slice(bytes);
+				::core::option::Option::Some({})
+			}},
+			::core::option::Option::None => ::core::option::Option::None,
+		}}
+	}}", unsafety, size, from_bytes));
+}
+// The constructors are safe if zeroed bytes are a valid value of every field
+fn emit_constructors(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let unsafety = if struct_zeroable(stru) { "" } else { "unsafe" };
+	emit_static(code, "/// Creates the structure with all its bytes zeroed.");
+	if !unsafety.is_empty() {
+		emit_static(code, "///\n/// # Safety\n///\n/// Every field for which zeroed bytes are not a valid value must be written before it is read or dropped.");
+	}
+	emit_static(code, "#[allow(dead_code)]");
+	emit_vis(code, &stru.vis);
+	emit_text(code, &format!("const {} fn zeroed() -> Self {{ {} }}", unsafety, struct_zeroed(stru)));
+	// The fields of a union overlap, setting all of them would only keep the last
+	if stru.layout.kind == LayoutKind::Union {
+		return;
+	}
+	let fields: Vec<&Field> = stru.fields.iter()
+		.filter(|field| field.layout.method_set && is_single_value(field) && field.layout.variant.is_none() && field.layout.checksum.is_none() && field.layout.check_cfg.is_none())
+		.collect();
+	let params: Vec<String> = fields.iter().map(|field| format!("{}: {}", field.name, field.ty)).collect();
+	let sets: String = fields.iter().map(|field| format!("instance.set_{}({});", field.accessor, field.name)).collect();
+	// Safe if the zeroed bytes of every other field are valid, the setters of allow_drop fields drop the zeroed value
+	let set = |field: &Field| !field.layout.allow_drop && fields.iter().any(|other| other.name.to_string() == field.name.to_string());
+	let safe = stru.fields.iter().chain(&stru.optional).all(|field| is_checked_pod(stru, field) || is_zeroable_field(field) || set(field)) && stru.layout.base.is_none();
+	emit_static(code, "/// Creates the structure from the values of its fields with a set accessor, the other bytes are zeroed.");
+	if !safe {
+		emit_static(code, "///\n/// # Safety\n///\n/// Zeroed bytes must be a valid value of every field with `allow_drop`, whose setter drops the old value.\n/// Every other field left out for which this is not the case must be written before it is read or dropped.");
+	}
+	emit_static(code, "#[allow(dead_code, clippy::too_many_arguments)]");
+	emit_vis(code, &stru.vis);
+	let (unsafety, zeroed) = match (safe, unsafety.is_empty()) {
+		(false, _) => ("unsafe", "unsafe { Self::zeroed() }"),
+		(true, false) => ("", "unsafe { Self::zeroed() }"),
+		(true, true) => ("", "Self::zeroed()"),
+	};
+	emit_text(code, &format!("{} fn new({}) -> Self {} {{ let mut instance = {}; {} instance }}", unsafety, params.join(", "), fields_check_bounds(stru, &fields), zeroed, sets));
+}
+// The where clause requiring the check trait of every field, eg. for methods which access several fields
+fn fields_check_bounds(stru: &Structure, fields: &[&Field]) -> String {
+	// Asserted once next to the structure instead
+	if stru.layout.compact || fields.is_empty() {
+		return String::new();
+	}
+	let mut bounds = String::from("where ");
+	for field in fields {
+		bounds.push_str(&format!("{}: {} + 'static,", field_storage_ty(field), field_check_bound(stru, field)));
+		if field.layout.nested {
+			bounds.push_str(&format!("{}: ::struct_layout::ExplicitLayout,", nested_ty(field)));
+		}
+	}
+	bounds
+}
+// Any bytes are a valid value of the field, its type is a primitive without invalid values or an array of these
+fn is_pod_field(field: &Field) -> bool {
+	let elem = field_elem_ty(field);
+	is_zeroable_field(field) && (is_int(&elem) || is_float(&elem) || elem == "()" || is_phantom_data(&elem))
+}
+// Zeroed bytes are a valid value of the field
+fn is_zeroable_field(field: &Field) -> bool {
+	let layout = &field.layout;
+	if layout.nested || layout.allow_drop || layout.manually_drop.is_some() {
+		return false;
+	}
+	let elem = field_elem_ty(field);
+	is_int(&elem) || is_float(&elem) || matches!(&*elem, "()" | "bool" | "char") || is_raw_pointer(&elem) || is_phantom_data(&elem) || is_null_option(&elem)
+}
+// An option of a function pointer, NonNull or NonZero type is None when zeroed
+// Other types are only recognized by their full path in core or std, any other path may name a type of the user
+fn is_null_option(ty: &str) -> bool {
+	let inner = match ty.strip_prefix("Option < ").or_else(|| std_path(ty, "option", "Option < ")) {
+		Some(inner) => match inner.strip_suffix('>') {
+			Some(inner) => inner.trim_end(),
+			None => return false,
+		},
+		None => return false,
+	};
+	if is_fn_pointer(inner) || std_path(inner, "ptr", "NonNull < ").is_some() {
+		return true;
+	}
+	const NON_ZERO: [&str; 13] = ["NonZeroU8", "NonZeroU16", "NonZeroU32", "NonZeroU64", "NonZeroU128", "NonZeroUsize", "NonZeroI8", "NonZeroI16", "NonZeroI32", "NonZeroI64", "NonZeroI128", "NonZeroIsize", "NonZero < "];
+	NON_ZERO.iter().any(|name| matches!(std_path(inner, "num", name), Some(rest) if name.ends_with("< ") || rest.is_empty()))
+}
+// A function pointer type, eg. `fn(u32) -> u32`, `unsafe fn()` or `extern "C" fn()`
+fn is_fn_pointer(ty: &str) -> bool {
+	let ty = ty.strip_prefix("unsafe ").unwrap_or(ty);
+	let ty = match ty.strip_prefix("extern ") {
+		// The ABI is a single string literal token
+		Some(abi) if abi.starts_with('"') => match abi[1..].split_once("\" ") {
+			Some((_, ty)) => ty,
+			None => return false,
+		},
+		Some(ty) => ty,
+		None => ty,
+	};
+	// The parameters follow the `fn` keyword, not the rest of an identifier such as `fnord`
+	matches!(ty.strip_prefix("fn"), Some(params) if params.trim_start().starts_with('('))
+}
+fn is_raw_pointer(ty: &str) -> bool {
+	matches!(ty.strip_prefix('*'), Some(ptr) if ptr.trim_start().starts_with("const ") || ptr.trim_start().starts_with("mut "))
+}
+fn is_phantom_data(ty: &str) -> bool {
+	std_path(ty, "marker", "PhantomData < ").is_some()
+}
+// The rest of the type after the full path of an item of core or std, eg. `::core::marker::PhantomData<`
+fn std_path<'a>(ty: &'a str, module: &str, item: &str) -> Option<&'a str> {
+	let ty = ty.strip_prefix(":: ").unwrap_or(ty);
+	let ty = ty.strip_prefix("core :: ").or_else(|| ty.strip_prefix("std :: "))?;
+	ty.strip_prefix(module)?.strip_prefix(" :: ")?.strip_prefix(item)
+}
+fn field_elem_ty(field: &Field) -> String {
+	let mut elem = field_storage_ty(field).clone();
+	while let Some((inner, _)) = array_ty(&elem) {
+		elem = inner;
+	}
+	elem.to_string()
+}
+// The check trait is a Pod trait asserted for every field, type parameters are only checked by the accessors
+fn check_is_pod(stru: &Structure) -> bool {
+	let check = match &stru.layout.check {
+		Some(check) if stru.generics.names.is_empty() => check.replace(' ', ""),
+		_ => return false,
+	};
+	check.split('+').any(|bound| matches!(bound.trim_start_matches("::"), "struct_layout::Pod" | "dataview::Pod" | "bytemuck::Pod"))
+}
+// The field is asserted to implement the Pod check trait, unless the check only applies to some configurations
+fn is_checked_pod(stru: &Structure, field: &Field) -> bool {
+	check_is_pod(stru) && field.layout.check_cfg.is_none()
+}
+// The structure can be created from any bytes, eg. by from_bytes
+fn struct_pod(stru: &Structure) -> bool {
+	stru.layout.base.is_none() && stru.fields.iter().all(|field| is_checked_pod(stru, field) || is_pod_field(field)) && stru.optional.iter().all(is_pod_field)
+}
+// The structure can be created from zeroed bytes, eg. by zeroed
+fn struct_zeroable(stru: &Structure) -> bool {
+	stru.layout.base.is_none() && stru.fields.iter().all(|field| is_checked_pod(stru, field) || is_zeroable_field(field)) && stru.optional.iter().all(is_zeroable_field)
+}
+// Expression constructing a zero filled instance
+fn struct_zeroed(stru: &Structure) -> String {
+	let zeroes = match &stru.layout.storage {
+		// Atomics are not Copy, the array is repeated from a constant
+		Some(storage) if is_atomic_storage(&stru.layout) => format!("{{ const ZERO: {0} = {0}::new(0); [ZERO; ({1}) / ::core::mem::size_of::<{0}>()] }}", storage, stru.layout.size.0),
+		Some(storage) => format!("[0; ({1}) / ::core::mem::size_of::<{0}>()]", storage, stru.layout.size.0),
+		None => format!("[0u8; {}]", stru.layout.size.0),
+	};
+	match marker_ty(stru) {
+		Some(_) => format!("{}({}, ::core::marker::PhantomData)", stru.name, zeroes),
+		None => format!("{}({})", stru.name, zeroes),
+	}
+}
+
+//----------------------------------------------------------------
+// Emitters
+
+fn emit_punct(code: &mut Vec<TokenTree>, ch: char) {
+	code.push(TokenTree::Punct(Punct::new(ch, Spacing::Alone)));
+}
+fn emit_ident(code: &mut Vec<TokenTree>, name: &str) {
+	code.push(TokenTree::Ident(Ident::new(name, Span::call_site())));
+}
+fn emit_text(code: &mut Vec<TokenTree>, text: &str) {
+	let stream: TokenStream = text.parse().unwrap();
+	code.extend(stream);
+}
+fn emit_text_spanned(code: &mut Vec<TokenTree>, text: &str, span: Span) {
+	fn respan(tt: TokenTree, span: Span) -> TokenTree {
+		match tt {
+			TokenTree::Group(group) => {
+				let mut inner = Group::new(group.delimiter(), group.stream().into_iter().map(|tt| respan(tt, span)).collect());
+				inner.set_span(span);
+				TokenTree::Group(inner)
+			},
+			mut tt => {
+				tt.set_span(span);
+				tt
+			},
+		}
+	}
+	let stream: TokenStream = text.parse().unwrap();
+	code.extend(stream.into_iter().map(|tt| respan(tt, span)));
+}
+thread_local! {
+	// Static templates are parsed once per expansion, token streams must not outlive the expansion
+	static TEMPLATES: RefCell<HashMap<&'static str, TokenStream>> = RefCell::new(HashMap::new());
+}
+struct Expansion;
+impl Drop for Expansion {
+	fn drop(&mut self) {
+		TEMPLATES.with(|templates| templates.borrow_mut().clear());
+	}
+}
+
+// The span of the next token, at the end of the tokens the error is reported at the macro invocation
+fn next_span(tokens: &[TokenTree]) -> Span {
+	tokens.first().map_or_else(Span::call_site, TokenTree::span)
+}
+// Reports a parse error as a compile error at its span
+fn emit_error(code: &mut Vec<TokenTree>, (span, message): (Span, String)) {
+	emit_text_spanned(code, &format!("::core::compile_error!({:?});", message), span);
+}
+// A parse error which cannot be recovered from replaces the expansion
+fn emit_result(result: ParseResult<TokenStream>) -> TokenStream {
+	match result {
+		Ok(code) => code,
+		Err(err) => {
+			let mut errors = Vec::new();
+			emit_error(&mut errors, err);
+			errors.into_iter().collect()
+		},
+	}
+}
+fn emit_static(code: &mut Vec<TokenTree>, text: &'static str) {
+	TEMPLATES.with(|templates| {
+		let mut templates = templates.borrow_mut();
+		let stream = templates.entry(text).or_insert_with(|| text.parse().unwrap());
+		code.extend(stream.clone());
+	});
+}
+fn emit_group_f(code: &mut Vec<TokenTree>, delim: Delimiter, f: impl FnOnce(&mut Vec<TokenTree>)) {
+	let mut tokens = Vec::new();
+	f(&mut tokens);
+	let stream: TokenStream = tokens.into_iter().collect();
+	code.push(TokenTree::Group(Group::new(delim, stream)));
+}
+
+fn emit_attrs(code: &mut Vec<TokenTree>, attrs: &[Attribute]) {
+	for attr in attrs {
+		code.push(TokenTree::Punct(attr.punct.clone()));
+		code.push(TokenTree::Group(attr.meta.clone()));
+	}
+}
+fn emit_vis(code: &mut Vec<TokenTree>, vis: &Vis) {
+	code.extend(vis.0.iter().cloned());
+}
+// Visibility of the items generated next to the structure, the accessors keep the visibility of their field
+fn companion_vis(stru: &Structure) -> &Vis {
+	stru.layout.companion_vis.as_ref().unwrap_or(&stru.vis)
+}
+// The attributes of the items generated next to the structure followed by their visibility
+fn emit_companion_vis(code: &mut Vec<TokenTree>, stru: &Structure) {
+	if let Some(pred) = &stru.layout.doc_cfg {
+		emit_text(code, &doc_cfg_attr(pred));
+	}
+	emit_vis(code, companion_vis(stru));
+}
+// Tells docs.rs which cfg enables an item, the docsrs cfg is only set when building the documentation
+fn doc_cfg_attr(pred: &str) -> String {
+	format!("#[cfg_attr(docsrs, doc(cfg({})))]", pred)
+}
+fn emit_ty(code: &mut Vec<TokenTree>, ty: &Type) {
+	code.extend(ty.0.iter().cloned());
+}
+fn emit_impl_f(code: &mut Vec<TokenTree>, stru: &Structure, f: impl FnOnce(&mut Vec<TokenTree>)) {
+	emit_text(code, &format!("impl{} {}", impl_generics(stru), self_ty(stru)));
+	code.push(TokenTree::Group(Group::new(Delimiter::Brace, {
+		let mut tokens = Vec::new();
+		f(&mut tokens);
+		tokens.into_iter().collect()
+	})));
+}
+fn emit_trait_bounds(code: &mut Vec<TokenTree>, stru: &Structure, tr: &str) {
+	// The comparison derives only require their trait of the values they compare
+	let compared;
+	let tys: Vec<&Type> = match tr {
+		"::core::cmp::PartialEq" | "::core::cmp::Eq" | "::core::hash::Hash" | "::core::cmp::PartialOrd" | "::core::cmp::Ord" => {
+			compared = compared_fields(stru);
+			compared.iter().filter_map(|compared| compared.ty.as_ref()).collect()
+		},
+		// Printed as hex digits without the Debug implementation of the field type
+		"::core::fmt::Debug" => stru.fields.iter().filter(|field| !field.layout.debug_hex).map(|field| &field.ty).collect(),
+		_ => stru.fields.iter().map(|field| &field.ty).collect(),
+	};
+	if !tys.is_empty() {
+		emit_ident(code, "where");
+		let bound = format!(": {},", tr);
+		for ty in tys {
+			emit_ty(code, ty);
+			// Unsatisfied bounds are reported at the type of the field
+			emit_text_spanned(code, &bound, ty.0[0].span());
+		}
+	}
+}
+fn emit_trait_impl_f(code: &mut Vec<TokenTree>, stru: &Structure, tr: &str, f: impl FnOnce(&mut Vec<TokenTree>)) {
+	emit_text(code, &format!("impl{} {} for {}", impl_generics(stru), tr, self_ty(stru)));
+	emit_trait_bounds(code, stru, tr);
+	code.push(TokenTree::Group(Group::new(Delimiter::Brace, {
+		let mut tokens = Vec::new();
+		f(&mut tokens);
+		tokens.into_iter().collect()
+	})));
+}
+
+// Flattening a structure into another forwards its accessors through this macro
+// as the outer invocation cannot see the fields of the nested structure
+fn emit_flatten_shim(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let mut get = Vec::new();
+	let mut set = Vec::new();
+	for field in &stru.fields {
+		if !is_single_value(field) {
+			continue;
+		}
+		if field.layout.method_get {
+			emit_attrs(&mut get, &field.attrs);
+			emit_vis(&mut get, &field.vis);
+			emit_text(&mut get, &format!("fn {0}(&self) -> {1} {{ {2} }}", field.accessor, field_get_ty(field), field_get_call(field, "self.$get()")));
+		}
+		if field.layout.method_set {
+			emit_attrs(&mut set, &field.attrs);
+			emit_vis(&mut set, &field.vis);
+			emit_text(&mut set, &format!("fn set_{0}(&mut self, value: {1}) -> &mut Self {{
+				let mut inner = self.$get(); inner.set_{0}(value); self.$set(inner)
+			}}", field.accessor, field.ty));
+		}
+	}
+	emit_text(code, &format!("#[doc(hidden)] #[allow(unused_macros)] macro_rules! __struct_layout_flatten_{}", stru.name));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_static(body, "(get $get:ident) =>");
+		emit_group_f(body, Delimiter::Brace, |body| body.extend(get.iter().cloned()));
+		emit_static(body, "; (set $get:ident $set:ident) =>");
+		emit_group_f(body, Delimiter::Brace, |body| {
+			body.extend(get);
+			body.extend(set);
+		});
+		emit_punct(body, ';');
+	});
+}
+
+// The base structure is stored at the start of the structure
+fn emit_base(code: &mut Vec<TokenTree>, stru: &Structure, base: &Type) {
+	let check = format!("let _: [(); (<{0} as ::struct_layout::ExplicitLayout>::SIZE <= ::core::mem::size_of::<Self>() &&
+		::core::mem::align_of::<Self>() % <{0} as ::struct_layout::ExplicitLayout>::ALIGN == 0) as usize - 1];", base);
+	emit_impl_f(code, stru, |body| {
+		emit_static(body, "/// Returns the base structure.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn base(&self) -> &{} {{ {} unsafe {{ &*(self as *const Self as *const {0}) }} }}", base, check));
+		emit_static(body, "/// Returns the base structure mutably.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn base_mut(&mut self) -> &mut {} {{ {} unsafe {{ &mut *(self as *mut Self as *mut {0}) }} }}", base, check));
+	});
+	emit_text(code, &format!("impl ::core::ops::Deref for {} {{ type Target = {}; fn deref(&self) -> &{1} {{ self.base() }} }}", stru.name, base));
+	emit_text(code, &format!("impl ::core::ops::DerefMut for {} {{ fn deref_mut(&mut self) -> &mut {} {{ self.base_mut() }} }}", stru.name, base));
+}
+
+// Unmangled functions accessing the fields for code written in other languages
+fn emit_export_c(code: &mut Vec<TokenTree>, stru: &Structure, prefix: &str, errors: &mut Vec<TokenTree>) {
+	for field in &stru.fields {
+		if !field.layout.method_get && !field.layout.method_set {
+			continue;
+		}
+		let ty = field.ty.to_string();
+		let ffi_safe = is_int(&ty) || is_float(&ty) || ty == "bool" || is_ptr(&field.ty);
+		if !ffi_safe || !is_single_value(field) || !is_infallible(field) {
+			emit_error(errors, (field.name.span(), format!("parse field: export_c requires field `{}` to be a single integer, float, bool or raw pointer, found `{}`", field.name, ty)));
+			continue;
+		}
+		if field.layout.method_get {
+			emit_text(code, &format!("/// Reads the `{}` field of `{}`.", field.name, stru.name));
+			emit_static(code, "///\n/// # Safety\n///\n/// The pointer must point to a valid instance.");
+			emit_static(code, "#[no_mangle]");
+			emit_vis(code, &stru.vis);
+			emit_text(code, &format!("unsafe extern \"C\" fn {}get_{}(p: *const {}) -> {} {{ unsafe {{ {} }} }}", prefix, field.name, stru.name, field.ty, field_get_call(field, "(*p)")));
+		}
+		if field.layout.method_set {
+			emit_text(code, &format!("/// Writes the `{}` field of `{}`.", field.name, stru.name));
+			emit_static(code, "///\n/// # Safety\n///\n/// The pointer must point to a valid instance.");
+			emit_static(code, "#[no_mangle]");
+			emit_vis(code, &stru.vis);
+			emit_text(code, &format!("unsafe extern \"C\" fn {}set_{}(p: *mut {}, v: {}) {{ unsafe {{ (*p).set_{4}(v); }} }}", prefix, field.name, stru.name, field.ty, field.accessor));
+		}
+	}
+}
+
+// The size, alignment and the offset and size of every field as plain constants in a module
+fn emit_consts_mod(code: &mut Vec<TokenTree>, stru: &Structure, module: &Ident) {
+	// The layout expressions are repeated instead of naming the structure, a module in a function body cannot see the items of the function
+	let mut consts = String::new();
+	if stru.layout.kind == LayoutKind::Implicit {
+		consts.push_str(&implicit_layout_item(stru));
+	}
+	consts.push_str(&format!("/// Size of the structure in bytes.
+		pub const SIZE: usize = {};
+		/// Alignment of the structure in bytes.
+		pub const ALIGN: usize = {};", stru.layout.size.0, stru.layout.align.0));
+	for field in &stru.fields {
+		// The offsets of dynamic fields are not known at compile time
+		if field.layout.dynamic || field.layout.pad {
+			continue;
+		}
+		let upper = field.name.to_string().to_uppercase();
+		consts.push_str(&format!("/// Offset of the field `{3}` in bytes.
+			pub const {0}_OFFSET: usize = {1};
+			/// Size of the field `{3}` in bytes.
+			pub const {0}_SIZE: usize = {2};", upper, field.layout.offset.0, field_extent(field).0, field.name));
+	}
+	emit_text(code, &format!("/// Layout constants of [`{}`].", stru.name));
+	emit_static(code, "#[allow(dead_code)]");
+	emit_companion_vis(code, stru);
+	emit_text(code, &format!("mod {} {{ #[allow(unused_imports)] use super::*; {} }}", module, consts));
+}
+// Getters and setters exported to JavaScript, the attributes only apply when targeting wasm
+fn emit_wasm_bindgen(code: &mut Vec<TokenTree>, stru: &Structure, skip: bool, errors: &mut Vec<TokenTree>) {
+	emit_static(code, "#[cfg_attr(target_arch = \"wasm32\", ::wasm_bindgen::prelude::wasm_bindgen)]");
+	emit_impl_f(code, stru, |body| {
+		for field in &stru.fields {
+			if field.layout.pad {
+				continue;
+			}
+			let ty = field.ty.to_string();
+			let bytes = is_byte_array(&field.ty);
+			let compatible = is_int(&ty) || is_float(&ty) || ty == "bool" || ty == "char" || bytes;
+			if !compatible || !is_single_value(field) || !is_infallible(field) || field.layout.nested {
+				if !skip {
+					emit_error(errors, (field.name.span(), format!("parse field: field `{}` of type `{}` is not supported by wasm_bindgen, use `wasm_bindgen(skip)` to leave it out", field.name, ty)));
+				}
+				continue;
+			}
+			if field.layout.method_get {
+				emit_text(body, &format!("#[doc(hidden)] #[cfg_attr(target_arch = \"wasm32\", wasm_bindgen(getter = {}))]", field.name));
+				if bytes {
+					emit_text(body, &format!("pub fn wasm_get_{0}(&self) -> ::struct_layout::__alloc::vec::Vec<u8> {{ self.{0}().to_vec() }}", field.accessor));
+				}
+				else {
+					emit_text(body, &format!("pub fn wasm_get_{0}(&self) -> {1} {{ {2} }}", field.accessor, ty, field_get_call(field, "self")));
+				}
+			}
+			if field.layout.method_set {
+				emit_text(body, &format!("#[doc(hidden)] #[cfg_attr(target_arch = \"wasm32\", wasm_bindgen(setter = {}))]", field.name));
+				if bytes {
+					emit_text(body, &format!("pub fn wasm_set_{0}(&mut self, value: &[u8]) {{
+						match ::core::convert::TryFrom::try_from(value) {{
+							Ok(value) => {{ self.set_{0}(value); }},
+							Err(_) => panic!(\"wasm_bindgen: field `{2}` expects {1} bytes\"),
+						}}
+					}}", field.accessor, array_ty(&field.ty).unwrap().1.0, field.name));
+				}
+				else {
+					emit_text(body, &format!("pub fn wasm_set_{0}(&mut self, value: {1}) {{ self.set_{0}(value); }}", field.accessor, ty));
+				}
+			}
+		}
+	});
+}
+
+// Casts between byte slices and slices of back-to-back records
+fn emit_slice_cast(code: &mut Vec<TokenTree>, stru: &Structure) {
+	emit_impl_f(code, stru, |body| {
+		// The slice casts return an Option unless specified otherwise
+		let policy = stru.layout.on_error.unwrap_or(OnError::Option);
+		let checks = format!("use ::core::mem;
+			if bytes.as_ptr() as usize % mem::align_of::<Self>() != 0 {{ {} }}
+			if bytes.len() % mem::size_of::<Self>() != 0 {{ let len = bytes.len(); {} }}",
+			checked_err(policy, "\"slice_from_bytes: the bytes are misaligned\"", "Misaligned"),
+			checked_err(policy, "\"slice_from_bytes: the len {} is not a multiple of the record size\", len", "InvalidLength(len)"));
+		emit_static(body, "/// Casts the bytes to records, fails if the bytes are misaligned or not a whole number of records.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn slice_from_bytes(bytes: &[u8]) -> {} {{ {} {} }}", checked_ty(policy, "&[Self]"), checks,
+			checked_ok(policy, "unsafe { ::core::slice::from_raw_parts(bytes.as_ptr() as *const Self, bytes.len() / mem::size_of::<Self>()) }")));
+		emit_static(body, "/// Casts the bytes to records, fails if the bytes are misaligned or not a whole number of records.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn slice_from_bytes_mut(bytes: &mut [u8]) -> {} {{ {} {} }}", checked_ty(policy, "&mut [Self]"), checks,
+			checked_ok(policy, "unsafe { ::core::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut Self, bytes.len() / mem::size_of::<Self>()) }")));
+		emit_static(body, "/// Returns the bytes of the records.");
+		emit_vis(body, &stru.vis);
+		emit_static(body, "fn slice_as_bytes(items: &[Self]) -> &[u8] {
+			unsafe { ::core::slice::from_raw_parts(items.as_ptr() as *const u8, ::core::mem::size_of_val(items)) }
+		}");
+	});
+}
+
+// Reinterprets the structure as another explicit structure of the same size
+fn emit_cast_to(code: &mut Vec<TokenTree>, stru: &Structure, other: &Type) {
+	let name = match other.0.last() {
+		Some(TokenTree::Ident(ident)) => snake_case(&ident.to_string()),
+		_ => unreachable!(),
+	};
+	emit_text(code, &format!("const _: () = {{
+		assert!(<{0} as ::struct_layout::ExplicitLayout>::SIZE == <{1} as ::struct_layout::ExplicitLayout>::SIZE, \"cast_to: `{0}` and `{1}` differ in size\");
+		assert!(<{0} as ::struct_layout::ExplicitLayout>::ALIGN <= <{1} as ::struct_layout::ExplicitLayout>::ALIGN, \"cast_to: `{0}` is aligned stricter than `{1}`\");
+	}};", other, stru.name));
+	// Any bytes must be valid on both sides, the other structure implements Pod with `check(struct_layout::Pod, impl)`
+	let (unsafety, check) = if struct_pod(stru) {
+		("", format!("fn check<T: ::struct_layout::Pod>() {{}} let _: fn() = check::<{}>;", other))
+	}
+	else {
+		("unsafe", String::new())
+	};
+	let safety = |body: &mut Vec<TokenTree>, doc: &str| if !unsafety.is_empty() {
+		emit_text(body, &format!("///\n/// # Safety\n///\n/// {}", doc));
+	};
+	emit_impl_f(code, stru, |body| {
+		emit_text(body, &format!("/// Views the structure as [`{}`].", other));
+		safety(body, "The bytes of the structure must hold a valid value of every field of the other structure.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("{} fn as_{}(&self) -> &{} {{ {} unsafe {{ &*(self as *const Self as *const {2}) }} }}", unsafety, name, other, check));
+		emit_text(body, &format!("/// Views the structure mutably as [`{}`].", other));
+		safety(body, "The bytes of the structure must hold a valid value of every field of the other structure, and of every field of this structure when the borrow ends.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("{} fn as_{}_mut(&mut self) -> &mut {} {{ {} unsafe {{ &mut *(self as *mut Self as *mut {2}) }} }}", unsafety, name, other, check));
+		emit_text(body, &format!("/// Converts the structure into [`{}`].", other));
+		safety(body, "The bytes of the structure must hold a valid value of every field of the other structure.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("{} fn into_{}(self) -> {} {{ {} let bytes = self.0; unsafe {{ ::core::ptr::read_unaligned(bytes.as_ptr() as *const {2}) }} }}", unsafety, name, other, check));
+	});
+}
+
+// Copies the fields with the same name from another structure
+fn emit_copy_from(code: &mut Vec<TokenTree>, stru: &Structure, other: &Type, names: Option<&[Ident]>) {
+	emit_impl_f(code, stru, |body| {
+		emit_text(body, &format!("/// Copies the fields with the same name from [`{}`].", other));
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn copy_fields_from(&mut self, other: &{}) -> &mut Self", other));
+		emit_group_f(body, Delimiter::Brace, |body| {
+			for field in &stru.fields {
+				let name = match names {
+					Some(names) => match names.iter().find(|name| name.to_string() == field.name.to_string()) {
+						Some(name) => name.clone(),
+						None => continue,
+					},
+					None if field.layout.method_set && is_single_value(field) => field.name.clone(),
+					None => continue,
+				};
+				// The type annotation reports mismatched field types at the name of the field
+				emit_text(body, &format!("let {}: {} = other.", name, field.ty));
+				body.push(TokenTree::Ident(name.clone()));
+				emit_text(body, &format!("(); self.set_{}({});", accessor_name(&stru.layout, &name), name));
+			}
+			emit_ident(body, "self");
+		});
+	});
+}
+
+// Accessors for several fields at once as a tuple in declaration order
+fn emit_groups(code: &mut Vec<TokenTree>, stru: &Structure, items: &mut ItemNames) {
+	let mut groups: Vec<(String, Vec<&Field>, Vec<&Field>)> = Vec::new();
+	let mut origins = Vec::new();
+	if stru.layout.grouped {
+		let get = stru.fields.iter().filter(|field| is_single_value(field) && field.layout.method_get).collect();
+		let set = stru.fields.iter().filter(|field| is_single_value(field) && field.layout.method_set).collect();
+		groups.push((String::from("many"), get, set));
+		origins.push((String::from("the `grouped` argument"), argument_span(&stru.layout, "grouped")));
+	}
+	for field in &stru.fields {
+		for group in &field.layout.groups {
+			let name = group.to_string();
+			match groups.iter_mut().skip(stru.layout.grouped as usize).find(|(other, _, _)| *other == name) {
+				Some((_, get, set)) => {
+					get.push(field);
+					set.push(field);
+				},
+				None => {
+					origins.push((format!("group `{}`", name), group.span()));
+					groups.push((name, vec![field], vec![field]));
+				},
+			}
+		}
+	}
+	if groups.is_empty() {
+		return;
+	}
+	emit_impl_f(code, stru, |body| {
+		for (i, (name, get, set)) in groups.iter().enumerate() {
+			let start = body.len();
+			// The named groups are called like a field, all fields are accessed by get_many and set_many
+			let getter = if stru.layout.grouped && i == 0 { String::from("get_many") } else { name.clone() };
+			let tys: String = get.iter().map(|field| format!("{},", field_get_ty(field))).collect();
+			let values: String = get.iter().map(|field| format!("{},", field_get_call(field, "self"))).collect();
+			let names: Vec<String> = get.iter().map(|field| format!("`{}`", field.name)).collect();
+			emit_text(body, &format!("/// Returns the fields {}.", names.join(", ")));
+			emit_vis(body, &stru.vis);
+			emit_text(body, &format!("fn {}(&self) -> ({}) {{ ({}) }}", getter, tys, values));
+			let tys: String = set.iter().map(|field| format!("{},", field.ty)).collect();
+			let values: String = set.iter().enumerate().map(|(i, field)| format!("self.set_{}(values.{});", field.accessor, i)).collect();
+			let names: Vec<String> = set.iter().map(|field| format!("`{}`", field.name)).collect();
+			emit_text(body, &format!("/// Sets the fields {}.", names.join(", ")));
+			emit_vis(body, &stru.vis);
+			emit_text(body, &format!("fn set_{}(&mut self, values: ({})) -> &mut Self {{ {} self }}", name, tys, values));
+			items.register(&body[start..], &origins[i].0, origins[i].1);
+		}
+	});
+}
+
+// Byte swaps every multi-byte primitive of the fields to convert between byte orders
+fn emit_byte_order(code: &mut Vec<TokenTree>, stru: &Structure, errors: &mut Vec<TokenTree>) {
+	// Every field covers a number of primitives: (offset, size, count, stride)
+	let mut extents = Vec::new();
+	for field in &stru.fields {
+		let layout = &field.layout;
+		if layout.opaque || layout.len.is_some() {
+			continue;
+		}
+		let mut unknown = || {
+			let message = format!("parse field: the byte order of field `{}` of type `{}` is unknown, mark it `opaque` to leave its bytes untouched", field.name, field_storage_ty(field));
+			emit_error(errors, (field.name.span(), message));
+		};
+		if layout.tail.is_some() || layout.dynamic || layout.variant.is_some() || layout.nested || layout.manually_drop.is_some() {
+			unknown();
+			continue;
+		}
+		// Arrays are swapped per element
+		let mut elem = field_storage_ty(field).clone();
+		let mut len = String::from("1");
+		while let Some((inner, n)) = array_ty(&elem) {
+			len = format!("{} * ({})", len, n.0);
+			elem = inner;
+		}
+		let elem_name = elem.to_string();
+		if !is_int(&elem_name) && !is_float(&elem_name) && elem_name != "bool" {
+			unknown();
+			continue;
+		}
+		// Single bytes have no byte order
+		if let "u8" | "i8" | "bool" = &*elem_name {
+			continue;
+		}
+		let offset = format!("{}::{}", stru.name, field_offset_const(field));
+		let size = format!("::core::mem::size_of::<{}>()", elem);
+		let extent = if let Some((rows, cols)) = &layout.dims {
+			format!("({0}, {1}, ({2}) * ({3}) * {4}, {1})", offset, size, rows.0, cols.0, len)
+		}
+		else if let Some(count) = &layout.count {
+			if len != "1" {
+				unknown();
+				continue;
+			}
+			let stride = layout.stride.as_ref().map(|stride| stride.0.to_string()).unwrap_or_else(|| size.clone());
+			format!("({}, {}, {}, {})", offset, size, count.0, stride)
+		}
+		else {
+			format!("({0}, {1}, {2}, {1})", offset, size, len)
+		};
+		// Bitfields sharing their stored integer swap it once
+		if layout.bits.is_some() && extents.contains(&extent) {
+			continue;
+		}
+		extents.push(extent);
+	}
+	let base = |order: &str| match &stru.layout.base {
+		Some(_) => format!("self.base_mut().make_{}();", order),
+		None => String::new(),
+	};
+	let copy = match marker_ty(stru) {
+		Some(_) => format!("{}(self.0, ::core::marker::PhantomData)", stru.name),
+		None => format!("{}(self.0)", stru.name),
+	};
+	emit_impl_f(code, stru, |body| {
+		emit_text(body, &format!("fn __swap_bytes(&mut self) {{
+			const EXTENTS: [(usize, usize, usize, usize); {}] = [{}];
+			let bytes: &mut [u8] = {};
+			for &(offset, size, count, stride) in EXTENTS.iter() {{
+				for i in 0..count {{
+					let start = offset + i * stride;
+					bytes[start..start + size].reverse();
+				}}
+			}}
+		}}", extents.len(), extents.join(", "), storage_bytes(&stru.layout, "self.0", true)));
+		emit_static(body, "/// Converts the fields from native to little endian byte order, or back.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn make_le(&mut self) -> &mut Self {{ {} if cfg!(target_endian = \"big\") {{ self.__swap_bytes(); }} self }}", base("le")));
+		emit_static(body, "/// Converts the fields from native to big endian byte order, or back.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn make_be(&mut self) -> &mut Self {{ {} if cfg!(target_endian = \"little\") {{ self.__swap_bytes(); }} self }}", base("be")));
+		emit_static(body, "/// Returns a copy with the fields in little endian byte order.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn to_le(&self) -> Self {{ let mut copy = {}; copy.make_le(); copy }}", copy));
+		emit_static(body, "/// Returns a copy with the fields in big endian byte order.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn to_be(&self) -> Self {{ let mut copy = {}; copy.make_be(); copy }}", copy));
+	});
+}
+
+// Random instances for test data, every settable field gets a random value of the field type
+fn emit_random(code: &mut Vec<TokenTree>, stru: &Structure) {
+	emit_impl_f(code, stru, |body| {
+		emit_static(body, "/// Writes a random value into every field with a set accessor, other bytes are left untouched.");
+		emit_vis(body, &stru.vis);
+		emit_static(body, "fn fill_random<R: ::rand::Rng + ?Sized>(&mut self, rng: &mut R) -> &mut Self");
+		emit_group_f(body, Delimiter::Brace, |body| {
+			for field in &stru.fields {
+				let layout = &field.layout;
+				if !layout.method_set || layout.variant.is_some() || layout.dynamic || layout.tail.is_some() || layout.len.is_some() || layout.manually_drop.is_some() {
+					continue;
+				}
+				if let Some((rows, cols)) = &layout.dims {
+					emit_text(body, &format!("for row in 0..{} {{ for col in 0..{} {{ let _ = self.set_{}_at(row, col, ::rand::Rng::gen(rng)); }} }}", rows.0, cols.0, field.accessor));
+				}
+				else if let Some(count) = &layout.count {
+					emit_text(body, &format!("for index in 0..{} {{ let _ = self.set_{}(index, ::rand::Rng::gen(rng)); }}", count.0, field.accessor));
+				}
+				else {
+					emit_text(body, &format!("self.set_{}(::rand::Rng::gen(rng));", field.accessor));
+				}
+			}
+			emit_static(body, "self");
+		});
+		emit_static(body, "/// Returns an instance with random fields, the bytes not covered by a settable field are zeroed.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn random<R: ::rand::Rng + ?Sized>(rng: &mut R) -> Self {{ let mut instance = {}; instance.fill_random(rng); instance }}", struct_zeroed(stru)));
+	});
+}
+
+// Conversions between the structure and a tuple of all its fields
+fn emit_parts(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let fields: Vec<&Field> = stru.fields.iter().filter(|field| !field.layout.pad).collect();
+	let tys: String = fields.iter().map(|field| format!("{},", field.ty)).collect();
+	let values: String = fields.iter().map(|field| format!("{},", field_get_call(field, "self"))).collect();
+	let sets: String = fields.iter().enumerate().map(|(i, field)| format!("instance.set_{}(parts.{});", field.accessor, i)).collect();
+	emit_impl_f(code, stru, |body| {
+		emit_static(body, "/// Returns all the fields as a tuple.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn into_parts(self) -> ({}) {{ ({}) }}", tys, values));
+		emit_static(body, "/// Creates the structure from a tuple of all the fields.");
+		emit_vis(body, &stru.vis);
+		emit_text(body, &format!("fn from_parts(parts: ({})) -> Self {{ let mut instance = {}; {} instance }}", tys, struct_zeroed(stru), sets));
+	});
+}
+
+// Enums of the fields and their values for dynamic dispatch
+fn emit_field_enum(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let fields = field_enum_fields(stru);
+	let field_enum = format!("{}Field", stru.name);
+	let value_enum = format!("{}Value", stru.name);
+	let variants: Vec<String> = fields.iter().map(|field| camel_case(&field.name.to_string())).collect();
+	emit_text(code, &format!("/// The fields of [`{}`].", stru.name));
+	emit_static(code, "#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]");
+	emit_companion_vis(code, stru);
+	let docs: Vec<String> = fields.iter().zip(&variants).map(|(field, variant)| format!("/// The field `{}`.\n{}", field.name, variant)).collect();
+	emit_text(code, &format!("enum {} {{ {} }}", field_enum, docs.join(", ")));
+	emit_text(code, &format!("/// The values of the fields of [`{}`].", stru.name));
+	emit_companion_vis(code, stru);
+	let values: String = fields.iter().zip(&variants).map(|(field, variant)| format!("/// The value of the field `{}`.\n{}({}),", field.name, variant, field_get_ty(field))).collect();
+	emit_text(code, &format!("enum {} {{ {} }}", value_enum, values));
+	let mut all = String::new();
+	let mut offsets = String::new();
+	let mut sizes = String::new();
+	let mut aligned = String::new();
+	let mut names = String::new();
+	let mut reads = String::new();
+	for (field, variant) in fields.iter().zip(&variants) {
+		all.push_str(&format!("{}::{},", field_enum, variant));
+		offsets.push_str(&format!("{}::{} => {}::{},", field_enum, variant, stru.name, field_offset_const(field)));
+		sizes.push_str(&format!("{}::{} => ::core::mem::size_of::<{}>(),", field_enum, variant, field_storage_ty(field)));
+		aligned.push_str(&format!("{}::{} => {}::{},", field_enum, variant, stru.name, field_aligned_const(field)));
+		names.push_str(&format!("{}::{} => \"{}\",", field_enum, variant, field.name));
+		reads.push_str(&format!("{}::{} => {}::{}({}),", field_enum, variant, value_enum, variant, field_get_call(field, "self")));
+	}
+	emit_text(code, &format!("impl {} {{
+		/// All the fields in declaration order.
+		pub const ALL: [{0}; {1}] = [{2}];
+		/// Offset of the field in bytes.
+		pub const fn offset(self) -> usize {{ match self {{ {3} }} }}
+		/// Size of the field in bytes.
+		pub const fn size(self) -> usize {{ match self {{ {4} }} }}
+		/// Whether the field is aligned for references to it.
+		pub const fn is_aligned(self) -> bool {{ match self {{ {5} }} }}
+		/// Name of the field.
+		pub fn name(self) -> &'static str {{ match self {{ {6} }} }}
+	}}", field_enum, fields.len(), all, offsets, sizes, aligned, names));
+	emit_impl_f(code, stru, |body| {
+		emit_static(body, "/// Reads the value of the field.");
+		emit_companion_vis(body, stru);
+		emit_text(body, &format!("fn read_dyn(&self, field: {}) -> {} {{ match field {{ {} }} }}", field_enum, value_enum, reads));
+	});
+}
+fn field_enum_fields(stru: &Structure) -> Vec<&Field> {
+	stru.fields.iter().filter(|field| is_single_value(field) && field.layout.method_get).collect()
+}
+// Wrapper which remembers the fields modified through its setters
+fn emit_tracked(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let fields = field_enum_fields(stru);
+	let tracked = format!("{}Tracked", stru.name);
+	let field_enum = format!("{}Field", stru.name);
+	// One bit per variant of the field enum
+	let words = fields.len().div_ceil(64);
+	let mut setters = String::new();
+	let mut flush = String::new();
+	for (i, field) in fields.iter().enumerate().filter(|(_, field)| field.layout.method_set) {
+		let vis: TokenStream = field.vis.0.iter().cloned().collect();
+		setters.push_str(&format!("/// Sets the field `{5}` and marks it dirty.
+		{0} fn set_{1}(&mut self, value: {2}) -> &mut Self {{
+			self.value.set_{1}(value);
+			self.dirty[{3}] |= 1 << {4};
+			self
+		}}", vis, field.accessor, field.ty, i / 64, i % 64, field.name));
+		flush.push_str(&format!("if self.dirty[{0}] & 1 << {1} != 0 {{ target.set_{2}({3}); }}", i / 64, i % 64, field.accessor, field_get_call(field, "self.value")));
+	}
+	emit_text(code, &format!("/// Wraps [`{}`] and tracks the fields modified through its setters.", stru.name));
+	emit_companion_vis(code, stru);
+	emit_text(code, &format!("struct {} {{ value: {}, dirty: [u64; {}] }}", tracked, stru.name, words));
+	emit_text(code, &format!("impl {0} {{
+		/// Wraps the value with no dirty fields.
+		pub fn new(value: {1}) -> {0} {{ {0} {{ value, dirty: [0; {2}] }} }}
+		/// Returns the wrapped value.
+		pub fn into_inner(self) -> {1} {{ self.value }}
+		/// Returns whether the field was modified since the dirty fields were last cleared.
+		pub fn is_dirty(&self, field: {3}) -> bool {{
+			let index = field as usize;
+			self.dirty[index / 64] & 1 << (index % 64) != 0
+		}}
+		/// Returns the modified fields in declaration order.
+		pub fn dirty(&self) -> impl Iterator<Item = {3}> + '_ {{
+			let all: &'static [{3}] = &{3}::ALL;
+			all.iter().cloned().filter(move |&field| self.is_dirty(field))
+		}}
+		/// Clears the dirty fields.
+		pub fn clear_dirty(&mut self) {{ self.dirty = [0; {2}]; }}
+		/// Copies the dirty fields into the target and clears them.
+		pub fn flush_into(&mut self, target: &mut {1}) {{
+			{4}
+			self.clear_dirty();
+		}}
+		{5}
+	}}", tracked, stru.name, words, field_enum, flush, setters));
+	emit_text(code, &format!("impl ::core::ops::Deref for {} {{
+		type Target = {};
+		fn deref(&self) -> &{1} {{ &self.value }}
+	}}", tracked, stru.name));
+}
+
+// Initializes the structure in place, a const flag per field records whether it was set
+fn emit_init(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let fields: Vec<&Field> = stru.fields.iter().filter(|field| !field.layout.pad).collect();
+	let init = format!("{}Init", stru.name);
+	let flags: Vec<String> = fields.iter().map(|field| field.name.to_string().to_uppercase()).collect();
+	let params: String = flags.iter().map(|flag| format!(", const {}: bool", flag)).collect();
+	let unset: String = flags.iter().map(|_| ", false").collect();
+	let done: String = flags.iter().map(|_| ", true").collect();
+	let mut setters = String::new();
+	for (i, field) in fields.iter().enumerate() {
+		let vis: TokenStream = field.vis.0.iter().cloned().collect();
+		let others: String = flags.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, flag)| format!(", const {}: bool", flag)).collect();
+		let args = |set: &str| -> String { flags.iter().enumerate().map(|(j, flag)| if j == i { format!(", {}", set) } else { format!(", {}", flag) }).collect() };
+		setters.push_str(&format!("impl<'a{0}> {1}<'a{2}> {{
+			/// Sets the field `{3}`, it cannot be set again.
+			{4} fn set_{5}(self, value: {6}) -> {1}<'a{7}> {{
+				self.value.set_{5}(value);
+				{1} {{ value: self.value }}
+			}}
+		}}", others, init, args("false"), field.name, vis, field.accessor, field.ty, args("true")));
+	}
+	emit_text(code, &format!("/// Initializes [`{}`] in place, `finish` is only available once every field has been set.", stru.name));
+	emit_static(code, "#[must_use]");
+	emit_companion_vis(code, stru);
+	emit_text(code, &format!("struct {}<'a{}> {{ value: &'a mut {} }}", init, params, stru.name));
+	emit_impl_f(code, stru, |body| {
+		emit_static(body, "/// Zeroes the slot and returns the initializer which writes the fields into it.");
+		emit_companion_vis(body, stru);
+		emit_text(body, &format!("fn init(slot: &mut ::core::mem::MaybeUninit<Self>) -> {}<'_{}> {{
+			// The storage of the structure is plain bytes, all zeroes is a valid value
+			unsafe {{ ::core::ptr::write_bytes(slot.as_mut_ptr(), 0, 1); }}
+			{0} {{ value: unsafe {{ &mut *slot.as_mut_ptr() }} }}
+		}}", init, unset));
+	});
+	emit_text(code, &setters);
+	emit_text(code, &format!("impl<'a> {}<'a{}> {{
+		/// Returns the structure with every field set.
+		pub fn finish(self) -> &'a mut {} {{ self.value }}
+	}}", init, done, stru.name));
+}
+
+// Expression which is true if the stored `value` of the field fails its conversion, clamp range or validate function
+fn field_invalid_expr(field: &Field) -> Option<String> {
+	let layout = &field.layout;
+	let exposed = field.ty.to_string();
+	let invalid = match (&layout.stored, layout.convert) {
+		(Some(_), Some(Convert::Valid)) | (Some(_), Some(Convert::Strict)) if exposed == "bool" => String::from("value > 1"),
+		(Some(_), Some(Convert::Valid)) | (Some(_), Some(Convert::Strict)) => String::from("::core::char::from_u32(value).is_none()"),
+		(Some(stored), Some(Convert::Enum)) => format!("{}.is_none()", stored_get_expr(field, stored)),
+		(Some(stored), Some(Convert::TryFrom)) => format!("{}.is_err()", stored_get_expr(field, stored)),
+		_ => match (&layout.clamp, &layout.validate) {
+			(Some((min, max)), _) => format!("{{ const MIN: {0} = {1}; const MAX: {0} = {2}; !(MIN..=MAX).contains(&value) }}", field.ty, min.0, max.0),
+			(None, Some(validate)) => {
+				let value = match &layout.stored {
+					Some(stored) if is_infallible(field) => stored_get_expr(field, stored),
+					Some(_) => return None,
+					None => String::from("value"),
+				};
+				format!("!::struct_layout::Validity::accepted({}({}))", validate.0, value)
+			},
+			(None, None) => return None,
+		},
+	};
+	Some(invalid)
+}
+
+// Writes the bytes of a single field at a runtime offset, eg. patches from a script
+fn emit_apply_patch(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let fields: Vec<&Field> = stru.fields.iter()
+		.filter(|field| is_single_value(field) && !field.layout.pad && field.layout.manually_drop.is_none() && field.layout.check_cfg.is_none())
+		.collect();
+	let mut arms = String::new();
+	for field in &fields {
+		let validate = match field_invalid_expr(field) {
+			Some(invalid) => format!("let value = unsafe {{ ptr::read_unaligned(bytes.as_ptr() as *const FieldT) }};
+				if {} {{
+					return ::core::result::Result::Err(::struct_layout::PatchError::Invalid {{ field: \"{}\" }});
+				}}", invalid, field.name),
+			None => String::new(),
+		};
+		let mut update = Vec::new();
+		emit_checksum_auto(&mut update, stru, field);
+		arms.push_str(&format!("{{
+			const FIELD_OFFSET: usize = {0}::{1};
+			type FieldT = {2};
+			const FIELD_END: usize = FIELD_OFFSET + mem::size_of::<FieldT>();
+			if (FIELD_OFFSET..FIELD_END).contains(&offset) {{
+				if end > FIELD_END {{
+					return ::core::result::Result::Err(::struct_layout::PatchError::Straddle {{ field: \"{3}\", offset, len: bytes.len() }});
+				}}
+				if offset != FIELD_OFFSET || end != FIELD_END {{
+					return ::core::result::Result::Err(::struct_layout::PatchError::Size {{ field: \"{3}\", expected: mem::size_of::<FieldT>(), len: bytes.len() }});
+				}}
+				{4}
+				unsafe {{ ptr::copy_nonoverlapping(bytes.as_ptr(), (self as *mut Self as *mut u8).add(FIELD_OFFSET), bytes.len()); }}
+				{5}
+				return ::core::result::Result::Ok(\"{3}\");
+			}}
+		}}", stru.name, field_offset_const(field), field_storage_ty(field), field.name, validate, update.into_iter().collect::<TokenStream>()));
+	}
+	// The patch is read as the stored type before the conversion checks it, any bytes must be a valid value of it
+	let unsafety = if fields.iter().all(|field| is_pod_field(field) || is_checked_pod(stru, field)) { "" } else { "unsafe" };
+	emit_static(code, "/// Writes the bytes of the field at the offset and returns its name, the bytes must cover exactly one field and hold a valid value.");
+	if !unsafety.is_empty() {
+		emit_static(code, "///\n/// # Safety\n///\n/// The bytes must hold a valid value of the field they cover.");
+	}
+	emit_companion_vis(code, stru);
+	emit_text(code, &format!("{} fn apply_patch(&mut self, offset: usize, bytes: &[u8]) -> ::core::result::Result<&'static str, ::struct_layout::PatchError> {} {{
+		use ::core::{{mem, ptr}};
+		let end = match offset.checked_add(bytes.len()) {{
+			::core::option::Option::Some(end) if end <= mem::size_of::<Self>() => end,
+			_ => return ::core::result::Result::Err(::struct_layout::PatchError::OutOfBounds {{ offset, len: bytes.len() }}),
+		}};
+		{}
+		let _ = end;
+		::core::result::Result::Err(::struct_layout::PatchError::Padding {{ offset }})
+	}}", unsafety, fields_check_bounds(stru, &fields), arms));
+}
+
+// Checks a buffer of consecutive records against the declared conversions, clamps and validate functions
+fn emit_validate_all(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let error = format!("{}ValidateError", stru.name);
+	let mut checks = String::new();
+	for field in stru.fields.iter().filter(|field| is_single_value(field) && !field.layout.pad) {
+		let invalid = match field_invalid_expr(field) {
+			Some(invalid) => invalid,
+			None => continue,
+		};
+		checks.push_str(&format!("{{
+			const FIELD_OFFSET: usize = {0}::{1};
+			type FieldT = {2};
+			let value = unsafe {{ ::core::ptr::read_unaligned(bytes.as_ptr().add(FIELD_OFFSET) as *const FieldT) }};
+			if {3} {{
+				return ::core::result::Result::Err({4}::Field {{ record, field: \"{5}\", bytes: &bytes[FIELD_OFFSET..FIELD_OFFSET + ::core::mem::size_of::<FieldT>()] }});
+			}}
+		}}", stru.name, field_offset_const(field), field_storage_ty(field), invalid, error, field.name));
+	}
+	emit_text(code, &format!("/// Error of [`{}::validate_all`].", stru.name));
+	emit_static(code, "#[derive(Copy, Clone, Debug, Eq, PartialEq)]");
+	emit_companion_vis(code, stru);
+	emit_text(code, &format!("enum {}<'a> {{
+		/// The length of the buffer is not a multiple of the size of the structure.
+		Length(usize),
+		/// The field of a record holds an invalid value, the bytes are those of the field.
+		Field {{ record: usize, field: &'static str, bytes: &'a [u8] }},
+	}}", error));
+	emit_text(code, &format!("impl<'a> ::core::fmt::Display for {0}<'a> {{
+		fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {{
+			match self {{
+				{0}::Length(len) => write!(f, \"the length {{}} is not a multiple of the size {{}} of `{1}`\", len, ::core::mem::size_of::<{1}>()),
+				{0}::Field {{ record, field, bytes }} => write!(f, \"record {{}} has an invalid value in field `{{}}`: {{:02x?}}\", record, field, bytes),
+			}}
+		}}
+	}}", error, stru.name));
+	emit_impl_f(code, stru, |body| {
+		emit_static(body, "/// Checks that the buffer holds whole records whose fields hold valid values, returns the number of records.");
+		emit_companion_vis(body, stru);
+		emit_text(body, &format!("fn validate_all(buf: &[u8]) -> ::core::result::Result<usize, {0}<'_>> {{
+			const SIZE: usize = ::core::mem::size_of::<{1}>();
+			if buf.len().checked_rem(SIZE) != ::core::option::Option::Some(0) {{
+				return ::core::result::Result::Err({0}::Length(buf.len()));
+			}}
+			for (record, bytes) in buf.chunks_exact(SIZE).enumerate() {{
+				let _ = (record, bytes);
+				{2}
+			}}
+			::core::result::Result::Ok(buf.len() / SIZE)
+		}}", error, stru.name, checks));
+	});
+}
+
+// Borrows the structure for a batch of writes and increments the sequence field after them
+fn emit_update_guard(code: &mut Vec<TokenTree>, stru: &Structure, seq: &Field) {
+	let guard = format!("{}Guard", stru.name);
+	let mut setters = String::new();
+	for field in stru.fields.iter().filter(|field| field.layout.method_set && is_single_value(field) && !field.layout.seq) {
+		let vis: TokenStream = field.vis.0.iter().cloned().collect();
+		setters.push_str(&format!("/// Sets the field `{3}`.
+		{0} fn set_{1}(&mut self, value: {2}) -> &mut Self {{
+			self.value.set_{1}(value);
+			self
+		}}", vis, field.accessor, field.ty, field.name));
+	}
+	emit_text(code, &format!("/// Updates [`{}`] and increments the field `{}` once it is dropped.", stru.name, seq.name));
+	emit_static(code, "#[must_use]");
+	emit_companion_vis(code, stru);
+	emit_text(code, &format!("struct {}<'a> {{ value: &'a mut {} }}", guard, stru.name));
+	emit_impl_f(code, stru, |body| {
+		emit_text(body, &format!("/// Returns the guard whose setters update the fields, the field `{}` is incremented after them.", seq.name));
+		emit_companion_vis(body, stru);
+		emit_text(body, &format!("fn update_guarded(&mut self) -> {}<'_> {{ {0} {{ value: self }} }}", guard));
+	});
+	emit_text(code, &format!("impl<'a> {}<'a> {{ {} }}", guard, setters));
+	emit_text(code, &format!("impl<'a> ::core::ops::Deref for {}<'a> {{
+		type Target = {};
+		fn deref(&self) -> &{1} {{ self.value }}
+	}}", guard, stru.name));
+	// The fence keeps the writes of the setters before the write of the sequence field
+	emit_text(code, &format!("impl<'a> ::core::ops::Drop for {}<'a> {{
+		fn drop(&mut self) {{
+			let seq = self.value.{1}().wrapping_add(1);
+			::core::sync::atomic::fence(::core::sync::atomic::Ordering::Release);
+			self.value.set_{1}(seq);
+		}}
+	}}", guard, seq.accessor));
+}
+
+// The layout as the macro parsed it, shown by the deprecation warning of its use
+fn emit_dump(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let dump = layout_dump(stru);
+	emit_impl_f(code, stru, |body| {
+		emit_static(body, "/// The layout as parsed by the attribute.");
+		emit_text(body, &format!("#[deprecated(note = {:?})]", dump));
+		emit_companion_vis(body, stru);
+		emit_text(body, &format!("const LAYOUT_DUMP: &'static str = {:?};", dump));
+	});
+	emit_text(code, &format!("const _: &str = {}::LAYOUT_DUMP;", stru.name));
+}
+fn layout_dump(stru: &Structure) -> String {
+	let expr = |expr: &Expr| type_string(&Type(expr.0.clone().into_iter().collect()));
+	let layout = &stru.layout;
+	let check = layout.check.as_deref().unwrap_or("none");
+	let mut dump = format!("struct_layout dump of `{}`: size = {}, align = {}, check = {}", stru.name, expr(&layout.size), expr(&layout.align), check);
+	for field in stru.fields.iter().chain(&stru.optional) {
+		let layout = &field.layout;
+		dump.push_str(&format!("\nfield `{}`: ", field.name));
+		if layout.dynamic {
+			dump.push_str("offset = dynamic");
+		}
+		else {
+			dump.push_str(&format!("offset = {}", expr(&layout.offset)));
+		}
+		dump.push_str(&format!(", type = {}", type_string(&field.ty)));
+		if let Some(stored) = &layout.stored {
+			dump.push_str(&format!(", stored = {}", type_string(stored)));
+		}
+		if let Some((rows, cols)) = &layout.dims {
+			dump.push_str(&format!(", dims = ({}, {})", expr(rows), expr(cols)));
+		}
+		if let Some(count) = &layout.count {
+			dump.push_str(&format!(", count = {}", expr(count)));
+		}
+		if let Some(stride) = &layout.stride {
+			dump.push_str(&format!(", stride = {}", expr(stride)));
+		}
+		if let Some(len) = &layout.len {
+			dump.push_str(&format!(", len = {}", expr(len)));
+		}
+		if layout.tail.is_some() {
+			dump.push_str(", tail");
+		}
+		if layout.optional.is_some() {
+			dump.push_str(", optional");
+		}
+		dump.push_str(&format!(", accessors = {}", field_accessors(layout)));
+	}
+	dump
+}
+// The enabled accessors of a field separated by spaces
+fn field_accessors(layout: &FieldLayout) -> String {
+	let methods = [
+		("get", layout.method_get), ("set", layout.method_set), ("ref", layout.method_ref), ("mut", layout.method_mut),
+		("slice", layout.method_slice), ("str", layout.method_str), ("cstr", layout.method_cstr), ("wstr", layout.method_wstr),
+		("modify", layout.method_modify), ("copy_into", layout.method_copy_into), ("copy_from", layout.method_copy_from), ("zero", layout.method_zero),
+		("bytes_array", layout.method_bytes_array), ("column", layout.method_column), ("unaligned", layout.method_unaligned_ref), ("shiftable", layout.method_shiftable), ("clamped", layout.method_clamped),
+		("atomic_ordered", layout.method_atomic), ("endian", layout.method_endian),
+	];
+	let methods: Vec<&str> = methods.iter().filter(|&&(_, enabled)| enabled).map(|&(name, _)| name).collect();
+	if methods.is_empty() { String::from("none") } else { methods.join(" ") }
+}
+
+// Markdown table of the fields after the documentation of the structure
+fn emit_doc_layout(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let source = |expr: &Expr| type_string(&Type(expr.0.clone().into_iter().collect()));
+	// Offsets are easier to compare in hex
+	let expr = |expr: &Expr| match int_literal_value(expr) {
+		Some(value) => format!("{:#x}", value),
+		None => source(expr),
+	};
+	let mut lines = vec![
+		String::new(),
+		String::from("# Layout"),
+		String::new(),
+		format!("Size {} bytes, alignment {}.", markdown_code(&source(&stru.layout.size)), markdown_code(&source(&stru.layout.align))),
+		String::new(),
+	];
+	let mut rows = Vec::new();
+	for field in stru.fields.iter().chain(&stru.optional) {
+		let layout = &field.layout;
+		let mut offset = if layout.dynamic { String::from("dynamic") } else if layout.optional.is_some() { format!("{} if present", markdown_code(&expr(&layout.offset))) } else { markdown_code(&expr(&layout.offset)) };
+		if let Some((declared, unit)) = &layout.unit_offset {
+			offset = format!("{} ({} × {})", offset, markdown_code(&expr(declared)), markdown_code(&source(unit)));
+		}
+		let size = match field_literal_size(field) {
+			Some(size) => size.to_string(),
+			None if layout.tail.is_some() => String::from("tail"),
+			None if layout.len.is_some() => markdown_code(&expr(layout.len.as_ref().unwrap())),
+			None => markdown_code(&format!("size_of::<{}>()", type_string(field_storage_ty(field)))),
+		};
+		let ty = match &layout.stored {
+			Some(stored) => format!("{} stored as {}", markdown_code(&type_string(&field.ty)), markdown_code(&type_string(stored))),
+			None => markdown_code(&type_string(&field.ty)),
+		};
+		rows.push(vec![markdown_code(&field.name.to_string()), offset, size, ty, field_accessors(layout), field_endianness(field).to_string()]);
+	}
+	lines.extend(markdown_table(&["Field", "Offset", "Size", "Type", "Accessors", "Endianness"], &rows));
+	for line in lines {
+		emit_text(code, &format!("#[doc = {:?}]", line));
+	}
+}
+// Reads the field by value through its getter, which returns a reference with the auto_ref argument
+fn field_get_call(field: &Field, recv: &str) -> String {
+	if field.layout.get_ref {
+		format!("(*{}.{}())", recv, field.accessor)
+	}
+	else {
+		format!("{}.{}()", recv, field.accessor)
+	}
+}
+
+// Hashes the declared layout with 64-bit FNV-1a, the tokens are normalized so the hash does not depend on the compiler version
+fn layout_hash(stru: &Structure) -> u64 {
+	fn expr_string(expr: &Expr) -> String {
+		match int_literal_value(expr) {
+			Some(value) => value.to_string(),
+			None => type_string(&Type(expr.0.clone().into_iter().collect())),
+		}
+	}
+	let mut hash = fnv1a(FNV_OFFSET_BASIS, stru.name.to_string().as_bytes());
+	for part in &[expr_string(&stru.layout.size), expr_string(&stru.layout.align)] {
+		hash = fnv1a(fnv1a(hash, &[0]), part.as_bytes());
+	}
+	for field in &stru.fields {
+		for part in &[field.name.to_string(), expr_string(&field.layout.offset), type_string(&field.ty)] {
+			hash = fnv1a(fnv1a(hash, &[0]), part.as_bytes());
+		}
+		if let Some((start, end)) = field.layout.bits {
+			hash = fnv1a(fnv1a(hash, &[0]), format!("{}..{}", start, end).as_bytes());
+		}
+	}
+	hash
+}
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+	for &byte in bytes {
+		hash ^= byte as u64;
+		hash = hash.wrapping_mul(0x100000001b3);
+	}
+	hash
+}
+
+// The number of bytes covered by the field if known while expanding, see field_extent
+fn field_literal_size(field: &Field) -> Option<u128> {
+	let layout = &field.layout;
+	if layout.tail.is_some() || layout.dynamic {
+		return None;
+	}
+	if let Some(len) = &layout.len {
+		return int_literal_value(len);
+	}
+	let mut elem = field_storage_ty(field).clone();
+	let mut size = 1;
+	while let Some((inner, len)) = array_ty(&elem) {
+		size *= int_literal_value(&len)?;
+		elem = inner;
+	}
+	let size = size * primitive_size(&elem)? as u128;
+	if let Some((rows, cols)) = &layout.dims {
+		return Some(int_literal_value(rows)? * int_literal_value(cols)? * size);
+	}
+	if let Some(count) = &layout.count {
+		let count = int_literal_value(count)?;
+		let stride = match &layout.stride {
+			Some(stride) => int_literal_value(stride)?,
+			None => size,
+		};
+		return Some(if count == 0 { 0 } else { (count - 1) * stride + size });
+	}
+	Some(size)
+}
+fn field_endianness(field: &Field) -> &'static str {
+	let layout = &field.layout;
+	if layout.opaque {
+		return "opaque";
+	}
+	let mut elem = field_storage_ty(field).clone();
+	while let Some((inner, _)) = array_ty(&elem) {
+		elem = inner;
+	}
+	match primitive_size(&elem) {
+		Some(1) => "n/a",
+		Some(_) if layout.method_endian => "native, le and be accessors",
+		Some(_) => "native",
+		None if is_pointer_sized(&elem) => "native",
+		None => "of the type",
+	}
+}
+// Inline code in a markdown table, pipes would end the cell
+fn markdown_code(text: &str) -> String {
+	format!("`{}`", text.replace('|', "\\|"))
+}
+fn markdown_table(header: &[&str], rows: &[Vec<String>]) -> Vec<String> {
+	let mut lines = vec![format!("| {} |", header.join(" | ")), format!("|{}", "---|".repeat(header.len()))];
+	for row in rows {
+		lines.push(format!("| {} |", row.join(" | ")));
+	}
+	lines
+}
+
+// Conversions to and from the only field, which is also the target of Deref
+fn emit_transparent(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let field = &stru.fields[0];
+	emit_text(code, &format!("const _: () = assert!({0}::{1} == 0 && ::core::mem::size_of::<{2}>() == ::core::mem::size_of::<{0}>(),
+		\"struct_layout: the field `{3}` of the transparent structure `{0}` must be at offset 0 and span the whole structure\");", stru.name, field_offset_const(field), field.ty, field.name));
+	emit_text(code, &format!("const _: () = assert!({}::{}, \"struct_layout: the field `{}` of the transparent structure `{0}` must be aligned for Deref\");",
+		stru.name, field_aligned_const(field), field.name));
+	let bounds = format!("where {0}: {1}, {0}: 'static", field.ty, field_check_bound(stru, field));
+	emit_text(code, &format!("impl ::core::convert::From<{0}> for {1} {2} {{
+		fn from(value: {0}) -> {1} {{
+			let mut instance = {3};
+			unsafe {{ ::core::ptr::write_unaligned(&mut instance as *mut {1} as *mut {0}, value); }}
+			instance
+		}}
+	}}", field.ty, stru.name, bounds, struct_zeroed(stru)));
+	emit_text(code, &format!("impl ::core::convert::From<{1}> for {0} {2} {{
+		fn from(value: {1}) -> {0} {{ unsafe {{ ::core::ptr::read_unaligned(&value as *const {1} as *const {0}) }} }}
+	}}", field.ty, stru.name, bounds));
+	emit_text(code, &format!("impl ::core::ops::Deref for {1} {2} {{
+		type Target = {0};
+		fn deref(&self) -> &{0} {{ unsafe {{ &*(self as *const {1} as *const {0}) }} }}
+	}}", field.ty, stru.name, bounds));
+}
+
+// Plain structure with the values of the fields
+// The packed structure has no padding, the bytes before a field must be declared with a pad field
+fn emit_ffi_twin(code: &mut Vec<TokenTree>, stru: &Structure, twin: &Ident) {
+	let mut fields: Vec<&Field> = stru.fields.iter().collect();
+	// Literal offsets may be declared in any order, the fields of the twin are sorted by offset
+	let offsets: Option<Vec<u128>> = fields.iter().map(|field| int_literal_value(&field.layout.offset)).collect();
+	if let Some(offsets) = offsets {
+		let mut sorted: Vec<(u128, &Field)> = offsets.into_iter().zip(fields).collect();
+		sorted.sort_by_key(|&(offset, _)| offset);
+		fields = sorted.into_iter().map(|(_, field)| field).collect();
+	}
+	let field_ty = |field: &Field| {
+		let ty = field_storage_ty(field);
+		let layout = &field.layout;
+		if let Some((rows, cols)) = &layout.dims {
+			let (outer, inner) = if layout.col_major { (cols, rows) } else { (rows, cols) };
+			format!("[[{}; {}]; {}]", ty, inner.0, outer.0)
+		}
+		else if let Some(count) = &layout.count {
+			format!("[{}; {}]", ty, count.0)
+		}
+		else if let Some(len) = &layout.len {
+			format!("[u8; {}]", len.0)
+		}
+		else {
+			ty.to_string()
+		}
+	};
+	emit_text(code, &format!("/// Packed twin of [`{}`] with a field for every field, for C APIs which take the structure by value.", stru.name));
+	if stru.derived.contains(&DerivedTrait::Copy) {
+		emit_static(code, "#[derive(Copy, Clone)]");
+	}
+	emit_static(code, "#[repr(C, packed)]");
+	emit_companion_vis(code, stru);
+	emit_text(code, &format!("struct {}", twin));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		for field in &fields {
+			emit_attrs(body, &field.attrs);
+			emit_vis(body, &field.vis);
+			emit_text(body, &format!("{}: {},", field.name, field_ty(field)));
+		}
+	});
+	let mut asserts = String::new();
+	for field in &fields {
+		asserts.push_str(&format!("assert!(::core::mem::offset_of!({0}, {2}) == {1}::{3}, \"struct_layout: the field `{2}` of the ffi twin `{0}` is not at its offset, declare the bytes before it with a pad field\");",
+			twin, stru.name, field.name, field_offset_const(field)));
+	}
+	asserts.push_str(&format!("assert!(::core::mem::size_of::<{0}>() == ::core::mem::size_of::<{1}>(), \"struct_layout: the ffi twin `{0}` does not cover the whole of `{1}`, declare the trailing bytes with a pad field\");", twin, stru.name));
+	emit_text(code, &format!("const _: () = {{ {} }};", asserts));
+	emit_text(code, &format!("impl ::core::convert::From<{0}> for {1} {{
+		fn from(value: {0}) -> {1} {{
+			let mut twin = ::core::mem::MaybeUninit::<{1}>::uninit();
+			unsafe {{
+				::core::ptr::copy_nonoverlapping(&value as *const {0} as *const u8, twin.as_mut_ptr() as *mut u8, ::core::mem::size_of::<{1}>());
+				twin.assume_init()
+			}}
+		}}
+	}}
+	impl ::core::convert::From<{1}> for {0} {{
+		fn from(twin: {1}) -> {0} {{
+			let mut value = {2};
+			unsafe {{ ::core::ptr::copy_nonoverlapping(&twin as *const {1} as *const u8, &mut value as *mut {0} as *mut u8, ::core::mem::size_of::<{1}>()); }}
+			value
+		}}
+	}}", stru.name, twin, struct_zeroed(stru)));
+}
+fn emit_mirror(code: &mut Vec<TokenTree>, stru: &Structure, mirror: &Mirror) {
+	let name = format!("{}Values", stru.name);
+	// Fallible conversions mirror the stored value
+	let fields: Vec<(&Field, String, &Type)> = stru.fields.iter()
+		.filter(|field| field.layout.method_get && field.layout.method_set && is_single_value(field))
+		.map(|field| match field.layout.convert {
+			Some(Convert::TryFrom) | Some(Convert::Enum) | Some(Convert::Strict) => (field, "_raw", field_storage_ty(field)),
+			_ => (field, "", &field.ty),
+		})
+		.map(|(field, suffix, ty)| (field, suffix.to_string(), ty))
+		.collect();
+	match mirror {
+		Mirror::Plain => (),
+		Mirror::Derive => {
+			let derived: Vec<String> = stru.derived.iter()
+				.filter(|derive| !matches!(derive, DerivedTrait::Arbitrary | DerivedTrait::QuickcheckArbitrary | DerivedTrait::LowerHex | DerivedTrait::UpperHex))
+				.map(|derive| format!("{:?}", derive)).collect();
+			emit_text(code, &format!("#[derive({})]", derived.join(", ")));
+		},
+		Mirror::DeriveList(list) => emit_text(code, &format!("#[derive({})]", list)),
+	}
+	emit_companion_vis(code, stru);
+	emit_text(code, &format!("struct {}", name));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		for (field, _, ty) in &fields {
+			emit_attrs(body, &field.attrs);
+			emit_text(body, &format!("pub {}: {},", field.name, ty));
+		}
+	});
+	emit_text(code, &format!("impl<'a> ::core::convert::From<&'a {}> for {}", stru.name, name));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_text(body, &format!("fn from(value: &'a {}) -> {}", stru.name, name));
+		emit_group_f(body, Delimiter::Brace, |body| {
+			emit_ident(body, &name);
+			emit_group_f(body, Delimiter::Brace, |body| {
+				for (field, suffix, _) in &fields {
+					let value = if suffix.is_empty() { field_get_call(field, "value") } else { format!("value.{}{}()", field.accessor, suffix) };
+					emit_text(body, &format!("{}: {},", field.name, value));
+				}
+			});
+		});
+	});
+	emit_text(code, &format!("impl<'a> ::core::convert::From<&'a {}> for {}", name, stru.name));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_text(body, &format!("fn from(value: &'a {}) -> {}", name, stru.name));
+		emit_group_f(body, Delimiter::Brace, |body| {
+			emit_text(body, &format!("let mut instance = {};", struct_zeroed(stru)));
+			for (field, suffix, _) in &fields {
+				emit_text(body, &format!("instance.set_{}{}(value.{});", field.accessor, suffix, field.name));
+			}
+			emit_static(body, "; instance");
+		});
+	});
+	// Compares the fields of the structure with the plain structure if it can be compared
+	let partial_eq = match mirror {
+		Mirror::DeriveList(list) => list.clone().into_iter().any(|tt| matches!(tt, TokenTree::Ident(ident) if ident.to_string() == "PartialEq")),
+		_ => false,
+	};
+	if partial_eq {
+		let mut cmp: Vec<String> = fields.iter().map(|(field, suffix, _)| {
+			let value = if suffix.is_empty() { field_get_call(field, "self") } else { format!("self.{}{}()", field.accessor, suffix) };
+			format!("{} == other.{}", value, field.name)
+		}).collect();
+		if cmp.is_empty() {
+			cmp.push(String::from("{ let _ = other; true }"));
+		}
+		emit_text(code, &format!("impl ::core::cmp::PartialEq<{}> for {} {{ fn eq(&self, other: &{0}) -> bool {{ {} }} }}", name, stru.name, cmp.join(" && ")));
+		emit_text(code, &format!("impl ::core::cmp::PartialEq<{}> for {} {{ fn eq(&self, other: &{0}) -> bool {{ other == self }} }}", stru.name, name));
+	}
+}
+
+// Accessor trait shared by structures with the same fields
+fn emit_impl_trait(code: &mut Vec<TokenTree>, stru: &Structure, tr: &Ident, define: bool) {
+	let fields: Vec<&Field> = stru.fields.iter().filter(|field| is_single_value(field)).collect();
+	if define {
+		emit_text(code, &format!("/// Accessors of the fields of [`{}`].", stru.name));
+		emit_companion_vis(code, stru);
+		emit_text(code, &format!("trait {}", tr));
+		emit_group_f(code, Delimiter::Brace, |body| {
+			for field in &fields {
+				if field.layout.method_get {
+					emit_attrs(body, &field.attrs);
+					emit_text(body, &format!("fn {}(&self) -> {};", field.accessor, field_get_ty(field)));
+				}
+				if field.layout.method_set {
+					emit_attrs(body, &field.attrs);
+					emit_text(body, &format!("fn set_{}(&mut self, value: {});", field.accessor, field.ty));
+				}
+			}
+		});
+	}
+	emit_text(code, &format!("impl {} for {}", tr, stru.name));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		for field in &fields {
+			if field.layout.method_get {
+				emit_text(body, &format!("fn {0}(&self) -> {1} {{ {2}::{0}(self) }}", field.accessor, field_get_ty(field), stru.name));
+			}
+			if field.layout.method_set {
+				emit_text(body, &format!("fn set_{0}(&mut self, value: {1}) {{ {2}::set_{0}(self, value); }}", field.accessor, field.ty, stru.name));
+			}
+		}
+	});
+}
+
+// Trait with every accessor of the fields, the setters do not return the structure to keep the trait object safe
+fn emit_mockable(code: &mut Vec<TokenTree>, stru: &Structure, tr: &Ident) {
+	let methods = mockable_methods(stru);
+	emit_text(code, &format!("/// Accessors of [`{}`], implement this trait to substitute a fake.", stru.name));
+	emit_companion_vis(code, stru);
+	emit_text(code, &format!("trait {}", tr));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		for (field, sig, _) in &methods {
+			emit_attrs(body, &field.attrs);
+			emit_text(body, &format!("{};", sig));
+		}
+	});
+	emit_text(code, &format!("impl {} for {}", tr, stru.name));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		for (field, sig, call) in &methods {
+			emit_attrs(body, &field.attrs);
+			emit_text(body, &format!("{} {{ {} }}", sig, call));
+		}
+	});
+}
+// The signature of every accessor in the trait and the call forwarding to the inherent accessor
+fn mockable_methods(stru: &Structure) -> Vec<(&Field, String, String)> {
+	let policy = index_policy(stru);
+	let setter = |call: String| match policy {
+		OnError::Panic => format!("{};", call),
+		_ => format!("{}.map(|_| ())", call),
+	};
+	let mut methods = Vec::new();
+	for field in &stru.fields {
+		let layout = &field.layout;
+		if layout.flatten || layout.variant.is_some() || layout.dynamic || layout.manually_drop.is_some() || layout.tail.is_some() || layout.len.is_some() {
+			continue;
+		}
+		let (name, ty, this) = (&field.accessor, &field.ty, &stru.name);
+		if layout.dims.is_some() {
+			if layout.method_get {
+				methods.push((field, format!("fn {}_at(&self, row: usize, col: usize) -> {}", name, checked_ty(policy, &ty.to_string())), format!("{}::{}_at(self, row, col)", this, name)));
+			}
+			if layout.method_set {
+				methods.push((field, format!("fn set_{}_at(&mut self, row: usize, col: usize, value: {}) -> {}", name, ty, checked_ty(policy, "()")), setter(format!("{}::set_{}_at(self, row, col, value)", this, name))));
+			}
+			continue;
+		}
+		if layout.count.is_some() {
+			if layout.method_get {
+				methods.push((field, format!("fn {}(&self, index: usize) -> {}", name, checked_ty(policy, &ty.to_string())), format!("{}::{}(self, index)", this, name)));
+			}
+			if layout.method_set {
+				methods.push((field, format!("fn set_{}(&mut self, index: usize, value: {}) -> {}", name, ty, checked_ty(policy, "()")), setter(format!("{}::set_{}(self, index, value)", this, name))));
+			}
+			if layout.method_ref {
+				methods.push((field, format!("fn {}_ref(&self, index: usize) -> {}", name, checked_ty(policy, &format!("&{}", ty))), format!("{}::{}_ref(self, index)", this, name)));
+			}
+			if layout.method_mut {
+				methods.push((field, format!("fn {}_mut(&mut self, index: usize) -> {}", name, checked_ty(policy, &format!("&mut {}", ty))), format!("{}::{}_mut(self, index)", this, name)));
+			}
+			continue;
+		}
+		if layout.method_get {
+			methods.push((field, format!("fn {}(&self) -> {}", name, field_get_ty(field)), format!("{}::{}(self)", this, name)));
+		}
+		if layout.method_set {
+			methods.push((field, format!("fn set_{}(&mut self, value: {})", name, ty), format!("{}::set_{}(self, value);", this, name)));
+		}
+		if layout.method_ref {
+			methods.push((field, format!("fn {}_ref(&self) -> &{}", name, ty), format!("{}::{}_ref(self)", this, name)));
+		}
+		if layout.method_mut {
+			methods.push((field, format!("fn {}_mut(&mut self) -> &mut {}", name, ty), format!("{}::{}_mut(self)", this, name)));
+		}
+		if let (None, Some((elem, _))) = (&layout.stored, array_ty(ty)) {
+			if layout.method_get {
+				methods.push((field, format!("fn {}_at(&self, index: usize) -> {}", name, checked_ty(policy, &elem.to_string())), format!("{}::{}_at(self, index)", this, name)));
+			}
+			if layout.method_set {
+				methods.push((field, format!("fn set_{}_at(&mut self, index: usize, value: {}) -> {}", name, elem, checked_ty(policy, "()")), setter(format!("{}::set_{}_at(self, index, value)", this, name))));
+			}
+		}
+	}
+	methods
+}
+
+// Zeroes the bytes which are not covered by any field
+fn emit_clear_padding(code: &mut Vec<TokenTree>, stru: &Structure) {
+	emit_static(code, "/// Zeroes the bytes which are not covered by any field.");
+	emit_vis(code, &stru.vis);
+	emit_text(code, &format!("fn clear_padding(&mut self) -> &mut Self {{
+		const COVERED: [bool; {}] = {};
+		let bytes: &mut [u8] = {};
+		for (byte, &covered) in bytes.iter_mut().zip(COVERED.iter()) {{
+			if !covered {{
+				*byte = 0;
+			}}
+		}}
+		self
+	}}", stru.layout.size.0, covered_bytes(stru), storage_bytes(&stru.layout, "self.0", true)));
+}
+// The byte ranges covered by the fields and the gaps between them
+fn emit_coverage(code: &mut Vec<TokenTree>, stru: &Structure) {
+	for (name, covered, doc) in [("COVERAGE", "", "covered by the fields"), ("GAPS", "!", "not covered by any field")] {
+		emit_text(code, &format!("/// The byte ranges {}, sorted and merged.", doc));
+		emit_companion_vis(code, stru);
+		emit_text(code, &format!("const {0}: &'static [::core::ops::Range<usize>] = {{
+			const SIZE: usize = {1};
+			const COVERED: [bool; SIZE] = {2};
+			const LEN: usize = {{
+				let mut len = 0;
+				let mut i = 0;
+				while i < SIZE {{
+					if {3}COVERED[i] && (i == 0 || !{3}COVERED[i - 1]) {{
+						len += 1;
+					}}
+					i += 1;
+				}}
+				len
+			}};
+			const RANGES: [::core::ops::Range<usize>; LEN] = {{
+				const EMPTY: ::core::ops::Range<usize> = 0..0;
+				let mut ranges = [EMPTY; LEN];
+				let mut len = 0;
+				let mut i = 0;
+				while i < SIZE {{
+					if {3}COVERED[i] {{
+						let start = i;
+						while i < SIZE && {3}COVERED[i] {{
+							i += 1;
+						}}
+						ranges[len] = start..i;
+						len += 1;
+					}}
+					else {{
+						i += 1;
+					}}
+				}}
+				ranges
+			}};
+			&RANGES
+		}};", name, stru.layout.size.0, covered_bytes(stru), covered));
+	}
+}
+// Const expression of the bytes covered by the base and the fields, pad regions are not covered
+fn covered_bytes(stru: &Structure) -> String {
+	let size = &stru.layout.size.0;
+	// Every field covers a number of elements: (offset, size, count, stride)
+	let mut extents = Vec::new();
+	if let Some(base) = &stru.layout.base {
+		extents.push(format!("(0, ::core::mem::size_of::<{}>(), 1, 0)", base));
+	}
+	// Pad regions are cleared as well
+	for field in stru.fields.iter().filter(|field| !field.layout.pad).chain(&stru.optional) {
+		extents.push(field_elements(stru, field));
+	}
+	format!("{{
+		const EXTENTS: [(usize, usize, usize, usize); {0}] = [{1}];
+		let mut covered = [false; {2}];
+		let mut i = 0;
+		while i < EXTENTS.len() {{
+			let (offset, size, count, stride) = EXTENTS[i];
+			// Out of bounds fields are reported by their asserts
+			let in_bounds = matches!(::struct_layout::__extent_end(offset, size, count, stride), Some(end) if end <= {2});
+			let mut j = 0;
+			while in_bounds && j < count {{
+				let mut k = 0;
+				while k < size {{
+					covered[offset + j * stride + k] = true;
+					k += 1;
+				}}
+				j += 1;
+			}}
+			i += 1;
+		}}
+		covered
+	}}", extents.len(), extents.join(", "), size)
+}
+// The strict argument asserts that the size is a multiple of the alignment and that no two fields share a byte
+fn emit_strict_asserts(code: &mut Vec<TokenTree>, stru: &Structure) {
+	// Sizes are otherwise rounded up to the alignment
+	let mut asserts = format!("assert!(({0}) % ({1}) == 0, \"struct_layout: the size of `{2}` is not a multiple of its alignment\");", stru.layout.size.0, stru.layout.align.0, stru.name);
+	let mut extents = Vec::new();
+	if let Some(base) = &stru.layout.base {
+		extents.push((None, format!("the base `{}`", type_string(base)), format!("(0, ::core::mem::size_of::<{}>(), 1, 0)", base)));
+	}
+	for field in stru.fields.iter().chain(&stru.optional) {
+		if !field.layout.overlaps && !field.layout.dynamic {
+			extents.push((Some(field), format!("field `{}`", field.name), field_elements(stru, field)));
+		}
+	}
+	for (i, (a, a_name, a_extent)) in extents.iter().enumerate() {
+		for (b, b_name, b_extent) in &extents[i + 1..] {
+			if let (Some(a), Some(b)) = (a, b) {
+				if shares_bytes_apart(a, b) {
+					continue;
+				}
+			}
+			asserts.push_str(&format!("assert!(!::struct_layout::__extents_overlap({}, {}), \"struct_layout: {} and {} of `{}` overlap, mark the field with the overlaps argument if this is intended\");",
+				a_extent, b_extent, a_name, b_name, stru.name));
+		}
+	}
+	emit_text(code, &format!("const _: () = {{ {} }};", asserts));
+}
+// Fields sharing bytes without overlapping: the variants of one discriminant and bitfields of one integer with disjoint bits
+fn shares_bytes_apart(a: &Field, b: &Field) -> bool {
+	if let (Some(a), Some(b)) = (&a.layout.variant, &b.layout.variant) {
+		if a.field.to_string() == b.field.to_string() && a.value.0.to_string() != b.value.0.to_string() {
+			return true;
+		}
+	}
+	if let (Some((a_start, a_end)), Some((b_start, b_end))) = (a.layout.bits, b.layout.bits) {
+		let same_place = a.layout.offset.0.to_string() == b.layout.offset.0.to_string() && type_string(field_storage_ty(a)) == type_string(field_storage_ty(b));
+		if same_place && (a_end <= b_start || b_end <= a_start) {
+			return true;
+		}
+	}
+	false
+}
+// The bytes covered by a field as the (offset, size, count, stride) arguments of `__extent_end`
+fn field_elements(stru: &Structure, field: &Field) -> String {
+	let layout = &field.layout;
+	let offset = format!("{}::{}", stru.name, field_offset_const(field));
+	if let Some(preds) = &layout.optional {
+		return format!("({}, ::core::mem::size_of::<{}>(), if cfg!(any({})) {{ 1 }} else {{ 0 }}, 0)", offset, field.ty, preds.join(", "));
+	}
+	let elem = format!("::core::mem::size_of::<{}>()", field_storage_ty(field));
+	if let Some((rows, cols)) = &layout.dims {
+		// Overflowing dims are reported by the asserts of the field
+		format!("({0}, {1}, match usize::checked_mul({2}, {3}) {{ Some(count) => count, None => 0 }}, {1})", offset, elem, rows.0, cols.0)
+	}
+	else if let Some(count) = &layout.count {
+		let stride = layout.stride.as_ref().map(|stride| stride.0.to_string()).unwrap_or_else(|| elem.clone());
+		format!("({}, {}, {}, {})", offset, elem, count.0, stride)
+	}
+	else if layout.tail.is_some() {
+		format!("({0}, {1} - ({0}), 1, 0)", offset, stru.layout.size.0)
+	}
+	else if let Some(len) = &layout.len {
+		format!("({}, {}, 1, 0)", offset, len.0)
+	}
+	else {
+		format!("({}, {}, 1, 0)", offset, elem)
+	}
+}
+// The JSON document is assembled at compile time from text with the values of the constant expressions between them
+fn emit_export_json(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let mut text = vec![format!("{{\"name\":{},\"size\":", json_string(&stru.name.to_string()))];
+	let mut values = Vec::new();
+	let mut value = |text: &mut Vec<String>, expr: String, next: String| {
+		values.push(expr);
+		text.push(next);
+	};
+	value(&mut text, stru.layout.size.0.to_string(), String::from(",\"align\":"));
+	let base = match &stru.layout.base {
+		Some(base) => format!(",\"base\":{}", json_string(&type_string(base))),
+		None => String::new(),
+	};
+	value(&mut text, stru.layout.align.0.to_string(), format!("{},\"fields\":[", base));
+	let fields = stru.fields.iter().filter(|field| !field.layout.pad).chain(&stru.optional);
+	for (i, field) in fields.enumerate() {
+		let layout = &field.layout;
+		let separator = if i == 0 { "" } else { "," };
+		text.last_mut().unwrap().push_str(&format!("{}{{\"name\":{},\"offset\":", separator, json_string(&field.name.to_string())));
+		// The offsets of dynamic fields are given at runtime
+		if layout.dynamic {
+			text.last_mut().unwrap().push_str("null,\"size\":");
+		}
+		else {
+			value(&mut text, format!("{}::{}", stru.name, field_offset_const(field)), String::from(",\"size\":"));
+		}
+		let elem = format!("::core::mem::size_of::<{}>()", field_storage_ty(field));
+		let size = if layout.tail.is_some() {
+			format!("({}) - {}::{}", stru.layout.size.0, stru.name, field_offset_const(field))
+		}
+		else if let Some(len) = &layout.len {
+			len.0.to_string()
+		}
+		else {
+			elem.clone()
+		};
+		value(&mut text, size, String::new());
+		let repeated = match (&layout.dims, &layout.count) {
+			(Some((rows, cols)), _) => Some((format!("match usize::checked_mul({}, {}) {{ Some(count) => count, None => 0 }}", rows.0, cols.0), elem)),
+			(None, Some(count)) => Some((count.0.to_string(), layout.stride.as_ref().map(|stride| stride.0.to_string()).unwrap_or(elem))),
+			(None, None) => None,
+		};
+		if let Some((count, stride)) = repeated {
+			text.last_mut().unwrap().push_str(",\"count\":");
+			value(&mut text, count, String::from(",\"stride\":"));
+			value(&mut text, stride, String::new());
+		}
+		let optional = if layout.optional.is_some() { ",\"optional\":true" } else { "" };
+		text.last_mut().unwrap().push_str(&format!(",\"type\":{}{}}}", json_string(&type_string(&field.ty)), optional));
+	}
+	text.last_mut().unwrap().push_str("]}");
+	emit_static(code, "/// Describes the layout as a JSON document with the name, size and alignment of the structure and the fields with their name, offset, size and type.");
+	emit_companion_vis(code, stru);
+	// The bytes are the string literals of the text interleaved with ASCII digits, they are always UTF-8
+	emit_text(code, &format!("const LAYOUT_JSON: &'static str = {{
+		const TEXT: [&str; {0}] = [{1}];
+		const VALUES: [usize; {2}] = [{3}];
+		const fn digits(mut value: usize) -> usize {{
+			let mut len = 1;
+			while value >= 10 {{
+				value /= 10;
+				len += 1;
+			}}
+			len
+		}}
+		const LEN: usize = {{
+			let mut len = 0;
+			let mut i = 0;
+			while i < TEXT.len() {{
+				len += TEXT[i].len();
+				if i < VALUES.len() {{
+					len += digits(VALUES[i]);
+				}}
+				i += 1;
+			}}
+			len
+		}};
+		const BYTES: [u8; LEN] = {{
+			let mut bytes = [0u8; LEN];
+			let mut len = 0;
+			let mut i = 0;
+			while i < TEXT.len() {{
+				let text = TEXT[i].as_bytes();
+				let mut j = 0;
+				while j < text.len() {{
+					bytes[len] = text[j];
+					len += 1;
+					j += 1;
+				}}
+				if i < VALUES.len() {{
+					let mut value = VALUES[i];
+					let end = len + digits(value);
+					let mut j = end;
+					while j > len {{
+						j -= 1;
+						bytes[j] = b'0' + (value % 10) as u8;
+						value /= 10;
+					}}
+					len = end;
+				}}
+				i += 1;
+			}}
+			bytes
+		}};
+		match ::core::str::from_utf8(&BYTES) {{
+			Ok(json) => json,
+			Err(_) => unreachable!(),
+		}}
+	}};", text.len(), text.iter().map(|text| format!("{:?}", text)).collect::<Vec<_>>().join(", "), values.len(), values.join(", ")));
+}
+// Quoted JSON string with its special characters escaped
+fn json_string(s: &str) -> String {
+	let mut json = String::from("\"");
+	for chr in s.chars() {
+		match chr {
+			'"' => json.push_str("\\\""),
+			'\\' => json.push_str("\\\\"),
+			'\n' => json.push_str("\\n"),
+			'\r' => json.push_str("\\r"),
+			'\t' => json.push_str("\\t"),
+			chr if (chr as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", chr as u32)),
+			chr => json.push(chr),
+		}
+	}
+	json.push('"');
+	json
+}
+// Type as written in source without the spacing of the token stream, eg. `Option<[u8; 4]>`
+fn type_string(ty: &Type) -> String {
+	fn write(s: &mut String, tokens: &[TokenTree]) {
+		let mut word = false;
+		for tt in tokens {
+			match tt {
+				TokenTree::Ident(_) | TokenTree::Literal(_) => {
+					if word {
+						s.push(' ');
+					}
+					s.push_str(&tt.to_string());
+					word = true;
+				},
+				TokenTree::Punct(punct) => {
+					s.push(punct.as_char());
+					// Separators are followed by a space, except the trailing one of the group
+					if matches!(punct.as_char(), ',' | ';') {
+						s.push(' ');
+					}
+					word = false;
+				},
+				TokenTree::Group(group) => {
+					let (open, close) = match group.delimiter() {
+						Delimiter::Parenthesis => ("(", ")"),
+						Delimiter::Bracket => ("[", "]"),
+						Delimiter::Brace => ("{", "}"),
+						Delimiter::None => ("", ""),
+					};
+					if word && group.delimiter() == Delimiter::None {
+						s.push(' ');
+					}
+					s.push_str(open);
+					let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+					write(s, &tokens);
+					if s.ends_with(' ') {
+						s.pop();
+					}
+					s.push_str(close);
+					word = group.delimiter() == Delimiter::None;
+				},
+			}
+		}
+	}
+	let mut s = String::new();
+	write(&mut s, &ty.0);
+	s.trim_end().to_string()
+}
+// Visitor method and the type it is called with
+fn visit_category(field: &Field) -> Option<(&'static str, &'static str)> {
+	if field.layout.nested {
+		// Arrays of nested structures are passed to visit_debug
+		if array_ty(&field.ty).is_some() {
+			return None;
+		}
+		return Some(("nested", ""));
+	}
+	if field.layout.convert.is_some() {
+		return None;
+	}
+	if is_byte_array(&field.ty) {
+		return Some(("bytes", "&[u8]"));
+	}
+	let category = match &*field.ty.to_string() {
+		"i8" | "i16" | "i32" | "i64" | "isize" => ("int", "i64"),
+		"u8" | "u16" | "u32" | "u64" | "usize" => ("uint", "u64"),
+		"f32" | "f64" => ("float", "f64"),
+		"bool" => ("bool", "bool"),
+		"char" => ("char", "char"),
+		_ => return None,
+	};
+	Some(category)
+}
+fn emit_visit_fields(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let fields: Vec<&Field> = stru.fields.iter().filter(|field| is_single_value(field) && field.layout.method_get).collect();
+	let mut body = String::new();
+	let mut body_mut = String::new();
+	for field in &fields {
+		let (name, accessor) = (&field.name, &field.accessor);
+		let get = field_get_call(field, "self");
+		match visit_category(field) {
+			Some(("nested", _)) => {
+				body.push_str(&format!("{{ let value = {1}; visitor.visit_nested(\"{0}\", &mut |visitor| value.visit_fields(visitor)); }}", name, get));
+				if field.layout.method_set {
+					body_mut.push_str(&format!("{{ let mut value = {2}; visitor.visit_nested(\"{0}\", &mut |visitor| value.visit_fields_mut(visitor)); self.set_{1}(value); }}", name, accessor, get));
+					continue;
+				}
+			},
+			Some(("bytes", _)) => {
+				body.push_str(&format!("visitor.visit_bytes(\"{0}\", &{1});", name, get));
+				if field.layout.method_set {
+					body_mut.push_str(&format!("{{ let value = {2}; visitor.visit_bytes(\"{0}\", &value, &mut |bytes| {{ let mut value = value; value.copy_from_slice(bytes); self.set_{1}(value); }}); }}", name, accessor, get));
+					continue;
+				}
+			},
+			Some((method, ty)) => {
+				body.push_str(&format!("visitor.visit_{1}(\"{0}\", {3} as {2});", name, method, ty, get));
+				if field.layout.method_set {
+					body_mut.push_str(&format!("visitor.visit_{1}(\"{0}\", {5} as {2}, &mut |value| {{ self.set_{4}(value as {3}); }});", name, method, ty, field.ty, accessor, get));
+					continue;
+				}
+			},
+			None => body.push_str(&format!("visitor.visit_debug(\"{0}\", &{1});", name, get)),
+		}
+		body_mut.push_str(&format!("visitor.visit_debug(\"{0}\", &{1});", name, get));
+	}
+	emit_static(code, "/// Visits every field with its name and value.");
+	emit_vis(code, &stru.vis);
+	emit_text(code, &format!("fn visit_fields<V: ::struct_layout::FieldVisitor>(&self, visitor: &mut V) {{ {} }}", body));
+	emit_static(code, "/// Visits every field with its name and value, settable fields can be modified.");
+	emit_vis(code, &stru.vis);
+	emit_text(code, &format!("fn visit_fields_mut<V: ::struct_layout::FieldVisitorMut>(&mut self, visitor: &mut V) {{ {} }}", body_mut));
+}
+// The adapter is local to the method, only its Display implementation is visible to the caller
+fn emit_display_field(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let fields: Vec<&Field> = stru.fields.iter()
+		.filter(|field| is_single_value(field) && field.layout.method_get && is_infallible(field) && !field.layout.display_skip)
+		.collect();
+	let variants: Vec<String> = fields.iter().map(|field| format!("{}({}),", camel_case(&field.name.to_string()), field.ty)).collect();
+	let fmts: Vec<String> = fields.iter().map(|field| format!("DisplayField::{}(ref value) => ::core::fmt::Display::fmt(value, f),", camel_case(&field.name.to_string()))).collect();
+	let arms: Vec<String> = fields.iter().map(|field| format!("\"{}\" => Some(DisplayField::{}({})),", field.name, camel_case(&field.name.to_string()), field_get_call(field, "self"))).collect();
+	emit_static(code, "/// Formats the value of the field with the given name, `None` if there is no such field.");
+	emit_vis(code, &stru.vis);
+	emit_text(code, &format!("fn display_field(&self, name: &str) -> ::core::option::Option<impl ::core::fmt::Display + '_> {{
+		enum DisplayField {{ {} }}
+		impl ::core::fmt::Display for DisplayField {{
+			fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {{ match *self {{ {} }} }}
+		}}
+		let field: ::core::option::Option<DisplayField> = match name {{ {} _ => None }};
+		field
+	}}", variants.concat(), fmts.concat(), arms.concat()));
+}
+fn camel_case(name: &str) -> String {
+	let mut result = String::new();
+	for part in name.trim_start_matches("r#").split('_') {
+		let mut chars = part.chars();
+		if let Some(first) = chars.next() {
+			result.extend(first.to_uppercase());
+			result.extend(chars);
+		}
+	}
+	result
+}
+// Converts the structure name to snake case
+fn snake_case(name: &str) -> String {
+	let mut result = String::new();
+	for (i, chr) in name.char_indices() {
+		if chr.is_uppercase() {
+			if i > 0 {
+				result.push('_');
+			}
+			result.extend(chr.to_lowercase());
+		}
+		else {
+			result.push(chr);
+		}
+	}
+	result
+}
+// Extension trait reading the fields from byte slices
+fn emit_slice_ext(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let prefix = snake_case(&stru.name.to_string());
+	let tr = format!("{}SliceExt", stru.name);
+	let fields: Vec<&Field> = stru.fields.iter().filter(|field| is_single_value(field)).collect();
+	let get_sig = |code: &mut Vec<TokenTree>, field: &Field| {
+		emit_attrs(code, &field.attrs);
+		emit_text(code, &format!("fn {}_{}(&self) -> ::core::option::Option<{}>", prefix, field.accessor, field_get_ty(field)));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+	};
+	let set_sig = |code: &mut Vec<TokenTree>, field: &Field| {
+		emit_attrs(code, &field.attrs);
+		emit_text(code, &format!("fn set_{}_{}(&mut self, value: {}) -> ::core::option::Option<()>", prefix, field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+	};
+	// References borrow the slice itself, they outlive whichever value the slice was obtained from
+	let ref_sig = |code: &mut Vec<TokenTree>, field: &Field, suffix: &str| {
+		emit_attrs(code, &field.attrs);
+		let (receiver, ty) = if suffix == "mut" { ("&mut self", "&mut ") } else { ("&self", "&") };
+		emit_text(code, &format!("fn {}_{}_{}({}) -> ::core::option::Option<{}{}>", prefix, field.accessor, suffix, receiver, ty, field.ty));
+		emit_field_check(code, stru, field);
+	};
+	let prelude = |body: &mut Vec<TokenTree>, field: &Field| {
+		emit_text(body, &format!("const FIELD_OFFSET: usize = {}::{};", stru.name, field_offset_const(field)));
+		emit_text(body, &format!("type FieldT = {};", field_storage_ty(field)));
+		emit_static(body, "use ::core::{mem, ptr}; if FIELD_OFFSET + mem::size_of::<FieldT>() > self.len() { return None; }");
+	};
+	// The alignment of the slice is only known at runtime
+	let ref_prelude = |body: &mut Vec<TokenTree>, field: &Field| {
+		emit_text(body, &format!("const FIELD_OFFSET: usize = {}::{};", stru.name, field_offset_const(field)));
+		emit_text(body, &format!("type FieldT = {};", field_storage_ty(field)));
+		emit_static(body, "use ::core::mem; if FIELD_OFFSET + mem::size_of::<FieldT>() > self.len() { return None; }");
+		emit_static(body, "if (self.as_ptr() as usize).wrapping_add(FIELD_OFFSET) % mem::align_of::<FieldT>() != 0 { return None; }");
+	};
+	emit_text(code, &format!("/// Reads and writes the fields of [`{}`] directly in byte slices.", stru.name));
+	emit_companion_vis(code, stru);
+	emit_text(code, &format!("trait {}", tr));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		for field in &fields {
+			if field.layout.method_get {
+				get_sig(body, field);
+				emit_punct(body, ';');
+			}
+			if field.layout.method_set {
+				set_sig(body, field);
+				emit_punct(body, ';');
+			}
+			if field.layout.method_ref {
+				ref_sig(body, field, "ref");
+				emit_punct(body, ';');
+			}
+			if field.layout.method_mut {
+				ref_sig(body, field, "mut");
+				emit_punct(body, ';');
+			}
+		}
+	});
+	emit_text(code, &format!("impl {} for [u8]", tr));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		for field in &fields {
+			if field.layout.method_get {
+				get_sig(body, field);
+				emit_group_f(body, Delimiter::Brace, |body| {
+					prelude(body, field);
+					emit_static(body, "let value = unsafe { ptr::read_unaligned(self.as_ptr().add(FIELD_OFFSET) as *const FieldT) };");
+					match &field.layout.stored {
+						Some(stored) => emit_text(body, &format!("Some({})", stored_get_expr(field, stored))),
+						None => emit_static(body, "Some(value)"),
+					}
+				});
+			}
+			if field.layout.method_set {
+				set_sig(body, field);
+				emit_group_f(body, Delimiter::Brace, |body| {
+					prelude(body, field);
+					if field.layout.bits.is_some() {
+						emit_static(body, "let old = unsafe { ptr::read_unaligned(self.as_ptr().add(FIELD_OFFSET) as *const FieldT) };");
+					}
+					if let Some(stored) = &field.layout.stored {
+						emit_text(body, &format!("let value: FieldT = {};", stored_set_expr(field, stored)));
+					}
+					emit_static(body, "unsafe { ptr::write_unaligned(self.as_mut_ptr().add(FIELD_OFFSET) as *mut FieldT, value); } Some(())");
+				});
+			}
+			if field.layout.method_ref {
+				ref_sig(body, field, "ref");
+				emit_group_f(body, Delimiter::Brace, |body| {
+					ref_prelude(body, field);
+					emit_static(body, "Some(unsafe { &*(self.as_ptr().add(FIELD_OFFSET) as *const FieldT) })");
+				});
+			}
+			if field.layout.method_mut {
+				ref_sig(body, field, "mut");
+				emit_group_f(body, Delimiter::Brace, |body| {
+					ref_prelude(body, field);
+					emit_static(body, "Some(unsafe { &mut *(self.as_mut_ptr().add(FIELD_OFFSET) as *mut FieldT) })");
+				});
+			}
+		}
+	});
+}
+
+fn emit_derive_copy(code: &mut Vec<TokenTree>, stru: &Structure) {
+	emit_trait_impl_f(code, stru, "Copy", |_| {});
+}
+fn emit_derive_clone(code: &mut Vec<TokenTree>, stru: &Structure) {
+	emit_static(code, "#[allow(clippy::expl_impl_clone_on_copy)]");
+	emit_trait_impl_f(code, stru, "Clone", |code| {
+		emit_static(code, "fn clone(&self) -> Self { *self }");
+	})
+}
+fn emit_derive_debug(code: &mut Vec<TokenTree>, stru: &Structure) {
+	emit_trait_impl_f(code, stru, "::core::fmt::Debug", |code| {
+		emit_static(code, "fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result");
+		emit_group_f(code, Delimiter::Brace, |code| {
+			// The fields of unions are different views of the same bytes
+			if stru.layout.kind == LayoutKind::Union {
+				emit_text(code, &format!("f.debug_tuple(\"{}\").field({}).finish()", &stru.name, storage_bytes(&stru.layout, "self.0", false)));
+				return;
+			}
+			let annotated = stru.layout.debug_annotated;
+			if annotated {
+				// Prints `name @ 0x04: value` and in alternate mode every field on its own line after the size and alignment
+				emit_static(code, "let alternate = f.alternate();
+					let mut first = true;
+					let mut field = |f: &mut ::core::fmt::Formatter, name: &str, offset: usize, value: &dyn ::core::fmt::Debug| -> ::core::fmt::Result {
+						if alternate {
+							return writeln!(f, \"    {} @ {:#04x}: {:?},\", name, offset, value);
+						}
+						let sep = if first { \" \" } else { \", \" };
+						first = false;
+						write!(f, \"{}{} @ {:#04x}: {:?}\", sep, name, offset, value)
+					};");
+				emit_text(code, &format!("if alternate {{ writeln!(f, \"{0} (size {{:#x}}, align {{:#x}}) {{{{\", <Self as ::struct_layout::ExplicitLayout>::SIZE, <Self as ::struct_layout::ExplicitLayout>::ALIGN)?; }} else {{ f.write_str(\"{0} {{\")?; }}", &stru.name));
+			}
+			else {
+				emit_text(code, &format!("let mut debug = f.debug_struct(\"{}\");", &stru.name));
+			}
+			for field in &stru.fields {
+				if !is_single_value(field) {
+					continue;
+				}
+				// Fields gated by check_cfg are printed if their accessors are present
+				let restored;
+				let (field, pred) = match check_cfg_field(field) {
+					Some((pred, field)) => {
+						restored = field;
+						(&restored, Some(pred))
+					},
+					None => (field, None),
+				};
+				let print = |value: &str| match annotated {
+					true => format!("field(f, \"{}\", Self::{}, {})?;", field.name, field_offset_const(field), value),
+					false => format!("debug.field(\"{}\", {});", field.name, value),
+				};
+				let emit_print = |code: &mut Vec<TokenTree>| {
+					if field.layout.debug_hex && (field.layout.method_ref || field.layout.method_get) {
+						let bytes = if field.layout.method_ref { format!("self.{}_ref()", field.accessor) } else { format!("&self.{}()", field.accessor) };
+						emit_text(code, &print(&format!("&::struct_layout::__HexBytes(&{}[..])", bytes)));
+					}
+					else if field.layout.method_str || field.layout.pstr.is_some() {
+						// Print the bytes if they are not valid utf8
+						let bytes = if field.layout.method_ref { format!("self.{}_ref()", field.accessor) } else { format!("&self.{}()", field.accessor) };
+						emit_text(code, &format!("match self.{}_str() {{
+							Ok(value) => {{ {} }},
+							Err(_) => {{ {} }},
+						}}", field.accessor, print("&value"), print(&bytes)));
+					}
+					else if field.layout.method_ref {
+						emit_text(code, &print(&format!("self.{}_ref()", field.accessor)));
+					}
+					else if let (true, Some(Convert::TryFrom)) | (true, Some(Convert::Enum)) | (true, Some(Convert::Strict)) = (field.layout.method_get, field.layout.convert) {
+						// Print the raw stored value if it cannot be converted
+						let value = if field.layout.convert == Some(Convert::TryFrom) { ".ok()" } else { "" };
+						emit_text(code, &format!("match self.{}(){} {{
+							Some(value) => {{ {} }},
+							None => {{ {} }},
+						}}", field.accessor, value, print("&value"), print(&format!("&self.{}_raw()", field.accessor))));
+					}
+					else if field.layout.method_get {
+						emit_text(code, &print(&format!("&self.{}()", field.accessor)));
+					}
+				};
+				// Fields of another interpretation of the bytes are skipped
+				let emit_print = |code: &mut Vec<TokenTree>| match &field.layout.debug_when {
+					Some((tag, value)) => {
+						emit_text(code, &format!("if self.{}() == {}", accessor_name(&stru.layout, tag), value.0));
+						emit_group_f(code, Delimiter::Brace, emit_print);
+					},
+					None => emit_print(code),
+				};
+				match pred {
+					Some(pred) => {
+						emit_text(code, &format!("#[cfg({})]", pred));
+						emit_group_f(code, Delimiter::Brace, emit_print);
+					},
+					None => emit_print(code),
+				}
+			}
+			if annotated {
+				emit_static(code, "f.write_str(if alternate { \"}\" } else { \" }\" })");
+			}
+			else {
+				emit_static(code, "debug.finish()");
+			}
+		});
+	});
+}
+// The field with the accessors gated by its check_cfg enabled and the predicate under which they are present
+fn check_cfg_field(field: &Field) -> Option<(&str, Field)> {
+	let pred = field.layout.check_cfg.as_deref()?;
+	let mut field = field.clone();
+	for &(method, _) in &field.layout.gated.clone() {
+		match method {
+			"get" => field.layout.method_get = true,
+			"set" => field.layout.method_set = true,
+			"ref" => field.layout.method_ref = true,
+			_ => field.layout.method_mut = true,
+		}
+	}
+	Some((pred, field))
+}
+// The derived Default overwrites the zeroed bytes of the field with its default value
+fn is_default_set(stru: &Structure, field: &Field) -> bool {
+	if stru.layout.kind == LayoutKind::Union || field.layout.optional.is_some() || field.layout.allow_drop {
+		return false;
+	}
+	match check_cfg_field(field) {
+		// Without the cfg the field has no accessors which could read it
+		Some((_, field)) => field.layout.method_set,
+		None => field.layout.method_set && is_single_value(field),
+	}
+}
+fn emit_derive_default(code: &mut Vec<TokenTree>, stru: &Structure) {
+	emit_trait_impl_f(code, stru, "Default", |code| {
+		emit_static(code, "fn default() -> Self");
+		emit_group_f(code, Delimiter::Brace, |code| {
+			// Every field for which zeroed bytes are not valid is set before it is read
+			if struct_zeroable(stru) {
+				emit_static(code, "let mut instance = Self::zeroed();");
+			}
+			else {
+				emit_static(code, "let mut instance = unsafe { Self::zeroed() };");
+			}
+			for field in &stru.fields {
+				if field.layout.method_set && is_single_value(field) && !(stru.layout.kind == LayoutKind::Union) {
+					emit_text(code, &format!("instance.set_{}(Default::default());", field.accessor));
+				}
+				else if let (Some((pred, field)), false) = (check_cfg_field(field), stru.layout.kind == LayoutKind::Union) {
+					if field.layout.method_set {
+						emit_text(code, &format!("#[cfg({})] instance.set_{}(Default::default());", pred, field.accessor));
+					}
+				}
+			}
+			emit_static(code, "; instance");
+		});
+	});
+}
+fn emit_derive_arbitrary(code: &mut Vec<TokenTree>, stru: &Structure) {
+	code.push(TokenTree::Ident(Ident::new("impl", Span::call_site())));
+	emit_text(code, &format!("<'a> ::arbitrary::Arbitrary<'a> for {}", stru.name));
+	if stru.layout.arbitrary_fields {
+		emit_trait_bounds(code, stru, "::arbitrary::Arbitrary<'a>");
+	}
+	emit_group_f(code, Delimiter::Brace, |code| {
+		emit_static(code, "fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self>");
+		emit_group_f(code, Delimiter::Brace, |code| {
+			emit_text(code, &format!("let mut instance = {};", struct_zeroed(stru)));
+			if stru.layout.arbitrary_fields {
+				// Generate the fields independently, leaving the padding zeroed
+				for field in &stru.fields {
+					if field.layout.method_set && is_single_value(field) {
+						emit_text(code, &format!("instance.set_{}(::arbitrary::Arbitrary::arbitrary(u)?);", field.accessor));
+					}
+				}
+			}
+			else {
+				emit_text(code, &format!("u.fill_buffer({})?;", storage_bytes(&stru.layout, "instance.0", true)));
+			}
+			emit_static(code, "Ok(instance)");
+		});
+	});
+}
+// Prints the bytes of the structure as one hex string, the formatter handles the width, fill and `0x` prefix
+fn emit_derive_hex(code: &mut Vec<TokenTree>, stru: &Structure, tr: &str, digits: &str) {
+	emit_text(code, &format!("impl{} ::core::fmt::{} for {} {{
+		fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {{
+			const DIGITS: &[u8; 16] = b\"{}\";
+			let bytes: &[u8] = {};
+			let mut buf = [0u8; ({}) * 2];
+			for (chunk, &byte) in buf.chunks_exact_mut(2).zip(bytes.iter()) {{
+				chunk[0] = DIGITS[(byte >> 4) as usize];
+				chunk[1] = DIGITS[(byte & 0xf) as usize];
+			}}
+			match ::core::str::from_utf8(&buf) {{
+				Ok(hex) => f.pad_integral(true, \"0x\", hex),
+				Err(_) => Err(::core::fmt::Error),
+			}}
+		}}
+	}}", impl_generics(stru), tr, self_ty(stru), digits, storage_bytes(&stru.layout, "self.0", false), stru.layout.size.0));
+}
+fn emit_derive_quickcheck(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let fields: Vec<&Field> = stru.fields.iter().filter(|field| field.layout.method_set && is_single_value(field)).collect();
+	emit_text(code, &format!("impl ::quickcheck::Arbitrary for {}", stru.name));
+	if !fields.is_empty() {
+		emit_ident(code, "where");
+		for field in &fields {
+			emit_ty(code, &field.ty);
+			emit_static(code, ": ::quickcheck::Arbitrary,");
+		}
+	}
+	emit_group_f(code, Delimiter::Brace, |code| {
+		// Generate the fields independently, leaving the padding zeroed
+		emit_static(code, "fn arbitrary(g: &mut ::quickcheck::Gen) -> Self");
+		emit_group_f(code, Delimiter::Brace, |code| {
+			emit_text(code, &format!("let mut instance = {};", struct_zeroed(stru)));
+			for field in &fields {
+				emit_text(code, &format!("instance.set_{}(::quickcheck::Arbitrary::arbitrary(g));", field.accessor));
+			}
+			emit_static(code, "instance");
+		});
+		// Shrink the fields one at a time, writing the shrunk value back into a copy of the structure
+		emit_static(code, "fn shrink(&self) -> ::struct_layout::__alloc::boxed::Box<dyn Iterator<Item = Self>>");
+		emit_group_f(code, Delimiter::Brace, |code| {
+			emit_static(code, "let shrunk = ::core::iter::empty();");
+			for field in &fields {
+				if field.layout.method_get && is_infallible(field) {
+					emit_text(code, &format!("let this = ::core::clone::Clone::clone(self);
+						let shrunk = shrunk.chain(::quickcheck::Arbitrary::shrink(&{1}).map(move |value| {{
+							let mut instance = ::core::clone::Clone::clone(&this);
+							instance.set_{0}(value);
+							instance
+						}}));", field.accessor, field_get_call(field, "self")));
+				}
+			}
+			emit_static(code, "::struct_layout::__alloc::boxed::Box::new(shrunk)");
+		});
+	});
+}
+// A value compared and hashed by the comparison derives
+struct Compared {
+	// Reads the value from the receiver `$`
+	read: String,
+	// The type which must implement the derived trait
+	ty: Option<Type>,
+	// The accessors are gated by the check_cfg predicate
+	pred: Option<String>,
+}
+// The comparison derives read the fields through their getters like the Debug derive, so padding never takes part
+fn compared_fields(stru: &Structure) -> Vec<Compared> {
+	// The fields of unions are different views of the same bytes
+	if stru.layout.kind == LayoutKind::Union {
+		return vec![Compared { read: storage_bytes(&stru.layout, "$.0", false), ty: None, pred: None }];
+	}
+	let mut compared = Vec::new();
+	for field in &stru.fields {
+		if !is_single_value(field) {
+			continue;
+		}
+		let (field, pred) = match check_cfg_field(field) {
+			Some((pred, field)) => (field, Some(pred.to_string())),
+			None => (field.clone(), None),
+		};
+		// Fallible conversions compare their stored value so that values are equal if their bytes are
+		let (read, ty) = match (field.layout.method_get, field.layout.convert) {
+			(true, Some(Convert::TryFrom)) | (true, Some(Convert::Enum)) | (true, Some(Convert::Strict)) => (format!("$.{}_raw()", field.accessor), field.layout.stored.clone().unwrap()),
+			(true, _) => (format!("$.{}()", field.accessor), field.ty.clone()),
+			(false, _) if field.layout.method_ref => (format!("$.{}_ref()", field.accessor), field.ty.clone()),
+			// Fields without a getter are skipped
+			_ => continue,
+		};
+		compared.push(Compared { read, ty: Some(ty), pred });
+	}
+	compared
+}
+// Emits the statement for every compared value given how to read it from `self` and `other`
+fn emit_compared(code: &mut Vec<TokenTree>, compared: &[Compared], stmt: impl Fn(&str, &str) -> String) {
+	for compared in compared {
+		let stmt = stmt(&compared.read.replace('$', "self"), &compared.read.replace('$', "other"));
+		match &compared.pred {
+			Some(pred) => emit_text(code, &format!("#[cfg({})] {{ {} }}", pred, stmt)),
+			None => emit_text(code, &stmt),
+		}
+	}
+}
+fn emit_derive_partial_eq(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let compared = compared_fields(stru);
+	emit_trait_impl_f(code, stru, "::core::cmp::PartialEq", |code| {
+		emit_static(code, "fn eq(&self, other: &Self) -> bool");
+		emit_group_f(code, Delimiter::Brace, |code| {
+			if compared.is_empty() {
+				emit_static(code, "let _ = other;");
+			}
+			emit_compared(code, &compared, |lhs, rhs| format!("if {} != {} {{ return false; }}", lhs, rhs));
+			emit_static(code, "true");
+		});
+	});
+}
+// Hashes the values compared by the PartialEq derive
+fn emit_derive_hash(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let compared = compared_fields(stru);
+	emit_trait_impl_f(code, stru, "::core::hash::Hash", |code| {
+		emit_static(code, "fn hash<H: ::core::hash::Hasher>(&self, state: &mut H)");
+		emit_group_f(code, Delimiter::Brace, |code| {
+			if compared.is_empty() {
+				emit_static(code, "let _ = state;");
+			}
+			emit_compared(code, &compared, |lhs, _| format!("::core::hash::Hash::hash(&{}, state);", lhs));
+		});
+	});
+}
+// Compares the values lexicographically in declaration order
+fn emit_derive_partial_ord(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let compared = compared_fields(stru);
+	emit_trait_impl_f(code, stru, "::core::cmp::PartialOrd", |code| {
+		emit_static(code, "fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering>");
+		emit_group_f(code, Delimiter::Brace, |code| {
+			// Consistent with the Ord derive
+			if stru.derived.contains(&DerivedTrait::Ord) {
+				emit_static(code, "::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))");
+				return;
+			}
+			if compared.is_empty() {
+				emit_static(code, "let _ = other;");
+			}
+			emit_compared(code, &compared, |lhs, rhs| format!("match ::core::cmp::PartialOrd::partial_cmp(&{}, &{}) {{
+				::core::option::Option::Some(::core::cmp::Ordering::Equal) => (),
+				cmp => return cmp,
+			}}", lhs, rhs));
+			emit_static(code, "::core::option::Option::Some(::core::cmp::Ordering::Equal)");
+		});
+	});
+}
+fn emit_derive_ord(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let compared = compared_fields(stru);
+	emit_trait_impl_f(code, stru, "::core::cmp::Ord", |code| {
+		emit_static(code, "fn cmp(&self, other: &Self) -> ::core::cmp::Ordering");
+		emit_group_f(code, Delimiter::Brace, |code| {
+			if compared.is_empty() {
+				emit_static(code, "let _ = other;");
+			}
+			emit_compared(code, &compared, |lhs, rhs| format!("match ::core::cmp::Ord::cmp(&{}, &{}) {{
+				::core::cmp::Ordering::Equal => (),
+				cmp => return cmp,
+			}}", lhs, rhs));
+			emit_static(code, "::core::cmp::Ordering::Equal");
+		});
+	});
+}
+fn emit_derives(code: &mut Vec<TokenTree>, stru: &Structure) {
+	for derive in &stru.derived {
+		match derive {
+			DerivedTrait::Copy => emit_derive_copy(code, stru),
+			DerivedTrait::Clone => emit_derive_clone(code, stru),
+			DerivedTrait::Debug => emit_derive_debug(code, stru),
+			DerivedTrait::Default => emit_derive_default(code, stru),
+			DerivedTrait::Arbitrary => emit_derive_arbitrary(code, stru),
+			DerivedTrait::QuickcheckArbitrary => emit_derive_quickcheck(code, stru),
+			DerivedTrait::LowerHex => emit_derive_hex(code, stru, "LowerHex", "0123456789abcdef"),
+			DerivedTrait::UpperHex => emit_derive_hex(code, stru, "UpperHex", "0123456789ABCDEF"),
+			DerivedTrait::PartialEq => emit_derive_partial_eq(code, stru),
+			DerivedTrait::Eq => emit_trait_impl_f(code, stru, "::core::cmp::Eq", |_| {}),
+			DerivedTrait::Hash => emit_derive_hash(code, stru),
+			DerivedTrait::PartialOrd => emit_derive_partial_ord(code, stru),
+			DerivedTrait::Ord => emit_derive_ord(code, stru),
+		}
+	}
+}
+// Fields with regular accessors for a single value of the field type
+fn is_single_value(field: &Field) -> bool {
+	is_single_value_layout(&field.layout) && field.layout.variant.is_none() && !field.layout.dynamic && field.layout.manually_drop.is_none()
+}
+fn is_single_value_layout(layout: &FieldLayout) -> bool {
+	layout.dims.is_none() && layout.count.is_none() && layout.tail.is_none() && layout.len.is_none()
+}
+// The name of a field as it appears in the names of its accessors
+fn accessor_name(layout: &ExplicitLayout, name: &impl std::fmt::Display) -> String {
+	format!("{}{}{}", layout.method_prefix, name, layout.method_suffix)
+}
+// The affix must keep the accessor names identifiers, a prefix cannot start with a digit
+fn is_accessor_affix(affix: &str, prefix: bool) -> bool {
+	!affix.is_empty() && affix.chars().all(|chr| chr.is_ascii_alphanumeric() || chr == '_') && !(prefix && affix.starts_with(|chr: char| chr.is_ascii_digit()))
+}
+// The getter returns the field type itself
+fn is_infallible(field: &Field) -> bool {
+	!matches!(field.layout.convert, Some(Convert::TryFrom) | Some(Convert::Enum) | Some(Convert::Strict))
+}
+// The check bound applies to the type stored in the layout
+fn field_storage_ty(field: &Field) -> &Type {
+	field.layout.stored.as_ref().or(field.layout.manually_drop.as_ref()).unwrap_or(&field.ty)
+}
+// The offset of the field named like the constants of consts_mod and the derive
+fn emit_offset_const(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	if field.layout.pad {
+		return;
+	}
+	emit_text(code, &format!("/// Offset of the field `{}` in bytes.", field.name));
+	emit_static(code, "#[allow(dead_code)]");
+	emit_vis(code, stru.layout.companion_vis.as_ref().unwrap_or(&field.vis));
+	emit_text(code, &format!("const {}_OFFSET: usize = Self::{};", field.name.to_string().to_uppercase(), field_offset_const(field)));
+}
+fn emit_field(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	if field.layout.dynamic {
+		emit_field_dynamic(code, stru, field);
+		return;
+	}
+	// The offset is shared by all the accessors of the field
+	emit_static(code, "#[doc(hidden)]");
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("const {}: usize = {};", field_offset_const(field), field.layout.offset.0));
+	emit_offset_const(code, stru, field);
+	if is_single_value_layout(&field.layout) && field.layout.variant.is_none() {
+		// Lets generic code choose between references and copies without failing to compile
+		emit_text(code, &format!("/// Whether the field `{}` is aligned for references to it.", field.name));
+		emit_static(code, "#[allow(dead_code)]");
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("const {0}: bool = {1}::{2} % ::core::mem::align_of::<{3}>() == 0 && ::core::mem::align_of::<{1}>() % ::core::mem::align_of::<{3}>() == 0;",
+			field_aligned_const(field), type_path(stru), field_offset_const(field), field_storage_ty(field)));
+	}
+	if let Some((rows, cols)) = &field.layout.dims {
+		emit_field_dims(code, stru, field, rows, cols);
+		return;
+	}
+	if let Some(count) = &field.layout.count {
+		emit_field_strided(code, stru, field, count);
+		return;
+	}
+	if let Some(len) = &field.layout.len {
+		if !field.layout.pad {
+			emit_field_region(code, stru, field, len);
+		}
+		return;
+	}
+	if let Some(len) = &field.layout.tail {
+		emit_field_tail(code, stru, field, len.as_ref());
+		return;
+	}
+	if let Some(variant) = &field.layout.variant {
+		emit_field_variant(code, stru, field, variant);
+		return;
+	}
+	if field.layout.method_get {
+		emit_field_get(code, stru, field);
+	}
+	if field.layout.flatten {
+		let args = if field.layout.method_set { format!("set {0} set_{0}", field.accessor) } else { format!("get {}", field.accessor) };
+		emit_text(code, &format!("__struct_layout_flatten_{}! {{ {} }}", field.ty, args));
+	}
+	if field.layout.method_set {
+		emit_field_set(code, stru, field);
+		if field.layout.validate.is_some() {
+			emit_field_try_set(code, stru, field);
+		}
+	}
+	if field.layout.method_clamped {
+		emit_field_set_clamped(code, stru, field);
+	}
+	if stru.layout.handles && field.layout.stored.is_none() && field.layout.manually_drop.is_none() {
+		emit_field_handle(code, stru, field);
+	}
+	if stru.layout.remote && !field.layout.flatten && field.layout.manually_drop.is_none() {
+		if field.layout.method_get {
+			emit_field_read(code, stru, field);
+		}
+		// Writing a bitfield requires reading the other bits of the remote integer first
+		if field.layout.method_set && field.layout.bits.is_none() {
+			emit_field_write(code, stru, field);
+		}
+	}
+	if field.layout.method_ref {
+		emit_field_ref(code, stru, field);
+	}
+	if field.layout.method_modify {
+		emit_field_modify(code, stru, field);
+	}
+	if let Some(checksum) = &field.layout.checksum {
+		emit_field_checksum(code, stru, field, checksum);
+	}
+	if field.layout.method_copy_into {
+		emit_field_copy_into(code, stru, field);
+	}
+	if field.layout.method_unaligned_ref {
+		emit_field_unaligned_ref(code, stru, field);
+	}
+	if field.layout.method_shiftable {
+		emit_field_shiftable(code, stru, field);
+	}
+	if let Some(old) = &field.layout.old_offset {
+		emit_field_legacy(code, stru, field, old);
+	}
+	if field.layout.method_copy_from {
+		emit_field_copy_from(code, stru, field);
+	}
+	if field.layout.method_zero {
+		emit_field_zero(code, stru, field);
+	}
+	if field.layout.method_bytes_array {
+		emit_field_bytes_array(code, stru, field);
+	}
+	if field.layout.method_column {
+		emit_field_column(code, stru, field);
+	}
+	if field.layout.method_atomic {
+		emit_field_atomic(code, stru, field);
+	}
+	if field.layout.method_endian {
+		emit_field_endian(code, stru, field, "le", "little");
+		emit_field_endian(code, stru, field, "be", "big");
+	}
+	if field.layout.method_mut {
+		emit_field_mut(code, stru, field);
+	}
+	if field.layout.stored.is_none() && field.layout.manually_drop.is_none() {
+		if let Some((elem, len)) = array_ty(&field.ty) {
+			emit_field_array(code, stru, field, &elem, &len);
+			if field.layout.method_slice {
+				emit_field_slice(code, stru, field, &elem);
+			}
+			if field.layout.method_str {
+				emit_field_str(code, stru, field);
+			}
+			if field.layout.method_cstr {
+				emit_field_cstr(code, stru, field);
+			}
+			if let Some(cap) = &field.layout.pstr {
+				emit_field_pstr(code, stru, field, cap.as_ref());
+			}
+			if field.layout.method_wstr {
+				emit_field_wstr(code, stru, field);
+			}
+		}
+	}
+	if let Some(Convert::TryFrom) | Some(Convert::Enum) | Some(Convert::Sentinel) | Some(Convert::Valid) | Some(Convert::Strict) = field.layout.convert {
+		if field.layout.method_get {
+			emit_field_get_raw(code, stru, field);
+		}
+		if field.layout.method_set {
+			emit_field_set_raw(code, stru, field);
+		}
+	}
+}
+// Return type of the getter
+fn field_get_ty(field: &Field) -> String {
+	match (&field.layout.stored, field.layout.convert) {
+		(Some(stored), Some(Convert::TryFrom)) => {
+			format!("::core::result::Result<{0}, <{0} as ::core::convert::TryFrom<{1}>>::Error>", field.ty, stored)
+		},
+		(Some(_), Some(Convert::Enum)) | (Some(_), Some(Convert::Strict)) => format!("::core::option::Option<{}>", field.ty),
+		_ => field.ty.to_string(),
+	}
+}
+fn emit_field_get(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	if field.layout.manually_drop.is_some() {
+		// Reading the field duplicates the ownership of its value
+		emit_ident(code, "unsafe");
+	}
+	else if stru.layout.const_fn && field.layout.stored.is_none() {
+		// Conversions of stored fields go through traits which are not const
+		emit_ident(code, "const");
+	}
+	emit_ident(code, "fn");
+	code.push(TokenTree::Ident(field.accessor.clone()));
+	if field.layout.get_ref {
+		emit_static(code, "(&self) -> &");
+		emit_ty(code, &field.ty);
+		emit_field_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			emit_field_prelude_aligned(body, stru, field);
+			emit_static(body, "unsafe { &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) }");
+		});
+		return;
+	}
+	emit_static(code, "(&self) -> ");
+	emit_text(code, &field_get_ty(field));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		if field.layout.manually_drop.is_some() {
+			emit_static(body, "mem::ManuallyDrop::into_inner(unsafe { ptr::read_unaligned((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) })");
+			return;
+		}
+		match &field.layout.stored {
+			Some(stored) => {
+				emit_static(body, "let value = unsafe { ptr::read_unaligned((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) };");
+				emit_text(body, &stored_get_expr(field, stored));
+			},
+			None => {
+				emit_static(body, "unsafe { ptr::read_unaligned((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) }");
+			},
+		}
+	});
+}
+fn emit_field_set(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_ident(code, "fn");
+	emit_ident(code, &format!("set_{}", field.accessor));
+	emit_group_f(code, Delimiter::Parenthesis, |params| {
+		emit_static(params, "&mut self, value: ");
+		emit_ty(params, &field.ty);
+	});
+	emit_static(code, " -> &mut Self");
+	emit_field_check(code, stru, field);
+	if !field.layout.allow_drop {
+		emit_field_copy_check(code, stru, field);
+	}
+	if field.layout.validate.is_some() && stru.layout.strict_validate {
+		// The strict setter is the checked setter which panics on invalid values
+		emit_text(code, &format!("{{ match self.try_set_{}(value) {{ Ok(this) => this, Err(_) => panic!(\"invalid value for field `{}`\") }} }}", field.accessor, field.name));
+		return;
+	}
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		if !field.layout.method_clamped {
+			emit_field_clamp(body, field);
+		}
+		emit_field_store(body, stru, field);
+		emit_ident(body, "self");
+	})
+}
+// Clamps the value into the range before storing it, next to the raw setter
+fn emit_field_set_clamped(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn set_{}_clamped(&mut self, value: {}) -> &mut Self", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_field_clamp(body, field);
+		emit_field_store(body, stru, field);
+		emit_ident(body, "self");
+	})
+}
+fn emit_field_clamp(body: &mut Vec<TokenTree>, field: &Field) {
+	if let Some((min, max)) = &field.layout.clamp {
+		emit_text(body, &format!("const MIN: {0} = {1}; const MAX: {0} = {2}; let value = value.clamp(MIN, MAX);", field.ty, min.0, max.0));
+	}
+}
+// Sets the field if the validate function accepts the value
+fn emit_field_try_set(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	let validate = field.layout.validate.as_ref().unwrap();
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn try_set_{}(&mut self, value: {}) -> ::core::result::Result<&mut Self, ::struct_layout::InvalidValue>", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_text(body, &format!("if !::struct_layout::Validity::accepted({}(value)) {{ return Err(::struct_layout::InvalidValue); }}", validate.0));
+		emit_field_store(body, stru, field);
+		emit_static(body, "Ok(self)");
+	})
+}
+// Stores the value in the field of self
+fn emit_field_store(body: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	if field.layout.bits.is_some() {
+		// The other bits of the stored integer are kept
+		emit_static(body, "let old = unsafe { ptr::read_unaligned((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) };");
+	}
+	if let Some(stored) = &field.layout.stored {
+		emit_text(body, &format!("let value: FieldT = {};", stored_set_expr(field, stored)));
+	}
+	if field.layout.manually_drop.is_some() {
+		// The old value is forgotten
+		emit_static(body, "let value: FieldT = mem::ManuallyDrop::new(value);");
+	}
+	if field.layout.allow_drop {
+		// Drops the old value after it has been replaced
+		emit_static(body, "unsafe {
+			let ptr = (self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT;
+			let old = ptr::read_unaligned(ptr);
+			ptr::write_unaligned(ptr, value);
+			drop(old);
+		}");
+	}
+	else {
+		emit_static(body, "unsafe { ptr::write_unaligned((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT, value); }");
+	}
+	emit_checksum_auto(body, stru, field);
+}
+// Typed handle to the field as an associated constant
+fn emit_field_handle(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	let check = stru.layout.check.as_deref().unwrap_or("Copy + 'static");
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("const {}: ::struct_layout::Field<Self, {}> = ", field.name.to_string().to_uppercase(), field.ty));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		// The field type must meet the same bounds as the accessors
+		emit_text(body, &format!("fn check<T: ?Sized + {}>() {{}} let _: fn() = check::<FieldT>;", check));
+		emit_static(body, "unsafe { ::struct_layout::Field::new(FIELD_OFFSET) }");
+	});
+	emit_punct(code, ';');
+}
+// Reads the field from remote memory at the address of the structure
+fn emit_field_read(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn read_{}<M: ::struct_layout::MemoryRead + ?Sized>(memory: &M, base: u64) -> ::core::result::Result<{}, M::Error>", field.accessor, field_get_ty(field)));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_static(body, "let mut value = mem::MaybeUninit::<FieldT>::zeroed();
+			let bytes = unsafe { ::core::slice::from_raw_parts_mut(value.as_mut_ptr() as *mut u8, mem::size_of::<FieldT>()) };
+			memory.read(base + FIELD_OFFSET as u64, bytes)?;
+			let value = unsafe { value.assume_init() };");
+		match &field.layout.stored {
+			Some(stored) => {
+				emit_text(body, &format!("Ok({})", stored_get_expr(field, stored)));
+			},
+			None => emit_static(body, "Ok(value)"),
+		}
+	});
+}
+// Writes the field to remote memory at the address of the structure
+fn emit_field_write(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn write_{}<M: ::struct_layout::MemoryWrite + ?Sized>(memory: &mut M, base: u64, value: {}) -> ::core::result::Result<(), M::Error>", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		if let Some(stored) = &field.layout.stored {
+			emit_text(body, &format!("let value: FieldT = {};", stored_set_expr(field, stored)));
+		}
+		emit_static(body, "let bytes = unsafe { ::core::slice::from_raw_parts(&value as *const FieldT as *const u8, mem::size_of::<FieldT>()) };
+			memory.write(base + FIELD_OFFSET as u64, bytes)");
+	});
+}
+// Read-modify-write of the field with a single load and store
+fn emit_field_modify(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_modify<F: FnOnce({1}) -> {1}>(&mut self, f: F) -> &mut Self", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_text(code, &format!("{{ let value = self.{0}(); self.set_{0}(f(value)) }}", field.accessor));
+}
+// Copies the bytes of the field directly to or from the caller's buffer without a temporary
+fn emit_field_copy_into(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_into(&self, out: &mut {})", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_static(body, "unsafe { ptr::copy_nonoverlapping((self as *const Self as *const u8).add(FIELD_OFFSET), out as *mut FieldT as *mut u8, mem::size_of::<FieldT>()); }");
+	});
+}
+fn emit_field_copy_from(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_from(&mut self, src: &{}) -> &mut Self", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_static(body, "unsafe { ptr::copy_nonoverlapping(src as *const FieldT as *const u8, (self as *mut Self as *mut u8).add(FIELD_OFFSET), mem::size_of::<FieldT>()); }");
+		emit_checksum_auto(body, stru, field);
+		emit_ident(body, "self");
+	});
+}
+// Fills the bytes of the field with zeroes without constructing a value
+fn emit_field_zero(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn zero_{}(&mut self) -> &mut Self", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_static(body, "unsafe { ptr::write_bytes((self as *mut Self as *mut u8).add(FIELD_OFFSET), 0, mem::size_of::<FieldT>()); }");
+		emit_checksum_auto(body, stru, field);
+		emit_ident(body, "self");
+	});
+}
+// The bytes of the field by value, the length is known for primitive types or given by the size argument
+fn emit_field_atomic(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	let atomic = format!("::core::sync::atomic::{}", atomic_ty(&field.ty).unwrap());
+	let cast = format!("const FIELD_OFFSET: usize = {}::{};
+		let atomic = unsafe {{ &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const {}) }};", stru.name, field_offset_const(field), atomic);
+	let safety = "///
+		/// # Safety
+		///
+		/// The field may not be accessed concurrently by its other accessors and the storage of the structure must permit mutation through shared references,
+		/// eg. `storage = AtomicU64`.";
+	emit_attrs(code, &field.attrs);
+	emit_text(code, &format!("/// Loads the field `{}` atomically.
+		///
+		/// Loading is sound on any instance, the stores of other threads require storage which permits mutation through shared references,
+		/// see the safety of `{}_store`.", field.name, field.accessor));
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_load(&self, order: ::core::sync::atomic::Ordering) -> {} {{ {} atomic.load(order) }}", field.accessor, field.ty, cast));
+	emit_attrs(code, &field.attrs);
+	emit_text(code, &format!("/// Stores the field `{}` atomically.\n{}", field.name, safety));
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("unsafe fn {}_store(&self, order: ::core::sync::atomic::Ordering, value: {}) {{ {} atomic.store(value, order) }}", field.accessor, field.ty, cast));
+	emit_attrs(code, &field.attrs);
+	emit_text(code, &format!("/// Stores `new` in the field `{}` atomically if it holds `current`, returns the previous value.\n{}", field.name, safety));
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("unsafe fn {0}_compare_exchange(&self, current: {1}, new: {1}, success: ::core::sync::atomic::Ordering, failure: ::core::sync::atomic::Ordering)
+		-> ::core::result::Result<{1}, {1}> {{ {2} atomic.compare_exchange(current, new, success, failure) }}", field.accessor, field.ty, cast));
+}
+// Reads and writes the field in the given byte order regardless of the target
+fn emit_field_endian(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, order: &str, desc: &str) {
+	emit_attrs(code, &field.attrs);
+	emit_text(code, &format!("/// Reads the field `{}` stored in {} endian byte order.", field.name, desc));
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_{}(&self) -> {}", field.accessor, order, field.ty));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_text(body, &format!("FieldT::from_{}(unsafe {{ ptr::read_unaligned((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) }})", order));
+	});
+	emit_attrs(code, &field.attrs);
+	emit_text(code, &format!("/// Writes the field `{}` in {} endian byte order.", field.name, desc));
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn set_{}_{}(&mut self, value: {}) -> &mut Self", field.accessor, order, field.ty));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_text(body, &format!("unsafe {{ ptr::write_unaligned((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT, value.to_{}()); }}", order));
+		emit_checksum_auto(body, stru, field);
+		emit_ident(body, "self");
+	});
+}
+fn emit_field_bytes_array(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	let len = match &field.layout.size {
+		Some(size) => size.0.to_string(),
+		None => primitive_size(field_storage_ty(field)).unwrap().to_string(),
+	};
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("const fn {}_to_bytes(&self) -> [u8; {}]", field.accessor, len));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_text(body, &format!("unsafe {{ ptr::read_unaligned((self as *const Self as *const u8).add(FIELD_OFFSET) as *const [u8; {}]) }}", len));
+	});
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn set_{}_from_bytes(&mut self, bytes: [u8; {}]) -> &mut Self", field.accessor, len));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_text(body, &format!("unsafe {{ ptr::write_unaligned((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut [u8; {}], bytes); }}", len));
+		emit_checksum_auto(body, stru, field);
+		emit_ident(body, "self");
+	});
+}
+// Iterates over the field in consecutive records of a byte buffer, eg. interleaved vertex data
+fn emit_field_column(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	// The number of records in which the field is in bounds
+	let records = "let records = match buf.len().checked_sub(FIELD_OFFSET + mem::size_of::<FieldT>()) { Some(len) => len / mem::size_of::<Self>() + 1, None => 0 };";
+	let read = "let value = unsafe { ptr::read_unaligned(buf.as_ptr().add(index * mem::size_of::<Self>() + FIELD_OFFSET) as *const FieldT) };";
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_iter(buf: &[u8]) -> impl ::core::iter::Iterator<Item = {}> + '_", field.accessor, field_get_ty(field)));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_static(body, records);
+		let value = match &field.layout.stored {
+			Some(stored) => stored_get_expr(field, stored),
+			None => String::from("value"),
+		};
+		emit_text(body, &format!("(0..records).map(move |index| {{ {} {} }})", read, value));
+	});
+	if field.layout.method_set {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_write_iter<I: ::core::iter::IntoIterator<Item = {}>>(buf: &mut [u8], values: I)", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			emit_field_prelude(body, stru, field);
+			emit_static(body, records);
+			let value = match &field.layout.stored {
+				Some(stored) => format!("let value: FieldT = {};", stored_set_expr(field, stored)),
+				None => String::new(),
+			};
+			emit_text(body, &format!("for (index, value) in (0..records).zip(values) {{ {} unsafe {{ ptr::write_unaligned(buf.as_mut_ptr().add(index * mem::size_of::<Self>() + FIELD_OFFSET) as *mut FieldT, value); }} }}", value));
+		});
+	}
+}
+// Handles which borrow the structure and access the field without alignment
+fn emit_field_unaligned_ref(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_unaligned(&self) -> ::struct_layout::Unaligned<'_, {}>", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_static(body, "unsafe { ::struct_layout::Unaligned::new((self as *const Self as *const u8).add(FIELD_OFFSET)) }");
+	});
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_unaligned_mut(&mut self) -> ::struct_layout::UnalignedMut<'_, {}>", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_static(body, "unsafe { ::struct_layout::UnalignedMut::new((self as *mut Self as *mut u8).add(FIELD_OFFSET)) }");
+	});
+}
+// Accessors at the offset of the field shifted by a delta, None if the shifted field does not fit in the structure
+fn emit_field_shiftable(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	let in_bounds = "if delta > mem::size_of::<Self>() - FIELD_OFFSET - mem::size_of::<FieldT>() { return ::core::option::Option::None; }";
+	if field.layout.method_get {
+		emit_attrs(code, &field.attrs);
+		emit_static(code, "#[inline]");
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_at(&self, delta: usize) -> ::core::option::Option<{}>", field.accessor, field_get_ty(field)));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			emit_field_prelude(body, stru, field);
+			emit_text(body, &format!("if delta == 0 {{ return ::core::option::Option::Some(self.{}()); }}", field.accessor));
+			emit_static(body, in_bounds);
+			emit_static(body, "let value = unsafe { ptr::read_unaligned((self as *const Self as *const u8).add(FIELD_OFFSET + delta) as *const FieldT) };");
+			match &field.layout.stored {
+				Some(stored) => emit_text(body, &format!("::core::option::Option::Some({})", stored_get_expr(field, stored))),
+				None => emit_static(body, "::core::option::Option::Some(value)"),
+			}
+		});
+	}
+	if field.layout.method_set {
+		emit_attrs(code, &field.attrs);
+		emit_static(code, "#[inline]");
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn set_{}_at(&mut self, delta: usize, value: {}) -> ::core::option::Option<&mut Self>", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			emit_field_prelude(body, stru, field);
+			emit_text(body, &format!("if delta == 0 {{ return ::core::option::Option::Some(self.set_{}(value)); }}", field.accessor));
+			emit_static(body, in_bounds);
+			if let Some(stored) = &field.layout.stored {
+				emit_text(body, &format!("let value: FieldT = {};", stored_set_expr(field, stored)));
+			}
+			emit_static(body, "unsafe { ptr::write_unaligned((self as *mut Self as *mut u8).add(FIELD_OFFSET + delta) as *mut FieldT, value); }");
+			emit_checksum_auto(body, stru, field);
+			emit_static(body, "::core::option::Option::Some(self)");
+		});
+	}
+}
+// Deprecated accessors at the offset of the field in earlier versions of the layout
+fn emit_field_legacy(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, old: &Expr) {
+	let note = format!("the field `{}` moved from offset {} to {}, use `{}` instead", field.name, old.0, field.layout.offset.0, field.accessor);
+	let note = format!("#[deprecated(note = {:?})]", note);
+	let prelude = |body: &mut Vec<TokenTree>| {
+		emit_text(body, &format!("const OLD_OFFSET: usize = {};", old.0));
+		emit_static(body, "type FieldT = "); emit_ty(body, field_storage_ty(field));
+		emit_static(body, "; #[allow(unused_imports)] use ::core::{mem, ptr};");
+	};
+	if field.layout.method_get {
+		emit_attrs(code, &field.attrs);
+		emit_text(code, &note);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_legacy(&self) -> {}", field.accessor, field_get_ty(field)));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_static(body, "let value = unsafe { ptr::read_unaligned((self as *const Self as *const u8).add(OLD_OFFSET) as *const FieldT) };");
+			match &field.layout.stored {
+				Some(stored) => emit_text(body, &stored_get_expr(field, stored)),
+				None => emit_static(body, "value"),
+			}
+		});
+	}
+	if field.layout.method_set {
+		emit_attrs(code, &field.attrs);
+		emit_text(code, &note);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn set_{}_legacy(&mut self, value: {}) -> &mut Self", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			if let Some(stored) = &field.layout.stored {
+				emit_text(body, &format!("let value: FieldT = {};", stored_set_expr(field, stored)));
+			}
+			emit_static(body, "unsafe { ptr::write_unaligned((self as *mut Self as *mut u8).add(OLD_OFFSET) as *mut FieldT, value); }");
+			emit_checksum_auto(body, stru, field);
+			emit_ident(body, "self");
+		});
+	}
+}
+// Computes the checksum over its range of bytes and stores it in the field
+fn emit_field_checksum(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, checksum: &Checksum) {
+	let compute = format!("let bytes: &[u8] = {}; let value = ::struct_layout::checksum::{}(&bytes[{}..{}]) as FieldT;", storage_bytes(&stru.layout, "self.0", false), checksum.algorithm, checksum.start.0, checksum.end.0);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn update_{}(&mut self)", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_text(body, &compute);
+		emit_static(body, "unsafe { ptr::write_unaligned((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT, value); }");
+	});
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn verify_{}(&self) -> bool", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_text(body, &compute);
+		emit_static(body, "unsafe { ptr::read_unaligned((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) == value }");
+	});
+}
+// Setters of the other fields keep the automatic checksums up to date
+fn emit_checksum_auto(body: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	for other in &stru.fields {
+		if matches!(&other.layout.checksum, Some(checksum) if checksum.auto) && other.name.to_string() != field.name.to_string() {
+			emit_text(body, &format!("self.update_{}();", other.accessor));
+		}
+	}
+}
+// Access to the stored value of fields with a fallible conversion or a sentinel
+fn emit_field_get_raw(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_raw(&self) -> ", field.accessor));
+	emit_ty(code, field_storage_ty(field));
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_static(body, "unsafe { ptr::read_unaligned((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) }");
+	});
+}
+fn emit_field_set_raw(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_ident(code, "fn");
+	emit_ident(code, &format!("set_{}_raw", field.accessor));
+	emit_group_f(code, Delimiter::Parenthesis, |params| {
+		emit_static(params, "&mut self, value: ");
+		emit_ty(params, field_storage_ty(field));
+	});
+	emit_static(code, " -> &mut Self");
+	emit_field_check(code, stru, field);
+	emit_field_copy_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude(body, stru, field);
+		emit_static(body, "unsafe { ptr::write_unaligned((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT, value); }");
+		emit_checksum_auto(body, stru, field);
+		emit_ident(body, "self");
+	})
+}
+fn emit_field_array(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, elem: &Type, len: &Expr) {
+	emit_text(code, &format!("/// The number of elements of the field `{}`.", field.name));
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_len() -> usize {{ {} }}", field.accessor, len.0));
+	if field.layout.method_get {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_at(&self, index: usize) -> {}", field.accessor, checked_ty(index_policy(stru), &elem.to_string())));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			emit_field_prelude(body, stru, field);
+			emit_field_index_check(body, stru, field, &len.0.to_string());
+			emit_text(body, &format!("type ElemT = {};", elem));
+			let value = format!("unsafe {{ ptr::read_unaligned((self as *const Self as *const u8).add({}) as *const ElemT) }}", element_offset(field, "mem::size_of::<ElemT>()"));
+			emit_text(body, &checked_ok(index_policy(stru), &value));
+		});
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_get(&self, index: usize) -> ::core::option::Option<{}>", field.accessor, elem));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		let value = checked_unwrap(index_policy(stru), &format!("self.{}_at(index)", field.accessor));
+		emit_text(code, &format!("{{ if index < {} {{ Some({}) }} else {{ None }} }}", len.0, value));
+	}
+	if field.layout.method_set {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn set_{}_at(&mut self, index: usize, value: {}) -> {}", field.accessor, elem, checked_ty(index_policy(stru), "&mut Self")));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			emit_field_prelude(body, stru, field);
+			emit_field_index_check(body, stru, field, &len.0.to_string());
+			emit_text(body, &format!("type ElemT = {};", elem));
+			emit_text(body, &format!("unsafe {{ ptr::write_unaligned((self as *mut Self as *mut u8).add({}) as *mut ElemT, value); }}", element_offset(field, "mem::size_of::<ElemT>()")));
+			emit_checksum_auto(body, stru, field);
+			emit_text(body, &checked_ok(index_policy(stru), "self"));
+		});
+	}
+}
+fn emit_field_index_check(body: &mut Vec<TokenTree>, stru: &Structure, field: &Field, len: &str) {
+	let message = format!("\"index out of bounds: the len is {{}} but the index is {{}} in field `{}::{}`\", len, index", stru.name, field.name);
+	emit_text(body, &format!("let len: usize = {}; if index >= len {{ {} }}", len, checked_err(index_policy(stru), &message, "OutOfBounds { index, len }")));
+}
+// The index accessors panic unless specified otherwise
+fn index_policy(stru: &Structure) -> OnError {
+	stru.layout.on_error.unwrap_or(OnError::Panic)
+}
+// Return type of a checked accessor returning `ty` on success
+fn checked_ty(policy: OnError, ty: &str) -> String {
+	match policy {
+		OnError::Panic => ty.to_string(),
+		OnError::Option => format!("::core::option::Option<{}>", ty),
+		OnError::Result => format!("::core::result::Result<{}, ::struct_layout::AccessError>", ty),
+	}
+}
+fn checked_ok(policy: OnError, value: &str) -> String {
+	match policy {
+		OnError::Panic => value.to_string(),
+		OnError::Option => format!("::core::option::Option::Some({})", value),
+		OnError::Result => format!("::core::result::Result::Ok({})", value),
+	}
+}
+// Panics with the format arguments of the message or returns the error, a variant of AccessError
+fn checked_err(policy: OnError, message: &str, error: &str) -> String {
+	match policy {
+		OnError::Panic => format!("panic!({});", message),
+		OnError::Option => String::from("return ::core::option::Option::None;"),
+		OnError::Result => format!("return ::core::result::Result::Err(::struct_layout::AccessError::{});", error),
+	}
+}
+// Unwraps the result of a checked accessor which is known to succeed
+fn checked_unwrap(policy: OnError, value: &str) -> String {
+	match policy {
+		OnError::Panic => value.to_string(),
+		OnError::Option => format!("match {} {{ ::core::option::Option::Some(value) => value, ::core::option::Option::None => unreachable!() }}", value),
+		OnError::Result => format!("match {} {{ ::core::result::Result::Ok(value) => value, ::core::result::Result::Err(_) => unreachable!() }}", value),
+	}
+}
+// Shared prelude of the by-value accessors, asserts the field is in bounds
+fn emit_field_prelude(body: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_generic_asserts(body, stru);
+	emit_text(body, &format!("const FIELD_OFFSET: usize = {}::{};", type_path(stru), field_offset_const(field)));
+	emit_static(body, "type FieldT = "); emit_ty(body, field_storage_ty(field));
+	emit_static(body, "; #[allow(unused_imports)] use ::core::{mem, ptr};");
+}
+fn emit_field_ref(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_ref(&self) -> &", field.accessor));
+	emit_ty(code, &field.ty);
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_static(body, "unsafe { &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) }");
+	});
+}
+fn emit_field_mut(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_mut(&mut self) -> &mut ", field.accessor));
+	emit_ty(code, &field.ty);
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_static(body, "unsafe { &mut *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) }");
+	});
+}
+fn emit_field_slice(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, elem: &Type) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_slice(&self) -> &[{}]", field.accessor, elem));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_static(body, "let array = unsafe { &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) }; array");
+	});
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_slice_mut(&mut self) -> &mut [{}]", field.accessor, elem));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_static(body, "let array = unsafe { &mut *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) }; array");
+	});
+}
+fn emit_field_str(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_str(&self) -> ::core::result::Result<&str, ::core::str::Utf8Error>", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_static(body, "let bytes = unsafe { &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) };");
+		emit_static(body, "let len = bytes.iter().position(|&byte| byte == 0).unwrap_or(bytes.len());");
+		emit_static(body, "::core::str::from_utf8(&bytes[..len])");
+	});
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn set_{}_str(&mut self, value: &str) -> ::core::result::Result<&mut Self, ::struct_layout::TooLong>", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_static(body, "let bytes = unsafe { &mut *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) };");
+		emit_static(body, "let value = value.as_bytes(); if value.len() > bytes.len() { return Err(::struct_layout::TooLong); }");
+		emit_static(body, "let (head, tail) = bytes.split_at_mut(value.len()); head.copy_from_slice(value);");
+		emit_static(body, "for byte in tail { *byte = 0; }");
+		emit_static(body, "Ok(self)");
+	});
+}
+fn emit_field_cstr(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_cstr(&self) -> ::core::result::Result<&::core::ffi::CStr, ::core::ffi::FromBytesUntilNulError>", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_static(body, "let bytes = unsafe { &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) };");
+		emit_static(body, "::core::ffi::CStr::from_bytes_until_nul(bytes)");
+	});
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn set_{}_cstr(&mut self, value: &::core::ffi::CStr) -> ::core::result::Result<&mut Self, ::struct_layout::TooLong>", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_static(body, "let bytes = unsafe { &mut *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) };");
+		emit_static(body, "let value = value.to_bytes_with_nul(); if value.len() > bytes.len() { return Err(::struct_layout::TooLong); }");
+		emit_static(body, "let (head, tail) = bytes.split_at_mut(value.len()); head.copy_from_slice(value);");
+		emit_static(body, "for byte in tail { *byte = 0; }");
+		emit_static(body, "Ok(self)");
+	});
+}
+fn emit_field_pstr(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, cap: Option<&Expr>) {
+	let prelude = |body: &mut Vec<TokenTree>| {
+		emit_field_prelude_aligned(body, stru, field);
+		match cap {
+			Some(cap) => emit_text(body, &format!("const CAP: usize = {};", cap.0)),
+			None => emit_static(body, "const CAP: usize = if mem::size_of::<FieldT>() > 256 { 255 } else { mem::size_of::<FieldT>() - 1 };"),
+		}
+	};
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_str(&self) -> ::core::result::Result<&str, ::struct_layout::StrError>", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		prelude(body);
+		emit_static(body, "let bytes = unsafe { &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) };");
+		emit_static(body, "let len = bytes[0] as usize; if len > CAP { return Err(::struct_layout::StrError::Length(len)); }");
+		emit_static(body, "::core::str::from_utf8(&bytes[1..1 + len]).map_err(::struct_layout::StrError::Utf8)");
+	});
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn set_{}_str(&mut self, value: &str) -> ::core::result::Result<&mut Self, ::struct_layout::TooLong>", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		prelude(body);
+		emit_static(body, "let bytes = unsafe { &mut *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) };");
+		emit_static(body, "let value = value.as_bytes(); if value.len() > CAP { return Err(::struct_layout::TooLong); }");
+		emit_static(body, "bytes[0] = value.len() as u8;");
+		emit_static(body, "let (head, tail) = bytes[1..].split_at_mut(value.len()); head.copy_from_slice(value);");
+		emit_static(body, "for byte in tail { *byte = 0; }");
+		emit_static(body, "Ok(self)");
+	});
+}
+fn emit_field_wstr(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_units(&self) -> &[u16]", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_static(body, "let units = unsafe { &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) };");
+		emit_static(body, "let len = units.iter().position(|&unit| unit == 0).unwrap_or(units.len());");
+		emit_static(body, "&units[..len]");
+	});
+	if cfg!(feature = "alloc") {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_string(&self) -> ::struct_layout::__alloc::string::String", field.accessor));
+		emit_field_check(code, stru, field);
+		emit_text(code, &format!("{{ ::core::char::decode_utf16(self.{}_units().iter().cloned())
+			.map(|chr| chr.unwrap_or(::core::char::REPLACEMENT_CHARACTER)).collect() }}", field.accessor));
+	}
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn set_{}_str(&mut self, value: &str) -> ::core::result::Result<&mut Self, ::struct_layout::TooLong>", field.accessor));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_static(body, "let units = unsafe { &mut *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) };");
+		if !field.layout.wstr_truncate {
+			emit_static(body, "if value.encode_utf16().count() > units.len() { return Err(::struct_layout::TooLong); }");
+		}
+		// Never splits a surrogate pair when truncating
+		emit_static(body, "let mut len = 0; for chr in value.chars() {
+			let mut buf = [0u16; 2];
+			let encoded = chr.encode_utf16(&mut buf);
+			if len + encoded.len() > units.len() { break; }
+			units[len..len + encoded.len()].copy_from_slice(encoded);
+			len += encoded.len();
+		}");
+		emit_static(body, "for unit in &mut units[len..] { *unit = 0; }");
+		emit_static(body, "Ok(self)");
+	});
+}
+fn emit_field_dims(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, rows: &Expr, cols: &Expr) {
+	let upper = field.name.to_string().to_uppercase();
+	emit_text(code, &format!("/// The number of rows of the field `{}`.", field.name));
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("const {}_ROWS: usize = {};", upper, rows.0));
+	emit_text(code, &format!("/// The number of columns of the field `{}`.", field.name));
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("const {}_COLS: usize = {};", upper, cols.0));
+	let index = if field.layout.col_major { "col * ROWS + row" } else { "row * COLS + col" };
+	let prelude = |body: &mut Vec<TokenTree>| {
+		emit_text(body, &format!("const FIELD_OFFSET: usize = {}::{};", stru.name, field_offset_const(field)));
+		emit_text(body, &format!("const ROWS: usize = {}; const COLS: usize = {};", rows.0, cols.0));
+		emit_static(body, "type FieldT = "); emit_ty(body, &field.ty);
+		emit_static(body, "; #[allow(unused_imports)] use ::core::{mem, ptr};");
+		let message = format!("\"index out of bounds: the dims are ({{}}, {{}}) but the index is ({{}}, {{}}) in field `{}::{}`\", ROWS, COLS, row, col", stru.name, field.name);
+		emit_text(body, &format!("if row >= ROWS || col >= COLS {{ {} }}", checked_err(index_policy(stru), &message, "OutOfDims { row, col, rows: ROWS, cols: COLS }")));
+		emit_text(body, &format!("let index = {};", index));
+	};
+	if field.layout.method_get {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_at(&self, row: usize, col: usize) -> {}", field.accessor, checked_ty(index_policy(stru), &field.ty.to_string())));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			let value = format!("unsafe {{ ptr::read_unaligned((self as *const Self as *const u8).add({}) as *const FieldT) }}", element_offset(field, "mem::size_of::<FieldT>()"));
+			emit_text(body, &checked_ok(index_policy(stru), &value));
+		});
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_row(&self, row: usize) -> {}", field.accessor, checked_ty(index_policy(stru), &format!("[{}; {}]", field.ty, cols.0))));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			let message = format!("\"index out of bounds: the rows are {{}} but the row is {{}} in field `{}::{}`\", ROWS, row", stru.name, field.name);
+			emit_text(body, &format!("const ROWS: usize = {}; const COLS: usize = {}; if row >= ROWS {{ {} }}", rows.0, cols.0, checked_err(index_policy(stru), &message, "OutOfDims { row, col: 0, rows: ROWS, cols: COLS }")));
+			let value = format!("::core::array::from_fn(|col| {})", checked_unwrap(index_policy(stru), &format!("self.{}_at(row, col)", field.accessor)));
+			emit_text(body, &checked_ok(index_policy(stru), &value));
+		});
+	}
+	if field.layout.method_set {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn set_{}_at(&mut self, row: usize, col: usize, value: {}) -> {}", field.accessor, field.ty, checked_ty(index_policy(stru), "&mut Self")));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_text(body, &format!("unsafe {{ ptr::write_unaligned((self as *mut Self as *mut u8).add({}) as *mut FieldT, value); }}", element_offset(field, "mem::size_of::<FieldT>()")));
+			emit_checksum_auto(body, stru, field);
+			emit_text(body, &checked_ok(index_policy(stru), "self"));
+		});
+	}
+}
+fn emit_field_strided(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, count: &Expr) {
+	let stride = match &field.layout.stride {
+		Some(stride) => stride.0.to_string(),
+		None => String::from("::core::mem::size_of::<FieldT>()"),
+	};
+	let prelude = |body: &mut Vec<TokenTree>| {
+		emit_text(body, &format!("const FIELD_OFFSET: usize = {}::{};", stru.name, field_offset_const(field)));
+		emit_static(body, "type FieldT = "); emit_ty(body, &field.ty);
+		emit_text(body, &format!("; const COUNT: usize = {}; const STRIDE: usize = {};", count.0, stride));
+		emit_static(body, "#[allow(unused_imports)] use ::core::mem;");
+		emit_field_index_check(body, stru, field, "COUNT");
+		emit_text(body, &format!("let offset = {};", element_offset(field, "STRIDE")));
+	};
+	emit_text(code, &format!("/// The number of elements of the field `{}`.", field.name));
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_len() -> usize {{ {} }}", field.accessor, count.0));
+	if field.layout.method_get {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}(&self, index: usize) -> {}", field.accessor, checked_ty(index_policy(stru), &field.ty.to_string())));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_text(body, &checked_ok(index_policy(stru), "unsafe { ::core::ptr::read_unaligned((self as *const Self as *const u8).add(offset) as *const FieldT) }"));
+		});
+	}
+	if field.layout.method_set {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn set_{}(&mut self, index: usize, value: {}) -> {}", field.accessor, field.ty, checked_ty(index_policy(stru), "&mut Self")));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_static(body, "unsafe { ::core::ptr::write_unaligned((self as *mut Self as *mut u8).add(offset) as *mut FieldT, value); }");
+			emit_checksum_auto(body, stru, field);
+			emit_text(body, &checked_ok(index_policy(stru), "self"));
+		});
+	}
+	if field.layout.method_ref {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_ref(&self, index: usize) -> {}", field.accessor, checked_ty(index_policy(stru), &format!("&{}", field.ty))));
+		emit_field_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_text(body, &checked_ok(index_policy(stru), "unsafe { &*((self as *const Self as *const u8).add(offset) as *const FieldT) }"));
+		});
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_iter(&self) -> impl ::core::iter::Iterator<Item = &{}>", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_text(code, &format!("{{ (0..{}).map(move |index| {}) }}", count.0, checked_unwrap(index_policy(stru), &format!("self.{}_ref(index)", field.accessor))));
+	}
+	if field.layout.method_mut {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_mut(&mut self, index: usize) -> {}", field.accessor, checked_ty(index_policy(stru), &format!("&mut {}", field.ty))));
+		emit_field_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_text(body, &checked_ok(index_policy(stru), "unsafe { &mut *((self as *mut Self as *mut u8).add(offset) as *mut FieldT) }"));
+		});
+	}
+}
+fn emit_field_tail(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, len_field: Option<&Ident>) {
+	let prelude = |body: &mut Vec<TokenTree>| {
+		emit_text(body, &format!("const FIELD_OFFSET: usize = {}::{};", stru.name, field_offset_const(field)));
+		emit_static(body, "type FieldT = "); emit_ty(body, &field.ty);
+		emit_static(body, "; use ::core::mem;");
+		emit_static(body, "match len.checked_mul(mem::size_of::<FieldT>()).and_then(|size| size.checked_add(FIELD_OFFSET)) {
+			Some(end) if end <= mem::size_of::<Self>() => (),
+			_ => return None,
+		}");
+	};
+	if field.layout.method_ref {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_get(&self, len: usize) -> ::core::option::Option<&[{}]>", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_static(body, "Some(unsafe { ::core::slice::from_raw_parts((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT, len) })");
+		});
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}(&self, len: usize) -> &[{}]", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_text(code, &format!("{{ match self.{}_get(len) {{
+			Some(slice) => slice,
+			None => panic!(\"tail length out of bounds: the len is {{}}\", len),
+		}} }}", field.accessor));
+		if let Some(len_field) = len_field {
+			emit_attrs(code, &field.attrs);
+			emit_vis(code, &field.vis);
+			emit_text(code, &format!("fn {}_by(&self) -> &[{}]", field.accessor, field.ty));
+			emit_field_check(code, stru, field);
+			emit_text(code, &format!("{{ self.{}(self.{}() as usize) }}", field.accessor, accessor_name(&stru.layout, len_field)));
+		}
+	}
+	if field.layout.method_mut {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_get_mut(&mut self, len: usize) -> ::core::option::Option<&mut [{}]>", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_static(body, "Some(unsafe { ::core::slice::from_raw_parts_mut((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT, len) })");
+		});
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_mut(&mut self, len: usize) -> &mut [{}]", field.accessor, field.ty));
+		emit_field_check(code, stru, field);
+		emit_text(code, &format!("{{ match self.{}_get_mut(len) {{
+			Some(slice) => slice,
+			None => panic!(\"tail length out of bounds: the len is {{}}\", len),
+		}} }}", field.accessor));
+		if let Some(len_field) = len_field {
+			emit_attrs(code, &field.attrs);
+			emit_vis(code, &field.vis);
+			emit_text(code, &format!("fn {}_by_mut(&mut self) -> &mut [{}]", field.accessor, field.ty));
+			emit_field_check(code, stru, field);
+			emit_text(code, &format!("{{ let len = self.{}() as usize; self.{}_mut(len) }}", accessor_name(&stru.layout, len_field), field.accessor));
+		}
+	}
+}
+// Untyped byte regions, accessible as byte arrays
+fn emit_field_region(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, len: &Expr) {
+	let prelude = |body: &mut Vec<TokenTree>| {
+		emit_text(body, &format!("const FIELD_OFFSET: usize = {}::{}; type FieldT = [u8; {}];", stru.name, field_offset_const(field), len.0));
+	};
+	if field.layout.method_get || field.layout.method_ref {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}(&self) -> &[u8; {}]", field.accessor, len.0));
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_static(body, "unsafe { &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) }");
+		});
+	}
+	if field.layout.method_mut {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}_mut(&mut self) -> &mut [u8; {}]", field.accessor, len.0));
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_static(body, "unsafe { &mut *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) }");
+		});
+	}
+	if field.layout.method_set {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn set_{}(&mut self, bytes: &[u8; {}]) -> &mut Self", field.accessor, len.0));
+		emit_group_f(code, Delimiter::Brace, |body| {
+			prelude(body);
+			emit_static(body, "unsafe { *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) = *bytes; }");
+			emit_checksum_auto(body, stru, field);
+			emit_ident(body, "self");
+		});
+	}
+}
+// The offset is read from the offsets structure and checked at runtime
+fn emit_field_dynamic(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	let prelude = format!("let offset = offsets.{}; type FieldT = {}; use ::core::{{mem, ptr}};
+		if offset > mem::size_of::<Self>() || mem::size_of::<Self>() - offset < mem::size_of::<FieldT>() {{
+			panic!(\"field offset out of bounds: the offset is {{}} but the size is {{}}\", offset, mem::size_of::<Self>());
+		}}", field.name, field.ty);
+	if field.layout.method_get {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn {}(&self, offsets: &{}Offsets) -> {}", field.accessor, stru.name, field.ty));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			emit_text(body, &prelude);
+			emit_static(body, "unsafe { ptr::read_unaligned((self as *const Self as *const u8).add(offset) as *const FieldT) }");
+		});
+	}
+	if field.layout.method_set {
+		emit_attrs(code, &field.attrs);
+		emit_vis(code, &field.vis);
+		emit_text(code, &format!("fn set_{}(&mut self, offsets: &{}Offsets, value: {}) -> &mut Self", field.accessor, stru.name, field.ty));
+		emit_field_check(code, stru, field);
+		emit_field_copy_check(code, stru, field);
+		emit_group_f(code, Delimiter::Brace, |body| {
+			emit_text(body, &prelude);
+			emit_static(body, "unsafe { ptr::write_unaligned((self as *mut Self as *mut u8).add(offset) as *mut FieldT, value); }");
+			emit_checksum_auto(body, stru, field);
+			emit_ident(body, "self");
+		});
+	}
+}
+// Offsets of the dynamic fields
+fn emit_dynamic_offsets(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let fields: Vec<&Field> = stru.fields.iter().filter(|field| field.layout.dynamic).collect();
+	if fields.is_empty() {
+		return;
+	}
+	let name = format!("{}Offsets", stru.name);
+	emit_text(code, &format!("/// Offsets of the dynamic fields of [`{}`].", stru.name));
+	emit_static(code, "#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]");
+	emit_vis(code, &stru.vis);
+	emit_text(code, &format!("struct {}", name));
+	emit_group_f(code, Delimiter::Brace, |body| {
+		for field in &fields {
+			emit_text(body, &format!("/// Offset of the field `{0}` in bytes.\npub {0}: usize,", field.name));
+		}
+	});
+	let params: Vec<String> = fields.iter().map(|field| format!("{}: usize", field.name)).collect();
+	let names: Vec<String> = fields.iter().map(|field| field.name.to_string()).collect();
+	emit_text(code, &format!("impl {} {{ /// Creates the offsets of the dynamic fields.\npub const fn new({}) -> {0} {{ {0} {{ {} }} }} }}", name, params.join(", "), names.join(", ")));
+}
+fn emit_field_variant(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field, variant: &Variant) {
+	let check = format!("if self.{}() != {} {{ return None; }}", accessor_name(&stru.layout, &variant.field), variant.value.0);
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}(&self) -> ::core::option::Option<&{}>", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_text(body, &check);
+		emit_static(body, "Some(unsafe { &*((self as *const Self as *const u8).add(FIELD_OFFSET) as *const FieldT) })");
+	});
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn {}_mut(&mut self) -> ::core::option::Option<&mut {}>", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_text(body, &check);
+		emit_static(body, "Some(unsafe { &mut *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) })");
+	});
+	// Writes the discriminant along with the payload
+	emit_attrs(code, &field.attrs);
+	emit_vis(code, &field.vis);
+	emit_text(code, &format!("fn set_{}(&mut self, value: {}) -> &mut Self", field.accessor, field.ty));
+	emit_field_check(code, stru, field);
+	emit_group_f(code, Delimiter::Brace, |body| {
+		emit_field_prelude_aligned(body, stru, field);
+		emit_text(body, &format!("self.set_{}({});", accessor_name(&stru.layout, &variant.field), variant.value.0));
+		emit_static(body, "unsafe { *((self as *mut Self as *mut u8).add(FIELD_OFFSET) as *mut FieldT) = value; }");
+		emit_checksum_auto(body, stru, field);
+		emit_ident(body, "self");
+	});
+}
+fn field_offset_const(field: &Field) -> String {
+	format!("__OFFSET_{}", field.name.to_string().to_uppercase())
+}
+// Offset of the element at `index`, the index is checked against the length before
+fn element_offset(field: &Field, stride: &str) -> String {
+	format!("match index.checked_mul({}).and_then(|offset| offset.checked_add(FIELD_OFFSET)) {{
+		Some(offset) => offset,
+		None => panic!(\"offset of an element of field `{}` overflows\"),
+	}}", stride, field.name)
+}
+fn field_aligned_const(field: &Field) -> String {
+	format!("{}_IS_ALIGNED", field.name.to_string().to_uppercase())
+}
+// Shared prelude of the reference accessors
+fn emit_field_prelude_aligned(body: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	emit_generic_asserts(body, stru);
+	emit_text(body, &format!("const FIELD_OFFSET: usize = {}::{};", type_path(stru), field_offset_const(field)));
+	emit_static(body, "type FieldT = "); emit_ty(body, &field.ty);
+	emit_punct(body, ';');
+}
+// The asserts of a generic structure are evaluated once the accessors are instantiated
+fn emit_generic_asserts(body: &mut Vec<TokenTree>, stru: &Structure) {
+	if !stru.generics.names.is_empty() {
+		emit_static(body, "let () = Self::__STRUCT_LAYOUT_ASSERTS;");
+	}
+}
+// Items nested in the accessors cannot refer to the type parameters of a generic structure,
+// the FieldT and FIELD_OFFSET items are removed and their definitions inlined at their uses
+fn inline_field_items(tokens: Vec<TokenTree>) -> Vec<TokenTree> {
+	fn inline(tokens: Vec<TokenTree>, defs: &mut Vec<(String, Vec<TokenTree>)>) -> Vec<TokenTree> {
+		let scope = defs.len();
+		let mut code = Vec::new();
+		let mut tokens = tokens.into_iter();
+		while let Some(tt) = tokens.next() {
+			match &tt {
+				TokenTree::Ident(ident) if matches!(&*ident.to_string(), "type" | "const") => {
+					// Not the `const` of a pointer type to the field
+					let name = match (&*ident.to_string(), tokens.as_slice()) {
+						("type", [TokenTree::Ident(name), TokenTree::Punct(punct), ..]) if name.to_string() == "FieldT" && punct.as_char() == '=' => name.to_string(),
+						("const", [TokenTree::Ident(name), TokenTree::Punct(punct), ..]) if name.to_string() == "FIELD_OFFSET" && punct.as_char() == ':' => name.to_string(),
+						_ => {
+							code.push(tt);
+							continue;
+						},
+					};
+					// The definition follows the `=` up to the semicolon
+					let mut def: Vec<TokenTree> = tokens.by_ref().skip_while(|tt| !matches!(tt, TokenTree::Punct(punct) if punct.as_char() == '=')).skip(1)
+						.take_while(|tt| !matches!(tt, TokenTree::Punct(punct) if punct.as_char() == ';')).collect();
+					def = inline(def, defs);
+					defs.push((name, def));
+				},
+				TokenTree::Ident(ident) => match defs.iter().rev().find(|(name, _)| *name == ident.to_string()) {
+					Some((_, def)) => code.push(TokenTree::Group(Group::new(Delimiter::None, def.iter().cloned().collect()))),
+					None => code.push(tt),
+				},
+				TokenTree::Group(group) => {
+					let stream = inline(group.stream().into_iter().collect(), defs);
+					let mut inlined = Group::new(group.delimiter(), stream.into_iter().collect());
+					inlined.set_span(group.span());
+					code.push(TokenTree::Group(inlined));
+				},
+				_ => code.push(tt),
+			}
+		}
+		defs.truncate(scope);
+		code
+	}
+	inline(tokens, &mut Vec::new())
+}
+// Checks the layout of every field once in a const block next to the structure
+fn emit_field_asserts(code: &mut Vec<TokenTree>, stru: &Structure) {
+	let mut blocks = String::new();
+	for field in &stru.fields {
+		blocks.push_str(&field_asserts(stru, field));
+	}
+	emit_text(code, &format!("const _: () = {{ use ::core::mem; {} }};", blocks));
+}
+// The elements of the field end within the structure, overflowing offsets are out of bounds
+fn extent_in_bounds(name: &str, count: &str, size: &str, stride: &str) -> String {
+	format!("matches!(::struct_layout::__extent_end(FIELD_OFFSET, {}, {}, {}), Some(end) if end <= mem::size_of::<{}>())", size, count, stride, name)
+}
+fn field_asserts(stru: &Structure, field: &Field) -> String {
+	let (name, layout) = (&*type_path(stru), &field.layout);
+	let mut asserts = format!("type FieldT = {};", field_storage_ty(field));
+	if !layout.dynamic {
+		asserts.push_str(&format!("const FIELD_OFFSET: usize = {}::{};", name, field_offset_const(field)));
+	}
+	if stru.layout.compact {
+		match &layout.check_cfg {
+			Some(pred) => asserts.push_str(&format!("#[cfg({})] const _: () = {{ {} }};", pred, field_check_asserts(stru, field))),
+			None => asserts.push_str(&field_check_asserts(stru, field)),
+		}
+	}
+	// Points at the nested structure instead of the accessors if it does not implement the check trait, eg. lacks a derive of Copy
+	if layout.nested && field.layout.manually_drop.is_none() {
+		asserts.push_str(&format!("fn nested_structure_requires<T: ?Sized + {}>() {{}} let _: fn() = nested_structure_requires::<{}>;", field_check_bound(stru, field), nested_ty(field)));
+	}
+	// The constructors from bytes are safe for structures checked to be Pod, the fields without accessors are checked here
+	if let (true, Some(check)) = (is_checked_pod(stru, field), &stru.layout.check) {
+		asserts.push_str(&format!("fn field_requires_pod<T: ?Sized + {}>() {{}} let _: fn() = field_requires_pod::<FieldT>;", check));
+	}
+	// Shared references to the structure must not observe mutation through the references of the ref accessors
+	if stru.layout.freeze {
+		asserts.push_str(&format!("fn field_requires_freeze<T: ?Sized + ::struct_layout::Freeze>() {{}} let _: fn() = field_requires_freeze::<{}>;", field.ty));
+	}
+	let mut assert = |cond: String, msg: &str| {
+		asserts.push_str(&format!("assert!({}, \"field `{}` {}\");", cond, field.name, msg));
+	};
+	// The accessors copy the bytes of the field, types with drop glue would be leaked or dropped twice
+	if !layout.allow_drop {
+		assert(String::from("!mem::needs_drop::<FieldT>()"), "has drop glue, use `allow_drop` to accept it");
+	}
+	if let Some(size) = &layout.size {
+		assert(format!("mem::size_of::<FieldT>() == {}", size.0), "does not have the asserted size on this target");
+	}
+	if let (Some(align), false) = (&layout.align, layout.dynamic) {
+		assert(format!("mem::align_of::<FieldT>() <= {}", align.0), "has an align argument smaller than the alignment of its type");
+		assert(format!("FIELD_OFFSET % ({}) == 0", align.0), "is not aligned to its align argument");
+		assert(format!("mem::align_of::<{}>() % ({}) == 0", name, align.0), "has an align argument stricter than the alignment of the structure");
+	}
+	let aligned = format!("FIELD_OFFSET % mem::align_of::<FieldT>() == 0 && mem::align_of::<{}>() % mem::align_of::<FieldT>() == 0", name);
+	// Atomics may be stricter aligned than their integer, eg. 64-bit integers on 32-bit x86
+	if let (true, Some(atomic)) = (layout.method_atomic, atomic_ty(&field.ty)) {
+		assert(format!("FIELD_OFFSET % mem::align_of::<::core::sync::atomic::{0}>() == 0 && mem::align_of::<{1}>() % mem::align_of::<::core::sync::atomic::{0}>() == 0", atomic, name), "is not aligned for atomic access");
+	}
+	let unaligned = if stru.layout.packed { "is not aligned, packed layouts cannot hand out references to it" } else { "is not aligned" };
+	if layout.dynamic {
+		// Checked at runtime
+	}
+	else if let Some((rows, cols)) = &layout.dims {
+		assert(format!("match usize::checked_mul({}, {}) {{ Some(count) => {}, None => false }}", rows.0, cols.0, extent_in_bounds(name, "count", "mem::size_of::<FieldT>()", "mem::size_of::<FieldT>()")), "is out of bounds");
+	}
+	else if let Some(count) = &layout.count {
+		let stride = layout.stride.as_ref().map(|stride| stride.0.to_string()).unwrap_or_else(|| String::from("mem::size_of::<FieldT>()"));
+		assert(extent_in_bounds(name, &count.0.to_string(), "mem::size_of::<FieldT>()", &stride), "is out of bounds");
+		if layout.method_ref || layout.method_mut {
+			assert(format!("{} && ({}) % mem::align_of::<FieldT>() == 0", aligned, stride), unaligned);
+		}
+	}
+	else if layout.tail.is_some() {
+		assert(format!("FIELD_OFFSET <= mem::size_of::<{}>()", name), "is out of bounds");
+		assert(aligned, unaligned);
+	}
+	else if let Some(len) = &layout.len {
+		assert(extent_in_bounds(name, "1", &len.0.to_string(), "0"), "is out of bounds");
+	}
+	else {
+		assert(extent_in_bounds(name, "1", "mem::size_of::<FieldT>()", "0"), "is out of bounds");
+		if let Some(old) = &layout.old_offset {
+			assert(format!("matches!(::struct_layout::__extent_end({}, mem::size_of::<FieldT>(), 1, 0), Some(end) if end <= mem::size_of::<{}>())", old.0, name), "has an old offset which is out of bounds");
+		}
+		if layout.get_ref {
+			assert(aligned, "is not aligned for the reference returned by its getter with the auto_ref argument, add `get` to the field for a getter by value");
+		}
+		else if layout.method_ref || layout.method_mut || layout.method_slice || layout.method_str || layout.method_cstr || layout.method_wstr || layout.pstr.is_some() || layout.variant.is_some() {
+			assert(aligned, unaligned);
+		}
+		// Nested explicit layouts are checked against their declared size and alignment, arrays of them for every element
+		if layout.nested {
+			let (nested, count) = match array_ty(&field.ty) {
+				Some((elem, len)) => (elem, len.0.to_string()),
+				None => (field.ty.clone(), String::from("1")),
+			};
+			let nested_size = format!("<{} as ::struct_layout::ExplicitLayout>::SIZE", nested);
+			assert(format!("{0} &&
+				FIELD_OFFSET % <{1} as ::struct_layout::ExplicitLayout>::ALIGN == 0 &&
+				mem::align_of::<{2}>() % <{1} as ::struct_layout::ExplicitLayout>::ALIGN == 0",
+				extent_in_bounds(name, &count, &nested_size, &format!("mem::size_of::<{}>()", nested)), nested, name), "does not meet the layout of the nested structure");
+		}
+		if let Some(checksum) = &layout.checksum {
+			assert(format!("({0}) <= ({1}) && ({1}) <= mem::size_of::<{2}>()", checksum.start.0, checksum.end.0, name), "has its checksum range out of bounds");
+			assert(format!("FIELD_OFFSET >= ({}) || matches!(::struct_layout::__extent_end(FIELD_OFFSET, mem::size_of::<FieldT>(), 1, 0), Some(end) if end <= ({}))", checksum.end.0, checksum.start.0), "overlaps its checksum range");
+		}
+		if let Some((min, max)) = &layout.clamp {
+			assert(format!("{{ const MIN: {0} = {1}; const MAX: {0} = {2}; MIN <= MAX }}", field.ty, min.0, max.0), "has an empty clamp range");
+		}
+		if let Some(pstr) = &layout.pstr {
+			let cap = pstr.as_ref().map(|cap| cap.0.to_string()).unwrap_or_else(|| String::from("if mem::size_of::<FieldT>() > 256 { 255 } else { mem::size_of::<FieldT>() - 1 }"));
+			assert(format!("({0}) < mem::size_of::<FieldT>() && ({0}) <= 255", cap), "does not fit its length prefix");
+		}
+		if layout.stored.is_some() && layout.convert.is_none() {
+			assert(format!("mem::size_of::<FieldT>() == mem::size_of::<{}>()", field.ty), "does not have the size of its stored type");
+		}
+		if let Some((start, end)) = layout.bits {
+			assert(format!("{} <= mem::size_of::<FieldT>() * 8", end), "has a bit range which does not fit in its stored integer");
+			if field.ty.to_string() != "bool" {
+				assert(format!("{} <= mem::size_of::<{}>() * 8", end - start, field.ty), "has a bit range wider than its type");
+			}
+		}
+	}
+	format!("{{ {} }}", asserts)
+}
+fn field_check_bound<'a>(stru: &'a Structure, field: &Field) -> &'a str {
+	let default = if field.layout.manually_drop.is_some() { "'static" } else { "Copy + 'static" };
+	stru.layout.check.as_deref().unwrap_or(default)
+}
+// The bounds of the accessors of a field in compact mode
+fn field_check_asserts(stru: &Structure, field: &Field) -> String {
+	let layout = &field.layout;
+	let mut bounds = format!("?Sized + {} + 'static", field_check_bound(stru, field));
+	// Reading the field by value creates a copy regardless of the check trait
+	if layout.manually_drop.is_none() && (layout.method_get || layout.method_set && !layout.allow_drop || layout.method_modify || layout.method_copy_into || layout.method_copy_from || layout.method_zero || layout.method_unaligned_ref || layout.method_shiftable || layout.method_clamped || layout.gated.iter().any(|&(method, _)| method == "get" || method == "set")) {
+		bounds.push_str(" + ::core::marker::Copy");
+	}
+	let mut asserts = format!("fn check<T: {}>() {{}} let _: fn() = check::<FieldT>;", bounds);
+	if layout.nested {
+		asserts.push_str(&format!("fn nested<T: ?Sized + ::struct_layout::ExplicitLayout>() {{}} let _: fn() = nested::<{}>;", nested_ty(field)));
+	}
+	asserts
+}
+// Reading the field by value creates a copy regardless of the check trait
+fn emit_field_copy_check(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	// The getter is unsafe instead
+	if field.layout.manually_drop.is_some() || stru.layout.compact {
+		return;
+	}
+	emit_static(code, ", ");
+	emit_ty(code, field_storage_ty(field));
+	emit_static(code, ": ::core::marker::Copy");
+}
+fn emit_field_check(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
+	// Asserted once next to the structure instead
+	if stru.layout.compact {
+		return;
+	}
+	let check = field_check_bound(stru, field);
+	emit_ident(code, "where");
+	emit_ty(code, field_storage_ty(field));
+	emit_punct(code, ':');
+	emit_text(code, check);
+	// Always required, even if the check trait does not imply it
+	emit_static(code, ", ");
+	emit_ty(code, field_storage_ty(field));
+	emit_static(code, ": 'static");
+	if field.layout.nested {
+		emit_text(code, &format!(", {}: ::struct_layout::ExplicitLayout", nested_ty(field)));
+	}
+}
+// The nested structure of the field, arrays nest their element type
+fn nested_ty(field: &Field) -> Type {
+	array_ty(&field.ty).map(|(elem, _)| elem).unwrap_or_else(|| field.ty.clone())
+}
+
+//----------------------------------------------------------------
+// C declarations
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct CStruct {
+	name: String,
+	fields: Vec<CField>,
+	size: usize,
+	align: usize,
+}
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct CField {
+	name: String,
+	// The equivalent Rust type
+	ty: String,
+	offset: usize,
+}
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct CError {
+	message: String,
+	line: usize,
+	col: usize,
+}
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum CToken {
+	Ident(String),
+	Number(usize),
+	Punct(char),
+}
+
+// Splits the C text into tokens with their line and column, skipping whitespace and comments
+fn c_tokens(text: &str) -> Result<Vec<(CToken, usize, usize)>, CError> {
+	let chars: Vec<char> = text.chars().collect();
+	let mut tokens = Vec::new();
+	let (mut i, mut line, mut col) = (0, 1, 1);
+	let advance = |i: &mut usize, line: &mut usize, col: &mut usize| {
+		if chars[*i] == '\n' {
+			*line += 1;
+			*col = 1;
+		}
+		else {
+			*col += 1;
+		}
+		*i += 1;
+	};
+	while i < chars.len() {
+		let (start_line, start_col) = (line, col);
+		let chr = chars[i];
+		if chr.is_whitespace() {
+			advance(&mut i, &mut line, &mut col);
+		}
+		else if chr == '/' && chars.get(i + 1) == Some(&'/') {
+			while i < chars.len() && chars[i] != '\n' {
+				advance(&mut i, &mut line, &mut col);
+			}
+		}
+		else if chr == '/' && chars.get(i + 1) == Some(&'*') {
+			advance(&mut i, &mut line, &mut col);
+			advance(&mut i, &mut line, &mut col);
+			while !(chars.get(i) == Some(&'*') && chars.get(i + 1) == Some(&'/')) {
+				if i >= chars.len() {
+					return Err(CError { message: String::from("unterminated comment"), line: start_line, col: start_col });
+				}
+				advance(&mut i, &mut line, &mut col);
+			}
+			advance(&mut i, &mut line, &mut col);
+			advance(&mut i, &mut line, &mut col);
+		}
+		else if chr.is_ascii_alphabetic() || chr == '_' {
+			let mut ident = String::new();
+			while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+				ident.push(chars[i]);
+				advance(&mut i, &mut line, &mut col);
+			}
+			tokens.push((CToken::Ident(ident), start_line, start_col));
+		}
+		else if chr.is_ascii_digit() {
+			let mut digits = String::new();
+			while i < chars.len() && chars[i].is_ascii_alphanumeric() {
+				digits.push(chars[i]);
+				advance(&mut i, &mut line, &mut col);
+			}
+			let value = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+				Some(hex) => usize::from_str_radix(hex, 16).ok(),
+				None => digits.parse().ok(),
+			};
+			match value {
+				Some(value) => tokens.push((CToken::Number(value), start_line, start_col)),
+				None => return Err(CError { message: format!("invalid number `{}`", digits), line: start_line, col: start_col }),
+			}
+		}
+		else {
+			tokens.push((CToken::Punct(chr), start_line, start_col));
+			advance(&mut i, &mut line, &mut col);
+		}
+	}
+	Ok(tokens)
+}
+
+// The Rust type and size of the fixed-width C types, the alignment equals the size
+fn c_primitive(words: &[&str]) -> Option<(&'static str, usize)> {
+	Some(match words {
+		["int8_t"] | ["signed", "char"] => ("i8", 1),
+		["uint8_t"] | ["char"] | ["unsigned", "char"] => ("u8", 1),
+		["int16_t"] | ["short"] | ["signed", "short"] => ("i16", 2),
+		["uint16_t"] | ["unsigned", "short"] => ("u16", 2),
+		["int32_t"] | ["int"] | ["signed", "int"] | ["signed"] => ("i32", 4),
+		["uint32_t"] | ["unsigned", "int"] | ["unsigned"] => ("u32", 4),
+		["int64_t"] | ["long", "long"] | ["signed", "long", "long"] => ("i64", 8),
+		["uint64_t"] | ["unsigned", "long", "long"] => ("u64", 8),
+		["float"] => ("f32", 4),
+		["double"] => ("f64", 8),
+		["bool"] | ["_Bool"] => ("bool", 1),
+		_ => return None,
+	})
+}
+
+// Parses a single `struct Name { .. };` or `typedef struct { .. } Name;` definition with natural C alignment
+fn parse_c_struct(text: &str) -> Result<CStruct, CError> {
+	let tokens = c_tokens(text)?;
+	let mut pos = 0;
+	let end = tokens.last().map(|&(_, line, col)| (line, col + 1)).unwrap_or((1, 1));
+	let error = |pos: usize, message: String| {
+		let (line, col) = tokens.get(pos).map(|&(_, line, col)| (line, col)).unwrap_or(end);
+		CError { message, line, col }
+	};
+	let ident = |pos: usize| match tokens.get(pos) {
+		Some((CToken::Ident(ident), _, _)) => Some(ident.as_str()),
+		_ => None,
+	};
+	let punct = |pos: usize, chr: char| matches!(tokens.get(pos), Some((CToken::Punct(p), _, _)) if *p == chr);
+
+	let typedef = ident(pos) == Some("typedef");
+	if typedef {
+		pos += 1;
+	}
+	match ident(pos) {
+		Some("struct") => pos += 1,
+		Some("union") => return Err(error(pos, String::from("unions are not supported"))),
+		_ => return Err(error(pos, String::from("expecting `struct`"))),
+	}
+	let mut name = match ident(pos) {
+		Some(tag) => {
+			pos += 1;
+			Some(tag.to_string())
+		},
+		None => None,
+	};
+	if !punct(pos, '{') {
+		return Err(error(pos, String::from("expecting `{` after the struct name")));
+	}
+	pos += 1;
+	let (mut fields, mut offset, mut align) = (Vec::<CField>::new(), 0usize, 1usize);
+	while !punct(pos, '}') {
+		let start = pos;
+		let mut words = Vec::new();
+		// The type is every word except the last before the declarator
+		while let (Some(word), true) = (ident(pos), ident(pos + 1).is_some() || punct(pos + 1, '*')) {
+			if word != "const" && word != "volatile" {
+				words.push(word);
+			}
+			pos += 1;
+		}
+		if let Some(&("struct" | "union" | "enum")) = words.first() {
+			return Err(error(start, format!("nested `{}` declarations are not supported, declare the field as an integer or byte array", words[0])));
+		}
+		if punct(pos, '*') {
+			return Err(error(pos, String::from("pointers are not supported, their size depends on the target, use `uint32_t` or `uint64_t` instead")));
+		}
+		if words.contains(&"long") && !words.windows(2).any(|w| w == ["long", "long"]) {
+			return Err(error(start, String::from("`long` has a platform dependent size, use `int32_t` or `int64_t` instead")));
+		}
+		let (ty, size) = match c_primitive(&words) {
+			Some(prim) => prim,
+			None if words.is_empty() => return Err(error(start, String::from("expecting a field declaration `<type> <name>;`"))),
+			None => return Err(error(start, format!("unsupported type `{}`, expecting a fixed-width type such as `int32_t`, `char` or `float`", words.join(" ")))),
+		};
+		loop {
+			if punct(pos, '*') {
+				return Err(error(pos, String::from("pointers are not supported, their size depends on the target, use `uint32_t` or `uint64_t` instead")));
+			}
+			let field = match ident(pos) {
+				Some(field) => field,
+				None => return Err(error(pos, String::from("expecting a field name"))),
+			};
+			if is_rust_keyword(field) {
+				return Err(error(pos, format!("the field name `{}` is a Rust keyword", field)));
+			}
+			if fields.iter().any(|other| other.name == field) {
+				return Err(error(pos, format!("duplicate field `{}`", field)));
+			}
+			pos += 1;
+			let mut dims = Vec::new();
+			while punct(pos, '[') {
+				match (tokens.get(pos + 1), punct(pos + 2, ']')) {
+					(Some((CToken::Number(len), _, _)), true) => dims.push(*len),
+					_ => return Err(error(pos + 1, String::from("expecting a constant array length `[<number>]`"))),
+				}
+				pos += 3;
+			}
+			if punct(pos, ':') {
+				return Err(error(pos, String::from("bit fields are not supported")));
+			}
+			let mut rust_ty = ty.to_string();
+			let mut field_size = size;
+			for &len in dims.iter().rev() {
+				rust_ty = format!("[{}; {}]", rust_ty, len);
+				field_size *= len;
+			}
+			// Natural alignment, arrays are aligned as their element
+			offset = offset.div_ceil(size) * size;
+			align = align.max(size);
+			fields.push(CField { name: field.to_string(), ty: rust_ty, offset });
+			offset += field_size;
+			if punct(pos, ',') {
+				pos += 1;
+				continue;
+			}
+			if punct(pos, ';') {
+				pos += 1;
+				break;
+			}
+			return Err(error(pos, String::from("expecting `;` after the field")));
+		}
+	}
+	pos += 1;
+	if typedef {
+		match ident(pos) {
+			Some(alias) => {
+				name = Some(alias.to_string());
+				pos += 1;
+			},
+			None => return Err(error(pos, String::from("expecting the typedef name"))),
+		}
+	}
+	if punct(pos, ';') {
+		pos += 1;
+	}
+	if pos < tokens.len() {
+		return Err(error(pos, String::from("expecting a single struct definition")));
+	}
+	let name = match name {
+		Some(name) => name,
+		None => return Err(error(0, String::from("the struct has no name"))),
+	};
+	let size = offset.div_ceil(align) * align;
+	Ok(CStruct { name, fields, size, align })
+}
+fn is_rust_keyword(name: &str) -> bool {
+	matches!(name, "as" | "break" | "const" | "continue" | "crate" | "else" | "enum" | "extern" | "false" | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod" | "move" | "mut" | "pub" | "ref" | "return" | "self" | "Self" | "static" | "struct" | "super" | "trait" | "true" | "type" | "unsafe" | "use" | "where" | "while" | "async" | "await" | "dyn" | "abstract" | "become" | "box" | "do" | "final" | "macro" | "override" | "priv" | "typeof" | "unsized" | "virtual" | "yield" | "try")
+}
+// The value of a string literal token, raw or with the common escapes
+fn string_literal_value(lit: &str) -> Option<String> {
+	if let Some(raw) = lit.strip_prefix('r') {
+		let hashes = raw.len() - raw.trim_start_matches('#').len();
+		let inner = raw.get(hashes + 1..raw.len().checked_sub(hashes + 1)?)?;
+		return Some(inner.to_string());
+	}
+	let inner = lit.strip_prefix('"')?.strip_suffix('"')?;
+	let mut value = String::new();
+	let mut chars = inner.chars();
+	while let Some(chr) = chars.next() {
+		if chr != '\\' {
+			value.push(chr);
+			continue;
+		}
+		match chars.next()? {
+			'n' => value.push('\n'),
+			't' => value.push('\t'),
+			'r' => value.push('\r'),
+			'0' => value.push('\0'),
+			'\\' => value.push('\\'),
+			'"' => value.push('"'),
+			'\'' => value.push('\''),
+			// Line continuation skips the leading whitespace of the next line
+			'\n' => {
+				let rest: String = chars.collect();
+				return Some(value + &string_literal_value(&format!("\"{}\"", rest.trim_start()))?);
+			},
+			_ => return None,
+		}
+	}
+	Some(value)
+}
+
+//----------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn edit_distances() {
+		assert_eq!(edit_distance("check", "check"), 0);
+		assert_eq!(edit_distance("chck", "check"), 1);
+		assert_eq!(edit_distance("aling", "align"), 1);
+		assert_eq!(edit_distance("packd", "packed"), 1);
+		assert_eq!(edit_distance("base", "visit"), 4);
+		assert_eq!(edit_distance("", "mirror"), 6);
+	}
+
+	#[test]
+	fn suggestions() {
+		assert_eq!(suggest_name("chck", LAYOUT_ARGUMENTS), Some("check"));
+		assert_eq!(suggest_name("fild_enum", LAYOUT_ARGUMENTS), Some("field_enum"));
+		assert_eq!(suggest_name("slice_xet", LAYOUT_ARGUMENTS), Some("slice_ext"));
+		assert_eq!(suggest_name("dataveiw", LAYOUT_ARGUMENTS), Some("dataview"));
+		assert_eq!(suggest_name("xyz", LAYOUT_ARGUMENTS), None);
+		assert_eq!(suggest_name("byts", LAYOUT_ARGUMENTS), Some("bytes"));
+		assert_eq!(suggest_name("pixels", LAYOUT_ARGUMENTS), None);
+		assert_eq!(suggest_name("gte", FIELD_ARGUMENTS), Some("get"));
+		assert_eq!(suggest_name("strid", FIELD_ARGUMENTS), Some("stride"));
+	}
+
+	#[test]
+	fn json_strings() {
+		assert_eq!(json_string("u32"), "\"u32\"");
+		assert_eq!(json_string("a\"b\\c\n\u{1}"), "\"a\\\"b\\\\c\\n\\u0001\"");
+	}
+
+	#[test]
+	fn c_struct() {
+		let c = parse_c_struct("struct Foo { int32_t health; char name[32]; float pos[3]; };").unwrap();
+		assert_eq!(c.name, "Foo");
+		let fields: Vec<(&str, &str, usize)> = c.fields.iter().map(|f| (&*f.name, &*f.ty, f.offset)).collect();
+		assert_eq!(fields, [("health", "i32", 0), ("name", "[u8; 32]", 4), ("pos", "[f32; 3]", 36)]);
+		assert_eq!((c.size, c.align), (48, 4));
+	}
+
+	#[test]
+	fn c_struct_padding() {
+		// Fields are aligned to their size and the size is rounded up to the largest alignment
+		let c = parse_c_struct("typedef struct _Bar {
+			uint8_t a; /* padded to 8 */
+			uint64_t b;
+			int16_t c, d[2][3]; // arrays align as their element
+			unsigned char e;
+		} Bar;").unwrap();
+		assert_eq!(c.name, "Bar");
+		let fields: Vec<(&str, &str, usize)> = c.fields.iter().map(|f| (&*f.name, &*f.ty, f.offset)).collect();
+		assert_eq!(fields, [("a", "u8", 0), ("b", "u64", 8), ("c", "i16", 16), ("d", "[[i16; 3]; 2]", 18), ("e", "u8", 30)]);
+		assert_eq!((c.size, c.align), (32, 8));
+	}
+
+	#[test]
+	fn c_struct_errors() {
+		let err = |text: &str| parse_c_struct(text).unwrap_err();
+		assert_eq!(err("struct A {\n  int *p;\n};").line, 2);
+		assert_eq!(err("struct A {\n  int *p;\n};").col, 7);
+		assert!(err("struct A { long x; };").message.contains("platform dependent"));
+		assert!(err("struct A { struct B b; };").message.contains("nested"));
+		assert!(err("struct A { int x : 3; };").message.contains("bit fields"));
+		assert!(err("struct A { int type; };").message.contains("Rust keyword"));
+		assert!(err("struct A { wchar_t x; };").message.contains("unsupported type `wchar_t`"));
+		assert!(err("struct A { int x; }; struct B { int y; };").message.contains("single struct"));
+		assert!(err("struct A { int x[n]; };").message.contains("constant array length"));
+		assert!(err("struct { int x; };").message.contains("no name"));
+		assert!(err("struct A { int x; /* open").message.contains("unterminated comment"));
+	}
+
+	#[test]
+	fn string_literals() {
+		assert_eq!(string_literal_value("r#\"a \"b\" c\"#").as_deref(), Some("a \"b\" c"));
+		assert_eq!(string_literal_value("r\"x\"").as_deref(), Some("x"));
+		assert_eq!(string_literal_value("\"a\\nb\\\"c\"").as_deref(), Some("a\nb\"c"));
+	}
+
+	#[test]
+	fn derive_order() {
+		use DerivedTrait::*;
+		let mut derived = vec![UpperHex, Debug, Clone, Copy, Clone, Default, LowerHex, Debug];
+		canonical_derives(&mut derived);
+		assert_eq!(derived, [Clone, Copy, Debug, Default, LowerHex, UpperHex]);
+	}
+
+	#[test]
+	fn int_literals() {
+		assert_eq!(int_literal("2_147_483_648"), Some(1 << 31));
+		assert_eq!(int_literal("0x8000_0000usize"), Some(1 << 31));
+		assert_eq!(int_literal("0b101"), Some(5));
+		assert_eq!(int_literal("16u32"), Some(16));
+		assert_eq!(int_literal("340282366920938463463374607431768211456"), None);
+		assert_eq!(int_literal("1.5"), None);
+		assert_eq!(int_literal("\"16\""), None);
+	}
+
+	#[test]
+	fn accessor_affixes() {
+		assert!(is_accessor_affix("_raw", false));
+		assert!(is_accessor_affix("2", false));
+		assert!(is_accessor_affix("raw_", true));
+		assert!(!is_accessor_affix("2_", true));
+		assert!(!is_accessor_affix("", false));
+		assert!(!is_accessor_affix("-raw", false));
+	}
+
+	#[test]
+	fn doc_cfg_attrs() {
+		assert_eq!(doc_cfg_attr("feature = \"layouts\""), "#[cfg_attr(docsrs, doc(cfg(feature = \"layouts\")))]");
+		assert_eq!(doc_cfg_attr("any(unix, windows)"), "#[cfg_attr(docsrs, doc(cfg(any(unix, windows))))]");
+	}
+	#[test]
+	fn fnv1a_hashes() {
+		assert_eq!(fnv1a(FNV_OFFSET_BASIS, b""), 0xcbf29ce484222325);
+		assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"a"), 0xaf63dc4c8601ec8c);
+		assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"foobar"), 0x85944171f73967e8);
+		assert_eq!(fnv1a(fnv1a(FNV_OFFSET_BASIS, b"foo"), b"bar"), fnv1a(FNV_OFFSET_BASIS, b"foobar"));
+	}
+
+	#[test]
+	fn markdown_tables() {
+		assert_eq!(markdown_code("[u8; 4]"), "`[u8; 4]`");
+		assert_eq!(markdown_code("Or<A | B>"), "`Or<A \\| B>`");
+		let rows = vec![
+			vec![markdown_code("health"), markdown_code("0x0"), String::from("4"), markdown_code("i32"), String::from("get set"), String::from("native")],
+			vec![markdown_code("name"), markdown_code("0x4"), String::from("8"), markdown_code("[u8; 8]"), String::from("get"), String::from("n/a")],
+		];
+		assert_eq!(markdown_table(&["Field", "Offset", "Size", "Type", "Accessors", "Endianness"], &rows), [
+			"| Field | Offset | Size | Type | Accessors | Endianness |",
+			"|---|---|---|---|---|---|",
+			"| `health` | `0x0` | 4 | `i32` | get set | native |",
+			"| `name` | `0x4` | 8 | `[u8; 8]` | get | n/a |",
+		]);
+	}
+}
@@ -0,0 +1,35 @@
+/*!
+Checksum algorithms used by the `checksum` field argument.
+ */
+
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+	let mut table = [0u32; 256];
+	let mut i = 0;
+	while i < 256 {
+		let mut crc = i as u32;
+		let mut k = 0;
+		while k < 8 {
+			crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB88320 } else { crc >> 1 };
+			k += 1;
+		}
+		table[i] = crc;
+		i += 1;
+	}
+	table
+}
+
+/// CRC-32 (IEEE 802.3) of the bytes.
+pub fn crc32(bytes: &[u8]) -> u32 {
+	let mut crc = !0u32;
+	for &byte in bytes {
+		crc = (crc >> 8) ^ CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize];
+	}
+	!crc
+}
+
+/// Wrapping sum of the bytes.
+pub fn sum(bytes: &[u8]) -> u64 {
+	bytes.iter().fold(0u64, |sum, &byte| sum.wrapping_add(byte as u64))
+}
@@ -0,0 +1,62 @@
+/*!
+Typed handles to the fields of an explicit structure.
+ */
+
+use core::{fmt, marker::PhantomData, mem, ptr};
+
+/// Handle to a field of type `T` in the structure `S`.
+///
+/// Generated by the `handles` argument as an associated constant for every field, eg. `Player::HEALTH`.
+/// Handles are plain values which can be stored in arrays to select fields at runtime.
+pub struct Field<S, T> {
+	offset: usize,
+	marker: PhantomData<fn(&S) -> T>,
+}
+
+impl<S, T> Field<S, T> {
+	/// Creates a handle to the field at the given offset.
+	///
+	/// # Safety
+	///
+	/// The handle reads and writes the field without further checks.
+	/// The field must be in bounds of the structure and its bytes must be a valid `T` whenever it is read.
+	/// The `explicit` attribute guarantees this for the handles it generates, a handle with a bogus offset reads out of bounds or creates invalid values.
+	pub const unsafe fn new(offset: usize) -> Field<S, T> {
+		Field { offset, marker: PhantomData }
+	}
+	/// Returns the offset of the field in bytes.
+	pub const fn offset(self) -> usize {
+		self.offset
+	}
+}
+
+impl<S, T: Copy> Field<S, T> {
+	/// Reads the field, the field does not need to be aligned.
+	#[inline]
+	pub fn get(self, s: &S) -> T {
+		unsafe { ptr::read_unaligned((s as *const S as *const u8).add(self.offset) as *const T) }
+	}
+	/// Writes the field, the field does not need to be aligned.
+	#[inline]
+	pub fn set(self, s: &mut S, value: T) {
+		unsafe { ptr::write_unaligned((s as *mut S as *mut u8).add(self.offset) as *mut T, value) }
+	}
+}
+
+impl<S, T> Copy for Field<S, T> {}
+impl<S, T> Clone for Field<S, T> {
+	fn clone(&self) -> Field<S, T> {
+		*self
+	}
+}
+impl<S, T> PartialEq for Field<S, T> {
+	fn eq(&self, other: &Field<S, T>) -> bool {
+		self.offset == other.offset
+	}
+}
+impl<S, T> Eq for Field<S, T> {}
+impl<S, T> fmt::Debug for Field<S, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Field<{}>({:#x}, {} bytes)", core::any::type_name::<S>(), self.offset, mem::size_of::<T>())
+	}
+}
@@ -13,7 +13,7 @@ struct Foo {
 	field: i32,
 }
 
-let mut foo: Foo = unsafe { std::mem::zeroed() };
+let mut foo = Foo::zeroed();
 
 foo.set_field(13);
 assert_eq!(foo.field(), 13);
@@ -42,6 +42,35 @@ foo.set_field('a');
 assert_eq!(format!("{:?}", foo), "Foo { field: 'a' }");
 ```
 
+## Nested structures
+
+Explicit structures can be nested in other explicit structures with the `nested` flag.
+The field offset is checked against the size and alignment of the nested structure.
+The outer structure only has a safe `zeroed` if the nested structure is checked to be `Pod`.
+
+```
+#[struct_layout::explicit(size = 8, align = 4, check(struct_layout::Pod, impl))]
+#[derive(Copy, Clone)]
+struct Header {
+	#[field(offset = 0)]
+	magic: u32,
+	#[field(offset = 4)]
+	version: u16,
+}
+
+#[struct_layout::explicit(size = 32, align = 8, check(struct_layout::Pod))]
+struct Record {
+	#[field(offset = 8, nested)]
+	header: Header,
+	#[field(offset = 16)]
+	value: f64,
+}
+
+let mut record = Record::zeroed();
+record.header_mut().set_magic(0x4c524543);
+assert_eq!(record.header().magic(), 0x4c524543);
+```
+
 ## Check argument to ensure safety
 
 The check attribute requires that all fields meet this trait bound.
@@ -70,729 +99,307 @@ struct Foo {
 }
 ```
 
- */
+## Reading from bytes
 
-use std::vec;
+With the `dataview` argument the structure can be read from a byte buffer with the [dataview](https://crates.io/crates/dataview) crate.
 
-extern crate proc_macro;
-use proc_macro::*;
+```
+#[struct_layout::explicit(size = 8, align = 4, check(dataview::Pod), dataview)]
+pub struct Foo {
+	#[field(offset = 4)]
+	pub int: i32,
+}
 
-//----------------------------------------------------------------
-// Definitions
+let bytes = [0u8, 0, 0, 0, 42, 0, 0, 0];
+let view = dataview::DataView::from(&bytes[..]);
+let foo: Foo = view.read(0);
+assert_eq!(foo.int(), i32::from_le(42));
+```
 
-#[derive(Clone, Debug)]
-struct KeyValue {
-	ident: Ident,
-	punct: Punct,
-	value: Expr,
-}
+ */
 
-#[derive(Clone, Debug)]
-struct ExplicitLayout {
-	size: Expr,
-	align: Expr,
-	check: Option<String>,
-}
+#![no_std]
+// The examples in the documentation are indented with tabs like the code
+#![allow(clippy::tabs_in_doc_comments)]
 
-#[derive(Clone, Debug)]
-struct FieldLayout {
-	offset: Expr,
-	method_get: bool,
-	method_set: bool,
-	method_ref: bool,
-	method_mut: bool,
-}
+use core::{fmt, str};
 
-#[derive(Clone, Debug)]
-struct Meta {
-	ident: Ident,
-	args: Group,
-}
+pub use struct_layout_derive::{check_layout, defaults, dispatch_by_size, explicit, from_c, implicit, union_layout, ExplicitLayout};
 
-#[derive(Clone, Debug)]
-struct Attribute {
-	punct: Punct,
-	meta: Group,
-}
+#[doc(hidden)]
+pub use struct_layout_derive::{__diagnostics, __expand};
 
-#[derive(Copy, Clone, Debug)]
-enum DerivedTrait {
-	Copy, Clone, Debug, Default
-}
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub extern crate alloc as __alloc;
 
-#[derive(Clone, Debug)]
-struct Vis(Vec<TokenTree>);
-
-#[derive(Clone, Debug)]
-struct Structure {
-	attrs: Vec<Attribute>,
-	derived: Vec<DerivedTrait>,
-	layout: ExplicitLayout,
-	vis: Vis,
-	stru: Ident,
-	name: Ident,
-	fields: Vec<Field>,
-}
+mod field;
+pub use self::field::Field;
 
-#[derive(Clone, Debug)]
-struct Type(Vec<TokenTree>);
-#[derive(Clone, Debug)]
-struct Expr(TokenStream);
-
-#[derive(Clone, Debug)]
-struct Field {
-	attrs: Vec<Attribute>,
-	layout: FieldLayout,
-	vis: Vis,
-	name: Ident,
-	ty: Type,
-}
+mod remote;
+pub use self::remote::{MemoryRead, MemoryWrite};
 
-//----------------------------------------------------------------
-// Lookahead queries
+mod visit;
+pub use self::visit::{FieldVisitor, FieldVisitorMut};
 
-fn is_ident(tokens: &[TokenTree]) -> bool {
-	match tokens.first() {
-		Some(TokenTree::Ident(_)) => true,
-		_ => false,
-	}
-}
-fn is_keyword(tokens: &[TokenTree], name: &str) -> bool {
-	match tokens.first() {
-		Some(TokenTree::Ident(ident)) => ident.to_string() == name,
-		_ => false,
-	}
-}
-fn is_punct(tokens: &[TokenTree], chr: char) -> bool {
-	match tokens.first() {
-		Some(TokenTree::Punct(punct)) => punct.as_char() == chr,
-		_ => false,
-	}
-}
-fn is_group(tokens: &[TokenTree], delim: Delimiter) -> bool {
-	match tokens.first() {
-		Some(TokenTree::Group(group)) => group.delimiter() == delim,
-		_ => false,
-	}
-}
-fn is_end(tokens: &[TokenTree]) -> bool {
-	tokens.len() == 0
-}
+mod unaligned;
+pub use self::unaligned::{Unaligned, UnalignedMut};
+
+pub mod checksum;
 
 //----------------------------------------------------------------
-// Parser elements
 
-fn parse_punct(tokens: &mut vec::IntoIter<TokenTree>, punct: char) -> Option<Punct> {
-	if !is_punct(tokens.as_slice(), punct) {
-		return None;
-	}
-	match tokens.next() {
-		Some(TokenTree::Punct(punct)) => Some(punct),
-		_ => unreachable!(),
-	}
+/// Structures with an explicit layout.
+///
+/// Implemented by the `explicit` attribute for every generated structure.
+///
+/// # Safety
+///
+/// The constants must match the size and alignment of the structure.
+pub unsafe trait ExplicitLayout {
+	/// Size of the structure in bytes.
+	const SIZE: usize;
+	/// Alignment of the structure in bytes.
+	const ALIGN: usize;
 }
-fn parse_ident(tokens: &mut vec::IntoIter<TokenTree>) -> Option<Ident> {
-	match tokens.next() {
-		Some(TokenTree::Ident(ident)) => Some(ident),
-		_ => None,
-	}
+
+/// Types without interior mutability.
+///
+/// The `freeze` argument requires the field types to implement this trait.
+/// Implemented for the primitive types, arrays, `PhantomData` and every generated structure.
+///
+/// # Safety
+///
+/// The type must not contain an `UnsafeCell`, eg. through `Cell`, `RefCell` or atomics.
+pub unsafe trait Freeze {}
+
+macro_rules! impl_freeze {
+	($($ty:ty),*) => { $(unsafe impl Freeze for $ty {})* };
 }
-fn parse_keyword(tokens: &mut vec::IntoIter<TokenTree>, keyword: &str) -> Option<Ident> {
-	if !is_keyword(tokens.as_slice(), keyword) {
-		return None;
-	}
-	match tokens.next() {
-		Some(TokenTree::Ident(ident)) => Some(ident),
-		_ => unreachable!(),
-	}
+impl_freeze!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, ());
+unsafe impl<T: Freeze, const N: usize> Freeze for [T; N] {}
+unsafe impl<T: ?Sized> Freeze for core::marker::PhantomData<T> {}
+
+/// Types for which any bytes are a valid value.
+///
+/// The generated `from_bytes`, `read_from` and `as_bytes_mut` are safe if every field is an integer, a float or an array of these,
+/// or if the structure declares `check(struct_layout::Pod)` which asserts the trait for every field, eg. nested structures.
+/// Use `check(struct_layout::Pod, impl)` to also implement the trait for the structure itself.
+///
+/// # Safety
+///
+/// Every bit pattern of the size of the type must be a valid value, the type must not have drop glue.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+	($($ty:ty),*) => { $(unsafe impl Pod for $ty {})* };
 }
-fn parse_group(tokens: &mut vec::IntoIter<TokenTree>, delim: Delimiter) -> Option<Group> {
-	if !is_group(tokens.as_slice(), delim) {
-		return None;
-	}
-	match tokens.next() {
-		Some(TokenTree::Group(group)) => Some(group),
-		_ => unreachable!(),
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, ());
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+unsafe impl<T: ?Sized + 'static> Pod for core::marker::PhantomData<T> {}
+
+/// Assigns the offsets of an implicit layout.
+///
+/// Every field is described by its size, alignment, padding before the field and minimum alignment (zero if absent).
+/// Returns the offsets of the fields and the size of the structure rounded up to its alignment.
+#[doc(hidden)]
+pub const fn __implicit_layout<const N: usize>(fields: [(usize, usize, usize, usize); N], packed: bool, align: usize) -> ([usize; N], usize) {
+	let mut offsets = [0; N];
+	let mut cursor: usize = 0;
+	let mut i = 0;
+	while i < N {
+		let (size, field_align, pad_before, align_to) = fields[i];
+		let mut field_align = if packed { 1 } else { field_align };
+		if align_to > field_align {
+			field_align = align_to;
+		}
+		cursor = round_up(checked(cursor.checked_add(pad_before)), field_align);
+		offsets[i] = cursor;
+		cursor = checked(cursor.checked_add(size));
+		i += 1;
 	}
+	(offsets, round_up(cursor, align))
 }
-fn parse_comma(tokens: &mut vec::IntoIter<TokenTree>) -> Option<()> {
-	if is_end(tokens.as_slice()) {
-		Some(())
-	}
-	else if is_punct(tokens.as_slice(), ',') {
-		let _ = tokens.next();
-		Some(())
-	}
-	else {
-		None
-	}
+const fn round_up(value: usize, align: usize) -> usize {
+	checked(value.div_ceil(align).checked_mul(align))
 }
-fn parse_end(tokens: &mut vec::IntoIter<TokenTree>) -> Option<()> {
-	if tokens.len() != 0 {
-		return None;
+const fn checked(value: Option<usize>) -> usize {
+	match value {
+		Some(value) => value,
+		None => panic!("struct_layout: the implicit layout overflows"),
 	}
-	Some(())
 }
-// $ident $group
-fn parse_meta(tokens: &mut vec::IntoIter<TokenTree>) -> Option<Meta> {
-	let slice = tokens.as_slice();
-	if !(is_ident(&slice[0..]) && is_group(&slice[1..], Delimiter::Parenthesis)) {
-		return None;
+
+/// End of `count` elements of `size` bytes `stride` apart starting at `offset`.
+///
+/// Returns `None` if it overflows.
+#[doc(hidden)]
+pub const fn __extent_end(offset: usize, size: usize, count: usize, stride: usize) -> Option<usize> {
+	if count == 0 {
+		return Some(offset);
 	}
-	let ident = match tokens.next() {
-		Some(TokenTree::Ident(ident)) => ident,
-		_ => unreachable!(),
-	};
-	let args = match tokens.next() {
-		Some(TokenTree::Group(group)) => group,
-		_ => unreachable!(),
-	};
-	Some(Meta { ident, args })
-}
-// $ident = $expr ,
-fn parse_kv(tokens: &mut vec::IntoIter<TokenTree>) -> Option<KeyValue> {
-	let slice = tokens.as_slice();
-	if !(is_ident(&slice[0..]) && is_punct(&slice[1..], '=')) {
-		return None;
+	match (count - 1).checked_mul(stride) {
+		Some(last) => match offset.checked_add(last) {
+			Some(start) => start.checked_add(size),
+			None => None,
+		},
+		None => None,
 	}
-	let ident = match tokens.next() {
-		Some(TokenTree::Ident(ident)) => ident,
-		_ => unreachable!(),
-	};
-	let punct = match tokens.next() {
-		Some(TokenTree::Punct(punct)) => punct,
-		_ => unreachable!(),
-	};
-	let value = parse_expr(tokens);
-	Some(KeyValue { ident, punct, value })
 }
-// # $group
-fn parse_attr(tokens: &mut vec::IntoIter<TokenTree>) -> Option<Attribute> {
-	if !is_punct(tokens.as_slice(), '#') {
-		return None;
-	}
-	let punct = match tokens.next() {
-		Some(TokenTree::Punct(punct)) => punct,
-		_ => unreachable!(),
-	};
-	let meta = match tokens.next() {
-		Some(TokenTree::Group(group)) => group,
-		_ => unreachable!(),
+
+/// Whether any element of two extents as in [`__extent_end`] share a byte.
+///
+/// Extents which overflow do not overlap, they are reported by the asserts of their field.
+#[doc(hidden)]
+pub const fn __extents_overlap(a: (usize, usize, usize, usize), b: (usize, usize, usize, usize)) -> bool {
+	let (a_end, b_end) = match (__extent_end(a.0, a.1, a.2, a.3), __extent_end(b.0, b.1, b.2, b.3)) {
+		(Some(a_end), Some(b_end)) => (a_end, b_end),
+		_ => return false,
 	};
-	Some(Attribute { punct, meta })
-}
-// $(# $group)*
-fn parse_attrs(tokens: &mut vec::IntoIter<TokenTree>) -> Vec<Attribute> {
-	let mut attrs = Vec::new();
-	while let Some(attr) = parse_attr(tokens) {
-		attrs.push(attr);
+	if a.1 == 0 || b.1 == 0 || a.2 == 0 || b.2 == 0 || a_end <= b.0 || b_end <= a.0 {
+		return false;
 	}
-	attrs
-}
-// $(pub $($group)?)?
-fn parse_vis(tokens: &mut vec::IntoIter<TokenTree>) -> Vis {
-	let mut vis = Vec::new();
-	if is_keyword(tokens.as_slice(), "pub") {
-		vis.push(tokens.next().unwrap());
-		if is_group(tokens.as_slice(), Delimiter::Brace) {
-			vis.push(tokens.next().unwrap());
-		}
+	// Without gaps between the elements the extents overlap as a whole
+	if a.3 <= a.1 && b.3 <= b.1 {
+		return true;
 	}
-	Vis(vis)
-}
-// $($tt)*,
-fn parse_ty(tokens: &mut vec::IntoIter<TokenTree>) -> Type {
-	let mut ty = Vec::new();
-	let mut depth = 0;
-	loop {
-		match tokens.next() {
-			Some(TokenTree::Punct(punct)) => {
-				match punct.as_char() {
-					',' | ';' if depth == 0 => break,
-					'<' => depth += 1,
-					'>' => depth -= 1,
-					_ => (),
-				}
-				ty.push(TokenTree::Punct(punct));
-			},
-			Some(tt) => ty.push(tt),
-			None => break,
+	let mut i = 0;
+	while i < a.2 {
+		let start = a.0 + i * a.3;
+		let mut j = 0;
+		while j < b.2 {
+			let other = b.0 + j * b.3;
+			if start < other + b.1 && other < start + a.1 {
+				return true;
+			}
+			j += 1;
 		}
+		i += 1;
 	}
-	Type(ty)
+	false
 }
-// $($tt)*,
-fn parse_expr(tokens: &mut vec::IntoIter<TokenTree>) -> Expr {
-	let mut expr = Vec::new();
-	loop {
-		match tokens.next() {
-			Some(TokenTree::Punct(punct)) => {
-				if let ',' | ';' = punct.as_char() {
-					break;
-				}
-				expr.push(TokenTree::Punct(punct));
-			},
-			Some(tt) => expr.push(tt),
-			None => break,
+
+/// Prints bytes as a string of hex digits, for fields declared with `debug = hex_bytes`.
+#[doc(hidden)]
+pub struct __HexBytes<'a>(pub &'a [u8]);
+
+impl<'a> fmt::Debug for __HexBytes<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for byte in self.0 {
+			write!(f, "{:02x}", byte)?;
 		}
+		Ok(())
 	}
-	Expr(expr.into_iter().collect())
 }
 
-//----------------------------------------------------------------
-// Parse struct layout attribute
-
-fn parse_explicit_layout(tokens: TokenStream) -> ExplicitLayout {
-	let tokens: Vec<TokenTree> = tokens.into_iter().collect();
-	let mut tokens = tokens.into_iter();
-	let size = parse_layout_size(&mut tokens);
-	let align = parse_layout_align(&mut tokens);
-	let check = parse_layout_check(&mut tokens);
-	parse_layout_end(&mut tokens);
-	ExplicitLayout { size, align, check }
-}
-fn parse_layout_size(tokens: &mut vec::IntoIter<TokenTree>) -> Expr {
-	let size = match parse_kv(tokens) {
-		Some(kv) => {
-			if kv.ident.to_string() == "size" { kv.value }
-			else { panic!("parse struct_layout: invalid format for size argument, expecting `size = <usize>`") }
-		},
-		None => panic!("parse struct_layout: invalid format for size argument, expecting `size = <usize>`"),
-	};
-	size
-}
-fn parse_layout_align(tokens: &mut vec::IntoIter<TokenTree>) -> Expr {
-	let align = match parse_kv(tokens) {
-		Some(kv) => {
-			if kv.ident.to_string() == "align" { kv.value }
-			else { panic!("parse struct_layout: invalid format for align argument, expecting `align = <usize>`") }
-		},
-		None => panic!("parse struct_layout: invalid format for align argument, expecting `align = <usize>`"),
-	};
-	align
-}
-fn parse_layout_check(tokens: &mut vec::IntoIter<TokenTree>) -> Option<String> {
-	let meta = parse_meta(tokens)?;
-	if let None = parse_comma(tokens) {
-		panic!("parse struct_layout: invalid format for check argument, expecting `check(PodTrait..)`");
-	}
-	if meta.ident.to_string() != "check" {
-		panic!("parse struct_layout: invalid format for check argument, expecting `check(PodTrait..)`");
-	}
-	Some(meta.args.stream().to_string())
-}
-fn parse_layout_end(tokens: &mut vec::IntoIter<TokenTree>) {
-	if let None = parse_end(tokens) {
-		panic!("parse struct_layout: unexpected additional tokens found")
-	}
-}
+/// Error returned when a value does not fit in the field.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct TooLong;
 
-//----------------------------------------------------------------
-// Parse struct fields
-
-fn parse_fields(tokens: TokenStream) -> Vec<Field> {
-	let tokens: Vec<TokenTree> = tokens.into_iter().collect();
-	let mut tokens = tokens.into_iter();
-	let mut fields = Vec::new();
-	while tokens.len() > 0 {
-		fields.push(parse_field(&mut tokens));
-	}
-	fields
-}
-fn parse_field(tokens: &mut vec::IntoIter<TokenTree>) -> Field {
-	let mut attrs = parse_attrs(tokens);
-	let layout = match parse_field_attrs(&mut attrs) {
-		Some(layout) => layout,
-		None => panic!("parse field: every field must have a `#[field(..)]` attribute"),
-	};
-	let vis = parse_vis(tokens);
-	let name = match parse_ident(tokens) {
-		Some(ident) => ident,
-		None => panic!("parse field: expecting field identifier not found"),
-	};
-	if let None = parse_punct(tokens, ':') {
-		panic!("parse field: colon must follow field identifier");
+impl fmt::Display for TooLong {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("value too long for the field")
 	}
-	let ty = parse_ty(tokens);
-	Field { attrs, layout, vis, name, ty }
-}
-fn parse_field_attrs(attrs: &mut Vec<Attribute>) -> Option<FieldLayout> {
-	let mut result = None;
-	attrs.retain(|attr| {
-		let tokens: Vec<TokenTree> = attr.meta.stream().into_iter().collect();
-		let mut tokens = tokens.into_iter();
-		match tokens.as_slice().first() {
-			Some(TokenTree::Ident(ident)) => {
-				match &*ident.to_string() {
-					"field" => {
-						let meta = match parse_meta(&mut tokens) {
-							Some(meta) => meta,
-							None => panic!("parse field: invalid field attribute syntax, expecting `#[field(..)]`"),
-						};
-						if let None = parse_end(&mut tokens) {
-							panic!("parse field: found extra tokens after field attribute");
-						}
-						let tokens: Vec<TokenTree> = meta.args.stream().into_iter().collect();
-						let mut tokens = tokens.into_iter();
-						result = Some(parse_field_layout(&mut tokens));
-						false
-					},
-					"doc" => true,
-					s => panic!("parse field: unsupported attribute `{}`", s),
-				}
-			},
-			Some(_) => panic!("parse field: unknown attribute syntax"),
-			None => false,
-		}
-	});
-	result
 }
-fn parse_field_layout(tokens: &mut vec::IntoIter<TokenTree>) -> FieldLayout {
-	let offset = match parse_kv(tokens) {
-		Some(kv) => {
-			if kv.ident.to_string() == "offset" { kv.value }
-			else { panic!("parse field_layout: invalid format for offset argument, expecting `offset = <usize>`") }
-		},
-		None => panic!("parse field_layout: invalid format for offset argument, expecting `offset = <usize>`"),
-	};
-	let mut method_get = false;
-	let mut method_set = false;
-	let mut method_ref = false;
-	let mut method_mut = false;
-	while tokens.len() > 0 {
-		let ident = match parse_ident(tokens) {
-			Some(ident) => ident,
-			None => panic!("parse field_layout: expecting an identifier"),
-		};
-		let method = ident.to_string();
-		match &*method {
-			"get" => method_get = true,
-			"set" => method_set = true,
-			"ref" => method_ref = true,
-			"mut" => method_mut = true,
-			_ => panic!("parse field_layout: expecting an identifier of `get`, `set`, `ref` or `mut`"),
-		}
-		if let None = parse_comma(tokens) {
-			panic!("parse field_layout: expecting comma after {}", method);
-		}
-	}
-	// If no methods are specified, enable all of them
-	if !method_get && !method_set && !method_ref && !method_mut {
-		method_get = true;
-		method_set = true;
-		method_ref = true;
-		method_mut = true;
+
+/// Error returned when the validate function of the field rejects a value.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct InvalidValue;
+
+impl fmt::Display for InvalidValue {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("invalid value for the field")
 	}
-	FieldLayout { offset, method_get, method_set, method_ref, method_mut }
 }
 
-//----------------------------------------------------------------
-// Parse structure
-
-fn parse_structure(tokens: TokenStream, layout: ExplicitLayout) -> Structure {
-	let tokens: Vec<TokenTree> = tokens.into_iter().collect();
-	let mut tokens = tokens.into_iter();
-	let mut attrs = parse_attrs(&mut tokens);
-	let derived = parse_structure_attrs(&mut attrs);
-	let vis = parse_vis(&mut tokens);
-	let stru = match parse_keyword(&mut tokens, "struct") {
-		Some(ident) => ident,
-		None => panic!("parse struct: struct layout is only allowed on structs"),
-	};
-	let name = match parse_ident(&mut tokens) {
-		Some(ident) => ident,
-		None => panic!("parse struct: struct name identifier not found"),
-	};
-	if is_punct(tokens.as_slice(), '<') {
-		panic!("parse struct: generic parameters not supported");
-	}
-	if is_keyword(tokens.as_slice(), "where") {
-		panic!("parse struct: where clause not supported");
+/// Outcome of a validate function.
+///
+/// Validate functions return either `bool` or `Result<(), E>`.
+pub trait Validity {
+	/// Returns true if the value was accepted.
+	fn accepted(self) -> bool;
+}
+impl Validity for bool {
+	#[inline]
+	fn accepted(self) -> bool {
+		self
 	}
-	let group = match parse_group(&mut tokens, Delimiter::Brace) {
-		Some(group) => group,
-		None => panic!("parse struct: tuple syntax not supported, struct layout requires {{}} to declare the fields"),
-	};
-	let fields = parse_fields(group.stream());
-	Structure { attrs, derived, layout, vis, stru, name, fields }
-}
-fn parse_structure_attrs(attrs: &mut Vec<Attribute>) -> Vec<DerivedTrait> {
-	let mut result = Vec::new();
-	attrs.retain(|attr| {
-		let tokens: Vec<TokenTree> = attr.meta.stream().into_iter().collect();
-		let mut tokens = tokens.into_iter();
-		match tokens.as_slice().first() {
-			Some(TokenTree::Ident(ident)) => {
-				match &*ident.to_string() {
-					"derive" => {
-						let meta = match parse_meta(&mut tokens) {
-							Some(meta) => meta,
-							None => panic!("parse struct: invalid derive syntax, expecting `#[derive(..)]`"),
-						};
-						if let None = parse_end(&mut tokens) {
-							panic!("parse struct: found extra tokens after derive attribute");
-						}
-						let tokens: Vec<TokenTree> = meta.args.stream().into_iter().collect();
-						let mut tokens = tokens.into_iter();
-						parse_structure_derive(&mut tokens, &mut result);
-						false
-					},
-					"doc" => true,
-					s => panic!("parse struct: unsupported attribute `{}`", s),
-				}
-			},
-			Some(_) => panic!("parse struct: unknown attribute syntax"),
-			None => false,
-		}
-	});
-	result
 }
-fn parse_structure_derive(tokens: &mut vec::IntoIter<TokenTree>, derived: &mut Vec<DerivedTrait>) {
-	while tokens.len() > 0 {
-		let ident = match parse_ident(tokens) {
-			Some(ident) => ident,
-			None => panic!("derive attribute: expecting list of comma separated identifiers"),
-		};
-		let tr = ident.to_string();
-		match &*tr {
-			"Copy" => derived.push(DerivedTrait::Copy),
-			"Clone" => derived.push(DerivedTrait::Clone),
-			"Debug" => derived.push(DerivedTrait::Debug),
-			"Default" => derived.push(DerivedTrait::Default),
-			s => panic!("derive attribute: unsupported trait: `{}`", s),
-		}
-		if let None = parse_comma(tokens) {
-			panic!("derive attribute: expecting comma after {}", tr);
-		}
+impl<E> Validity for Result<(), E> {
+	#[inline]
+	fn accepted(self) -> bool {
+		self.is_ok()
 	}
 }
 
-//----------------------------------------------------------------
+/// Error returned when a string field does not contain a valid string.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StrError {
+	/// The stored length exceeds the capacity of the field.
+	Length(usize),
+	/// The string is not valid UTF-8.
+	Utf8(str::Utf8Error),
+}
 
-/// Explicit field layout attribute.
-///
-/// For more information, see the crate-level documentation.
-#[proc_macro_attribute]
-pub fn explicit(attributes: TokenStream, input: TokenStream) -> TokenStream {
-	let layout = parse_explicit_layout(attributes);
-	let stru = parse_structure(input, layout);
-	// Emit the code
-	let mut code: Vec<TokenTree> = Vec::new();
-	emit_attrs(&mut code, &stru.attrs);
-	emit_text(&mut code, &format!("#[repr(C, align({}))]", stru.layout.align.0));
-	emit_vis(&mut code, &stru.vis);
-	code.push(TokenTree::Ident(stru.stru.clone()));
-	code.push(TokenTree::Ident(stru.name.clone()));
-	emit_text(&mut code, &format!("([u8; {}]);", stru.layout.size.0));
-	emit_impl_f(&mut code, &stru.name, |body| {
-		for field in &stru.fields {
-			emit_field(body, &stru, field);
+impl fmt::Display for StrError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			StrError::Length(len) => write!(f, "stored length {} exceeds the capacity of the field", len),
+			StrError::Utf8(err) => err.fmt(f),
 		}
-	});
-	emit_derives(&mut code, &stru);
-	code.into_iter().collect()
+	}
 }
 
-//----------------------------------------------------------------
-// Emitters
-
-fn emit_punct(code: &mut Vec<TokenTree>, ch: char) {
-	code.push(TokenTree::Punct(Punct::new(ch, Spacing::Alone)));
-}
-fn emit_ident(code: &mut Vec<TokenTree>, name: &str) {
-	code.push(TokenTree::Ident(Ident::new(name, Span::call_site())));
-}
-fn emit_text(code: &mut Vec<TokenTree>, text: &str) {
-	let stream: TokenStream = text.parse().unwrap();
-	code.extend(stream);
-}
-fn emit_group_f(code: &mut Vec<TokenTree>, delim: Delimiter, f: impl FnOnce(&mut Vec<TokenTree>)) {
-	let mut tokens = Vec::new();
-	f(&mut tokens);
-	let stream: TokenStream = tokens.into_iter().collect();
-	code.push(TokenTree::Group(Group::new(delim, stream)));
+/// Error returned by the checked accessors of structures with `on_error = result`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AccessError {
+	/// The index is out of bounds of the elements of the field.
+	OutOfBounds { index: usize, len: usize },
+	/// The row or column is out of bounds of the dimensions of the field.
+	OutOfDims { row: usize, col: usize, rows: usize, cols: usize },
+	/// The bytes are not aligned for the structure.
+	Misaligned,
+	/// The length of the bytes is not a whole number of structures.
+	InvalidLength(usize),
 }
 
-fn emit_attrs(code: &mut Vec<TokenTree>, attrs: &[Attribute]) {
-	for attr in attrs {
-		code.push(TokenTree::Punct(attr.punct.clone()));
-		code.push(TokenTree::Group(attr.meta.clone()));
-	}
-}
-fn emit_vis(code: &mut Vec<TokenTree>, vis: &Vis) {
-	code.extend(vis.0.iter().cloned());
-}
-fn emit_ty(code: &mut Vec<TokenTree>, ty: &Type) {
-	code.extend(ty.0.iter().cloned());
-}
-fn emit_impl_f(code: &mut Vec<TokenTree>, ident: &Ident, f: impl FnOnce(&mut Vec<TokenTree>)) {
-	code.push(TokenTree::Ident(Ident::new("impl", Span::call_site())));
-	code.push(TokenTree::Ident(ident.clone()));
-	code.push(TokenTree::Group(Group::new(Delimiter::Brace, {
-		let mut tokens = Vec::new();
-		f(&mut tokens);
-		tokens.into_iter().collect()
-	})));
-}
-fn emit_trait_bounds(code: &mut Vec<TokenTree>, stru: &Structure, tr: &str) {
-	if stru.fields.len() > 0 {
-		emit_ident(code, "where");
-		let bound = format!(": {},", tr);
-		for field in &stru.fields {
-			emit_ty(code, &field.ty);
-			emit_text(code, &bound);
+impl fmt::Display for AccessError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			AccessError::OutOfBounds { index, len } => write!(f, "index out of bounds: the len is {} but the index is {}", len, index),
+			AccessError::OutOfDims { row, col, rows, cols } => write!(f, "index out of bounds: the dims are ({}, {}) but the index is ({}, {})", rows, cols, row, col),
+			AccessError::Misaligned => f.write_str("bytes are misaligned for the structure"),
+			AccessError::InvalidLength(len) => write!(f, "length {} is not a whole number of structures", len),
 		}
 	}
 }
-fn emit_trait_impl_f(code: &mut Vec<TokenTree>, stru: &Structure, tr: &str, f: impl FnOnce(&mut Vec<TokenTree>)) {
-	code.push(TokenTree::Ident(Ident::new("impl", Span::call_site())));
-	emit_text(code, tr);
-	code.push(TokenTree::Ident(Ident::new("for", Span::call_site())));
-	code.push(TokenTree::Ident(stru.name.clone()));
-	emit_trait_bounds(code, stru, tr);
-	code.push(TokenTree::Group(Group::new(Delimiter::Brace, {
-		let mut tokens = Vec::new();
-		f(&mut tokens);
-		tokens.into_iter().collect()
-	})));
-}
 
-fn emit_derive_copy(code: &mut Vec<TokenTree>, stru: &Structure) {
-	emit_trait_impl_f(code, stru, "Copy", |_| {});
-}
-fn emit_derive_clone(code: &mut Vec<TokenTree>, stru: &Structure) {
-	emit_trait_impl_f(code, stru, "Clone", |code| {
-		emit_text(code, "fn clone(&self) -> Self { *self }");
-	})
-}
-fn emit_derive_debug(code: &mut Vec<TokenTree>, stru: &Structure) {
-	emit_trait_impl_f(code, stru, "::core::fmt::Debug", |code| {
-		emit_text(code, "fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result");
-		emit_group_f(code, Delimiter::Brace, |code| {
-			emit_text(code, &format!("f.debug_struct(\"{}\")", &stru.name));
-			for field in &stru.fields {
-				if field.layout.method_ref {
-					emit_text(code, &format!(".field(\"{0}\", self.{0}_ref())", field.name));
-				}
-				else if field.layout.method_get {
-					emit_text(code, &format!(".field(\"{0}\", &self.{0}())", field.name));
-				}
-			}
-			emit_text(code, ".finish()");
-		});
-	});
-}
-fn emit_derive_default(code: &mut Vec<TokenTree>, stru: &Structure) {
-	emit_trait_impl_f(code, stru, "Default", |code| {
-		emit_text(code, "fn default() -> Self");
-		emit_group_f(code, Delimiter::Brace, |code| {
-			emit_text(code, "let mut instance: Self = unsafe { ::core::mem::zeroed() };");
-			for field in &stru.fields {
-				emit_text(code, &format!("instance.set_{}(Default::default());", field.name));
-			}
-			emit_text(code, "; instance");
-		});
-	});
+/// Error returned by `apply_patch` when the bytes do not patch exactly one field.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PatchError {
+	/// The bytes extend past the end of the structure.
+	OutOfBounds { offset: usize, len: usize },
+	/// The offset is not within any field.
+	Padding { offset: usize },
+	/// The bytes start within the field and extend past its end.
+	Straddle { field: &'static str, offset: usize, len: usize },
+	/// The bytes are within the field but do not cover all of it.
+	Size { field: &'static str, expected: usize, len: usize },
+	/// The bytes are not a valid value of the field.
+	Invalid { field: &'static str },
 }
-fn emit_derives(code: &mut Vec<TokenTree>, stru: &Structure) {
-	for derive in &stru.derived {
-		match derive {
-			DerivedTrait::Copy => emit_derive_copy(code, stru),
-			DerivedTrait::Clone => emit_derive_clone(code, stru),
-			DerivedTrait::Debug => emit_derive_debug(code, stru),
-			DerivedTrait::Default => emit_derive_default(code, stru),
+
+impl fmt::Display for PatchError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			PatchError::OutOfBounds { offset, len } => write!(f, "patch of {} bytes at offset {} is out of bounds", len, offset),
+			PatchError::Padding { offset } => write!(f, "offset {} is not within any field", offset),
+			PatchError::Straddle { field, offset, len } => write!(f, "patch of {} bytes at offset {} extends past the end of field `{}`", len, offset, field),
+			PatchError::Size { field, expected, len } => write!(f, "patch of {} bytes does not cover field `{}` of {} bytes", len, field, expected),
+			PatchError::Invalid { field } => write!(f, "invalid value for field `{}`", field),
 		}
 	}
 }
-fn emit_field(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
-	if field.layout.method_get {
-		emit_field_get(code, stru, field);
-	}
-	if field.layout.method_set {
-		emit_field_set(code, stru, field);
-	}
-	if field.layout.method_ref {
-		emit_field_ref(code, stru, field);
-	}
-	if field.layout.method_mut {
-		emit_field_mut(code, stru, field);
-	}
-}
-fn emit_field_get(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
-	emit_attrs(code, &field.attrs);
-	emit_vis(code, &field.vis);
-	emit_ident(code, "fn");
-	code.push(TokenTree::Ident(field.name.clone()));
-	emit_text(code, "(&self) -> ");
-	emit_ty(code, &field.ty);
-	emit_field_check(code, stru, field);
-	emit_group_f(code, Delimiter::Brace, |body| {
-		emit_text(body, &format!("const FIELD_OFFSET: usize = {};", field.layout.offset.0));
-		emit_text(body, "type FieldT = "); emit_ty(body, &field.ty);
-		emit_text(body, "; use ::core::{mem, ptr}; let _: [();
-			(FIELD_OFFSET + mem::size_of::<FieldT>() <= mem::size_of::<Self>()) as usize - 1];");
-		emit_text(body, "unsafe { ptr::read_unaligned((self as *const _ as *const u8).offset(FIELD_OFFSET as isize) as *const FieldT) }");
-	});
-}
-fn emit_field_set(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
-	emit_attrs(code, &field.attrs);
-	emit_vis(code, &field.vis);
-	emit_ident(code, "fn");
-	emit_ident(code, &format!("set_{}", field.name));
-	emit_group_f(code, Delimiter::Parenthesis, |params| {
-		emit_text(params, "&mut self, value: ");
-		emit_ty(params, &field.ty);
-	});
-	emit_text(code, " -> &mut Self");
-	emit_field_check(code, stru, field);
-	emit_group_f(code, Delimiter::Brace, |body| {
-		emit_text(body, &format!("const FIELD_OFFSET: usize = {};", field.layout.offset.0));
-		emit_text(body, "type FieldT = "); emit_ty(body, &field.ty);
-		emit_text(body, "; use ::core::{mem, ptr}; let _: [();
-			(FIELD_OFFSET + mem::size_of::<FieldT>() <= mem::size_of::<Self>()) as usize - 1];");
-		emit_text(body, "unsafe { ptr::write_unaligned((self as *mut _ as *mut u8).offset(FIELD_OFFSET as isize) as *mut FieldT, value); }");
-		emit_ident(body, "self");
-	})
-}
-fn emit_field_ref(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
-	emit_attrs(code, &field.attrs);
-	emit_vis(code, &field.vis);
-	emit_text(code, &format!("fn {}_ref(&self) -> &", field.name));
-	emit_ty(code, &field.ty);
-	emit_field_check(code, stru, field);
-	emit_group_f(code, Delimiter::Brace, |body| {
-		emit_text(body, &format!("const FIELD_OFFSET: usize = {};", field.layout.offset.0));
-		emit_text(body, "type FieldT = "); emit_ty(body, &field.ty);
-		emit_text(body, "; use ::core::mem; let _: [();
-			(FIELD_OFFSET + mem::size_of::<FieldT>() <= mem::size_of::<Self>() &&
-			FIELD_OFFSET % mem::align_of::<FieldT>() == 0 &&
-			mem::align_of::<FieldT>() % mem::align_of::<FieldT>() == 0) as usize - 1];");
-		emit_text(body, "unsafe { &*((self as *const _ as *const u8).offset(FIELD_OFFSET as isize) as *const FieldT) }");
-	});
-}
-fn emit_field_mut(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
-	emit_attrs(code, &field.attrs);
-	emit_vis(code, &field.vis);
-	emit_text(code, &format!("fn {}_mut(&mut self) -> &mut ", field.name));
-	emit_ty(code, &field.ty);
-	emit_field_check(code, stru, field);
-	emit_group_f(code, Delimiter::Brace, |body| {
-		emit_text(body, &format!("const FIELD_OFFSET: usize = {};", field.layout.offset.0));
-		emit_text(body, "type FieldT = "); emit_ty(body, &field.ty);
-		emit_text(body, "; use ::core::mem; let _: [();
-			(FIELD_OFFSET + mem::size_of::<FieldT>() <= mem::size_of::<Self>() &&
-			FIELD_OFFSET % mem::align_of::<FieldT>() == 0 &&
-			mem::align_of::<FieldT>() % mem::align_of::<FieldT>() == 0) as usize - 1];");
-		emit_text(body, "unsafe { &mut *((self as *mut _ as *mut u8).offset(FIELD_OFFSET as isize) as *mut FieldT) }");
-	});
-}
-fn emit_field_check(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field) {
-	let check = stru.layout.check.as_ref().map(std::ops::Deref::deref).unwrap_or("Copy + 'static");
-	emit_ident(code, "where");
-	emit_ty(code, &field.ty);
-	emit_punct(code, ':');
-	emit_text(code, check);
-}
 
 //----------------------------------------------------------------
 
@@ -869,5 +476,1146 @@ fn emit_field_check(code: &mut Vec<TokenTree>, stru: &Structure, field: &Field)
 /// ```
 ///
 /// Unsupported attributes.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, stored = u64)]
+/// 	field: f32,
+/// }
+/// ```
+///
+/// Stored type size does not match the field type.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 2, slice)]
+/// 	field: [u32; 2],
+/// }
+/// ```
+///
+/// Slice accessor on an unaligned array field.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 64, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, count = 4, stride = 6)]
+/// 	field: u32,
+/// }
+/// ```
+///
+/// Stride is not a multiple of the field alignment.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 4, count = 4, get)]
+/// 	field: u32,
+/// }
+/// ```
+///
+/// Last element out of bounds.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// #[derive(Copy, Clone)]
+/// struct Inner {
+/// 	#[field(offset = 0)]
+/// 	field: u32,
+/// }
+///
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 2, nested, get)]
+/// 	inner: Inner,
+/// }
+/// ```
+///
+/// Nested structure does not meet its alignment requirements.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 8)]
+/// struct Base {
+/// 	#[field(offset = 0)]
+/// 	field: u64,
+/// }
+///
+/// #[struct_layout::explicit(size = 32, align = 4, base(Base))]
+/// struct Foo {
+/// 	#[field(offset = 16)]
+/// 	field: u32,
+/// }
+/// ```
+///
+/// Alignment of the structure is smaller than the alignment of its base.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Old {
+/// 	#[field(offset = 0)]
+/// 	field: u32,
+/// }
+///
+/// #[struct_layout::explicit(size = 8, align = 4, copy_from(Old { field }))]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	field: f32,
+/// }
+/// ```
+///
+/// Field types do not match when copying fields from another structure.
+///
+/// ```compile_fail
+/// #[derive(struct_layout::ExplicitLayout)]
+/// #[layout(size = 8, align = 4)]
+/// #[repr(C)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u16,
+/// 	#[field(offset = 2)]
+/// 	b: u32,
+/// }
+/// ```
+///
+/// Offset of the regular structure does not match the annotation.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 0x200, align = 8, offsets = "tests/offsets/player.toml")]
+/// struct Foo {
+/// 	#[field(offset = extern)]
+/// 	mana: i32,
+/// }
+/// ```
+///
+/// Field not found in the offsets file.
+///
+/// ```compile_fail
+/// trait Pod {
+/// 	fn check(&self);
+/// }
+///
+/// #[struct_layout::explicit(size = 8, align = 4, check(Pod, impl))]
+/// struct Foo {}
+/// ```
+///
+/// The implemented check trait must be an unsafe marker trait.
+///
+/// ```compile_fail
+/// trait Any {}
+/// impl<T> Any for T {}
+///
+/// #[struct_layout::explicit(size = 32, align = 8, check(Any))]
+/// struct Foo {
+/// 	#[field(offset = 8)]
+/// 	name: String,
+/// }
+/// ```
+///
+/// Field type has drop glue.
+///
+/// ```compile_fail
+/// #[derive(Clone)]
+/// struct Name(u32);
+///
+/// unsafe trait Pod {}
+/// unsafe impl Pod for Name {}
+///
+/// #[struct_layout::explicit(size = 8, align = 4, check(Pod))]
+/// struct Foo {
+/// 	#[field(offset = 0, get)]
+/// 	name: Name,
+/// }
+/// ```
+///
+/// Accessing a field by value requires `Copy` regardless of the check trait.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 8)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	name: &'static str,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use std::borrow::Cow;
+///
+/// trait Any {}
+/// impl<T: ?Sized> Any for T {}
+///
+/// #[struct_layout::explicit(size = 32, align = 8, check(Any))]
+/// struct Foo {
+/// 	#[field(offset = 0, ref)]
+/// 	name: Cow<'static, str>,
+/// }
+/// ```
+///
+/// References and borrowed types cannot be stored in an explicit layout.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 8)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	addr: usize,
+/// }
+/// ```
+///
+/// The size of pointer sized fields must be asserted.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 8)]
+/// struct Foo {
+/// 	#[field(offset = 0, size = 2)]
+/// 	addr: usize,
+/// }
+/// ```
+///
+/// Field does not have the asserted size.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size(cfg(target_os = "none") = 16), align = 8)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	field: u32,
+/// }
+/// ```
+///
+/// None of the per target sizes match.
+///
+/// ```compile_fail
+/// unsafe trait Pod {}
+/// unsafe impl Pod for u32 {}
+///
+/// #[struct_layout::explicit(size = 8, align = 4, check(Pod), compact)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// 	#[field(offset = 4)]
+/// 	b: i32,
+/// }
+/// ```
+///
+/// The compact argument still checks every field.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 12, checksum(crc32, range = 0..20))]
+/// 	crc: u32,
+/// }
+/// ```
+///
+/// The checksum range is out of bounds.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4, parts)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// 	#[field(offset = 4, get)]
+/// 	b: u32,
+/// }
+/// ```
+///
+/// The parts argument requires every field to have get and set accessors.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4, byte_order)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// 	#[field(offset = 4)]
+/// 	b: [char; 1],
+/// }
+/// ```
+///
+/// The byte order argument requires fields of unknown types to be marked opaque.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Small {}
+///
+/// #[struct_layout::explicit(size = 16, align = 4, cast_to(Small))]
+/// struct Foo {}
+/// ```
+///
+/// The cast_to argument requires structures of the same size.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 8)]
+/// struct Strict {}
+///
+/// #[struct_layout::explicit(size = 16, align = 4, cast_to(Strict))]
+/// struct Foo {}
+/// ```
+///
+/// The cast_to argument requires the other structure to be aligned no stricter.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4, export_c(prefix = "foo_"))]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	bytes: [u8; 4],
+/// }
+/// ```
+///
+/// The export_c argument requires the exported fields to have FFI-safe types.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4, keep_fields)]
+/// #[repr(C)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u16,
+/// 	#[field(offset = 2)]
+/// 	b: u32,
+/// }
+/// ```
+///
+/// The keep_fields argument checks the annotated offsets against the actual field offsets.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4, keep_fields)]
+/// #[repr(C)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The keep_fields argument checks the size of the structure.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 64, align = 16)]
+/// struct Foo {
+/// 	#[field(offset = 8, align = 16)]
+/// 	simd: [f32; 4],
+/// }
+/// ```
+///
+/// The field is not aligned to its align argument.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 64, align = 16)]
+/// struct Foo {
+/// 	#[field(offset = 16, align = 2)]
+/// 	simd: [f32; 4],
+/// }
+/// ```
+///
+/// The align argument may not be smaller than the alignment of the field type.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 64, align = 8)]
+/// struct Foo {
+/// 	#[field(offset = 16, align = 16)]
+/// 	simd: [f32; 4],
+/// }
+/// ```
+///
+/// The align argument may not exceed the alignment of the structure.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = after)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The first field cannot be placed after a previous field.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = b + 4)]
+/// 	a: u32,
+/// 	#[field(offset = 0)]
+/// 	b: u32,
+/// }
+/// ```
+///
+/// Offsets can only refer to fields declared before.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, pad = 4, get)]
+/// 	_reserved: (),
+/// }
+/// ```
+///
+/// Pad regions do not have accessors.
+///
+/// ```compile_fail
+/// fn is_valid(value: u8) -> bool { value < 10 }
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, count = 4, validate = is_valid)]
+/// 	a: u8,
+/// }
+/// ```
+///
+/// The validate argument is only supported on fields of a single value.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, clamp = 100..=0)]
+/// 	a: i32,
+/// }
+/// ```
+///
+/// The clamp range may not be empty.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = optional(cfg(any()) = 0))]
+/// 	a: u32,
+/// }
+/// Foo([0; 8]).a();
+/// ```
+///
+/// Optional fields without a matching predicate do not have accessors.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 12, align = 4, storage = u64)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The size must be a multiple of the storage element.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 5, align = 1, packed)]
+/// struct Foo {
+/// 	#[field(offset = 1, get, ref)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// Packed layouts cannot hand out references to misaligned fields.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4, consts_mod = foo_layout)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// 	#[field(offset = 4)]
+/// 	A: u32,
+/// }
+/// ```
+///
+/// The constants of the fields may not collide.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4, chck(Copy))]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// Unknown arguments are rejected with a suggestion, here `check`.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4, fild_enum)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// Here the suggestion is `field_enum`.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, get, set(cfg(test)))]
+/// 	a: u32,
+/// }
+/// Foo([0; 4]).set_a(1);
+/// ```
+///
+/// Accessors with a cfg are absent if it does not match.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 4, count = usize::MAX / 2, stride = 4)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// Offsets of the elements which overflow are out of bounds.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = usize::MAX, get)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The end of the field may not overflow.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = isize::MAX as usize + 1, align = 1)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u8,
+/// }
+/// ```
+///
+/// The size may not exceed `isize::MAX`.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 9_223_372_036_854_775_808, align = 1, huge)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u8,
+/// }
+/// ```
+///
+/// Literal sizes are checked against `isize::MAX` of any target while expanding.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 16, repr = manual)]
+/// #[repr(C, align(8))]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The manual repr must agree with the alignment of the layout.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, bytes_array)]
+/// 	a: [u16; 2],
+/// }
+/// ```
+///
+/// The length of the bytes array is only known for primitive types unless the size is given.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 17)]
+/// 	a: (),
+/// }
+/// ```
+///
+/// Zero sized fields may be placed at the end of the structure but not past it.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, sentinel = 0, enum(u8))]
+/// 	a: u8,
+/// }
+/// ```
+///
+/// The sentinel cannot be combined with another conversion.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, get, ref)]
+/// 	a: bool,
+/// }
+/// ```
+///
+/// References to bool and char fields require the assume_valid argument.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// #[derive(Copy, Clone)]
+/// struct Inner {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// }
+/// #[struct_layout::explicit(size = 32, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 4, nested)]
+/// 	table: [Inner; 4],
+/// }
+/// ```
+///
+/// Every element of an array of nested structures must be in bounds.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Inner {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// }
+/// #[struct_layout::explicit(size = 32, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, nested)]
+/// 	table: [Inner; 4],
+/// }
+/// ```
+///
+/// Nested structures must implement the check trait, `Copy` by default.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// #[derive(Debug)]
+/// struct Foo {
+/// 	#[field(offset = 0, debug = hex_bytes)]
+/// 	a: [u16; 4],
+/// }
+/// ```
+///
+/// Only byte arrays can be printed as hex digits.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	#[group(b_ref)]
+/// 	a: u32,
+/// 	#[field(offset = 4)]
+/// 	b: u32,
+/// }
+/// ```
+///
+/// The accessors of a group may not collide with the accessors of a field.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// 	#[field(offset = 4)]
+/// 	set_a: u32,
+/// }
+/// ```
+///
+/// The setter of a field may not collide with the getter of another field.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4, bytes)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	from_bytes: u32,
+/// }
+/// ```
+///
+/// The accessors of a field may not collide with the methods of the structure.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4, on_error = ignore)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: [u32; 4],
+/// }
+/// ```
+///
+/// The error policy is one of `panic`, `option` or `result`.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, count = 2, column)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The column accessors only apply to fields of a single value.
+///
+/// ```compile_fail
+/// struct_layout::from_c! {
+/// 	"struct Foo { int32_t a; void *p; };"
+/// }
+/// ```
+///
+/// The C definitions may not contain pointers.
+///
+/// ```compile_fail
+/// unsafe trait Plain {}
+/// unsafe impl Plain for core::cell::Cell<u32> {}
+/// #[struct_layout::explicit(size = 8, align = 4, check(Plain), freeze)]
+/// struct Foo {
+/// 	#[field(offset = 0, ref)]
+/// 	a: core::cell::Cell<u32>,
+/// }
+/// ```
+///
+/// With `freeze` the field types may not have interior mutability.
+///
+/// ```compile_fail
+/// mod shapes {
+/// 	#[struct_layout::explicit(size = 4, align = 4, consts_mod = point_layout, companion_vis = pub(self))]
+/// 	pub struct Point {
+/// 		#[field(offset = 0, get, set)]
+/// 		pub x: i32,
+/// 	}
+/// }
+/// const X: usize = shapes::point_layout::X_OFFSET;
+/// ```
+///
+/// The `companion_vis` argument restricts the visibility of the generated constants.
+///
+/// ```compile_fail
+/// mod shapes {
+/// 	#[struct_layout::explicit(size = 4, align = 4, companion_vis = pub(self))]
+/// 	pub struct Point {
+/// 		#[field(offset = 0, get, set)]
+/// 		pub x: i32,
+/// 	}
+/// }
+/// const SIZE: usize = shapes::Point::SIZE;
+/// ```
+///
+/// Including the constants of the structure itself.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4, transparent)]
+/// struct Handle {
+/// 	#[field(offset = 0)]
+/// 	raw: u32,
+/// }
+/// ```
+///
+/// The only field of a `transparent` structure must span the whole size.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 2, get, atomic_ordered)]
+/// 	flag: u32,
+/// }
+/// ```
+///
+/// The `atomic_ordered` accessors require the field to be aligned for its atomic type.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, endian = both)]
+/// 	scale: f32,
+/// }
+/// ```
+///
+/// The `endian = both` accessors require an integer field.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4, slice_ext)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: i32,
+/// }
+/// let a = {
+/// 	let buffer = vec![0u8; 4];
+/// 	buffer.foo_a_ref()
+/// };
+/// ```
+///
+/// References into a byte slice cannot outlive the buffer.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// #[derive(Copy, Clone, Debug)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	kind: u8,
+/// 	#[field(offset = 4, debug_when(kind = 1))]
+/// 	value: u32,
+/// }
+/// ```
+///
+/// The field named by `debug_when` must be marked with `debug_tag`.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	a: u32,
+/// 	#[field(offset = 4, frobnicate)]
+/// 	b: u32,
+/// 	c: u32,
+/// }
+/// ```
+///
+/// Every field in error is reported, here the missing attributes of `a` and `c` and the unknown argument of `b`.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Ping {}
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Pong {}
+///
+/// let buf = [0u8; 8];
+/// struct_layout::dispatch_by_size!(buf, { Ping => |_| 1, Pong => |_| 2, _ => |_| 0 });
+/// ```
+///
+/// The arms of `dispatch_by_size!` must have distinct sizes.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4, consts_mod = foo_layout, doc_cfg())]
+/// struct Foo {
+/// 	#[field(offset = 0, get, set)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The `doc_cfg` argument requires a predicate.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4, method_suffix = "_raw")]
+/// #[derive(Default)]
+/// struct Foo {
+/// 	#[field(offset = 0, get, set)]
+/// 	size: u32,
+/// }
+///
+/// let mut foo = Foo::default();
+/// foo.set_size_raw(1);
+/// foo.size();
+/// ```
+///
+/// The `method_suffix` argument renames the accessors, the unsuffixed names do not exist.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4, ffi_twin = FooC)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u8,
+/// 	#[field(offset = 4)]
+/// 	b: u32,
+/// }
+/// ```
+///
+/// The packed ffi twin cannot reproduce the gap before `b` without a pad field.
+///
+/// ```compile_fail
+/// #[derive(Copy, Clone)]
+/// struct Opaque(u32);
+///
+/// #[struct_layout::explicit(size = 4, align = 4, display_field)]
+/// struct Foo {
+/// 	#[field(offset = 0, get)]
+/// 	handle: Opaque,
+/// }
+/// ```
+///
+/// The fields of `display_field` must implement `Display` unless they are marked `display = skip`.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, count = 4, shiftable)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// Only fields of a single value can be shifted.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4, init)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// 	#[field(offset = 4)]
+/// 	b: u32,
+/// }
+///
+/// let mut slot = core::mem::MaybeUninit::uninit();
+/// Foo::init(&mut slot).set_a(1).finish();
+/// ```
+///
+/// The initializer only finishes once every field has been set.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4, init)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// 	#[field(offset = 4)]
+/// 	b: u32,
+/// }
+///
+/// let mut slot = core::mem::MaybeUninit::uninit();
+/// Foo::init(&mut slot).set_a(1).set_b(2).set_a(3).finish();
+/// ```
+///
+/// Every field is set exactly once by the initializer.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, get, zero, check_cfg(feature = "extended"))]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The check_cfg argument only gates the get, set, ref and mut accessors.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: f32,
+/// 	#[field(offset = 4, seq)]
+/// 	seq: f32,
+/// }
+/// ```
+///
+/// The sequence field must be an integer.
+///
+/// ```compile_fail
+/// #![deny(deprecated)]
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 4, old_offset = 0)]
+/// 	a: u32,
+/// }
+///
+/// fn legacy(foo: &Foo) -> u32 {
+/// 	foo.a_legacy()
+/// }
+/// ```
+///
+/// The accessors at the old offset of a field are deprecated.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, old_offset = 6)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The old offset of a field must be in bounds.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4, offset_unit = 4)]
+/// struct Foo {
+/// 	#[field(offset = 3)]
+/// 	a: u32,
+/// 	#[field(offset = after(a))]
+/// 	b: u32,
+/// }
+/// ```
+///
+/// Offsets relative to another field are in bytes and must say so with `unit = 1`.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4, offset_unit = 4)]
+/// struct Foo {
+/// 	#[field(offset = 4)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The offset in units must be in bounds once converted to bytes.
+///
+/// ```compile_fail
+/// #[repr(C)]
+/// struct Packet {
+/// 	seq: u16,
+/// 	payload_len: u16,
+/// }
+/// struct_layout::check_layout! {
+/// 	Packet: size = 4, fields { seq: u16 @ 0, payload_len: u16 @ 4 }
+/// }
+/// ```
+///
+/// The offsets of a structure declared elsewhere must match.
+///
+/// ```compile_fail
+/// #[repr(C)]
+/// struct Packet {
+/// 	seq: u16,
+/// 	payload_len: u16,
+/// }
+/// struct_layout::check_layout! {
+/// 	Packet: fields { seq: u32 @ 0 }
+/// }
+/// ```
+///
+/// The types of its fields must match too.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 72, align = 4, auto_ref(threshold = 32))]
+/// struct Foo {
+/// 	#[field(offset = 2)]
+/// 	data: [u32; 16],
+/// }
+/// ```
+///
+/// Large fields with a getter returning a reference must be aligned.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, bits = 4..12, ref)]
+/// 	len: u16,
+/// }
+/// ```
+///
+/// Bitfields cannot be referenced.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, bits = 4..12)]
+/// 	len: u8,
+/// }
+/// ```
+///
+/// The bit range must fit in the stored integer.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 8, align = 4)]
+/// #[derive(Copy, Clone, PartialEq, Eq)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	id: u32,
+/// 	#[field(offset = 4)]
+/// 	weight: f32,
+/// }
+/// ```
+///
+/// The comparison derives require the trait of every compared field.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 24, align = 8, strict)]
+/// struct Foo {
+/// 	#[field(offset = 8)]
+/// 	a: i64,
+/// 	#[field(offset = 12)]
+/// 	b: u32,
+/// }
+/// ```
+///
+/// Fields may not overlap in strict layouts.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 16, align = 4, strict)]
+/// struct Foo {
+/// 	#[field(offset = 0, count = 4, stride = 4)]
+/// 	a: u16,
+/// 	#[field(offset = 1, count = 4, stride = 4)]
+/// 	b: u8,
+/// }
+/// ```
+///
+/// The elements of strided arrays may not overlap in strict layouts.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 12, align = 8, strict)]
+/// struct Foo {
+/// 	#[field(offset = 0)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// The size of strict layouts must be a multiple of the alignment.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 32, align = 8)]
+/// struct Frame<T: Copy + 'static> {
+/// 	#[field(offset = 16)]
+/// 	payload: T,
+/// }
+/// let frame = unsafe { Frame::<[u8; 32]>::zeroed() };
+/// let _ = frame.payload();
+/// ```
+///
+/// The fields of generic structures are checked for the type parameters the accessors are used with.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 32, align = 8, mirror)]
+/// struct Frame<T: Copy + 'static> {
+/// 	#[field(offset = 16)]
+/// 	payload: T,
+/// }
+/// ```
+///
+/// Most arguments are not supported on generic structures.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4, bytes)]
+/// struct Foo {
+/// 	#[field(offset = 0, get)]
+/// 	a: core::num::NonZeroU32,
+/// }
+/// let _ = Foo::from_bytes([0, 0, 0, 0]);
+/// ```
+///
+/// Creating a structure from bytes requires unsafe code unless any bytes are a valid value of its fields, see [`Pod`].
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4)]
+/// struct Foo {
+/// 	#[field(offset = 0, get)]
+/// 	a: core::num::NonZeroU32,
+/// }
+/// let _ = Foo::zeroed();
+/// ```
+///
+/// Creating a zeroed structure requires unsafe code unless zeroed bytes are a valid value of its fields.
+///
+/// ```compile_fail
+/// pub trait Any {}
+/// impl<T: ?Sized> Any for T {}
+///
+/// #[struct_layout::explicit(size = 16, align = 8, check(Any))]
+/// #[derive(Default)]
+/// struct Foo {
+/// 	#[field(offset = 8, set, ref, allow_drop)]
+/// 	a: Option<Box<i32>>,
+/// }
+/// ```
+///
+/// The setter of an allow_drop field drops the zeroed value, the derived Default cannot use it.
+///
+/// ```compile_fail
+/// pub trait Any {}
+/// impl<T: ?Sized> Any for T {}
+///
+/// #[struct_layout::explicit(size = 16, align = 8, check(Any))]
+/// #[derive(Clone)]
+/// struct Foo {
+/// 	#[field(offset = 8, set, ref, allow_drop)]
+/// 	a: Option<Box<i32>>,
+/// }
+/// ```
+///
+/// A copy of a structure with an allow_drop field would be a second owner of its value.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4, slice_ext)]
+/// struct Foo {
+/// 	#[field(offset = 0, ref)]
+/// 	a: core::num::NonZeroU32,
+/// }
+/// ```
+///
+/// The references of the `slice_ext` trait point at any bytes of the slice.
+///
+/// ```compile_fail
+/// #[struct_layout::explicit(size = 4, align = 4)]
+/// struct Other {
+/// 	#[field(offset = 0, get)]
+/// 	a: core::num::NonZeroU32,
+/// }
+///
+/// #[struct_layout::explicit(size = 4, align = 4, cast_to(Other))]
+/// struct Foo {
+/// 	#[field(offset = 0, get)]
+/// 	a: u32,
+/// }
+/// ```
+///
+/// Casting to another structure safely requires any bytes to be a valid value of it, see [`Pod`].
 #[allow(dead_code)]
 fn compile_fail() {}
@@ -0,0 +1,23 @@
+/*!
+Access to structures which live in the memory of another process.
+ */
+
+/// Reads bytes from remote memory.
+///
+/// Implement this trait over a process handle to use the `read_` functions generated by the `remote` argument.
+pub trait MemoryRead {
+	/// Error returned when the memory cannot be read.
+	type Error;
+	/// Reads `buf.len()` bytes starting at the address.
+	fn read(&self, addr: u64, buf: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// Writes bytes to remote memory.
+///
+/// Implement this trait over a process handle to use the `write_` functions generated by the `remote` argument.
+pub trait MemoryWrite {
+	/// Error returned when the memory cannot be written.
+	type Error;
+	/// Writes the bytes starting at the address.
+	fn write(&mut self, addr: u64, buf: &[u8]) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,92 @@
+/*!
+Place-like handles to fields which may not be aligned.
+ */
+
+use core::{fmt, marker::PhantomData, ptr};
+
+/// Shared handle to a possibly unaligned `T`.
+///
+/// Generated by the `unaligned_ref` field argument, eg. `foo.field_unaligned()`.
+/// The handle borrows the structure so it cannot outlive it.
+pub struct Unaligned<'a, T> {
+	ptr: *const u8,
+	marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Unaligned<'a, T> {
+	/// Creates a handle to the value at the pointer.
+	///
+	/// # Safety
+	///
+	/// The pointer must be valid for reads of `T` for the lifetime `'a` and its bytes must be a valid `T`, it does not need to be aligned.
+	pub const unsafe fn new(ptr: *const u8) -> Unaligned<'a, T> {
+		Unaligned { ptr, marker: PhantomData }
+	}
+}
+
+impl<'a, T: Copy> Unaligned<'a, T> {
+	/// Reads the value.
+	#[inline]
+	pub fn get(&self) -> T {
+		unsafe { ptr::read_unaligned(self.ptr as *const T) }
+	}
+}
+
+impl<'a, T> Copy for Unaligned<'a, T> {}
+impl<'a, T> Clone for Unaligned<'a, T> {
+	fn clone(&self) -> Unaligned<'a, T> {
+		*self
+	}
+}
+impl<'a, T: Copy + fmt::Debug> fmt::Debug for Unaligned<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.get().fmt(f)
+	}
+}
+unsafe impl<'a, T: Sync> Send for Unaligned<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Unaligned<'a, T> {}
+
+/// Exclusive handle to a possibly unaligned `T`.
+///
+/// Generated by the `unaligned_ref` field argument, eg. `foo.field_unaligned_mut()`.
+/// The handle mutably borrows the structure so it cannot outlive it.
+pub struct UnalignedMut<'a, T> {
+	ptr: *mut u8,
+	marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> UnalignedMut<'a, T> {
+	/// Creates a handle to the value at the pointer.
+	///
+	/// # Safety
+	///
+	/// The pointer must be valid for reads and writes of `T` for the lifetime `'a` without other access, and its bytes must be a valid `T`. It does not need to be aligned.
+	pub unsafe fn new(ptr: *mut u8) -> UnalignedMut<'a, T> {
+		UnalignedMut { ptr, marker: PhantomData }
+	}
+	/// Reborrows the handle as a shared handle.
+	pub fn as_unaligned(&self) -> Unaligned<'_, T> {
+		Unaligned { ptr: self.ptr, marker: PhantomData }
+	}
+}
+
+impl<'a, T: Copy> UnalignedMut<'a, T> {
+	/// Reads the value.
+	#[inline]
+	pub fn get(&self) -> T {
+		unsafe { ptr::read_unaligned(self.ptr as *const T) }
+	}
+	/// Writes the value.
+	#[inline]
+	pub fn set(&mut self, value: T) {
+		unsafe { ptr::write_unaligned(self.ptr as *mut T, value) }
+	}
+}
+
+impl<'a, T: Copy + fmt::Debug> fmt::Debug for UnalignedMut<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.get().fmt(f)
+	}
+}
+unsafe impl<'a, T: Send> Send for UnalignedMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for UnalignedMut<'a, T> {}
@@ -0,0 +1,92 @@
+/*!
+Visitors over the fields of an explicit structure.
+ */
+
+use core::fmt;
+
+/// Visits the fields of a structure.
+///
+/// Implemented by tooling which handles every structure generically, eg. to dump or compare their fields.
+/// The `visit` argument generates `visit_fields` which calls the method matching the type of every field in declaration order.
+/// By default every method forwards the value to `visit_debug`.
+pub trait FieldVisitor {
+	/// Visits any other field type.
+	fn visit_debug(&mut self, name: &str, value: &dyn fmt::Debug);
+	/// Visits a signed integer field.
+	fn visit_int(&mut self, name: &str, value: i64) {
+		self.visit_debug(name, &value)
+	}
+	/// Visits an unsigned integer field.
+	fn visit_uint(&mut self, name: &str, value: u64) {
+		self.visit_debug(name, &value)
+	}
+	/// Visits a floating point field.
+	fn visit_float(&mut self, name: &str, value: f64) {
+		self.visit_debug(name, &value)
+	}
+	/// Visits a boolean field.
+	fn visit_bool(&mut self, name: &str, value: bool) {
+		self.visit_debug(name, &value)
+	}
+	/// Visits a character field.
+	fn visit_char(&mut self, name: &str, value: char) {
+		self.visit_debug(name, &value)
+	}
+	/// Visits a byte array field.
+	fn visit_bytes(&mut self, name: &str, value: &[u8]) {
+		self.visit_debug(name, &value)
+	}
+	/// Visits a nested structure field.
+	///
+	/// The callback visits the fields of the nested structure, the default recurses into them.
+	fn visit_nested(&mut self, name: &str, visit: &mut dyn FnMut(&mut Self)) where Self: Sized {
+		let _ = name;
+		visit(self)
+	}
+}
+
+/// Visits the fields of a structure with the ability to change their values.
+///
+/// The `visit` argument generates `visit_fields_mut` which passes the value of every field together with a setter.
+/// Fields without a setter are passed to `visit_debug`.
+pub trait FieldVisitorMut {
+	/// Visits any other field type.
+	fn visit_debug(&mut self, name: &str, value: &dyn fmt::Debug);
+	/// Visits a signed integer field, the new value is truncated to the field type.
+	fn visit_int(&mut self, name: &str, value: i64, set: &mut dyn FnMut(i64)) {
+		let _ = set;
+		self.visit_debug(name, &value)
+	}
+	/// Visits an unsigned integer field, the new value is truncated to the field type.
+	fn visit_uint(&mut self, name: &str, value: u64, set: &mut dyn FnMut(u64)) {
+		let _ = set;
+		self.visit_debug(name, &value)
+	}
+	/// Visits a floating point field.
+	fn visit_float(&mut self, name: &str, value: f64, set: &mut dyn FnMut(f64)) {
+		let _ = set;
+		self.visit_debug(name, &value)
+	}
+	/// Visits a boolean field.
+	fn visit_bool(&mut self, name: &str, value: bool, set: &mut dyn FnMut(bool)) {
+		let _ = set;
+		self.visit_debug(name, &value)
+	}
+	/// Visits a character field.
+	fn visit_char(&mut self, name: &str, value: char, set: &mut dyn FnMut(char)) {
+		let _ = set;
+		self.visit_debug(name, &value)
+	}
+	/// Visits a byte array field, the setter panics if the length does not match.
+	fn visit_bytes(&mut self, name: &str, value: &[u8], set: &mut dyn FnMut(&[u8])) {
+		let _ = set;
+		self.visit_debug(name, &value)
+	}
+	/// Visits a nested structure field.
+	///
+	/// The callback visits the fields of the nested structure, the default recurses into them.
+	fn visit_nested(&mut self, name: &str, visit: &mut dyn FnMut(&mut Self)) where Self: Sized {
+		let _ = name;
+		visit(self)
+	}
+}
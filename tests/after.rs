@@ -0,0 +1,82 @@
+
+#[struct_layout::explicit(size = 0x40, align = 8)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Packet {
+	#[field(offset = 0x10)]
+	kind: u16,
+	#[field(offset = after)]
+	flags: u16,
+	#[field(offset = after)]
+	length: u32,
+	#[field(offset = after + 8)]
+	timestamp: u64,
+	#[field(offset = after, count = 3)]
+	ports: u16,
+	#[field(offset = after)]
+	checksum: u8,
+}
+
+#[test]
+fn chained_offsets() {
+	assert_eq!(Packet::__OFFSET_KIND, 0x10);
+	assert_eq!(Packet::__OFFSET_FLAGS, 0x12);
+	assert_eq!(Packet::__OFFSET_LENGTH, 0x14);
+	assert_eq!(Packet::__OFFSET_TIMESTAMP, 0x20);
+	assert_eq!(Packet::__OFFSET_PORTS, 0x28);
+	assert_eq!(Packet::__OFFSET_CHECKSUM, 0x2e);
+}
+
+#[test]
+fn chained_accessors() {
+	let mut packet = Packet::default();
+	packet.set_length(9).set_timestamp(0x0102030405060708).set_checksum(0xee);
+	packet.set_ports(2, 0x1234);
+	let bytes = unsafe { std::mem::transmute::<Packet, [u8; 0x40]>(packet) };
+	assert_eq!(&bytes[0x14..0x18], &9u32.to_ne_bytes());
+	assert_eq!(&bytes[0x20..0x28], &0x0102030405060708u64.to_ne_bytes());
+	assert_eq!(&bytes[0x2c..0x2e], &0x1234u16.to_ne_bytes());
+	assert_eq!(bytes[0x2e], 0xee);
+}
+
+#[struct_layout::explicit(size = 0x40, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Record {
+	#[field(offset = 4)]
+	header: [u8; 8],
+	#[field(offset = header + 0x10)]
+	body: u32,
+	#[field(offset = after(header))]
+	length: u16,
+	#[field(offset = after(body) + 4)]
+	trailer: u32,
+}
+
+const HEADER_SIZE: usize = 0x20;
+
+#[struct_layout::explicit(size = 0x40, align = 4)]
+struct Message {
+	#[field(offset = HEADER_SIZE)]
+	id: u32,
+	#[field(offset = id * 2 - 0x1c)]
+	kind: u32,
+}
+
+#[test]
+fn named_offsets() {
+	assert_eq!(Record::__OFFSET_HEADER, 4);
+	assert_eq!(Record::__OFFSET_BODY, 0x14);
+	assert_eq!(Record::__OFFSET_LENGTH, 0x0c);
+	assert_eq!(Record::__OFFSET_TRAILER, 0x1c);
+	assert_eq!(Message::__OFFSET_ID, 0x20);
+	assert_eq!(Message::__OFFSET_KIND, 0x24);
+}
+
+#[test]
+fn named_accessors() {
+	let mut record = Record::default();
+	record.set_body(7).set_length(3).set_trailer(0x11223344);
+	let bytes = unsafe { std::mem::transmute::<Record, [u8; 0x40]>(record) };
+	assert_eq!(&bytes[0x14..0x18], &7u32.to_ne_bytes());
+	assert_eq!(&bytes[0x0c..0x0e], &3u16.to_ne_bytes());
+	assert_eq!(&bytes[0x1c..0x20], &0x11223344u32.to_ne_bytes());
+}
@@ -0,0 +1,68 @@
+use struct_layout::PatchError;
+
+fn is_percentage(value: u8) -> bool {
+	value <= 100
+}
+
+#[struct_layout::explicit(size = 16, align = 4, apply_patches)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Unit {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4, validate = is_percentage)]
+	health: u8,
+	#[field(offset = 5)]
+	alive: bool,
+	#[field(offset = 8)]
+	speed: f32,
+	#[field(offset = 12, count = 2)]
+	ids: u16,
+}
+
+#[test]
+fn exact_field() {
+	let mut unit = Unit::default();
+	assert_eq!(unit.apply_patch(8, &1.5f32.to_ne_bytes()), Ok("speed"));
+	assert_eq!(unit.apply_patch(0, &7u32.to_ne_bytes()), Ok("id"));
+	assert_eq!(unit.apply_patch(5, &[1]), Ok("alive"));
+	assert_eq!((unit.id(), unit.speed(), unit.alive()), (7, 1.5, true));
+}
+
+#[test]
+fn rejected() {
+	let mut unit = Unit::default();
+	// Between the fields and in fields which are not of a single value
+	assert_eq!(unit.apply_patch(6, &[1, 2]), Err(PatchError::Padding { offset: 6 }));
+	assert_eq!(unit.apply_patch(12, &[1, 2]), Err(PatchError::Padding { offset: 12 }));
+	assert_eq!(unit.apply_patch(6, &[0; 4]), Err(PatchError::Padding { offset: 6 }));
+	// Straddling the end of the field
+	let error = unit.apply_patch(2, &[0; 4]).unwrap_err();
+	assert_eq!(error, PatchError::Straddle { field: "id", offset: 2, len: 4 });
+	assert_eq!(error.to_string(), "patch of 4 bytes at offset 2 extends past the end of field `id`");
+	// Within a field but not all of it
+	assert_eq!(unit.apply_patch(9, &[0; 2]), Err(PatchError::Size { field: "speed", expected: 4, len: 2 }));
+	assert_eq!(unit.apply_patch(0, &[0; 2]), Err(PatchError::Size { field: "id", expected: 4, len: 2 }));
+	assert_eq!(unit.apply_patch(14, &[0; 4]), Err(PatchError::OutOfBounds { offset: 14, len: 4 }));
+	// Values the field does not accept
+	assert_eq!(unit.apply_patch(4, &[101]), Err(PatchError::Invalid { field: "health" }));
+	assert_eq!(unit.apply_patch(5, &[2]), Err(PatchError::Invalid { field: "alive" }));
+	assert_eq!((unit.id(), unit.health(), unit.alive(), unit.speed()), (0, 0, false, 0.0));
+}
+
+#[struct_layout::explicit(size = 8, align = 4, apply_patches)]
+#[derive(Copy, Clone, Debug)]
+struct Limit {
+	#[field(offset = 0)]
+	max: core::num::NonZeroU32,
+	#[field(offset = 4)]
+	min: u32,
+}
+
+#[test]
+fn unsafe_field() {
+	let mut limit = Limit::new(core::num::NonZeroU32::new(10).unwrap(), 1);
+	// Zero is not a valid NonZeroU32, the bytes are not checked
+	assert_eq!(unsafe { limit.apply_patch(0, &20u32.to_ne_bytes()) }, Ok("max"));
+	assert_eq!(unsafe { limit.apply_patch(4, &2u32.to_ne_bytes()) }, Ok("min"));
+	assert_eq!((limit.max().get(), limit.min()), (20, 2));
+}
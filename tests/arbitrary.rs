@@ -0,0 +1,66 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use std::convert::TryFrom;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Kind {
+	Ping = 0,
+	Pong = 1,
+}
+impl TryFrom<u8> for Kind {
+	type Error = u8;
+	fn try_from(value: u8) -> Result<Kind, u8> {
+		match value {
+			0 => Ok(Kind::Ping),
+			1 => Ok(Kind::Pong),
+			_ => Err(value),
+		}
+	}
+}
+
+#[struct_layout::explicit(size = 4, align = 2)]
+#[derive(Copy, Clone, Debug, Arbitrary)]
+struct RawPacket {
+	#[field(offset = 0, enum(u8))]
+	kind: Kind,
+	#[field(offset = 2)]
+	len: u16,
+}
+
+#[struct_layout::explicit(size = 8, align = 2, arbitrary = fields)]
+#[derive(Copy, Clone, Debug, Arbitrary)]
+struct Flags {
+	#[field(offset = 0)]
+	enabled: bool,
+	#[field(offset = 4)]
+	len: u16,
+}
+
+const SEED: [u8; 16] = [7, 1, 42, 0, 255, 3, 1, 9, 2, 0, 128, 64, 0, 1, 5, 6];
+
+#[test]
+fn arbitrary_bytes() {
+	let mut u = Unstructured::new(&SEED);
+	for _ in 0..4 {
+		let packet = RawPacket::arbitrary(&mut u).unwrap();
+		// Unknown discriminants are reported by the getter
+		assert!(matches!(packet.kind(), None | Some(Kind::Ping) | Some(Kind::Pong)));
+	}
+	let packet = RawPacket::arbitrary(&mut Unstructured::new(&SEED[1..])).unwrap();
+	assert_eq!(packet.kind(), Some(Kind::Pong));
+	assert_eq!(packet.len(), u16::from_ne_bytes([0, 255]));
+}
+
+#[test]
+fn arbitrary_fields() {
+	let mut u = Unstructured::new(&SEED);
+	for _ in 0..5 {
+		let flags = Flags::arbitrary(&mut u).unwrap();
+		let bytes = unsafe { std::mem::transmute::<Flags, [u8; 8]>(flags) };
+		assert!(bytes[0] <= 1);
+		// The padding is left zeroed
+		assert_eq!(&bytes[1..4], &[0, 0, 0]);
+		assert_eq!(&bytes[6..8], &[0, 0]);
+	}
+}
@@ -0,0 +1,167 @@
+
+#[struct_layout::explicit(size = 64, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Slots {
+	#[field(offset = 0x1, get, set)]
+	slots: [u32; 8],
+	#[field(offset = 0x24)]
+	bytes: [u8; 4],
+}
+
+#[test]
+fn element_access() {
+	let mut slots = Slots::default();
+	assert_eq!(Slots::slots_len(), 8);
+	slots.set_slots_at(0, 11);
+	slots.set_slots_at(7, 77);
+	assert_eq!(slots.slots_at(0), 11);
+	assert_eq!(slots.slots_at(7), 77);
+	assert_eq!(slots.slots(), [11, 0, 0, 0, 0, 0, 0, 77]);
+	assert_eq!(slots.slots_get(7), Some(77));
+	assert_eq!(slots.slots_get(8), None);
+
+	slots.set_bytes_at(3, 0xff);
+	assert_eq!(slots.bytes_ref(), &[0, 0, 0, 0xff]);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn element_out_of_range() {
+	let slots = Slots::default();
+	slots.slots_at(8);
+}
+
+#[struct_layout::explicit(size = 32, align = 4)]
+struct Sorted {
+	#[field(offset = 8, get, slice)]
+	values: [i32; 4],
+}
+
+#[test]
+fn slice_access() {
+	let mut sorted: Sorted = unsafe { std::mem::zeroed() };
+	sorted.values_slice_mut().copy_from_slice(&[3, -1, 7, 2]);
+	sorted.values_slice_mut().sort();
+	assert_eq!(sorted.values_slice(), &[-1, 2, 3, 7]);
+	assert_eq!(sorted.values_at(0), -1);
+	assert_eq!(sorted.values_at(3), 7);
+}
+
+#[struct_layout::explicit(size = 64, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct TileMap {
+	#[field(offset = 4)]
+	width: u32,
+	#[field(offset = 8, dims(4, 3))]
+	tiles: u8,
+	#[field(offset = 20, dims(2, 3), col_major)]
+	matrix: u16,
+}
+
+#[test]
+fn dims_row_major() {
+	let mut map = TileMap::default();
+	assert_eq!(TileMap::TILES_ROWS, 4);
+	assert_eq!(TileMap::TILES_COLS, 3);
+	map.set_tiles_at(0, 0, 1).set_tiles_at(1, 2, 5).set_tiles_at(3, 2, 9);
+	assert_eq!(map.tiles_at(1, 2), 5);
+	assert_eq!(map.tiles_row(0), [1, 0, 0]);
+	assert_eq!(map.tiles_row(1), [0, 0, 5]);
+	assert_eq!(map.tiles_row(3), [0, 0, 9]);
+	// Last cell is at offset 8 + 11
+	assert_eq!(unsafe { std::mem::transmute::<TileMap, [u8; 64]>(map) }[19], 9);
+	assert_eq!(map.width(), 0);
+}
+
+#[test]
+fn dims_col_major() {
+	let mut map = TileMap::default();
+	map.set_matrix_at(0, 1, 7).set_matrix_at(1, 0, 3);
+	assert_eq!(map.matrix_row(0), [0, 7, 0]);
+	assert_eq!(map.matrix_row(1), [3, 0, 0]);
+	let words = unsafe { std::mem::transmute::<TileMap, [u16; 32]>(map) };
+	assert_eq!(&words[10..16], &[0, 3, 7, 0, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn dims_out_of_range() {
+	let map = TileMap::default();
+	map.tiles_at(0, 3);
+}
+
+#[struct_layout::explicit(size = 264, align = 8)]
+#[derive(Copy, Clone)]
+struct Transform {
+	#[field(offset = 4, get, set, copy_into, copy_from)]
+	matrix: [f32; 64],
+	#[field(offset = 260)]
+	flags: u32,
+}
+
+#[test]
+fn copy_into_from() {
+	let mut transform = Transform([0; 264]);
+	let mut src = [0.0f32; 64];
+	for (i, value) in src.iter_mut().enumerate() {
+		*value = i as f32;
+	}
+	transform.set_flags(7).matrix_from(&src);
+	assert_eq!(transform.matrix()[..], src[..]);
+	assert_eq!(transform.flags(), 7);
+
+	let mut out = [1.0f32; 64];
+	transform.matrix_into(&mut out);
+	assert_eq!(out[..], src[..]);
+}
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Signed {
+	#[field(offset = 0, get, set, bytes_array)]
+	int: u32,
+	#[field(offset = 4, get, set, bytes_array, size = 6)]
+	mac: [u8; 6],
+	#[field(offset = 12, get, set, bytes_array)]
+	level: i16,
+}
+
+#[test]
+fn bytes_array() {
+	let mut signed = Signed::default();
+	signed.set_int(0x11223344).set_mac([1, 2, 3, 4, 5, 6]).set_level(-2);
+	let bytes: [u8; 4] = signed.int_to_bytes();
+	assert_eq!(u32::from_ne_bytes(bytes), 0x11223344);
+	assert_eq!(signed.mac_to_bytes(), [1, 2, 3, 4, 5, 6]);
+	assert_eq!(signed.level_to_bytes(), (-2i16).to_ne_bytes());
+
+	let mut copy = Signed::default();
+	copy.set_int_from_bytes(signed.int_to_bytes())
+		.set_mac_from_bytes(signed.mac_to_bytes())
+		.set_level_from_bytes(signed.level_to_bytes());
+	assert_eq!(copy.0, signed.0);
+
+	const SIGNED: Signed = Signed([0x44, 0x33, 0x22, 0x11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+	const INT: [u8; 4] = SIGNED.int_to_bytes();
+	assert_eq!(INT, [0x44, 0x33, 0x22, 0x11]);
+}
+
+#[struct_layout::explicit(size = 64, align = 16)]
+#[derive(Copy, Clone, Default)]
+struct Simd {
+	#[field(offset = 4)]
+	count: u32,
+	#[field(offset = 0x20, align = 16)]
+	lanes: [f32; 4],
+}
+
+#[test]
+fn simd_alignment() {
+	let mut simd = Simd::default();
+	simd.set_lanes([1.0, 2.0, 3.0, 4.0]).set_count(4);
+	let lanes = simd.lanes_ref();
+	assert_eq!(lanes as *const _ as usize % 16, 0);
+	assert_eq!(lanes[2], 3.0);
+	simd.lanes_mut()[3] = 8.0;
+	assert_eq!(simd.lanes(), [1.0, 2.0, 3.0, 8.0]);
+}
@@ -0,0 +1,48 @@
+use std::sync::atomic::Ordering;
+use std::thread;
+
+// The atomic storage permits the stores through shared references
+#[struct_layout::explicit(size = 16, align = 8, storage = AtomicU64)]
+struct Slot {
+	#[field(offset = 0, get, set, atomic_ordered)]
+	ready: u32,
+	#[field(offset = 4, atomic_ordered, assume_valid)]
+	busy: bool,
+	#[field(offset = 8, atomic_ordered)]
+	value: u64,
+}
+
+#[test]
+fn release_acquire() {
+	let slot = Slot::zeroed();
+	thread::scope(|scope| {
+		scope.spawn(|| {
+			// SAFETY: The fields are only accessed atomically while the threads run
+			unsafe {
+				slot.value_store(Ordering::Relaxed, 0x1122334455667788);
+				slot.ready_store(Ordering::Release, 1);
+			}
+		});
+		scope.spawn(|| {
+			while slot.ready_load(Ordering::Acquire) == 0 {
+				std::hint::spin_loop();
+			}
+			assert_eq!(slot.value_load(Ordering::Relaxed), 0x1122334455667788);
+		});
+	});
+	assert_eq!(slot.ready(), 1);
+}
+
+#[test]
+fn compare_exchange() {
+	let mut slot = Slot::zeroed();
+	slot.set_ready(5);
+	// SAFETY: The slot is not shared
+	unsafe {
+		assert_eq!(slot.ready_compare_exchange(4, 6, Ordering::AcqRel, Ordering::Acquire), Err(5));
+		assert_eq!(slot.ready_compare_exchange(5, 6, Ordering::AcqRel, Ordering::Acquire), Ok(5));
+		assert_eq!(slot.busy_compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire), Ok(false));
+	}
+	assert_eq!(slot.ready(), 6);
+	assert!(slot.busy());
+}
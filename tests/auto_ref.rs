@@ -0,0 +1,50 @@
+#[struct_layout::explicit(size = 96, align = 4, auto_ref(threshold = 32), mirror(derive(Copy, Clone, Debug, PartialEq)), tracked, parts, visit)]
+#[derive(Copy, Clone, Debug)]
+struct Asset {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4)]
+	name: [u8; 32],
+	#[field(offset = 36)]
+	data: [u8; 48],
+	// Explicit accessors keep the getter by value
+	#[field(offset = 84, get, set)]
+	hash: [u32; 3],
+}
+
+fn new() -> Asset {
+	unsafe { std::mem::zeroed() }
+}
+
+#[test]
+fn getters() {
+	let mut asset = new();
+	asset.set_id(7).set_name([1; 32]).set_data([2; 48]).set_hash([3; 3]);
+	// The fields up to the threshold are read by value, larger fields by reference
+	let id: u32 = asset.id();
+	let name: [u8; 32] = asset.name();
+	let data: &[u8; 48] = asset.data();
+	let hash: [u32; 3] = asset.hash();
+	assert_eq!((id, name, &data[..], hash), (7, [1; 32], &[2; 48][..], [3; 3]));
+	asset.data_mut()[0] = 9;
+	assert_eq!(asset.data()[..2], [9, 2]);
+}
+
+#[test]
+fn companions() {
+	let mut asset = new();
+	asset.set_id(1).set_data([5; 48]);
+	let values = AssetValues::from(&asset);
+	assert_eq!(values.data, [5; 48]);
+	assert!(asset == values);
+	assert!(matches!(asset.read_dyn(AssetField::Data), AssetValue::Data(data) if data == [5; 48]));
+
+	let mut tracked = AssetTracked::new(new());
+	tracked.set_data([6; 48]);
+	tracked.flush_into(&mut asset);
+	assert_eq!(asset.data(), &[6; 48]);
+
+	let parts = asset.into_parts();
+	assert_eq!(Asset::from_parts(parts).data(), &[6; 48]);
+	assert!(format!("{:?}", asset).contains("data: [6, 6"));
+}
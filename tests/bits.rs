@@ -0,0 +1,54 @@
+// Two bitfields and a flag packed into one u16
+#[struct_layout::explicit(size = 8, align = 4, byte_order)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Packet {
+	#[field(offset = 0, bits = 0..4, get, set)]
+	kind: u16,
+	#[field(offset = 0, bits = 4..15, get, set)]
+	len: u16,
+	#[field(offset = 0, bits = 15, stored = u16, get, set)]
+	last: bool,
+	#[field(offset = 4, bits = 8..=11, stored = u32)]
+	delta: i8,
+	#[field(offset = 4, bits = 31, stored = u32)]
+	enabled: bool,
+}
+
+#[test]
+fn packed_u16() {
+	let mut packet = Packet::default();
+	packet.set_kind(0xa).set_len(0x5ff).set_last(true);
+	assert_eq!((packet.kind(), packet.len(), packet.last()), (0xa, 0x5ff, true));
+	let bytes = unsafe { std::mem::transmute::<Packet, [u8; 8]>(packet) };
+	assert_eq!(u16::from_ne_bytes([bytes[0], bytes[1]]), 0xa | 0x5ff << 4 | 1 << 15);
+
+	// Setting a field leaves the other bits of the stored integer untouched
+	packet.set_last(false).set_kind(3);
+	assert_eq!((packet.kind(), packet.len(), packet.last()), (3, 0x5ff, false));
+
+	// Values are truncated to the width of their range
+	packet.set_kind(0x1f);
+	assert_eq!((packet.kind(), packet.len()), (0xf, 0x5ff));
+}
+
+#[test]
+fn signed_and_wide() {
+	let mut packet = Packet::default();
+	packet.set_delta(-3).set_enabled(true);
+	assert_eq!(packet.delta(), -3);
+	assert!(packet.enabled());
+	packet.set_delta(7);
+	assert_eq!((packet.delta(), packet.enabled()), (7, true));
+	let bytes = unsafe { std::mem::transmute::<Packet, [u8; 8]>(packet) };
+	assert_eq!(u32::from_ne_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]), 7 << 8 | 1 << 31);
+}
+
+#[test]
+fn byte_order() {
+	let mut packet = Packet::default();
+	packet.set_kind(1).set_len(0x123);
+	// The shared u16 is swapped once
+	packet.make_be();
+	let bytes = unsafe { std::mem::transmute::<Packet, [u8; 8]>(packet) };
+	assert_eq!(&bytes[..2], &(1u16 | 0x123 << 4).to_be_bytes());
+}
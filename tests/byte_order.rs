@@ -0,0 +1,111 @@
+
+#[struct_layout::explicit(size = 40, align = 8, byte_order)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Header {
+	#[field(offset = 0)]
+	magic: u32,
+	#[field(offset = 4)]
+	kind: u8,
+	#[field(offset = 6)]
+	version: i16,
+	#[field(offset = 8)]
+	length: u64,
+	#[field(offset = 16)]
+	scale: f32,
+	#[field(offset = 20)]
+	ports: [u16; 2],
+	#[field(offset = 24, count = 2, stride = 4)]
+	ids: u16,
+	#[field(offset = 32, opaque)]
+	tag: [char; 1],
+}
+
+fn sample() -> Header {
+	let mut header = Header::default();
+	header.set_magic(0x11223344).set_kind(0x55).set_version(0x6677).set_length(0x0102030405060708)
+		.set_scale(1.5).set_ports([0xaabb, 0xccdd]).set_tag(['x']);
+	header.set_ids(0, 0x1234);
+	header.set_ids(1, 0x5678);
+	header.0[5] = 0x99;
+	header.0[26] = 0xee;
+	header
+}
+
+fn expected(le: bool) -> [u8; 40] {
+	let header = sample();
+	let mut bytes = header.0;
+	let mut put = |offset: usize, le_bytes: &[u8]| {
+		let dest = &mut bytes[offset..offset + le_bytes.len()];
+		dest.copy_from_slice(le_bytes);
+		if !le {
+			dest.reverse();
+		}
+	};
+	put(0, &0x11223344u32.to_le_bytes());
+	put(6, &0x6677i16.to_le_bytes());
+	put(8, &0x0102030405060708u64.to_le_bytes());
+	put(16, &1.5f32.to_le_bytes());
+	put(20, &0xaabbu16.to_le_bytes());
+	put(22, &0xccddu16.to_le_bytes());
+	put(24, &0x1234u16.to_le_bytes());
+	put(28, &0x5678u16.to_le_bytes());
+	bytes
+}
+
+#[test]
+fn to_le() {
+	let header = sample();
+	assert_eq!(header.to_le().0, expected(true));
+	// The original is unchanged
+	assert_eq!(header.magic(), 0x11223344);
+}
+
+#[test]
+fn to_be() {
+	let header = sample();
+	let be = header.to_be();
+	assert_eq!(be.0, expected(false));
+	// Single bytes, undeclared bytes and opaque fields are untouched
+	assert_eq!(be.kind(), 0x55);
+	assert_eq!(be.0[5], 0x99);
+	assert_eq!(be.0[26], 0xee);
+	assert_eq!(be.tag(), ['x']);
+}
+
+#[test]
+fn make_round_trip() {
+	let mut header = sample();
+	header.make_be().make_be();
+	assert_eq!(header.0, sample().0);
+	header.make_be();
+	if cfg!(target_endian = "little") {
+		assert_eq!(header.magic(), 0x44332211);
+	}
+	header.make_be();
+	assert_eq!(header.0, sample().0);
+	assert_eq!(header.to_le().to_le().0, header.to_le().0);
+}
+
+#[struct_layout::explicit(size = 8, align = 4, byte_order)]
+#[derive(Copy, Clone, Default)]
+struct Base {
+	#[field(offset = 0)]
+	id: u32,
+}
+
+#[struct_layout::explicit(size = 12, align = 4, base(Base), byte_order)]
+#[derive(Copy, Clone, Default)]
+struct Derived {
+	#[field(offset = 8, dims(1, 2))]
+	pair: u16,
+}
+
+#[test]
+fn base_is_swapped() {
+	let mut derived = Derived::default();
+	derived.set_id(0x01020304);
+	derived.set_pair_at(0, 1, 0x0506);
+	let be = derived.to_be();
+	assert_eq!(&be.0[0..4], &[1, 2, 3, 4]);
+	assert_eq!(&be.0[10..12], &[5, 6]);
+}
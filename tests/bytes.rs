@@ -0,0 +1,70 @@
+#[struct_layout::explicit(size = 16, align = 4, bytes)]
+#[derive(Copy, Clone, Debug)]
+struct Header {
+	#[field(offset = 0)]
+	magic: [u8; 4],
+	#[field(offset = 4)]
+	version: u16,
+	#[field(offset = 8)]
+	len: u32,
+	#[field(offset = 12)]
+	crc: u32,
+}
+
+#[test]
+fn round_trip() {
+	let mut header = Header::zeroed();
+	header.set_magic(*b"HDR0").set_version(3).set_len(0x1234).set_crc(0xdeadbeef);
+	let bytes = *header.as_bytes();
+	assert_eq!(&bytes[..4], b"HDR0");
+	assert_eq!(bytes[8..12], 0x1234u32.to_ne_bytes());
+
+	let copy = Header::from_bytes(bytes);
+	assert_eq!((copy.magic(), copy.version(), copy.len(), copy.crc()), (*b"HDR0", 3, 0x1234, 0xdeadbeef));
+
+	header.as_bytes_mut()[4..6].copy_from_slice(&7u16.to_ne_bytes());
+	assert_eq!(header.version(), 7);
+}
+
+#[test]
+fn read_from() {
+	let mut file = Header::new(*b"HDR1", 1, 2, 3).as_bytes().to_vec();
+	file.extend_from_slice(b"payload");
+	let header = Header::read_from(&file).unwrap();
+	assert_eq!((header.magic(), header.version(), header.len(), header.crc()), (*b"HDR1", 1, 2, 3));
+	assert!(Header::read_from(&file[..15]).is_none());
+}
+
+// The bytes of structures with a wider storage element are viewed the same
+#[struct_layout::explicit(size = 8, align = 8, storage = u64, bytes)]
+struct Wide {
+	#[field(offset = 1)]
+	value: u8,
+}
+
+#[test]
+fn storage() {
+	let mut wide = Wide::zeroed();
+	wide.as_bytes_mut()[1] = 9;
+	assert_eq!(wide.value(), 9);
+	assert_eq!(wide.as_bytes(), &[0, 9, 0, 0, 0, 0, 0, 0]);
+}
+
+// Zero is not a valid count, the constructors from zeroed or any bytes are unsafe
+#[struct_layout::explicit(size = 4, align = 4, bytes)]
+struct Flags {
+	#[field(offset = 0)]
+	enabled: bool,
+	#[field(offset = 2)]
+	count: std::num::NonZeroU16,
+}
+
+#[test]
+fn invalid_bytes() {
+	// The count is set by new which is safe
+	let flags = Flags::new(true, std::num::NonZeroU16::new(3).unwrap());
+	let bytes = *flags.as_bytes();
+	let copy = unsafe { Flags::from_bytes(bytes) };
+	assert_eq!((copy.enabled(), copy.count().get()), (true, 3));
+	assert!(unsafe { Flags::read_from(&bytes[..2]) }.is_none());
+}
@@ -0,0 +1,77 @@
+
+#[struct_layout::explicit(size = 0x100, align = 4, check(struct_layout::Pod, impl), cast_to(PacketDecrypted))]
+#[derive(Copy, Clone, Default)]
+struct PacketRaw {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4)]
+	payload: [u8; 0x1c],
+}
+
+#[struct_layout::explicit(size = 0x100, align = 4, check(struct_layout::Pod, impl), cast_to(PacketRaw))]
+#[derive(Copy, Clone, Default)]
+struct PacketDecrypted {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4)]
+	command: u16,
+	#[field(offset = 8)]
+	argument: f32,
+}
+
+#[struct_layout::explicit(size = 0x100, align = 2, check(struct_layout::Pod, impl))]
+#[derive(Copy, Clone, Default)]
+struct PacketWords {
+	#[field(offset = 2)]
+	word: u16,
+}
+
+#[struct_layout::explicit(size = 0x100, align = 4, cast_to(PacketWords))]
+#[derive(Copy, Clone, Default)]
+struct PacketAligned {
+	#[field(offset = 0)]
+	id: u32,
+}
+
+#[test]
+fn views() {
+	let mut raw = PacketRaw::default();
+	raw.set_id(7);
+	raw.as_packet_decrypted_mut().set_command(0x1234).set_argument(2.5);
+	assert_eq!(raw.as_packet_decrypted().id(), 7);
+	assert_eq!(&raw.payload()[..2], &0x1234u16.to_ne_bytes());
+	assert_eq!(&raw.payload()[4..8], &2.5f32.to_ne_bytes());
+	assert_eq!(raw.as_packet_decrypted() as *const _ as *const u8, &raw as *const _ as *const u8);
+}
+
+#[test]
+fn into_other() {
+	let mut decrypted = PacketDecrypted::default();
+	decrypted.set_id(9).set_command(3);
+	let raw = decrypted.into_packet_raw();
+	assert_eq!(raw.id(), 9);
+	assert_eq!(raw.into_packet_decrypted().command(), 3);
+}
+
+#[test]
+fn weaker_alignment() {
+	let mut aligned = PacketAligned::default();
+	aligned.as_packet_words_mut().set_word(0xabcd);
+	assert_eq!(aligned.into_packet_words().word(), 0xabcd);
+	assert_eq!(aligned.0[2..4], 0xabcdu16.to_ne_bytes());
+}
+
+#[struct_layout::explicit(size = 0x100, align = 4, cast_to(PacketRaw))]
+struct PacketChecked {
+	#[field(offset = 0)]
+	id: core::num::NonZeroU32,
+}
+
+#[test]
+fn unsafe_cast() {
+	let checked = PacketChecked::new(core::num::NonZeroU32::new(5).unwrap());
+	// The casts are unsafe unless any bytes are valid for this structure as well, as_packet_raw_mut could write a zero id
+	let raw = unsafe { checked.into_packet_raw() };
+	assert_eq!(raw.id(), 5);
+	assert_eq!(unsafe { PacketChecked::zeroed().as_packet_raw() }.id(), 0);
+}
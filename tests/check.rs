@@ -0,0 +1,79 @@
+
+/// Plain old data.
+///
+/// # Safety
+///
+/// Every bit pattern must be a valid value.
+pub unsafe trait Pod: Copy + 'static {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for f32 {}
+
+#[struct_layout::explicit(size = 8, align = 4, check(Pod, impl))]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Vec2 {
+	#[field(offset = 0)]
+	pub x: f32,
+	#[field(offset = 4)]
+	pub y: f32,
+}
+
+#[struct_layout::explicit(size = 16, align = 4, check(Pod, impl))]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Body {
+	#[field(offset = 0)]
+	pub id: u32,
+	#[field(offset = 4, nested)]
+	pub pos: Vec2,
+}
+
+fn is_pod<T: Pod>() {}
+
+#[test]
+fn nested_with_implemented_check() {
+	is_pod::<Vec2>();
+	is_pod::<Body>();
+	let mut body = Body::default();
+	body.pos_mut().set_x(1.0).set_y(2.0);
+	assert_eq!(body.pos().y(), 2.0);
+}
+
+// Accepts any type
+pub trait Any {}
+impl<T: ?Sized> Any for T {}
+
+#[struct_layout::explicit(size = 16, align = 8, check(Any))]
+struct Shared {
+	#[field(offset = 8, set, ref, allow_drop)]
+	value: Option<std::rc::Rc<i32>>,
+}
+
+#[test]
+fn allow_drop_setter() {
+	let rc = std::rc::Rc::new(42);
+	let mut shared: Shared = unsafe { std::mem::zeroed() };
+	shared.set_value(Some(rc.clone()));
+	assert_eq!(std::rc::Rc::strong_count(&rc), 2);
+	assert_eq!(shared.value_ref().as_deref(), Some(&42));
+	// The old value is dropped by the setter
+	shared.set_value(None);
+	assert_eq!(std::rc::Rc::strong_count(&rc), 1);
+}
+
+#[struct_layout::explicit(size = 16, align = 8)]
+struct Owner {
+	#[field(offset = 8, manually_drop)]
+	boxed: Box<i32>,
+}
+
+#[test]
+fn manually_drop_field() {
+	let mut owner = Owner(Default::default());
+	owner.set_boxed(Box::new(5));
+	// Take back the ownership before the value is replaced
+	let boxed = unsafe { owner.boxed() };
+	assert_eq!(*boxed, 5);
+	owner.set_boxed(Box::new(6));
+	drop(boxed);
+	let boxed = unsafe { owner.boxed() };
+	assert_eq!(*boxed, 6);
+}
@@ -0,0 +1,77 @@
+// The check trait is only implemented for `Extended` with the `rand` feature, its accessors are gated likewise
+
+/// Plain old data.
+///
+/// # Safety
+///
+/// Every bit pattern must be a valid value.
+unsafe trait Pod: Copy + 'static {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for u8 {}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+struct Extended(u32);
+#[cfg(feature = "rand")]
+unsafe impl Pod for Extended {}
+
+// Never implements the check trait in integration tests
+#[allow(dead_code)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Release(u32);
+#[cfg(not(test))]
+unsafe impl Pod for Release {}
+
+#[struct_layout::explicit(size = 16, align = 4, check(Pod))]
+#[derive(Copy, Clone, Debug, Default)]
+struct Player {
+	#[field(offset = 0)]
+	health: u32,
+	#[field(offset = 4, check_cfg(feature = "rand"))]
+	extended: Extended,
+	#[field(offset = 8, get, set, check_cfg(not(test)))]
+	release: Release,
+	#[field(offset = 12, check_cfg(all()))]
+	alive: bool,
+}
+
+#[struct_layout::explicit(size = 8, align = 4, check(Pod), compact)]
+#[derive(Copy, Clone, Default)]
+struct Compact {
+	#[field(offset = 0)]
+	health: u32,
+	#[field(offset = 4, check_cfg(not(test)))]
+	release: Release,
+}
+
+#[test]
+fn present() {
+	let mut player = Player::default();
+	player.set_health(5).set_alive(true);
+	assert_eq!((player.health(), player.alive()), (5, true));
+	let mut compact = Compact::default();
+	compact.set_health(3);
+	assert_eq!(compact.health(), 3);
+}
+
+#[test]
+fn derives_follow_cfg() {
+	let mut player = Player::default();
+	player.set_alive(true);
+	let debug = format!("{:?}", player);
+	if cfg!(feature = "rand") {
+		assert_eq!(debug, "Player { health: 0, extended: Extended(0), alive: true }");
+	}
+	else {
+		assert_eq!(debug, "Player { health: 0, alive: true }");
+	}
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn present_with_feature() {
+	let mut player = Player::default();
+	player.set_extended(Extended(7));
+	*player.extended_mut() = Extended(8);
+	assert_eq!(player.extended(), Extended(8));
+	assert_eq!(&player.0[4..8], &8u32.to_ne_bytes());
+}
@@ -0,0 +1,54 @@
+// Stands in for a structure of another crate, eg. bindgen output
+mod bindings {
+	#[repr(C)]
+	pub struct Packet {
+		pub seq: u16,
+		pub payload_len: u16,
+		pub flags: u32,
+		pub payload: [u8; 56],
+	}
+
+	#[repr(C, packed)]
+	pub struct Header {
+		pub kind: u8,
+		pub len: u32,
+	}
+
+	#[repr(C)]
+	pub struct Pair<T> {
+		pub first: T,
+		pub second: T,
+	}
+}
+
+struct_layout::check_layout! {
+	bindings::Packet: size = 0x40, align = 4, fields {
+		seq: u16 @ 0x00,
+		payload_len: u16 @ 0x02,
+		flags: u32 @ 0x04,
+		payload: [u8; 56] @ 0x08,
+	}
+}
+
+// Fields may be listed partially and the size and alignment may be omitted
+struct_layout::check_layout! {
+	bindings::Header: align = 1, fields { len: u32 @ 1 }
+}
+
+struct_layout::check_layout! {
+	bindings::Pair<u64>: size = 16, fields { second: u64 @ 8 }
+}
+
+struct_layout::check_layout! {
+	::std::num::Wrapping<u32>: size = 4, align = 4
+}
+
+#[test]
+fn matching() {
+	let packet = bindings::Packet { seq: 1, payload_len: 2, flags: 3, payload: [0; 56] };
+	assert_eq!(packet.seq + packet.payload_len + packet.flags as u16 + packet.payload[0] as u16, 6);
+	let header = bindings::Header { kind: 0, len: 0 };
+	assert_eq!({ header.kind } as u32 + { header.len }, 0);
+	let pair = bindings::Pair { first: 1u64, second: 2 };
+	assert_eq!(pair.first + pair.second, 3);
+}
@@ -0,0 +1,41 @@
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Record {
+	#[field(offset = 0)]
+	data: [u8; 9],
+	#[field(offset = 12, checksum(crc32, range = 0..9))]
+	crc: u32,
+}
+
+#[test]
+fn crc32() {
+	let mut record = Record::default();
+	record.set_data(*b"123456789");
+	assert!(!record.verify_crc());
+	record.update_crc();
+	assert_eq!(record.crc(), 0xCBF43926);
+	assert!(record.verify_crc());
+
+	record.set_data_at(0, b'0');
+	assert!(!record.verify_crc());
+}
+
+#[struct_layout::explicit(size = 8, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Packet {
+	#[field(offset = 0)]
+	kind: u8,
+	#[field(offset = 2)]
+	len: u16,
+	#[field(offset = 4, checksum(sum, range = 0..4, auto))]
+	sum: u32,
+}
+
+#[test]
+fn auto() {
+	let mut packet = Packet::default();
+	packet.set_kind(3).set_len(0x0101);
+	assert_eq!(packet.sum(), 5);
+	assert!(packet.verify_sum());
+}
@@ -0,0 +1,55 @@
+
+#[struct_layout::explicit(size = 12, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Vertex {
+	#[field(offset = 0, column)]
+	int: i32,
+	#[field(offset = 4, column)]
+	weight: f32,
+	#[field(offset = 8, get, column)]
+	flags: u16,
+}
+
+fn buffer() -> [u8; 36] {
+	let mut buf = [0u8; 36];
+	for i in 0..3 {
+		buf[i * 12..i * 12 + 4].copy_from_slice(&(i as i32 * 10).to_ne_bytes());
+		buf[i * 12 + 4..i * 12 + 8].copy_from_slice(&(i as f32 + 0.5).to_ne_bytes());
+		buf[i * 12 + 8..i * 12 + 10].copy_from_slice(&(i as u16 + 1).to_ne_bytes());
+	}
+	buf
+}
+
+#[test]
+fn iter() {
+	let buf = buffer();
+	assert_eq!(Vertex::int_iter(&buf).collect::<Vec<_>>(), [0, 10, 20]);
+	assert_eq!(Vertex::weight_iter(&buf).collect::<Vec<_>>(), [0.5, 1.5, 2.5]);
+	assert_eq!(Vertex::flags_iter(&buf).collect::<Vec<_>>(), [1, 2, 3]);
+	// Records are only visited while the field is in bounds
+	assert_eq!(Vertex::int_iter(&buf[..28]).count(), 3);
+	assert_eq!(Vertex::int_iter(&buf[..27]).count(), 2);
+	assert_eq!(Vertex::flags_iter(&buf[..33]).count(), 2);
+	assert_eq!(Vertex::int_iter(&buf[..3]).count(), 0);
+	// The buffer does not need to be aligned
+	assert_eq!(Vertex::int_iter(&buffer_at(1)[1..]).collect::<Vec<_>>(), [0, 10, 20]);
+}
+
+fn buffer_at(shift: usize) -> [u8; 40] {
+	let mut buf = [0u8; 40];
+	buf[shift..shift + 36].copy_from_slice(&buffer());
+	buf
+}
+
+#[test]
+fn write_iter() {
+	let mut buf = buffer();
+	// Patch the middle record only
+	Vertex::int_write_iter(&mut buf[12..24], [-1]);
+	assert_eq!(Vertex::int_iter(&buf).collect::<Vec<_>>(), [0, -1, 20]);
+	assert_eq!(Vertex::weight_iter(&buf).collect::<Vec<_>>(), [0.5, 1.5, 2.5]);
+	// Extra values are ignored
+	Vertex::weight_write_iter(&mut buf, (0..10).map(|i| i as f32));
+	assert_eq!(Vertex::weight_iter(&buf).collect::<Vec<_>>(), [0.0, 1.0, 2.0]);
+	assert_eq!(Vertex::int_iter(&buf).collect::<Vec<_>>(), [0, -1, 20]);
+}
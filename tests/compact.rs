@@ -0,0 +1,337 @@
+
+/// Plain old data.
+///
+/// # Safety
+///
+/// Every bit pattern must be a valid value.
+pub unsafe trait Pod: Copy + 'static {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for f32 {}
+
+// The check bound is asserted once per field instead of on every accessor
+#[struct_layout::explicit(size = 1200, align = 4, check(Pod), compact)]
+#[derive(Copy, Clone, Default)]
+struct Compact {
+	#[field(offset = 0, get, set, ref, mut)] f000: u32,
+	#[field(offset = 4, get, set, ref, mut)] f001: f32,
+	#[field(offset = 8, get, set, ref, mut)] f002: u32,
+	#[field(offset = 12, get, set, ref, mut)] f003: f32,
+	#[field(offset = 16, get, set, ref, mut)] f004: u32,
+	#[field(offset = 20, get, set, ref, mut)] f005: f32,
+	#[field(offset = 24, get, set, ref, mut)] f006: u32,
+	#[field(offset = 28, get, set, ref, mut)] f007: f32,
+	#[field(offset = 32, get, set, ref, mut)] f008: u32,
+	#[field(offset = 36, get, set, ref, mut)] f009: f32,
+	#[field(offset = 40, get, set, ref, mut)] f010: u32,
+	#[field(offset = 44, get, set, ref, mut)] f011: f32,
+	#[field(offset = 48, get, set, ref, mut)] f012: u32,
+	#[field(offset = 52, get, set, ref, mut)] f013: f32,
+	#[field(offset = 56, get, set, ref, mut)] f014: u32,
+	#[field(offset = 60, get, set, ref, mut)] f015: f32,
+	#[field(offset = 64, get, set, ref, mut)] f016: u32,
+	#[field(offset = 68, get, set, ref, mut)] f017: f32,
+	#[field(offset = 72, get, set, ref, mut)] f018: u32,
+	#[field(offset = 76, get, set, ref, mut)] f019: f32,
+	#[field(offset = 80, get, set, ref, mut)] f020: u32,
+	#[field(offset = 84, get, set, ref, mut)] f021: f32,
+	#[field(offset = 88, get, set, ref, mut)] f022: u32,
+	#[field(offset = 92, get, set, ref, mut)] f023: f32,
+	#[field(offset = 96, get, set, ref, mut)] f024: u32,
+	#[field(offset = 100, get, set, ref, mut)] f025: f32,
+	#[field(offset = 104, get, set, ref, mut)] f026: u32,
+	#[field(offset = 108, get, set, ref, mut)] f027: f32,
+	#[field(offset = 112, get, set, ref, mut)] f028: u32,
+	#[field(offset = 116, get, set, ref, mut)] f029: f32,
+	#[field(offset = 120, get, set, ref, mut)] f030: u32,
+	#[field(offset = 124, get, set, ref, mut)] f031: f32,
+	#[field(offset = 128, get, set, ref, mut)] f032: u32,
+	#[field(offset = 132, get, set, ref, mut)] f033: f32,
+	#[field(offset = 136, get, set, ref, mut)] f034: u32,
+	#[field(offset = 140, get, set, ref, mut)] f035: f32,
+	#[field(offset = 144, get, set, ref, mut)] f036: u32,
+	#[field(offset = 148, get, set, ref, mut)] f037: f32,
+	#[field(offset = 152, get, set, ref, mut)] f038: u32,
+	#[field(offset = 156, get, set, ref, mut)] f039: f32,
+	#[field(offset = 160, get, set, ref, mut)] f040: u32,
+	#[field(offset = 164, get, set, ref, mut)] f041: f32,
+	#[field(offset = 168, get, set, ref, mut)] f042: u32,
+	#[field(offset = 172, get, set, ref, mut)] f043: f32,
+	#[field(offset = 176, get, set, ref, mut)] f044: u32,
+	#[field(offset = 180, get, set, ref, mut)] f045: f32,
+	#[field(offset = 184, get, set, ref, mut)] f046: u32,
+	#[field(offset = 188, get, set, ref, mut)] f047: f32,
+	#[field(offset = 192, get, set, ref, mut)] f048: u32,
+	#[field(offset = 196, get, set, ref, mut)] f049: f32,
+	#[field(offset = 200, get, set, ref, mut)] f050: u32,
+	#[field(offset = 204, get, set, ref, mut)] f051: f32,
+	#[field(offset = 208, get, set, ref, mut)] f052: u32,
+	#[field(offset = 212, get, set, ref, mut)] f053: f32,
+	#[field(offset = 216, get, set, ref, mut)] f054: u32,
+	#[field(offset = 220, get, set, ref, mut)] f055: f32,
+	#[field(offset = 224, get, set, ref, mut)] f056: u32,
+	#[field(offset = 228, get, set, ref, mut)] f057: f32,
+	#[field(offset = 232, get, set, ref, mut)] f058: u32,
+	#[field(offset = 236, get, set, ref, mut)] f059: f32,
+	#[field(offset = 240, get, set, ref, mut)] f060: u32,
+	#[field(offset = 244, get, set, ref, mut)] f061: f32,
+	#[field(offset = 248, get, set, ref, mut)] f062: u32,
+	#[field(offset = 252, get, set, ref, mut)] f063: f32,
+	#[field(offset = 256, get, set, ref, mut)] f064: u32,
+	#[field(offset = 260, get, set, ref, mut)] f065: f32,
+	#[field(offset = 264, get, set, ref, mut)] f066: u32,
+	#[field(offset = 268, get, set, ref, mut)] f067: f32,
+	#[field(offset = 272, get, set, ref, mut)] f068: u32,
+	#[field(offset = 276, get, set, ref, mut)] f069: f32,
+	#[field(offset = 280, get, set, ref, mut)] f070: u32,
+	#[field(offset = 284, get, set, ref, mut)] f071: f32,
+	#[field(offset = 288, get, set, ref, mut)] f072: u32,
+	#[field(offset = 292, get, set, ref, mut)] f073: f32,
+	#[field(offset = 296, get, set, ref, mut)] f074: u32,
+	#[field(offset = 300, get, set, ref, mut)] f075: f32,
+	#[field(offset = 304, get, set, ref, mut)] f076: u32,
+	#[field(offset = 308, get, set, ref, mut)] f077: f32,
+	#[field(offset = 312, get, set, ref, mut)] f078: u32,
+	#[field(offset = 316, get, set, ref, mut)] f079: f32,
+	#[field(offset = 320, get, set, ref, mut)] f080: u32,
+	#[field(offset = 324, get, set, ref, mut)] f081: f32,
+	#[field(offset = 328, get, set, ref, mut)] f082: u32,
+	#[field(offset = 332, get, set, ref, mut)] f083: f32,
+	#[field(offset = 336, get, set, ref, mut)] f084: u32,
+	#[field(offset = 340, get, set, ref, mut)] f085: f32,
+	#[field(offset = 344, get, set, ref, mut)] f086: u32,
+	#[field(offset = 348, get, set, ref, mut)] f087: f32,
+	#[field(offset = 352, get, set, ref, mut)] f088: u32,
+	#[field(offset = 356, get, set, ref, mut)] f089: f32,
+	#[field(offset = 360, get, set, ref, mut)] f090: u32,
+	#[field(offset = 364, get, set, ref, mut)] f091: f32,
+	#[field(offset = 368, get, set, ref, mut)] f092: u32,
+	#[field(offset = 372, get, set, ref, mut)] f093: f32,
+	#[field(offset = 376, get, set, ref, mut)] f094: u32,
+	#[field(offset = 380, get, set, ref, mut)] f095: f32,
+	#[field(offset = 384, get, set, ref, mut)] f096: u32,
+	#[field(offset = 388, get, set, ref, mut)] f097: f32,
+	#[field(offset = 392, get, set, ref, mut)] f098: u32,
+	#[field(offset = 396, get, set, ref, mut)] f099: f32,
+	#[field(offset = 400, get, set, ref, mut)] f100: u32,
+	#[field(offset = 404, get, set, ref, mut)] f101: f32,
+	#[field(offset = 408, get, set, ref, mut)] f102: u32,
+	#[field(offset = 412, get, set, ref, mut)] f103: f32,
+	#[field(offset = 416, get, set, ref, mut)] f104: u32,
+	#[field(offset = 420, get, set, ref, mut)] f105: f32,
+	#[field(offset = 424, get, set, ref, mut)] f106: u32,
+	#[field(offset = 428, get, set, ref, mut)] f107: f32,
+	#[field(offset = 432, get, set, ref, mut)] f108: u32,
+	#[field(offset = 436, get, set, ref, mut)] f109: f32,
+	#[field(offset = 440, get, set, ref, mut)] f110: u32,
+	#[field(offset = 444, get, set, ref, mut)] f111: f32,
+	#[field(offset = 448, get, set, ref, mut)] f112: u32,
+	#[field(offset = 452, get, set, ref, mut)] f113: f32,
+	#[field(offset = 456, get, set, ref, mut)] f114: u32,
+	#[field(offset = 460, get, set, ref, mut)] f115: f32,
+	#[field(offset = 464, get, set, ref, mut)] f116: u32,
+	#[field(offset = 468, get, set, ref, mut)] f117: f32,
+	#[field(offset = 472, get, set, ref, mut)] f118: u32,
+	#[field(offset = 476, get, set, ref, mut)] f119: f32,
+	#[field(offset = 480, get, set, ref, mut)] f120: u32,
+	#[field(offset = 484, get, set, ref, mut)] f121: f32,
+	#[field(offset = 488, get, set, ref, mut)] f122: u32,
+	#[field(offset = 492, get, set, ref, mut)] f123: f32,
+	#[field(offset = 496, get, set, ref, mut)] f124: u32,
+	#[field(offset = 500, get, set, ref, mut)] f125: f32,
+	#[field(offset = 504, get, set, ref, mut)] f126: u32,
+	#[field(offset = 508, get, set, ref, mut)] f127: f32,
+	#[field(offset = 512, get, set, ref, mut)] f128: u32,
+	#[field(offset = 516, get, set, ref, mut)] f129: f32,
+	#[field(offset = 520, get, set, ref, mut)] f130: u32,
+	#[field(offset = 524, get, set, ref, mut)] f131: f32,
+	#[field(offset = 528, get, set, ref, mut)] f132: u32,
+	#[field(offset = 532, get, set, ref, mut)] f133: f32,
+	#[field(offset = 536, get, set, ref, mut)] f134: u32,
+	#[field(offset = 540, get, set, ref, mut)] f135: f32,
+	#[field(offset = 544, get, set, ref, mut)] f136: u32,
+	#[field(offset = 548, get, set, ref, mut)] f137: f32,
+	#[field(offset = 552, get, set, ref, mut)] f138: u32,
+	#[field(offset = 556, get, set, ref, mut)] f139: f32,
+	#[field(offset = 560, get, set, ref, mut)] f140: u32,
+	#[field(offset = 564, get, set, ref, mut)] f141: f32,
+	#[field(offset = 568, get, set, ref, mut)] f142: u32,
+	#[field(offset = 572, get, set, ref, mut)] f143: f32,
+	#[field(offset = 576, get, set, ref, mut)] f144: u32,
+	#[field(offset = 580, get, set, ref, mut)] f145: f32,
+	#[field(offset = 584, get, set, ref, mut)] f146: u32,
+	#[field(offset = 588, get, set, ref, mut)] f147: f32,
+	#[field(offset = 592, get, set, ref, mut)] f148: u32,
+	#[field(offset = 596, get, set, ref, mut)] f149: f32,
+	#[field(offset = 600, get, set, ref, mut)] f150: u32,
+	#[field(offset = 604, get, set, ref, mut)] f151: f32,
+	#[field(offset = 608, get, set, ref, mut)] f152: u32,
+	#[field(offset = 612, get, set, ref, mut)] f153: f32,
+	#[field(offset = 616, get, set, ref, mut)] f154: u32,
+	#[field(offset = 620, get, set, ref, mut)] f155: f32,
+	#[field(offset = 624, get, set, ref, mut)] f156: u32,
+	#[field(offset = 628, get, set, ref, mut)] f157: f32,
+	#[field(offset = 632, get, set, ref, mut)] f158: u32,
+	#[field(offset = 636, get, set, ref, mut)] f159: f32,
+	#[field(offset = 640, get, set, ref, mut)] f160: u32,
+	#[field(offset = 644, get, set, ref, mut)] f161: f32,
+	#[field(offset = 648, get, set, ref, mut)] f162: u32,
+	#[field(offset = 652, get, set, ref, mut)] f163: f32,
+	#[field(offset = 656, get, set, ref, mut)] f164: u32,
+	#[field(offset = 660, get, set, ref, mut)] f165: f32,
+	#[field(offset = 664, get, set, ref, mut)] f166: u32,
+	#[field(offset = 668, get, set, ref, mut)] f167: f32,
+	#[field(offset = 672, get, set, ref, mut)] f168: u32,
+	#[field(offset = 676, get, set, ref, mut)] f169: f32,
+	#[field(offset = 680, get, set, ref, mut)] f170: u32,
+	#[field(offset = 684, get, set, ref, mut)] f171: f32,
+	#[field(offset = 688, get, set, ref, mut)] f172: u32,
+	#[field(offset = 692, get, set, ref, mut)] f173: f32,
+	#[field(offset = 696, get, set, ref, mut)] f174: u32,
+	#[field(offset = 700, get, set, ref, mut)] f175: f32,
+	#[field(offset = 704, get, set, ref, mut)] f176: u32,
+	#[field(offset = 708, get, set, ref, mut)] f177: f32,
+	#[field(offset = 712, get, set, ref, mut)] f178: u32,
+	#[field(offset = 716, get, set, ref, mut)] f179: f32,
+	#[field(offset = 720, get, set, ref, mut)] f180: u32,
+	#[field(offset = 724, get, set, ref, mut)] f181: f32,
+	#[field(offset = 728, get, set, ref, mut)] f182: u32,
+	#[field(offset = 732, get, set, ref, mut)] f183: f32,
+	#[field(offset = 736, get, set, ref, mut)] f184: u32,
+	#[field(offset = 740, get, set, ref, mut)] f185: f32,
+	#[field(offset = 744, get, set, ref, mut)] f186: u32,
+	#[field(offset = 748, get, set, ref, mut)] f187: f32,
+	#[field(offset = 752, get, set, ref, mut)] f188: u32,
+	#[field(offset = 756, get, set, ref, mut)] f189: f32,
+	#[field(offset = 760, get, set, ref, mut)] f190: u32,
+	#[field(offset = 764, get, set, ref, mut)] f191: f32,
+	#[field(offset = 768, get, set, ref, mut)] f192: u32,
+	#[field(offset = 772, get, set, ref, mut)] f193: f32,
+	#[field(offset = 776, get, set, ref, mut)] f194: u32,
+	#[field(offset = 780, get, set, ref, mut)] f195: f32,
+	#[field(offset = 784, get, set, ref, mut)] f196: u32,
+	#[field(offset = 788, get, set, ref, mut)] f197: f32,
+	#[field(offset = 792, get, set, ref, mut)] f198: u32,
+	#[field(offset = 796, get, set, ref, mut)] f199: f32,
+	#[field(offset = 800, get, set, ref, mut)] f200: u32,
+	#[field(offset = 804, get, set, ref, mut)] f201: f32,
+	#[field(offset = 808, get, set, ref, mut)] f202: u32,
+	#[field(offset = 812, get, set, ref, mut)] f203: f32,
+	#[field(offset = 816, get, set, ref, mut)] f204: u32,
+	#[field(offset = 820, get, set, ref, mut)] f205: f32,
+	#[field(offset = 824, get, set, ref, mut)] f206: u32,
+	#[field(offset = 828, get, set, ref, mut)] f207: f32,
+	#[field(offset = 832, get, set, ref, mut)] f208: u32,
+	#[field(offset = 836, get, set, ref, mut)] f209: f32,
+	#[field(offset = 840, get, set, ref, mut)] f210: u32,
+	#[field(offset = 844, get, set, ref, mut)] f211: f32,
+	#[field(offset = 848, get, set, ref, mut)] f212: u32,
+	#[field(offset = 852, get, set, ref, mut)] f213: f32,
+	#[field(offset = 856, get, set, ref, mut)] f214: u32,
+	#[field(offset = 860, get, set, ref, mut)] f215: f32,
+	#[field(offset = 864, get, set, ref, mut)] f216: u32,
+	#[field(offset = 868, get, set, ref, mut)] f217: f32,
+	#[field(offset = 872, get, set, ref, mut)] f218: u32,
+	#[field(offset = 876, get, set, ref, mut)] f219: f32,
+	#[field(offset = 880, get, set, ref, mut)] f220: u32,
+	#[field(offset = 884, get, set, ref, mut)] f221: f32,
+	#[field(offset = 888, get, set, ref, mut)] f222: u32,
+	#[field(offset = 892, get, set, ref, mut)] f223: f32,
+	#[field(offset = 896, get, set, ref, mut)] f224: u32,
+	#[field(offset = 900, get, set, ref, mut)] f225: f32,
+	#[field(offset = 904, get, set, ref, mut)] f226: u32,
+	#[field(offset = 908, get, set, ref, mut)] f227: f32,
+	#[field(offset = 912, get, set, ref, mut)] f228: u32,
+	#[field(offset = 916, get, set, ref, mut)] f229: f32,
+	#[field(offset = 920, get, set, ref, mut)] f230: u32,
+	#[field(offset = 924, get, set, ref, mut)] f231: f32,
+	#[field(offset = 928, get, set, ref, mut)] f232: u32,
+	#[field(offset = 932, get, set, ref, mut)] f233: f32,
+	#[field(offset = 936, get, set, ref, mut)] f234: u32,
+	#[field(offset = 940, get, set, ref, mut)] f235: f32,
+	#[field(offset = 944, get, set, ref, mut)] f236: u32,
+	#[field(offset = 948, get, set, ref, mut)] f237: f32,
+	#[field(offset = 952, get, set, ref, mut)] f238: u32,
+	#[field(offset = 956, get, set, ref, mut)] f239: f32,
+	#[field(offset = 960, get, set, ref, mut)] f240: u32,
+	#[field(offset = 964, get, set, ref, mut)] f241: f32,
+	#[field(offset = 968, get, set, ref, mut)] f242: u32,
+	#[field(offset = 972, get, set, ref, mut)] f243: f32,
+	#[field(offset = 976, get, set, ref, mut)] f244: u32,
+	#[field(offset = 980, get, set, ref, mut)] f245: f32,
+	#[field(offset = 984, get, set, ref, mut)] f246: u32,
+	#[field(offset = 988, get, set, ref, mut)] f247: f32,
+	#[field(offset = 992, get, set, ref, mut)] f248: u32,
+	#[field(offset = 996, get, set, ref, mut)] f249: f32,
+	#[field(offset = 1000, get, set, ref, mut)] f250: u32,
+	#[field(offset = 1004, get, set, ref, mut)] f251: f32,
+	#[field(offset = 1008, get, set, ref, mut)] f252: u32,
+	#[field(offset = 1012, get, set, ref, mut)] f253: f32,
+	#[field(offset = 1016, get, set, ref, mut)] f254: u32,
+	#[field(offset = 1020, get, set, ref, mut)] f255: f32,
+	#[field(offset = 1024, get, set, ref, mut)] f256: u32,
+	#[field(offset = 1028, get, set, ref, mut)] f257: f32,
+	#[field(offset = 1032, get, set, ref, mut)] f258: u32,
+	#[field(offset = 1036, get, set, ref, mut)] f259: f32,
+	#[field(offset = 1040, get, set, ref, mut)] f260: u32,
+	#[field(offset = 1044, get, set, ref, mut)] f261: f32,
+	#[field(offset = 1048, get, set, ref, mut)] f262: u32,
+	#[field(offset = 1052, get, set, ref, mut)] f263: f32,
+	#[field(offset = 1056, get, set, ref, mut)] f264: u32,
+	#[field(offset = 1060, get, set, ref, mut)] f265: f32,
+	#[field(offset = 1064, get, set, ref, mut)] f266: u32,
+	#[field(offset = 1068, get, set, ref, mut)] f267: f32,
+	#[field(offset = 1072, get, set, ref, mut)] f268: u32,
+	#[field(offset = 1076, get, set, ref, mut)] f269: f32,
+	#[field(offset = 1080, get, set, ref, mut)] f270: u32,
+	#[field(offset = 1084, get, set, ref, mut)] f271: f32,
+	#[field(offset = 1088, get, set, ref, mut)] f272: u32,
+	#[field(offset = 1092, get, set, ref, mut)] f273: f32,
+	#[field(offset = 1096, get, set, ref, mut)] f274: u32,
+	#[field(offset = 1100, get, set, ref, mut)] f275: f32,
+	#[field(offset = 1104, get, set, ref, mut)] f276: u32,
+	#[field(offset = 1108, get, set, ref, mut)] f277: f32,
+	#[field(offset = 1112, get, set, ref, mut)] f278: u32,
+	#[field(offset = 1116, get, set, ref, mut)] f279: f32,
+	#[field(offset = 1120, get, set, ref, mut)] f280: u32,
+	#[field(offset = 1124, get, set, ref, mut)] f281: f32,
+	#[field(offset = 1128, get, set, ref, mut)] f282: u32,
+	#[field(offset = 1132, get, set, ref, mut)] f283: f32,
+	#[field(offset = 1136, get, set, ref, mut)] f284: u32,
+	#[field(offset = 1140, get, set, ref, mut)] f285: f32,
+	#[field(offset = 1144, get, set, ref, mut)] f286: u32,
+	#[field(offset = 1148, get, set, ref, mut)] f287: f32,
+	#[field(offset = 1152, get, set, ref, mut)] f288: u32,
+	#[field(offset = 1156, get, set, ref, mut)] f289: f32,
+	#[field(offset = 1160, get, set, ref, mut)] f290: u32,
+	#[field(offset = 1164, get, set, ref, mut)] f291: f32,
+	#[field(offset = 1168, get, set, ref, mut)] f292: u32,
+	#[field(offset = 1172, get, set, ref, mut)] f293: f32,
+	#[field(offset = 1176, get, set, ref, mut)] f294: u32,
+	#[field(offset = 1180, get, set, ref, mut)] f295: f32,
+	#[field(offset = 1184, get, set, ref, mut)] f296: u32,
+	#[field(offset = 1188, get, set, ref, mut)] f297: f32,
+	#[field(offset = 1192, get, set, ref, mut)] f298: u32,
+	#[field(offset = 1196, get, set, ref, mut)] f299: f32,
+}
+
+#[struct_layout::explicit(size = 16, align = 4, compact)]
+#[derive(Copy, Clone, Default)]
+struct Defaults {
+	#[field(offset = 0)]
+	int: i32,
+	#[field(offset = 4, ref, mut)]
+	bytes: [u8; 12],
+}
+
+#[test]
+fn compact() {
+	let mut compact = Compact::default();
+	compact.set_f000(1).set_f151(2.5).set_f298(3);
+	*compact.f099_mut() = 4.0;
+	assert_eq!((compact.f000(), compact.f151(), compact.f298(), *compact.f099_ref()), (1, 2.5, 3, 4.0));
+
+	let mut defaults = Defaults::default();
+	defaults.set_int(-1).bytes_mut()[11] = 5;
+	assert_eq!(defaults.int(), -1);
+	assert_eq!(defaults.bytes_ref()[11], 5);
+}
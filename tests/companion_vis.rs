@@ -0,0 +1,92 @@
+
+mod shapes {
+	#[struct_layout::explicit(size = 8, align = 4, consts_mod = point_layout, field_enum)]
+	#[derive(Copy, Clone, Default)]
+	pub struct Point {
+		#[field(offset = 0, get, set)]
+		pub x: i32,
+		#[field(offset = 4, get, set)]
+		pub y: i32,
+	}
+
+	#[struct_layout::explicit(size = 8, align = 4, consts_mod = size_layout, field_enum, mirror, companion_vis = pub(crate))]
+	#[derive(Copy, Clone, Default)]
+	pub struct Size {
+		#[field(offset = 0, get, set)]
+		pub width: u32,
+		#[field(offset = 4, get, set)]
+		pub height: u32,
+	}
+
+	#[struct_layout::explicit(size = 4, align = 4, consts_mod = id_layout)]
+	#[derive(Copy, Clone, Default)]
+	pub(crate) struct Id {
+		#[field(offset = 0, get, set)]
+		pub(crate) value: u32,
+	}
+
+	// Layout of a regular structure with a restricted offset constant
+	#[derive(struct_layout::ExplicitLayout)]
+	#[layout(size = 8, align = 4, companion_vis = pub(crate))]
+	#[repr(C)]
+	pub struct Range {
+		#[field(offset = 0)]
+		pub start: u32,
+		#[field(offset = 4)]
+		pub end: u32,
+	}
+
+	// Companions documented as requiring the alloc feature on docs.rs
+	#[struct_layout::explicit(size = 8, align = 4, consts_mod = color_layout, field_enum, doc_cfg(feature = "alloc"))]
+	#[derive(Copy, Clone, Default)]
+	pub struct Color {
+		#[field(offset = 0, get, set(feature = "alloc"))]
+		pub rgba: u32,
+		#[field(offset = 4, get, set)]
+		pub depth: f32,
+	}
+}
+
+use self::shapes::*;
+
+#[test]
+fn default_vis() {
+	assert_eq!(point_layout::X_OFFSET, 0);
+	assert_eq!(point_layout::Y_OFFSET, 4);
+	assert_eq!(PointField::Y.offset(), 4);
+	let mut point = Point::default();
+	point.set_y(-3);
+	assert!(matches!(point.read_dyn(PointField::Y), PointValue::Y(-3)));
+}
+
+#[test]
+fn crate_vis() {
+	assert_eq!(size_layout::HEIGHT_OFFSET, 4);
+	assert_eq!(size_layout::SIZE, 8);
+	assert_eq!((Size::SIZE, Size::ALIGN, Size::HEIGHT_OFFSET), (8, 4, 4));
+	assert_eq!(SizeField::ALL, [SizeField::Width, SizeField::Height]);
+	let mut size = Size::default();
+	size.set_width(640).set_height(480);
+	let values = SizeValues::from(&size);
+	assert_eq!((values.width, values.height), (640, 480));
+	assert_eq!(Range::START_OFFSET, 0);
+	assert_eq!(Range::END_OFFSET, 4);
+}
+
+#[test]
+fn restricted_struct() {
+	let mut id = Id::default();
+	id.set_value(7);
+	assert_eq!(id.value(), 7);
+	assert_eq!(id_layout::VALUE_OFFSET, 0);
+}
+
+#[test]
+fn doc_cfg() {
+	assert_eq!(color_layout::DEPTH_OFFSET, 4);
+	assert_eq!(ColorField::Depth.offset(), 4);
+	let mut color = Color::default();
+	color.set_rgba(0xff00ff00).set_depth(0.5);
+	assert_eq!(color.rgba(), 0xff00ff00);
+	assert!(matches!(color.read_dyn(ColorField::Depth), ColorValue::Depth(depth) if depth == 0.5));
+}
@@ -0,0 +1,111 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::mem;
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Key {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 6)]
+	kind: u8,
+	#[field(offset = 8, set)]
+	scratch: u32,
+	#[field(offset = 12)]
+	valid: bool,
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	value.hash(&mut hasher);
+	hasher.finish()
+}
+
+fn with_padding(key: Key, garbage: u8) -> Key {
+	let mut bytes = unsafe { mem::transmute::<Key, [u8; 16]>(key) };
+	bytes[4] = garbage;
+	bytes[5] = garbage;
+	bytes[7] = garbage;
+	bytes[13..].copy_from_slice(&[garbage; 3]);
+	unsafe { mem::transmute::<[u8; 16], Key>(bytes) }
+}
+
+#[test]
+fn padding_is_ignored() {
+	let mut key = Key::default();
+	key.set_id(7).set_kind(2).set_valid(true);
+	let a = with_padding(key, 0x00);
+	let b = with_padding(key, 0xa5);
+	assert_ne!(unsafe { mem::transmute::<Key, [u8; 16]>(a) }, unsafe { mem::transmute::<Key, [u8; 16]>(b) });
+	assert_eq!(a, b);
+	assert_eq!(hash_of(&a), hash_of(&b));
+	assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+
+	let mut map = HashMap::new();
+	map.insert(a, "a");
+	assert_eq!(map.get(&b), Some(&"a"));
+}
+
+#[test]
+fn fields_without_getter_are_skipped() {
+	let mut a = Key::default();
+	let mut b = Key::default();
+	a.set_scratch(1);
+	b.set_scratch(2);
+	assert_eq!(a, b);
+	assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn declaration_order() {
+	let mut keys = Vec::new();
+	for &(id, kind) in &[(2, 0), (1, 9), (1, 3)] {
+		let mut key = Key::default();
+		key.set_id(id).set_kind(kind);
+		keys.push(key);
+	}
+	keys.sort();
+	let sorted: Vec<(u32, u8)> = keys.iter().map(|key| (key.id(), key.kind())).collect();
+	assert_eq!(sorted, [(1, 3), (1, 9), (2, 0)]);
+	assert!(keys[0] < keys[1]);
+	assert_ne!(keys[0], keys[1]);
+}
+
+#[struct_layout::explicit(size = 8, align = 4)]
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd)]
+struct Point {
+	#[field(offset = 0)]
+	x: f32,
+	#[field(offset = 4)]
+	y: f32,
+}
+
+#[test]
+fn partial_order() {
+	let mut a = Point::default();
+	let mut b = Point::default();
+	a.set_x(1.0);
+	b.set_x(f32::NAN);
+	assert!(a != b);
+	assert_eq!(a.partial_cmp(&b), None);
+	b.set_x(1.0).set_y(2.0);
+	assert!(a < b);
+}
+
+#[struct_layout::union_layout(size = 4, align = 4)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct Word {
+	int: u32,
+	float: f32,
+}
+
+#[test]
+fn union_bytes() {
+	let mut a: Word = unsafe { mem::zeroed() };
+	let mut b: Word = unsafe { mem::zeroed() };
+	a.set_int(1.0f32.to_bits());
+	b.set_float(1.0);
+	assert!(a == b);
+	assert_eq!(hash_of(&a), hash_of(&b));
+}
@@ -0,0 +1,51 @@
+
+#[struct_layout::explicit(size = 12, align = 4, const_fn, bytes)]
+#[derive(Copy, Clone)]
+struct Header {
+	#[field(offset = 0, get)]
+	magic: [u8; 4],
+	#[field(offset = 4, get)]
+	version: u16,
+	#[field(offset = 8, get)]
+	count: u32,
+}
+
+const HEADER: Header = Header::from_bytes(*include_bytes!("const_fn/header.bin"));
+const MAGIC: [u8; 4] = HEADER.magic();
+const VERSION: u16 = u16::from_le(HEADER.version());
+const COUNT: u32 = u32::from_le(HEADER.count());
+
+#[test]
+fn const_getters() {
+	assert_eq!(&MAGIC, b"SLAY");
+	assert_eq!(VERSION, 0x0102);
+	assert_eq!(COUNT, 7);
+}
+
+#[struct_layout::explicit(size = 8, align = 8, storage = u64, send = false, bytes)]
+struct Wide {
+	#[field(offset = 4, get)]
+	value: u32,
+}
+
+#[test]
+fn from_bytes() {
+	let wide = Wide::from_bytes([0, 0, 0, 0, 1, 2, 3, 4]);
+	assert_eq!(wide.value(), u32::from_ne_bytes([1, 2, 3, 4]));
+}
+
+#[struct_layout::explicit(size = 4, align = 4, const_fn, bytes)]
+#[derive(Copy, Clone)]
+struct Limit {
+	#[field(offset = 0, get)]
+	max: core::num::NonZeroU32,
+}
+
+// Zero is not a valid NonZeroU32, creating the structure from bytes requires unsafe code in const contexts as well
+const LIMIT: Limit = unsafe { Limit::from_bytes(16u32.to_ne_bytes()) };
+const MAX: core::num::NonZeroU32 = LIMIT.max();
+
+#[test]
+fn unsafe_from_bytes() {
+	assert_eq!(MAX.get(), 16);
+}
@@ -0,0 +1,72 @@
+mod shapes {
+	#[struct_layout::explicit(size = 16, align = 4)]
+	#[derive(Copy, Clone, Debug, Default)]
+	pub struct Circle {
+		#[field(offset = 0)]
+		pub x: f32,
+		#[field(offset = 4)]
+		pub y: f32,
+		#[field(offset = 8, get, set)]
+		pub radius: f32,
+		// Without a set accessor the field is not a parameter of `new`
+		#[field(offset = 12, get)]
+		pub id: u32,
+	}
+}
+use shapes::Circle;
+
+const ORIGIN: Circle = Circle::zeroed();
+
+#[test]
+fn zeroed() {
+	let circle = Circle::zeroed();
+	assert_eq!((circle.x(), circle.y(), circle.radius(), circle.id()), (0.0, 0.0, 0.0, 0));
+	assert_eq!(ORIGIN.id(), 0);
+}
+
+#[test]
+fn new() {
+	let circle = Circle::new(1.0, 2.0, 3.0);
+	assert_eq!((circle.x(), circle.y(), circle.radius(), circle.id()), (1.0, 2.0, 3.0, 0));
+}
+
+#[test]
+fn default() {
+	let circle = Circle::default();
+	assert_eq!(format!("{:?}", circle), format!("{:?}", Circle::zeroed()));
+}
+
+#[struct_layout::explicit(size = 8, align = 4)]
+struct Flags {
+	#[field(offset = 0)]
+	enabled: bool,
+	#[field(offset = 4)]
+	mode: char,
+}
+
+#[test]
+fn converted_fields() {
+	let flags = Flags::new(true, 'x');
+	assert!(flags.enabled());
+	assert_eq!(flags.mode(), 'x');
+	assert!(!Flags::zeroed().enabled());
+}
+
+#[struct_layout::explicit(size = 24, align = 8)]
+struct Callbacks {
+	#[field(offset = 0)]
+	map: Option<fn(u32) -> u32>,
+	#[field(offset = 8)]
+	hook: Option<unsafe extern "C" fn(*mut u8)>,
+	#[field(offset = 16)]
+	data: Option<core::ptr::NonNull<u8>>,
+}
+
+#[test]
+fn null_options() {
+	let callbacks = Callbacks::zeroed();
+	assert!(callbacks.map().is_none() && callbacks.hook().is_none() && callbacks.data().is_none());
+	fn double(value: u32) -> u32 { value * 2 }
+	let callbacks = Callbacks::new(Some(double), None, None);
+	assert_eq!(callbacks.map().map(|map| map(21)), Some(42));
+}
@@ -0,0 +1,37 @@
+
+#[struct_layout::explicit(size = 64, align = 8, consts_mod = foo_layout)]
+#[derive(Copy, Clone, Default)]
+pub struct Foo {
+	#[field(offset = 4)]
+	pub int: i32,
+	#[field(offset = 8)]
+	pub name: [u8; 16],
+	#[field(offset = 24, count = 4, stride = 8)]
+	pub ids: u32,
+	#[field(offset = after + 4, pad = 4)]
+	_reserved: (),
+}
+
+mod tooling {
+	use super::foo_layout::*;
+
+	pub const TABLE: [(usize, usize); 3] = [(INT_OFFSET, INT_SIZE), (NAME_OFFSET, NAME_SIZE), (IDS_OFFSET, IDS_SIZE)];
+	pub const EXTENT: (usize, usize) = (SIZE, ALIGN);
+}
+
+#[test]
+fn constants() {
+	assert_eq!(tooling::EXTENT, (64, 8));
+	assert_eq!(tooling::TABLE, [(4, 4), (8, 16), (24, 28)]);
+}
+
+macro_rules! end_of {
+	($field:ident) => {
+		crate::foo_layout::$field + crate::foo_layout::INT_SIZE
+	};
+}
+
+#[test]
+fn from_macro() {
+	assert_eq!(end_of!(INT_OFFSET), 8);
+}
@@ -0,0 +1,56 @@
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct RecordV1 {
+	#[field(offset = 0)]
+	health: f32,
+	#[field(offset = 4)]
+	mana: u16,
+	#[field(offset = 8)]
+	level: u32,
+}
+
+#[struct_layout::explicit(size = 32, align = 8, copy_from(RecordV1))]
+#[derive(Copy, Clone, Debug, Default)]
+struct RecordV2 {
+	#[field(offset = 0)]
+	level: u32,
+	#[field(offset = 8)]
+	health: f32,
+	#[field(offset = 12)]
+	mana: u16,
+}
+
+#[struct_layout::explicit(size = 16, align = 4, copy_from(RecordV2 { health, mana }))]
+#[derive(Copy, Clone, Debug, Default)]
+struct Stats {
+	#[field(offset = 0)]
+	health: f32,
+	#[field(offset = 4)]
+	mana: u16,
+	#[field(offset = 8)]
+	armor: i32,
+}
+
+#[test]
+fn copy_all_fields() {
+	let mut v1 = RecordV1::default();
+	v1.set_health(50.0).set_mana(20).set_level(4);
+	let mut v2 = RecordV2::default();
+	v2.copy_fields_from(&v1);
+	assert_eq!(v2.health(), 50.0);
+	assert_eq!(v2.mana(), 20);
+	assert_eq!(v2.level(), 4);
+}
+
+#[test]
+fn copy_listed_fields() {
+	let mut v2 = RecordV2::default();
+	v2.set_health(75.0).set_mana(30).set_level(9);
+	let mut stats = Stats::default();
+	stats.set_armor(-1);
+	stats.copy_fields_from(&v2);
+	assert_eq!(stats.health(), 75.0);
+	assert_eq!(stats.mana(), 30);
+	assert_eq!(stats.armor(), -1);
+}
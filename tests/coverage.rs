@@ -0,0 +1,38 @@
+use std::ops::Range;
+
+#[struct_layout::explicit(size = 24, align = 4, coverage)]
+#[derive(Copy, Clone, Default)]
+struct Record {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4)]
+	kind: u16,
+	#[field(offset = 6, pad = 2)]
+	_reserved: (),
+	#[field(offset = 12, count = 2)]
+	values: u32,
+	#[field(offset = 20, opaque)]
+	tag: [u8; 2],
+}
+
+#[test]
+fn coverage() {
+	// Pad regions are not covered
+	assert_eq!(Record::COVERAGE, &[0..6, 12..22]);
+	assert_eq!(Record::GAPS, &[6..12, 22..24]);
+}
+
+#[struct_layout::explicit(size = 8, align = 4, coverage)]
+struct Full {
+	#[field(offset = 0)]
+	a: u32,
+	#[field(offset = 4)]
+	b: f32,
+}
+
+#[test]
+fn no_gaps() {
+	const GAPS: &[Range<usize>] = Full::GAPS;
+	assert_eq!(Full::COVERAGE, &[Range { start: 0, end: 8 }]);
+	assert!(GAPS.is_empty());
+}
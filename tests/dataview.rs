@@ -0,0 +1,86 @@
+#[struct_layout::explicit(size = 8, align = 4, check(dataview::Pod), dataview)]
+#[derive(Copy, Clone, Debug)]
+struct Foo {
+	#[field(offset = 0)]
+	int: i32,
+	#[field(offset = 6)]
+	short: u16,
+}
+
+#[test]
+fn read_from_bytes() {
+	let mut buffer = [0u8; 12];
+	buffer[2..6].copy_from_slice(&(-7i32).to_ne_bytes());
+	buffer[8..10].copy_from_slice(&9u16.to_ne_bytes());
+	let foo: Foo = dataview::DataView::from(&buffer[..]).read(2);
+	assert_eq!(foo.int(), -7);
+	assert_eq!(foo.short(), 9);
+}
+
+// Aligned backing storage for record tables
+#[repr(C, align(4))]
+struct Table([u8; 28]);
+
+#[test]
+fn slice_from_bytes() {
+	let mut table = Table([0; 28]);
+	for i in 0..3 {
+		table.0[i * 8..i * 8 + 4].copy_from_slice(&(i as i32 * 10).to_ne_bytes());
+		table.0[i * 8 + 6..i * 8 + 8].copy_from_slice(&(i as u16 + 1).to_ne_bytes());
+	}
+	let records = Foo::slice_from_bytes(&table.0[..24]).unwrap();
+	assert_eq!(records.len(), 3);
+	let values: Vec<(i32, u16)> = records.iter().map(|foo| (foo.int(), foo.short())).collect();
+	assert_eq!(values, [(0, 1), (10, 2), (20, 3)]);
+	assert_eq!(Foo::slice_as_bytes(records), &table.0[..24]);
+
+	let records = Foo::slice_from_bytes_mut(&mut table.0[8..24]).unwrap();
+	records[1].set_int(-1);
+	assert_eq!(&table.0[16..20], &(-1i32).to_ne_bytes());
+}
+
+#[test]
+fn slice_from_bytes_misaligned() {
+	let mut table = Table([0; 28]);
+	assert!(Foo::slice_from_bytes(&table.0[2..18]).is_none());
+	assert!(Foo::slice_from_bytes_mut(&mut table.0[4..20]).is_some());
+	assert!(Foo::slice_from_bytes_mut(&mut table.0[1..17]).is_none());
+}
+
+#[test]
+fn slice_from_bytes_ragged() {
+	let table = Table([0; 28]);
+	assert!(Foo::slice_from_bytes(&table.0[..20]).is_none());
+	assert!(Foo::slice_from_bytes(&table.0[..28]).is_none());
+	assert_eq!(Foo::slice_from_bytes(&table.0[..0]).map(|records| records.len()), Some(0));
+}
+
+#[struct_layout::explicit(size = 8, align = 4, dataview, on_error = result)]
+#[derive(Copy, Clone, Debug)]
+struct Bar {
+	#[field(offset = 0)]
+	int: i32,
+}
+
+#[test]
+fn slice_from_bytes_result() {
+	let mut table = Table([0; 28]);
+	assert_eq!(Bar::slice_from_bytes(&table.0[..16]).map(|records| records.len()), Ok(2));
+	assert_eq!(Bar::slice_from_bytes(&table.0[2..18]).err(), Some(struct_layout::AccessError::Misaligned));
+	assert_eq!(Bar::slice_from_bytes_mut(&mut table.0[..20]).err(), Some(struct_layout::AccessError::InvalidLength(20)));
+}
+
+#[struct_layout::explicit(size = 8, align = 4, dataview, on_error = panic)]
+#[derive(Copy, Clone, Debug)]
+struct Baz {
+	#[field(offset = 0)]
+	int: i32,
+}
+
+#[test]
+#[should_panic(expected = "slice_from_bytes: the len 20 is not a multiple of the record size")]
+fn slice_from_bytes_panics() {
+	let table = Table([0; 28]);
+	assert_eq!(Baz::slice_from_bytes(&table.0[..16]).len(), 2);
+	Baz::slice_from_bytes(&table.0[..20]);
+}
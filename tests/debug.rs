@@ -0,0 +1,97 @@
+
+#[struct_layout::explicit(size = 16, align = 4, debug = annotated)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Entry {
+	#[field(offset = 0, get, set)]
+	id: u16,
+	#[field(offset = 4, get, set)]
+	value: i32,
+	#[field(offset = 12, get, set)]
+	flag: bool,
+}
+
+#[test]
+fn annotated() {
+	let mut entry = Entry::default();
+	entry.set_id(7).set_value(-2).set_flag(true);
+	assert_eq!(format!("{:?}", entry), "Entry { id @ 0x00: 7, value @ 0x04: -2, flag @ 0x0c: true }");
+}
+
+#[test]
+fn annotated_alternate() {
+	let mut entry = Entry::default();
+	entry.set_id(7).set_value(-2).set_flag(true);
+	assert_eq!(format!("{:#?}", entry), "Entry (size 0x10, align 0x4) {\n    id @ 0x00: 7,\n    value @ 0x04: -2,\n    flag @ 0x0c: true,\n}");
+}
+
+#[struct_layout::explicit(size = 6, align = 2)]
+#[derive(Copy, Clone, Default, LowerHex, UpperHex)]
+struct Packet {
+	#[field(offset = 0, get, set)]
+	kind: u8,
+	#[field(offset = 2, get, set)]
+	id: [u8; 3],
+}
+
+#[test]
+fn hex() {
+	let mut packet = Packet::default();
+	packet.set_kind(0xab).set_id([0x01, 0xcd, 0xef]);
+	assert_eq!(format!("{:x}", packet), "ab0001cdef00");
+	assert_eq!(format!("{:X}", packet), "AB0001CDEF00");
+	assert_eq!(format!("{:#x}", packet), "0xab0001cdef00");
+	assert_eq!(format!("{:>16x}|", packet), "    ab0001cdef00|");
+	assert_eq!(format!("{:#016x}", packet), "0x00ab0001cdef00");
+}
+
+#[struct_layout::explicit(size = 0x48, align = 4, bytes)]
+#[derive(Copy, Clone, Debug)]
+struct Signature {
+	#[field(offset = 0, get)]
+	version: u32,
+	#[field(offset = 4, debug = hex_bytes)]
+	key: [u8; 64],
+	#[field(offset = 0x44, get, debug = hex_bytes)]
+	tag: [u8; 2],
+}
+
+#[test]
+fn hex_bytes() {
+	let mut bytes = [0u8; 0x48];
+	bytes[0] = 1;
+	for (i, byte) in bytes[4..0x44].iter_mut().enumerate() {
+		*byte = i as u8;
+	}
+	bytes[0x44..].copy_from_slice(&[0xab, 0xcd, 0, 0]);
+	let signature = Signature::from_bytes(bytes);
+	let key: String = (0..64).map(|i| format!("{:02x}", i)).collect();
+	assert_eq!(format!("{:?}", signature), format!("Signature {{ version: 1, key: {}, tag: abcd }}", key));
+}
+
+#[struct_layout::explicit(size = 12, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Message {
+	#[field(offset = 0, get, set, debug_tag)]
+	kind: u8,
+	#[field(offset = 4, get, set, debug_when(kind = 1))]
+	move_x: f32,
+	#[field(offset = 8, get, set, debug_when(kind = 1))]
+	move_y: f32,
+	#[field(offset = 4, get, set, debug_when(kind = 0x2))]
+	chat_id: u32,
+	#[field(offset = 2, get, set)]
+	seq: u16,
+}
+
+#[test]
+fn tagged_fields() {
+	let mut message = Message::default();
+	message.set_kind(1).set_move_x(1.5).set_move_y(-2.0).set_seq(9);
+	assert_eq!(format!("{:?}", message), "Message { kind: 1, move_x: 1.5, move_y: -2.0, seq: 9 }");
+	// The same bytes under another tag
+	message.set_kind(2);
+	assert_eq!(format!("{:?}", message), format!("Message {{ kind: 2, chat_id: {}, seq: 9 }}", 1.5f32.to_bits()));
+	// Unknown tags only print the unconditional fields
+	message.set_kind(3);
+	assert_eq!(format!("{:?}", message), "Message { kind: 3, seq: 9 }");
+}
@@ -0,0 +1,49 @@
+
+/// Plain old data.
+///
+/// # Safety
+///
+/// Every bit pattern must be a valid value.
+pub unsafe trait Pod {}
+unsafe impl Pod for u8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for u32 {}
+
+#[struct_layout::defaults(align = 1, check(Pod))]
+mod packets {
+	use super::Pod;
+
+	#[struct_layout::explicit(size = 7)]
+	#[derive(Copy, Clone, Debug, Default)]
+	pub struct Header {
+		#[field(offset = 0)]
+		pub kind: u8,
+		#[field(offset = 1, get, set)]
+		pub len: u16,
+		#[field(offset = 3, get, set)]
+		pub seq: u32,
+	}
+
+	#[struct_layout::explicit(size = 8, align = 4)]
+	#[derive(Copy, Clone, Debug, Default)]
+	pub struct Ack {
+		#[field(offset = 4)]
+		pub seq: u32,
+	}
+}
+
+#[test]
+fn inherited_defaults() {
+	assert_eq!(packets::Header::ALIGN, 1);
+	assert_eq!(std::mem::align_of::<packets::Header>(), 1);
+	assert_eq!(std::mem::size_of::<packets::Header>(), 7);
+	// Specified arguments take precedence
+	assert_eq!(packets::Ack::ALIGN, 4);
+
+	let mut header = packets::Header::default();
+	header.set_len(3).set_seq(0x01020304);
+	assert_eq!(header.seq(), 0x01020304);
+	let mut ack = packets::Ack::default();
+	*ack.seq_mut() = header.seq();
+	assert_eq!(ack.seq_ref(), &0x01020304);
+}
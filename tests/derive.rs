@@ -0,0 +1,41 @@
+use struct_layout::ExplicitLayout;
+
+#[derive(Copy, Clone, Debug, Default, ExplicitLayout)]
+#[layout(size = 16, align = 4)]
+#[repr(C)]
+pub struct Foo {
+	#[field(offset = 0)]
+	pub a: u16,
+	#[field(offset = 4)]
+	pub int: i32,
+	/// Fields without annotation are not checked.
+	pub rest: [u8; 8],
+}
+
+#[test]
+fn verified_layout() {
+	assert_eq!(Foo::SIZE, 16);
+	assert_eq!(Foo::ALIGN, 4);
+	assert_eq!(Foo::A_OFFSET, 0);
+	assert_eq!(Foo::INT_OFFSET, 4);
+	let foo = Foo { int: 5, ..Foo::default() };
+	assert_eq!(foo.int, 5);
+}
+
+// Repeated derives are emitted once, in any order
+#[struct_layout::explicit(size = 8, align = 4)]
+#[derive(Debug, Clone, Default, Copy, Clone)]
+#[derive(Debug)]
+struct Repeated {
+	#[field(offset = 0)]
+	int: i32,
+}
+
+#[test]
+fn repeated_derives() {
+	let mut repeated = Repeated::default();
+	repeated.set_int(3);
+	let copy = repeated;
+	assert_eq!(copy.clone().int(), 3);
+	assert_eq!(format!("{:?}", repeated), "Repeated { int: 3 }");
+}
@@ -0,0 +1,207 @@
+//! Checks the compile errors of invalid layouts: their message and the source text they are reported at.
+
+// Every error as the source text it points at and its message
+fn errors(diagnostics: &str) -> Vec<(&str, &str)> {
+	diagnostics.lines().map(|line| {
+		let at = line.find(": ").unwrap();
+		(&line[..at], &line[at + 2..])
+	}).collect()
+}
+
+#[test]
+fn field_argument_typo() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4)
+		struct Foo {
+			#[field(offest = 4)]
+			a: u32,
+		}
+	);
+	assert_eq!(errors(diagnostics), [("offest", "parse field_layout: unknown argument `offest`, did you mean `offset`? expecting `offset = <usize>` first")]);
+}
+
+#[test]
+fn independent_fields() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4)
+		struct Foo {
+			#[field(offset = 0, gte)]
+			a: u32,
+			#[field(offset = 4)]
+			b: u32,
+			#[field(offset = 4, strid = 2)]
+			c: u8,
+		}
+	);
+	let errors = errors(diagnostics);
+	assert_eq!(errors.len(), 2, "{}", diagnostics);
+	assert_eq!(errors[0].0, "gte");
+	assert!(errors[0].1.starts_with("parse field_layout: unknown argument `gte`, did you mean `get`?"), "{}", errors[0].1);
+	assert_eq!(errors[1].0, "strid");
+	assert!(errors[1].1.starts_with("parse field_layout: unknown argument `strid`, did you mean `stride`?"), "{}", errors[1].1);
+}
+
+#[test]
+fn layout_arguments_and_fields() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4, packd, check(Copy), mirrror)
+		struct Foo {
+			#[field(offset = 0, bits = 4..12, ref)]
+			a: u16,
+			#[field(offset = 4)]
+			b: &'static u8,
+		}
+	);
+	let errors = errors(diagnostics);
+	let at: Vec<&str> = errors.iter().map(|&(at, _)| at).collect();
+	assert_eq!(at, ["packd", "mirrror", "a", "b"], "{}", diagnostics);
+	assert!(errors[0].1.starts_with("parse struct_layout: unknown argument `packd`, did you mean `packed`?"));
+	assert!(errors[1].1.starts_with("parse struct_layout: unknown argument `mirrror`, did you mean `mirror`?"));
+	// Combinations of arguments and the field type are reported at the name of the field
+	assert_eq!(errors[2].1, "parse field_layout: the `ref` and `mut` accessors are not supported on bitfields, a reference cannot point to a range of bits");
+	assert!(errors[3].1.starts_with("parse field: field `b` has reference type"));
+}
+
+#[test]
+fn size_and_align() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(sise = 8, align = 4) struct Foo {});
+	assert_eq!(errors(diagnostics), [("sise", "parse struct_layout: invalid format for size argument, expecting `size = <usize>`")]);
+	let diagnostics = struct_layout::__diagnostics!(implicit(algn = 4) struct Foo {});
+	assert_eq!(errors(diagnostics), [("algn", "parse struct_layout: invalid format for align argument, expecting `align = <usize>`")]);
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = ALIGN) struct Foo {});
+	assert_eq!(errors(diagnostics), [("align", "parse struct_layout: the align argument must be an integer literal, `repr(align(..))` does not accept constant expressions")]);
+}
+
+#[test]
+fn derive_trait() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4)
+		#[derive(Copy, Hsah)]
+		struct Foo {
+			#[field(offset = 0, get, rf)]
+			a: u32,
+		}
+	);
+	let errors = errors(diagnostics);
+	assert_eq!(errors[0], ("Hsah", "derive attribute: unsupported trait: `Hsah`"));
+	assert_eq!(errors[1].0, "rf");
+	assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn generic_parameters() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4) struct Foo<'a, T> { #[field(offset = 0)] a: T });
+	assert_eq!(errors(diagnostics), [("'a", "parse struct: lifetime parameters are not supported, the fields are stored as bytes")]);
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4, mirror) struct Foo<T> { #[field(offset = 0)] a: T });
+	assert_eq!(errors(diagnostics), [("mirror", "parse struct_layout: the mirror argument is not supported on generic structures")]);
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4) struct Foo<T> { #[field(offset = 0, count = 2)] a: T });
+	assert_eq!(errors(diagnostics), [("count", "parse field_layout: the count argument is not supported on generic structures")]);
+}
+
+#[test]
+fn offset_constants() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4)
+		struct Foo {
+			#[field(offset = 0)]
+			a: u32,
+			#[field(offset = 4, get)]
+			A_OFFSET: u32,
+		}
+	);
+	let at: Vec<&str> = errors(diagnostics).iter().map(|&(at, _)| at).collect();
+	assert_eq!(at, ["A_OFFSET", "a"], "{}", diagnostics);
+}
+
+#[test]
+fn atomic_storage() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 8, storage = AtomicU64)
+		#[derive(Clone)]
+		struct Foo {
+			#[field(offset = 0, atomic_ordered)]
+			a: u32,
+		}
+	);
+	assert_eq!(errors(diagnostics), [("storage", "parse struct_layout: deriving Clone is not supported with atomic storage")]);
+}
+
+#[test]
+fn field_case() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4)
+		struct Foo {
+			#[field(offset = 0)]
+			value: u32,
+			#[field(offset = 4)]
+			Value: u32,
+		}
+	);
+	assert_eq!(errors(diagnostics), [("Value", "parse field: the field `Value` only differs by case from the field `value`, their constants such as `VALUE_OFFSET` would collide")]);
+}
+
+#[test]
+fn valid_layout() {
+	let diagnostics = struct_layout::__diagnostics!(union_layout(size = 4, align = 4)
+		struct Foo {
+			a: u32,
+		}
+	);
+	assert_eq!(diagnostics, "");
+}
+
+#[test]
+fn structure_checks() {
+	// Errors caused by a layout argument are reported at the argument
+	let diagnostics = struct_layout::__diagnostics!(union_layout(size = 4, align = 4, strict)
+		struct Foo {
+			a: u32,
+		}
+	);
+	assert_eq!(errors(diagnostics), [("strict", "parse struct: the strict argument asserts that the fields do not overlap, the fields of a union layout always do")]);
+	// Every field is checked against the layout arguments, all of their errors are reported
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 16, align = 8)
+		struct Foo {
+			#[field(offset = 0)]
+			a: usize,
+			#[field(offset = 8)]
+			b: u64,
+			#[field(offset = 8)]
+			c: isize,
+		}
+	);
+	let errors = errors(diagnostics);
+	let at: Vec<&str> = errors.iter().map(|&(at, _)| at).collect();
+	assert_eq!(at, ["a", "c"], "{}", diagnostics);
+	assert!(errors[0].1.starts_with("parse field: the size of field `a` of type `usize` depends on the pointer width of the target"), "{}", errors[0].1);
+}
+
+#[test]
+fn zeroable_lookalike() {
+	// Only the types of core and std are known to be valid when zeroed, any other path may name a type of the user
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4)
+		#[derive(Default)]
+		struct Foo {
+			#[field(offset = 0, get)]
+			a: Option<fnord::NonZeroU32>,
+		}
+	);
+	assert_eq!(errors(diagnostics), [("a", "derive attribute: deriving Default requires zeroed bytes to be a valid value of field `a` or a set accessor without allow_drop to overwrite it")]);
+}
+
+#[test]
+fn slice_ext_invalid_bytes() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4, slice_ext)
+		struct Foo {
+			#[field(offset = 0)]
+			a: u32,
+			#[field(offset = 4)]
+			b: core::num::NonZeroU32,
+		}
+	);
+	assert_eq!(errors(diagnostics), [("slice_ext", "parse struct: the slice_ext argument reads field `b` from any bytes, which requires an integer, float or an array of these, or the structure must declare `check(::struct_layout::Pod)`")]);
+}
+
+#[test]
+fn column_invalid_bytes() {
+	let diagnostics = struct_layout::__diagnostics!(explicit(size = 8, align = 4)
+		struct Foo {
+			#[field(offset = 0, get, column)]
+			a: core::num::NonZeroU32,
+		}
+	);
+	assert_eq!(errors(diagnostics), [("column", "parse field: the column accessor reads field `a` from any bytes, which requires an integer, float or an array of these, or the structure must declare `check(::struct_layout::Pod)`")]);
+}
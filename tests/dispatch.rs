@@ -0,0 +1,64 @@
+use struct_layout::dispatch_by_size;
+
+#[struct_layout::explicit(size = 8, align = 4, bytes)]
+#[derive(Copy, Clone, Debug)]
+struct Ping {
+	#[field(offset = 0, get)]
+	seq: u32,
+	#[field(offset = 4, get)]
+	time: u32,
+}
+
+#[struct_layout::explicit(size = 12, align = 4, bytes)]
+#[derive(Copy, Clone, Debug)]
+struct Move {
+	#[field(offset = 0, get)]
+	x: f32,
+	#[field(offset = 4, get)]
+	y: f32,
+	#[field(offset = 8, get)]
+	z: f32,
+}
+
+#[derive(Debug, PartialEq)]
+enum Message {
+	Ping(u32),
+	Move(f32),
+	Unknown(usize),
+}
+
+fn handle_ping(ping: Ping) -> Message {
+	Message::Ping(ping.seq() + ping.time())
+}
+
+fn decode(buf: &[u8]) -> Message {
+	dispatch_by_size!(buf, {
+		Ping => handle_ping,
+		Move => |m: Move| Message::Move(m.x() + m.y() + m.z()),
+		_ => |bytes: &[u8]| Message::Unknown(bytes.len()),
+	})
+}
+
+#[test]
+fn dispatch() {
+	let mut ping = Vec::new();
+	ping.extend_from_slice(&3u32.to_ne_bytes());
+	ping.extend_from_slice(&4u32.to_ne_bytes());
+	assert_eq!(decode(&ping), Message::Ping(7));
+
+	let mut mv = Vec::new();
+	for value in [1.0f32, 2.0, 0.5] {
+		mv.extend_from_slice(&value.to_ne_bytes());
+	}
+	assert_eq!(decode(&mv), Message::Move(3.5));
+
+	assert_eq!(decode(&[0; 5]), Message::Unknown(5));
+	assert_eq!(decode(&[]), Message::Unknown(0));
+}
+
+#[test]
+fn dispatch_vec() {
+	let buf = vec![0u8; 8];
+	let seq = dispatch_by_size!(buf, { Ping => |p: Ping| p.seq(), _ => |_| u32::MAX });
+	assert_eq!(seq, 0);
+}
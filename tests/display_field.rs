@@ -0,0 +1,42 @@
+#[derive(Copy, Clone, Debug, Default)]
+struct Opaque(u32);
+
+#[struct_layout::explicit(size = 16, align = 4, display_field)]
+#[derive(Copy, Clone, Default)]
+struct Player {
+	#[field(offset = 0)]
+	health: i32,
+	#[field(offset = 4)]
+	speed: f32,
+	#[field(offset = 8, display = skip)]
+	handle: Opaque,
+	#[field(offset = 12, set)]
+	secret: u32,
+}
+
+#[test]
+fn by_name() {
+	let mut player = Player::default();
+	player.set_health(-12).set_speed(1.5).set_secret(7);
+	assert_eq!(format!("{}", player.display_field("health").unwrap()), "-12");
+	assert_eq!(format!("{:>5}", player.display_field("speed").unwrap()), "  1.5");
+}
+
+#[test]
+fn unknown_names() {
+	let player = Player::default();
+	assert!(player.display_field("mana").is_none());
+	// Skipped fields and fields without a getter cannot be displayed
+	assert!(player.display_field("handle").is_none());
+	assert!(player.display_field("secret").is_none());
+	assert_eq!(player.handle().0, 0);
+}
+
+#[struct_layout::explicit(size = 4, align = 4, display_field)]
+struct Empty {}
+
+#[test]
+fn no_fields() {
+	let empty: Empty = unsafe { std::mem::zeroed() };
+	assert!(empty.display_field("health").is_none());
+}
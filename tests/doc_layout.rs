@@ -0,0 +1,29 @@
+/// The table of the fields is appended to this documentation.
+#[struct_layout::explicit(size = 16, align = 4, doc_layout)]
+#[derive(Copy, Clone, Default)]
+pub struct Player {
+	#[field(offset = 0)]
+	pub health: i32,
+	#[field(offset = 4, get, endian = both)]
+	pub speed: u16,
+	#[field(offset = 8, get)]
+	pub name: [u8; 8],
+}
+
+#[struct_layout::explicit(size = 16, align = 8, doc_layout)]
+#[derive(Copy, Clone, Default)]
+pub struct Slots {
+	#[field(offset = 0, count = 2, stride = 4)]
+	pub ids: u16,
+	#[field(offset = 8, get)]
+	pub callback: Option<fn(u8)>,
+}
+
+#[test]
+fn accessors_unchanged() {
+	let mut player = Player::default();
+	player.set_health(-3);
+	assert_eq!((player.health(), player.speed(), player.name()), (-3, 0, [0; 8]));
+	let slots = Slots::default();
+	assert_eq!(slots.ids(1), 0);
+}
@@ -0,0 +1,29 @@
+// The use of the dump warns by design
+#![allow(deprecated)]
+
+#[struct_layout::explicit(size = 20, align = 4, dump)]
+struct Packet {
+	#[field(offset = 0, get, set)]
+	id: u32,
+	#[field(offset = 4, count = 2, get)]
+	ports: u16,
+	#[field(offset = 8, ref, mut)]
+	tag: [u8; 4],
+	#[field(offset = 12, stored = u32, get)]
+	scale: f32,
+	#[field(offset = 16, dims(2, 2))]
+	level: u8,
+}
+
+#[test]
+fn dump() {
+	let lines: Vec<&str> = Packet::LAYOUT_DUMP.lines().collect();
+	assert_eq!(lines, [
+		"struct_layout dump of `Packet`: size = 20, align = 4, check = none",
+		"field `id`: offset = 0, type = u32, accessors = get set",
+		"field `ports`: offset = 4, type = u16, count = 2, accessors = get",
+		"field `tag`: offset = 8, type = [u8; 4], accessors = ref mut",
+		"field `scale`: offset = 12, type = f32, stored = u32, accessors = get",
+		"field `level`: offset = 16, type = u8, dims = (2, 2), accessors = get set",
+	]);
+}
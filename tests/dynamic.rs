@@ -0,0 +1,36 @@
+
+#[struct_layout::explicit(size = 0x40, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Player {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(dynamic)]
+	health: i32,
+	#[field(dynamic, get)]
+	armor: u16,
+}
+
+#[test]
+fn dynamic_offsets() {
+	let offsets = PlayerOffsets::new(0x13, 0x20);
+	let mut player = Player::default();
+	player.set_id(1);
+	player.set_health(&offsets, -50);
+	assert_eq!(player.health(&offsets), -50);
+	assert_eq!(player.armor(&offsets), 0);
+	assert_eq!(player.id(), 1);
+
+	let bytes = unsafe { std::mem::transmute::<Player, [u8; 0x40]>(player) };
+	assert_eq!(&bytes[0x13..0x17], &(-50i32).to_ne_bytes());
+
+	// Debug skips the dynamic fields
+	assert_eq!(format!("{:?}", player), "Player { id: 1 }");
+}
+
+#[test]
+#[should_panic(expected = "field offset out of bounds")]
+fn dynamic_out_of_bounds() {
+	let offsets = PlayerOffsets { health: 0x3e, armor: 0 };
+	let player = Player::default();
+	player.health(&offsets);
+}
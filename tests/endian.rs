@@ -0,0 +1,51 @@
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Header {
+	#[field(offset = 0, get, set)]
+	big_endian: u8,
+	#[field(offset = 2, endian = both)]
+	version: u16,
+	#[field(offset = 4, endian = both)]
+	value: i32,
+	#[field(offset = 8, get, endian = both)]
+	length: u64,
+}
+
+impl Header {
+	fn read_value(&self) -> i32 {
+		if self.big_endian() != 0 { self.value_be() } else { self.value_le() }
+	}
+}
+
+#[test]
+fn read_known_order() {
+	let mut header = Header::default();
+	header.0[2..4].copy_from_slice(&[0x01, 0x02]);
+	header.0[4..8].copy_from_slice(&[0xff, 0xff, 0xff, 0xfe]);
+	header.0[8..16].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+	assert_eq!(header.version_le(), 0x0201);
+	assert_eq!(header.version_be(), 0x0102);
+	assert_eq!(header.value_le(), -0x01000001);
+	assert_eq!(header.value_be(), -2);
+	assert_eq!(header.length_le(), 0x0807060504030201);
+	assert_eq!(header.length_be(), 0x0102030405060708);
+	// The native accessor reads the bytes as they are
+	assert_eq!(header.length(), u64::from_ne_bytes([1, 2, 3, 4, 5, 6, 7, 8]));
+	header.set_big_endian(1);
+	assert_eq!(header.read_value(), -2);
+}
+
+#[test]
+fn write_known_order() {
+	let mut header = Header::default();
+	header.set_version_be(0x0102).set_value_le(-2).set_length_be(0x0102030405060708);
+	assert_eq!(&header.0[2..4], &[0x01, 0x02]);
+	assert_eq!(&header.0[4..8], &[0xfe, 0xff, 0xff, 0xff]);
+	assert_eq!(&header.0[8..16], &[1, 2, 3, 4, 5, 6, 7, 8]);
+	header.set_version_le(0x0102).set_value_be(-2).set_length_le(0x0102030405060708);
+	assert_eq!(&header.0[2..4], &[0x02, 0x01]);
+	assert_eq!(&header.0[4..8], &[0xff, 0xff, 0xff, 0xfe]);
+	assert_eq!(&header.0[8..16], &[8, 7, 6, 5, 4, 3, 2, 1]);
+	assert_eq!(header.read_value(), -0x01000001);
+}
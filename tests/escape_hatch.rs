@@ -0,0 +1,34 @@
+
+#[struct_layout::explicit(size = 16, align = 4, escape_hatch)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Unknown {
+	#[field(offset = 4)]
+	known: u32,
+}
+
+#[test]
+fn read_at() {
+	let mut unknown = Unknown::default();
+	unknown.set_known(0x01020304);
+	assert_eq!(unsafe { unknown.read_at::<u32>(4) }, 0x01020304);
+	assert_eq!(unsafe { unknown.read_at::<u16>(5) }, u16::from_ne_bytes([unknown.0[5], unknown.0[6]]));
+}
+
+#[test]
+fn write_at() {
+	let mut unknown = Unknown::default();
+	unsafe {
+		unknown.write_at(4, 42u32);
+		unknown.write_at(9, -1i16);
+	}
+	assert_eq!(unknown.known(), 42);
+	assert_eq!(&unknown.0[9..11], &[0xff, 0xff]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn out_of_bounds() {
+	let unknown = Unknown::default();
+	let _ = unsafe { unknown.read_at::<u64>(12) };
+}
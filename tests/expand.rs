@@ -0,0 +1,160 @@
+//! Compares the code generated for the fixtures with the snapshots in `tests/snapshots`.
+//!
+//! Run with `STRUCT_LAYOUT_BLESS=1` to update the snapshots after an intended change of the generated code.
+
+use std::{env, fs, path::Path};
+
+fn check(name: &str, expansion: &str) {
+	let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots").join(format!("{}.txt", name));
+	if env::var_os("STRUCT_LAYOUT_BLESS").is_some() {
+		fs::write(&path, expansion).unwrap();
+		return;
+	}
+	let snapshot = match fs::read_to_string(&path) {
+		Ok(snapshot) => snapshot.replace("\r\n", "\n"),
+		Err(_) => panic!("missing snapshot {}, run with STRUCT_LAYOUT_BLESS=1 to create it", path.display()),
+	};
+	if snapshot != expansion {
+		let (line, (expected, found)) = snapshot.lines().chain(Some("<end>"))
+			.zip(expansion.lines().chain(Some("<end>")))
+			.enumerate()
+			.find(|(_, (expected, found))| expected != found)
+			.unwrap_or((0, ("", "")));
+		panic!("the expansion of `{}` differs from {} at line {}:\nexpected: {}\n   found: {}\nrun with STRUCT_LAYOUT_BLESS=1 if the change is intended",
+			name, path.display(), line + 1, expected.trim(), found.trim());
+	}
+}
+
+#[test]
+fn derives() {
+	check("derives", struct_layout::__expand!(explicit(size = 8, align = 4)
+		#[derive(Copy, Clone, Debug, Default)]
+		struct Derives {
+			#[field(offset = 0)]
+			a: u32,
+			#[field(offset = 4, get, set)]
+			b: u16,
+		}
+	));
+}
+
+#[test]
+fn unaligned() {
+	check("unaligned", struct_layout::__expand!(explicit(size = 7, align = 1, packed)
+		struct Unaligned {
+			#[field(offset = 0)]
+			kind: u8,
+			#[field(offset = 1)]
+			len: u32,
+		}
+	));
+}
+
+#[test]
+fn check_trait() {
+	check("check_trait", struct_layout::__expand!(explicit(size = 8, align = 4, check(Pod))
+		struct Checked {
+			#[field(offset = 0, get, set)]
+			a: u32,
+			#[field(offset = 4, get, ref)]
+			b: f32,
+		}
+	));
+}
+
+#[test]
+fn visibility() {
+	check("visibility", struct_layout::__expand!(explicit(size = 12, align = 4)
+		pub(crate) struct Visible {
+			#[field(offset = 0, get)]
+			pub a: u32,
+			#[field(offset = 4, get)]
+			pub(crate) b: u32,
+			#[field(offset = 8, get)]
+			c: u32,
+		}
+	));
+}
+
+#[test]
+fn doc_attrs() {
+	check("doc_attrs", struct_layout::__expand!(explicit(size = 4, align = 4)
+		/// A documented structure.
+		#[doc(alias = "Docs")]
+		struct Documented {
+			/// A documented field.
+			#[field(offset = 0, get, set)]
+			a: u32,
+		}
+	));
+}
+
+#[test]
+fn generics() {
+	check("generics", struct_layout::__expand!(explicit(size = 8, align = 4)
+		#[derive(Copy, Clone)]
+		struct Generic<T: Copy + 'static> {
+			#[field(offset = 4, get, set)]
+			value: T,
+		}
+	));
+}
+
+// The number of tokens of the expansion, every punctuation character and delimiter is a token of its own as in proc_macro
+fn count_tokens(expansion: &str) -> usize {
+	let mut chars = expansion.chars().peekable();
+	let mut count = 0;
+	while let Some(chr) = chars.next() {
+		if chr.is_whitespace() {
+			continue;
+		}
+		count += 1;
+		if chr == '"' {
+			let mut escaped = false;
+			for chr in chars.by_ref() {
+				match chr {
+					'"' if !escaped => break,
+					'\\' => escaped = !escaped,
+					_ => escaped = false,
+				}
+			}
+		}
+		else if chr.is_alphanumeric() || chr == '_' {
+			while chars.next_if(|&chr| chr.is_alphanumeric() || chr == '_').is_some() {}
+		}
+	}
+	count
+}
+
+#[test]
+fn tokens_per_field() {
+	// The accessors refer to the hidden offset constant of the field instead of repeating the offset expression
+	let one = count_tokens(struct_layout::__expand!(explicit(size = 32, align = 4)
+		struct One {
+			#[field(offset = 0, get, set, ref, mut)]
+			f0: u32,
+		}
+	));
+	let eight = count_tokens(struct_layout::__expand!(explicit(size = 32, align = 4)
+		struct Eight {
+			#[field(offset = 0, get, set, ref, mut)]
+			f0: u32,
+			#[field(offset = 4, get, set, ref, mut)]
+			f1: u32,
+			#[field(offset = 8, get, set, ref, mut)]
+			f2: u32,
+			#[field(offset = 12, get, set, ref, mut)]
+			f3: u32,
+			#[field(offset = 16, get, set, ref, mut)]
+			f4: u32,
+			#[field(offset = 20, get, set, ref, mut)]
+			f5: u32,
+			#[field(offset = 24, get, set, ref, mut)]
+			f6: u32,
+			#[field(offset = 28, get, set, ref, mut)]
+			f7: u32,
+		}
+	));
+	let per_field = (eight - one) / 7;
+	assert!(per_field <= 800, "the accessors of a field expand to {} tokens, up from about 740", per_field);
+}
@@ -0,0 +1,57 @@
+
+#[struct_layout::explicit(size = 16, align = 8, export_c(prefix = "sample_"), portable = false)]
+#[derive(Copy, Clone, Default)]
+struct Sample {
+	#[field(offset = 0, get, set)]
+	int: i32,
+	#[field(offset = 4, get)]
+	ready: bool,
+	#[field(offset = 8, get, set)]
+	ptr: *const u8,
+	// Not exported without get and set accessors
+	#[field(offset = 5, ref)]
+	other: [u8; 3],
+}
+
+// Links against the unmangled symbols
+mod ffi {
+	use super::Sample;
+	extern "C" {
+		pub fn sample_get_int(p: *const Sample) -> i32;
+		pub fn sample_set_int(p: *mut Sample, v: i32);
+	}
+}
+
+#[test]
+fn function_pointers() {
+	let get: unsafe extern "C" fn(*const Sample) -> i32 = sample_get_int;
+	let set: unsafe extern "C" fn(*mut Sample, i32) = sample_set_int;
+	let ready: unsafe extern "C" fn(*const Sample) -> bool = sample_get_ready;
+	let mut sample = Sample::default();
+	unsafe {
+		set(&mut sample, -12);
+		assert_eq!(get(&sample), -12);
+		assert!(!ready(&sample));
+	}
+	assert_eq!(sample.int(), -12);
+}
+
+#[test]
+fn pointers() {
+	let byte = 3u8;
+	let mut sample = Sample::default();
+	unsafe {
+		sample_set_ptr(&mut sample, &byte);
+		assert_eq!(sample_get_ptr(&sample), &byte as *const u8);
+	}
+}
+
+#[test]
+fn symbols() {
+	let mut sample = Sample::default();
+	unsafe {
+		ffi::sample_set_int(&mut sample, 42);
+		assert_eq!(ffi::sample_get_int(&sample), 42);
+	}
+	assert_eq!(sample.int(), 42);
+}
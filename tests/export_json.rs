@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+
+// Minimal JSON reader for the exported layouts
+#[derive(Clone, Debug, PartialEq)]
+enum Json {
+	Null,
+	Bool(bool),
+	Number(u64),
+	String(String),
+	Array(Vec<Json>),
+	Object(BTreeMap<String, Json>),
+}
+impl Json {
+	fn parse(s: &str) -> Json {
+		let mut chars = s.chars().peekable();
+		let json = Json::value(&mut chars);
+		assert_eq!(chars.next(), None, "trailing characters");
+		json
+	}
+	fn value(chars: &mut std::iter::Peekable<std::str::Chars>) -> Json {
+		match chars.next().expect("unexpected end") {
+			'n' => { assert_eq!(chars.by_ref().take(3).collect::<String>(), "ull"); Json::Null },
+			't' => { assert_eq!(chars.by_ref().take(3).collect::<String>(), "rue"); Json::Bool(true) },
+			'f' => { assert_eq!(chars.by_ref().take(4).collect::<String>(), "alse"); Json::Bool(false) },
+			'"' => Json::String(Json::string(chars)),
+			'[' => {
+				let mut items = Vec::new();
+				if chars.peek() == Some(&']') {
+					chars.next();
+					return Json::Array(items);
+				}
+				loop {
+					items.push(Json::value(chars));
+					match chars.next() {
+						Some(',') => continue,
+						Some(']') => return Json::Array(items),
+						chr => panic!("unexpected {:?} in array", chr),
+					}
+				}
+			},
+			'{' => {
+				let mut members = BTreeMap::new();
+				loop {
+					assert_eq!(chars.next(), Some('"'));
+					let key = Json::string(chars);
+					assert_eq!(chars.next(), Some(':'));
+					members.insert(key, Json::value(chars));
+					match chars.next() {
+						Some(',') => continue,
+						Some('}') => return Json::Object(members),
+						chr => panic!("unexpected {:?} in object", chr),
+					}
+				}
+			},
+			chr @ '0'..='9' => {
+				let mut number = chr.to_digit(10).unwrap() as u64;
+				while let Some(digit) = chars.peek().and_then(|chr| chr.to_digit(10)) {
+					number = number * 10 + digit as u64;
+					chars.next();
+				}
+				Json::Number(number)
+			},
+			chr => panic!("unexpected {:?}", chr),
+		}
+	}
+	fn string(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+		let mut s = String::new();
+		loop {
+			match chars.next().expect("unterminated string") {
+				'"' => return s,
+				'\\' => match chars.next() {
+					Some('"') => s.push('"'),
+					Some('\\') => s.push('\\'),
+					Some('n') => s.push('\n'),
+					chr => panic!("unexpected escape {:?}", chr),
+				},
+				chr => s.push(chr),
+			}
+		}
+	}
+}
+impl std::ops::Index<&str> for Json {
+	type Output = Json;
+	fn index(&self, key: &str) -> &Json {
+		match self {
+			Json::Object(members) => &members[key],
+			_ => panic!("not an object"),
+		}
+	}
+}
+impl std::ops::Index<usize> for Json {
+	type Output = Json;
+	fn index(&self, index: usize) -> &Json {
+		match self {
+			Json::Array(items) => &items[index],
+			_ => panic!("not an array"),
+		}
+	}
+}
+fn string(s: &str) -> Json {
+	Json::String(String::from(s))
+}
+
+const NAME_OFFSET: usize = 0x10;
+
+#[struct_layout::explicit(size = 0x40, align = 8, export_json)]
+struct Player {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4, pad = 4)]
+	_reserved: (),
+	#[field(offset = 8)]
+	pos: [f32; 2],
+	#[field(offset = NAME_OFFSET)]
+	name: [u8; 16],
+	#[field(offset = 0x20, count = 4, stride = 6)]
+	ammo: u16,
+	#[field(offset = 0x38, enum(u8))]
+	team: Team,
+	#[field(offset = 0x3c, get)]
+	owner: Option<std::num::NonZeroU32>,
+}
+
+#[allow(dead_code)]
+#[derive(Copy, Clone, Debug)]
+#[repr(u8)]
+enum Team {
+	Red = 1,
+}
+impl std::convert::TryFrom<u8> for Team {
+	type Error = ();
+	fn try_from(value: u8) -> Result<Team, ()> {
+		match value {
+			1 => Ok(Team::Red),
+			_ => Err(()),
+		}
+	}
+}
+
+#[test]
+fn layout_json() {
+	let json = Json::parse(Player::LAYOUT_JSON);
+	assert_eq!(json["name"], string("Player"));
+	assert_eq!(json["size"], Json::Number(0x40));
+	assert_eq!(json["align"], Json::Number(8));
+
+	let fields = &json["fields"];
+	// Pad regions are not fields
+	assert_eq!(fields[0]["name"], string("id"));
+	assert_eq!(fields[1]["name"], string("pos"));
+	assert_eq!(fields[1]["type"], string("[f32; 2]"));
+	assert_eq!(fields[1]["size"], Json::Number(8));
+	assert_eq!(fields[2]["offset"], Json::Number(0x10));
+	assert_eq!(fields[3]["count"], Json::Number(4));
+	assert_eq!(fields[3]["stride"], Json::Number(6));
+	assert_eq!(fields[3]["size"], Json::Number(2));
+	assert_eq!(fields[4]["type"], string("Team"));
+	assert_eq!(fields[4]["size"], Json::Number(1));
+	assert_eq!(fields[5]["offset"], Json::Number(0x3c));
+	assert_eq!(fields[5]["type"], string("Option<std::num::NonZeroU32>"));
+}
+
+#[struct_layout::explicit(size = 48, align = 4, export_json)]
+struct Quoted {
+	#[field(offset = 0)]
+	kind: u32,
+	#[field(offset = 4)]
+	tag: [u8; b'"' as usize],
+}
+
+#[test]
+fn escaped() {
+	let json = Json::parse(Quoted::LAYOUT_JSON);
+	assert_eq!(json["fields"][1]["type"], string("[u8; b'\"' as usize]"));
+	assert_eq!(json["fields"][1]["size"], Json::Number(34));
+}
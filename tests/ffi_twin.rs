@@ -0,0 +1,57 @@
+use std::mem;
+
+// The offsets are declared out of order, the twin sorts its fields by offset
+#[struct_layout::explicit(size = 12, align = 4, ffi_twin = SampleC)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Sample {
+	#[field(offset = 4)]
+	int: i32,
+	#[field(offset = 2)]
+	flags: u16,
+	#[field(offset = 0)]
+	kind: u8,
+	#[field(offset = 1, pad = 1)]
+	reserved: (),
+	#[field(offset = 8, count = 4)]
+	ids: u8,
+}
+
+#[struct_layout::explicit(size = 7, align = 1, packed, ffi_twin = HeaderPacked)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Header {
+	#[field(offset = 0)]
+	kind: u8,
+	#[field(offset = 1)]
+	len: u32,
+	#[field(offset = 5)]
+	crc: u16,
+}
+
+#[test]
+fn sorted_fields() {
+	let mut sample = Sample::default();
+	sample.set_kind(3).set_flags(0x8001).set_int(-7);
+	sample.set_ids(2, 9);
+	let twin = SampleC::from(sample);
+	assert_eq!(mem::size_of::<SampleC>(), 12);
+	assert_eq!(({ twin.kind }, { twin.flags }, { twin.int }, { twin.ids }), (3, 0x8001, -7, [0, 0, 9, 0]));
+	assert_eq!(unsafe { mem::transmute::<SampleC, [u8; 12]>(twin) }, unsafe { mem::transmute::<Sample, [u8; 12]>(sample) });
+
+	let twin = SampleC { kind: 1, reserved: [0xff], flags: 2, int: 3, ids: [4, 5, 6, 7] };
+	let sample = Sample::from(twin);
+	assert_eq!((sample.kind(), sample.flags(), sample.int(), sample.ids(3)), (1, 2, 3, 7));
+	assert_eq!(unsafe { mem::transmute::<Sample, [u8; 12]>(sample) }, unsafe { mem::transmute::<SampleC, [u8; 12]>(twin) });
+}
+
+#[test]
+fn unaligned_fields() {
+	let mut header = Header::default();
+	header.set_kind(1).set_len(0x11223344).set_crc(0xbeef);
+	let twin = HeaderPacked::from(header);
+	assert_eq!(mem::align_of::<HeaderPacked>(), 1);
+	assert_eq!(({ twin.kind }, { twin.len }, { twin.crc }), (1, 0x11223344, 0xbeef));
+
+	let back = Header::from(HeaderPacked { len: 5, ..twin });
+	assert_eq!((back.kind(), back.len(), back.crc()), (1, 5, 0xbeef));
+	assert_eq!(unsafe { mem::transmute::<Header, [u8; 7]>(header) }[1..5], 0x11223344u32.to_ne_bytes());
+}
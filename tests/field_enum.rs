@@ -0,0 +1,44 @@
+
+#[struct_layout::explicit(size = 16, align = 4, field_enum)]
+#[derive(Copy, Clone, Default)]
+struct Player {
+	#[field(offset = 1, get, set)]
+	unaligned: u16,
+	#[field(offset = 4)]
+	int: i32,
+	#[field(offset = 8, get)]
+	speed_scale: f32,
+	#[field(offset = 12, ref)]
+	tag: [u8; 4],
+}
+
+#[test]
+fn fields() {
+	assert_eq!(PlayerField::ALL, [PlayerField::Unaligned, PlayerField::Int, PlayerField::SpeedScale]);
+	let layout: Vec<_> = PlayerField::ALL.iter().map(|field| (field.name(), field.offset(), field.size())).collect();
+	assert_eq!(layout, [("unaligned", 1, 2), ("int", 4, 4), ("speed_scale", 8, 4)]);
+	const INT_OFFSET: usize = PlayerField::Int.offset();
+	assert_eq!(INT_OFFSET, 4);
+}
+
+#[test]
+fn aligned() {
+	const FLAGS: [bool; 3] = [Player::UNALIGNED_IS_ALIGNED, Player::INT_IS_ALIGNED, Player::TAG_IS_ALIGNED];
+	assert_eq!(FLAGS, [false, true, true]);
+	let aligned: Vec<_> = PlayerField::ALL.iter().map(|field| field.is_aligned()).collect();
+	assert_eq!(aligned, [false, true, true]);
+}
+
+#[test]
+fn read_dyn() {
+	let mut player = Player::default();
+	player.set_unaligned(7).set_int(-3);
+	player.0[8..12].copy_from_slice(&1.5f32.to_ne_bytes());
+	for &field in PlayerField::ALL.iter() {
+		match player.read_dyn(field) {
+			PlayerValue::Unaligned(value) => assert_eq!(value, 7),
+			PlayerValue::Int(value) => assert_eq!(value, -3),
+			PlayerValue::SpeedScale(value) => assert_eq!(value, 1.5),
+		}
+	}
+}
@@ -0,0 +1,57 @@
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+#[struct_layout::explicit(size = 8, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Inner {
+	#[field(offset = 0)]
+	value: u32,
+}
+
+#[struct_layout::explicit(size = 32, align = 4, freeze)]
+#[derive(Copy, Clone, Default)]
+struct Frozen {
+	#[field(offset = 0, get, set, ref)]
+	int: i32,
+	#[field(offset = 4, get, set, ref)]
+	bytes: [u8; 4],
+	#[field(offset = 8, get, ref)]
+	marker: std::marker::PhantomData<u64>,
+	#[field(offset = 8, nested, get, set, ref)]
+	inner: Inner,
+	#[field(offset = 16, get, set)]
+	pair: [[u16; 2]; 2],
+}
+
+#[test]
+fn frozen() {
+	let mut frozen = Frozen::default();
+	frozen.set_int(-1).set_bytes([1, 2, 3, 4]);
+	frozen.set_inner(*Inner::default().set_value(5));
+	assert_eq!(*frozen.int_ref(), -1);
+	assert_eq!(frozen.bytes_ref(), &[1, 2, 3, 4]);
+	assert_eq!(frozen.inner_ref().value(), 5);
+	assert_eq!(frozen.marker(), PhantomData);
+}
+
+/// Plain types for the check below, includes `Cell` on purpose.
+///
+/// # Safety
+///
+/// Every bit pattern must be a valid value.
+unsafe trait Plain {}
+unsafe impl Plain for Cell<u32> {}
+
+// Without freeze interior mutability is up to the check trait
+#[struct_layout::explicit(size = 8, align = 4, check(Plain))]
+struct Counter {
+	#[field(offset = 0, ref)]
+	count: Cell<u32>,
+}
+
+#[test]
+fn interior_mutability_without_freeze() {
+	let counter = Counter([0; 8]);
+	counter.count_ref().set(3);
+	assert_eq!(counter.count_ref().get(), 3);
+}
@@ -0,0 +1,46 @@
+
+struct_layout::from_c! {
+	#[derive(Copy, Clone, Debug, Default)]
+	pub r#"
+	// Pasted from the decompiler
+	struct Player {
+		int32_t health;
+		char name[32];
+		float pos[3];
+		uint8_t team; /* followed by padding */
+		uint64_t flags;
+	};
+	"# size = 0x60, consts_mod = player_layout
+}
+
+struct_layout::from_c! {
+	"typedef struct { uint16_t kind, len; } Header;"
+}
+
+#[test]
+fn offsets() {
+	assert_eq!(player_layout::HEALTH_OFFSET, 0);
+	assert_eq!(player_layout::NAME_OFFSET, 4);
+	assert_eq!(player_layout::POS_OFFSET, 36);
+	assert_eq!(player_layout::TEAM_OFFSET, 48);
+	assert_eq!(player_layout::FLAGS_OFFSET, 56);
+	assert_eq!(std::mem::size_of::<Player>(), 0x60);
+	assert_eq!(std::mem::align_of::<Player>(), 8);
+	assert_eq!(std::mem::size_of::<Header>(), 4);
+	assert_eq!(std::mem::align_of::<Header>(), 2);
+}
+
+#[test]
+fn accessors() {
+	let mut player = Player::default();
+	player.set_health(100).set_pos([1.0, 2.0, 3.0]).set_flags(1 << 40);
+	player.set_name_at(0, b'A');
+	assert_eq!(player.health(), 100);
+	assert_eq!(player.name_at(0), b'A');
+	assert_eq!(player.pos(), [1.0, 2.0, 3.0]);
+	assert_eq!(player.flags(), 1 << 40);
+	assert_eq!(&player.0[56..64], &(1u64 << 40).to_ne_bytes());
+	let mut header = Header([0; 4]);
+	header.set_len(7);
+	assert_eq!(header.len(), 7);
+}
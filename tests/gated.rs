@@ -0,0 +1,58 @@
+// Integration tests are built with `cfg(test)`, the accessors under `cfg(not(test))` are absent
+// The getter of `secret` is only present with the `rand` feature
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Player {
+	#[field(offset = 0, get, set(cfg(test)))]
+	health: i32,
+	#[field(offset = 4, get, set(cfg(not(test))))]
+	armor: i32,
+	#[field(offset = 8, get(feature = "rand"), set)]
+	secret: u32,
+}
+
+// Only called if the inherent accessor is absent
+trait Absent {
+	fn set_armor(&mut self, _value: i32) -> bool { false }
+	#[cfg(not(feature = "rand"))]
+	fn secret(&self) -> bool { false }
+}
+impl Absent for Player {}
+
+#[test]
+fn present() {
+	let mut player = Player::default();
+	player.set_health(100).set_secret(7);
+	assert_eq!(player.health(), 100);
+	assert_eq!(&player.0[8..12], &7u32.to_ne_bytes());
+}
+
+#[test]
+fn absent() {
+	let mut player = Player::default();
+	assert!(!player.set_armor(5));
+	assert_eq!(player.armor(), 0);
+}
+
+#[cfg(not(feature = "rand"))]
+#[test]
+fn absent_without_feature() {
+	let player = Player::default();
+	assert!(!player.secret());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn present_with_feature() {
+	let mut player = Player::default();
+	player.set_secret(7);
+	assert_eq!(player.secret(), 7);
+}
+
+#[test]
+fn derives_skip_absent() {
+	let mut player = Player::default();
+	player.set_health(3);
+	assert_eq!(format!("{:?}", player), "Player { health: 3, armor: 0 }");
+}
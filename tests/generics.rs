@@ -0,0 +1,67 @@
+use std::fmt::Debug;
+use std::mem;
+
+/// Plain old data.
+///
+/// # Safety
+///
+/// Every bit pattern must be a valid value.
+pub unsafe trait Pod: Copy + 'static {}
+unsafe impl Pod for u8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for u32 {}
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+// A message frame with a header and a payload which depends on the kind of message
+#[struct_layout::explicit(size = 32, align = 8, check(Pod))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+struct Frame<T: Pod> {
+	#[field(offset = 0, get, set)]
+	len: u16,
+	#[field(offset = 2, pad = 6)]
+	reserved: (),
+	#[field(offset = 8, get, set)]
+	seq: u32,
+	#[field(offset = 16)]
+	payload: T,
+}
+
+fn roundtrip<T: Pod + Debug + Default + Eq>(payload: T) {
+	let mut frame = Frame::<T>::default();
+	frame.set_len(mem::size_of::<T>() as u16).set_seq(7).set_payload(payload);
+	assert_eq!((frame.len() as usize, frame.seq(), frame.payload()), (mem::size_of::<T>(), 7, payload));
+	assert_eq!(*frame.payload_ref(), payload);
+	assert_eq!(frame, frame.clone());
+}
+
+#[test]
+fn payloads() {
+	roundtrip(0x11223344u32);
+	roundtrip(*b"payload!");
+
+	let mut frame = Frame::<[u8; 8]>::default();
+	frame.payload_mut()[7] = 1;
+	assert_eq!(frame.payload(), [0, 0, 0, 0, 0, 0, 0, 1]);
+	let bytes = unsafe { mem::transmute::<Frame<[u8; 8]>, [u8; 32]>(frame) };
+	assert_eq!(bytes[23], 1);
+	assert_eq!((mem::size_of::<Frame<u32>>(), mem::align_of::<Frame<u32>>()), (32, 8));
+	assert!(format!("{:?}", frame).contains("payload: [0, 0, 0, 0, 0, 0, 0, 1]"));
+}
+
+// Several parameters and a bound with a path
+#[struct_layout::explicit(size = 8, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Pair<A: Copy + Default + 'static, B: core::marker::Copy + 'static> {
+	#[field(offset = 0)]
+	a: A,
+	#[field(offset = 4, get, set)]
+	b: B,
+}
+
+#[test]
+fn parameters() {
+	let mut pair = Pair::<u16, u32>::default();
+	pair.set_a(3).set_b(4);
+	assert_eq!((pair.a(), pair.b()), (3, 4));
+	assert_eq!([Pair::<u16, u32>::A_IS_ALIGNED, Pair::<u16, u32>::B_IS_ALIGNED], [true, true]);
+}
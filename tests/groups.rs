@@ -0,0 +1,78 @@
+
+#[struct_layout::explicit(size = 24, align = 4, grouped)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Entity {
+	#[field(offset = 0)]
+	id: u16,
+	#[field(offset = 4)]
+	#[group(pos)]
+	x: f32,
+	#[field(offset = 8)]
+	#[group(pos)]
+	y: f32,
+	#[field(offset = 12)]
+	#[group(pos)]
+	z: f32,
+	#[field(offset = 16, get)]
+	health: i32,
+	#[field(offset = 20, set)]
+	flags: u32,
+}
+
+#[test]
+fn many() {
+	let mut entity = Entity::default();
+	entity.set_many((7, 1.0, 2.0, 3.0, 0x10));
+	assert_eq!(entity.id(), 7);
+	assert_eq!((entity.x(), entity.y(), entity.z()), (1.0, 2.0, 3.0));
+	assert_eq!(&entity.0[20..24], &0x10u32.to_ne_bytes());
+
+	entity.set_id(9).set_x(4.0);
+	entity.0[16..20].copy_from_slice(&(-5i32).to_ne_bytes());
+	assert_eq!(entity.get_many(), (9, 4.0, 2.0, 3.0, -5));
+}
+
+#[test]
+fn named() {
+	let mut entity = Entity::default();
+	entity.set_pos((1.5, 2.5, 3.5));
+	assert_eq!((entity.x(), entity.y(), entity.z()), (1.5, 2.5, 3.5));
+
+	entity.set_y(-1.0);
+	assert_eq!(entity.pos(), (1.5, -1.0, 3.5));
+}
+
+#[struct_layout::explicit(size = 0x40, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Actor {
+	#[field(offset = 0x30)]
+	#[group(position)]
+	x: f32,
+	#[field(offset = 0x34)]
+	#[group(position)]
+	y: f32,
+	#[field(offset = 0x38)]
+	#[group(position)]
+	z: f32,
+	#[field(offset = 0x10)]
+	#[group(position, state)]
+	flags: u32,
+	#[field(offset = 0x3c)]
+	#[group(state)]
+	alive: bool,
+}
+
+#[test]
+fn scattered() {
+	let mut actor = Actor::default();
+	actor.set_position((1.0, 2.0, 3.0, 4));
+	assert_eq!(actor.position(), (1.0, 2.0, 3.0, 4));
+	assert_eq!(&actor.0[0x30..0x34], &1.0f32.to_ne_bytes());
+	assert_eq!(&actor.0[0x10..0x14], &4u32.to_ne_bytes());
+	// The bytes between the members are untouched
+	assert!(actor.0[0x14..0x30].iter().all(|&byte| byte == 0));
+
+	actor.set_state((9, true));
+	assert_eq!(actor.state(), (9, true));
+	assert_eq!(actor.position().3, 9);
+}
@@ -0,0 +1,31 @@
+use struct_layout::Field;
+
+#[struct_layout::explicit(size = 32, align = 4, handles)]
+struct Player {
+	#[field(offset = 4)]
+	health: i32,
+	#[field(offset = 9, get, set)]
+	armor: i32,
+	#[field(offset = 16)]
+	speed: f32,
+}
+
+#[test]
+fn field_handles() {
+	let mut player: Player = unsafe { std::mem::zeroed() };
+	player.set_health(100).set_armor(25);
+
+	assert_eq!(Player::HEALTH.offset(), 4);
+	assert_eq!(Player::HEALTH.get(&player), 100);
+	Player::SPEED.set(&mut player, 1.5);
+	assert_eq!(player.speed(), 1.5);
+
+	// Data driven access to a selection of fields
+	let fields: [Field<Player, i32>; 2] = [Player::HEALTH, Player::ARMOR];
+	let total: i32 = fields.iter().map(|field| field.get(&player)).sum();
+	assert_eq!(total, 125);
+	for field in &fields {
+		field.set(&mut player, 1);
+	}
+	assert_eq!((player.health(), player.armor()), (1, 1));
+}
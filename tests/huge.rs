@@ -0,0 +1,30 @@
+#![cfg(target_pointer_width = "64")]
+
+// Only type checked, the structures are never instantiated
+
+#[struct_layout::explicit(size = 2_147_483_648, align = 8, huge)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Table {
+	#[field(offset = 0, get, set)]
+	len: u32,
+	#[field(offset = 8, count = (1 << 28) - 1, get, set)]
+	entries: u64,
+}
+
+// The largest object the compiler accepts on 64-bit targets
+#[struct_layout::explicit(size = (1usize << 61) - 8, align = 8, huge)]
+struct Largest {
+	#[field(offset = 0, get, set)]
+	first: u8,
+	#[field(offset = (1usize << 61) - 16, get, set, ref, mut)]
+	last: u64,
+}
+
+#[test]
+fn sizes() {
+	assert_eq!(std::mem::size_of::<Table>(), 1 << 31);
+	assert_eq!(std::mem::size_of::<Largest>(), (1 << 61) - 8);
+	let _: fn(&Table, usize) -> u64 = Table::entries;
+	let _: fn(&Largest) -> &u64 = Largest::last_ref;
+	let _: fn(&mut Largest, u8) -> &mut Largest = Largest::set_first;
+}
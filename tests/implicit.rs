@@ -0,0 +1,89 @@
+fn offset_of<S, T>(base: &S, field: &T) -> usize {
+	field as *const T as usize - base as *const S as usize
+}
+
+#[struct_layout::implicit(align = 8)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Aligned {
+	a: u8,
+	b: u32,
+	c: u16,
+	d: f64,
+	e: u8,
+}
+
+#[test]
+fn alignment_rounding() {
+	let foo = Aligned::default();
+	assert_eq!(offset_of(&foo, foo.a_ref()), 0);
+	assert_eq!(offset_of(&foo, foo.b_ref()), 4);
+	assert_eq!(offset_of(&foo, foo.c_ref()), 8);
+	assert_eq!(offset_of(&foo, foo.d_ref()), 16);
+	assert_eq!(offset_of(&foo, foo.e_ref()), 24);
+	assert_eq!(Aligned::SIZE, 32);
+	assert_eq!(std::mem::size_of::<Aligned>(), 32);
+}
+
+#[struct_layout::implicit(align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Padded {
+	a: u8,
+	#[field(pad_before = 3)]
+	b: u8,
+	#[field(align_to = 8)]
+	c: u16,
+	#[field(pad_before = 1, count = 3)]
+	d: u16,
+}
+
+#[test]
+fn padding() {
+	let foo = Padded::default();
+	assert_eq!(offset_of(&foo, foo.a_ref()), 0);
+	assert_eq!(offset_of(&foo, foo.b_ref()), 4);
+	assert_eq!(offset_of(&foo, foo.c_ref()), 8);
+	assert_eq!(offset_of(&foo, foo.d_ref(0)), 12);
+	assert_eq!(Padded::SIZE, 20);
+}
+
+#[struct_layout::implicit(align = 4)]
+#[derive(Copy, Clone, Default)]
+struct ZeroSized {
+	a: u8,
+	empty: (),
+	b: u16,
+	#[field(len = 3)]
+	reserved: (),
+	c: u8,
+}
+
+#[test]
+fn zero_size_fields() {
+	let foo = ZeroSized::default();
+	assert_eq!(offset_of(&foo, foo.empty_ref()), 1);
+	assert_eq!(offset_of(&foo, foo.b_ref()), 2);
+	assert_eq!(offset_of(&foo, foo.reserved()), 4);
+	assert_eq!(offset_of(&foo, foo.c_ref()), 7);
+	assert_eq!(ZeroSized::SIZE, 8);
+}
+
+#[struct_layout::implicit(align = 2, packed)]
+#[derive(Copy, Clone, Default)]
+struct Packed {
+	#[field(get, set)]
+	a: u8,
+	#[field(get, set)]
+	b: u32,
+	#[field(get, set)]
+	c: u8,
+}
+
+#[test]
+fn packed() {
+	let mut foo = Packed::default();
+	foo.set_b(0x11223344).set_c(5);
+	assert_eq!(Packed::SIZE, 6);
+	let bytes = unsafe { std::mem::transmute::<Packed, [u8; 6]>(foo) };
+	assert_eq!(&bytes[1..5], &0x11223344u32.to_ne_bytes());
+	assert_eq!(bytes[5], 5);
+}
@@ -0,0 +1,35 @@
+use std::mem::MaybeUninit;
+
+#[struct_layout::explicit(size = 16, align = 4, init)]
+#[derive(Copy, Clone, Debug)]
+struct Player {
+	#[field(offset = 0)]
+	health: i32,
+	#[field(offset = 4, get, set)]
+	speed: f32,
+	#[field(offset = 8, pad = 4)]
+	_reserved: (),
+	#[field(offset = 12, get, set)]
+	alive: bool,
+}
+
+#[test]
+fn every_field() {
+	let mut slot = MaybeUninit::uninit();
+	let player = Player::init(&mut slot).set_speed(1.5).set_health(-4).set_alive(true).finish();
+	assert_eq!((player.health(), player.speed(), player.alive()), (-4, 1.5, true));
+	player.set_health(7);
+	let player = unsafe { slot.assume_init() };
+	assert_eq!(player.health(), 7);
+	// The padding is zeroed
+	assert_eq!(unsafe { std::mem::transmute::<Player, [u8; 16]>(player) }[8..12], [0; 4]);
+}
+
+#[struct_layout::explicit(size = 4, align = 4, init)]
+struct Empty {}
+
+#[test]
+fn no_fields() {
+	let mut slot = MaybeUninit::uninit();
+	let _: &mut Empty = Empty::init(&mut slot).finish();
+}
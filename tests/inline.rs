@@ -0,0 +1,61 @@
+
+#[struct_layout::explicit(size = 0x40, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Entity {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 0x20)]
+	transform: inline! {
+		#[field(offset = 0)]
+		x: f32,
+		#[field(offset = 4)]
+		y: f32,
+	},
+	#[field(offset = 0x30, as = Stats)]
+	stats: inline! {
+		#[field(offset = 0)]
+		health: u16,
+		#[field(offset = 4)]
+		flags: inline! {
+			#[field(offset = 1)]
+			visible: bool,
+		},
+	},
+}
+
+#[test]
+fn inline_accessors() {
+	let mut entity = Entity::default();
+	entity.transform_mut().set_x(1.5).set_y(-2.0);
+	entity.set_id(7);
+	assert_eq!(entity.transform_ref().x(), 1.5);
+	assert_eq!(entity.transform().y(), -2.0);
+	assert_eq!(entity.id(), 7);
+
+	let bytes = unsafe { std::mem::transmute::<Entity, [u8; 0x40]>(entity) };
+	assert_eq!(&bytes[0x20..0x24], &1.5f32.to_ne_bytes());
+	assert_eq!(&bytes[0x24..0x28], &(-2.0f32).to_ne_bytes());
+}
+
+#[test]
+fn inline_sizes() {
+	assert_eq!(EntityTransform::SIZE, 8);
+	assert_eq!(std::mem::size_of::<EntityTransform>(), 8);
+	// Rounded up to the alignment of the outer structure
+	assert_eq!(Stats::SIZE, 8);
+	assert_eq!(StatsFlags::SIZE, 4);
+}
+
+#[test]
+fn nested_inline() {
+	let mut entity = Entity::default();
+	entity.stats_mut().set_health(100);
+	entity.stats_mut().flags_mut().set_visible(true);
+	let stats: Stats = entity.stats();
+	assert_eq!(stats.health(), 100);
+	assert!(stats.flags().visible());
+
+	let bytes = unsafe { std::mem::transmute::<Entity, [u8; 0x40]>(entity) };
+	assert_eq!(bytes[0x35], 1);
+	assert!(format!("{:?}", entity).contains("health: 100"));
+}
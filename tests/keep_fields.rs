@@ -0,0 +1,34 @@
+
+// Shaped like a bindgen generated structure
+#[struct_layout::explicit(size = 0x20, align = 8, keep_fields)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Bound {
+	/// Documentation is kept.
+	#[field(offset = 0)]
+	pub id: u32,
+	#[field(offset = 4, size = 2)]
+	pub flags: u16,
+	pub reserved: u16,
+	#[field(offset = 8)]
+	pub timestamp: u64,
+	#[field(offset = 0x10)]
+	pub position: [f32; 3],
+}
+
+#[test]
+fn fields_are_kept() {
+	let bound = Bound { id: 1, flags: 2, reserved: 3, timestamp: 4, position: [5.0, 6.0, 7.0] };
+	assert_eq!(bound.clone(), bound);
+	assert_eq!(bound.position[2], 7.0);
+	assert_eq!(Bound::default().id, 0);
+}
+
+#[test]
+fn layout_constants() {
+	use struct_layout::ExplicitLayout;
+	assert_eq!(<Bound as ExplicitLayout>::SIZE, 0x20);
+	assert_eq!(<Bound as ExplicitLayout>::ALIGN, 8);
+	assert_eq!(Bound::TIMESTAMP_OFFSET, 8);
+	assert_eq!(Bound::POSITION_OFFSET, std::mem::offset_of!(Bound, position));
+}
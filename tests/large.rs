@@ -0,0 +1,1015 @@
+
+// Expands a structure with many fields to keep an eye on the expansion time
+#[struct_layout::explicit(size = 4000, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Large {
+	#[field(offset = 0, get, set, ref, mut)] f000: u32,
+	#[field(offset = 4, get, set, ref, mut)] f001: u32,
+	#[field(offset = 8, get, set, ref, mut)] f002: u32,
+	#[field(offset = 12, get, set, ref, mut)] f003: u32,
+	#[field(offset = 16, get, set, ref, mut)] f004: u32,
+	#[field(offset = 20, get, set, ref, mut)] f005: u32,
+	#[field(offset = 24, get, set, ref, mut)] f006: u32,
+	#[field(offset = 28, get, set, ref, mut)] f007: u32,
+	#[field(offset = 32, get, set, ref, mut)] f008: u32,
+	#[field(offset = 36, get, set, ref, mut)] f009: u32,
+	#[field(offset = 40, get, set, ref, mut)] f010: u32,
+	#[field(offset = 44, get, set, ref, mut)] f011: u32,
+	#[field(offset = 48, get, set, ref, mut)] f012: u32,
+	#[field(offset = 52, get, set, ref, mut)] f013: u32,
+	#[field(offset = 56, get, set, ref, mut)] f014: u32,
+	#[field(offset = 60, get, set, ref, mut)] f015: u32,
+	#[field(offset = 64, get, set, ref, mut)] f016: u32,
+	#[field(offset = 68, get, set, ref, mut)] f017: u32,
+	#[field(offset = 72, get, set, ref, mut)] f018: u32,
+	#[field(offset = 76, get, set, ref, mut)] f019: u32,
+	#[field(offset = 80, get, set, ref, mut)] f020: u32,
+	#[field(offset = 84, get, set, ref, mut)] f021: u32,
+	#[field(offset = 88, get, set, ref, mut)] f022: u32,
+	#[field(offset = 92, get, set, ref, mut)] f023: u32,
+	#[field(offset = 96, get, set, ref, mut)] f024: u32,
+	#[field(offset = 100, get, set, ref, mut)] f025: u32,
+	#[field(offset = 104, get, set, ref, mut)] f026: u32,
+	#[field(offset = 108, get, set, ref, mut)] f027: u32,
+	#[field(offset = 112, get, set, ref, mut)] f028: u32,
+	#[field(offset = 116, get, set, ref, mut)] f029: u32,
+	#[field(offset = 120, get, set, ref, mut)] f030: u32,
+	#[field(offset = 124, get, set, ref, mut)] f031: u32,
+	#[field(offset = 128, get, set, ref, mut)] f032: u32,
+	#[field(offset = 132, get, set, ref, mut)] f033: u32,
+	#[field(offset = 136, get, set, ref, mut)] f034: u32,
+	#[field(offset = 140, get, set, ref, mut)] f035: u32,
+	#[field(offset = 144, get, set, ref, mut)] f036: u32,
+	#[field(offset = 148, get, set, ref, mut)] f037: u32,
+	#[field(offset = 152, get, set, ref, mut)] f038: u32,
+	#[field(offset = 156, get, set, ref, mut)] f039: u32,
+	#[field(offset = 160, get, set, ref, mut)] f040: u32,
+	#[field(offset = 164, get, set, ref, mut)] f041: u32,
+	#[field(offset = 168, get, set, ref, mut)] f042: u32,
+	#[field(offset = 172, get, set, ref, mut)] f043: u32,
+	#[field(offset = 176, get, set, ref, mut)] f044: u32,
+	#[field(offset = 180, get, set, ref, mut)] f045: u32,
+	#[field(offset = 184, get, set, ref, mut)] f046: u32,
+	#[field(offset = 188, get, set, ref, mut)] f047: u32,
+	#[field(offset = 192, get, set, ref, mut)] f048: u32,
+	#[field(offset = 196, get, set, ref, mut)] f049: u32,
+	#[field(offset = 200, get, set, ref, mut)] f050: u32,
+	#[field(offset = 204, get, set, ref, mut)] f051: u32,
+	#[field(offset = 208, get, set, ref, mut)] f052: u32,
+	#[field(offset = 212, get, set, ref, mut)] f053: u32,
+	#[field(offset = 216, get, set, ref, mut)] f054: u32,
+	#[field(offset = 220, get, set, ref, mut)] f055: u32,
+	#[field(offset = 224, get, set, ref, mut)] f056: u32,
+	#[field(offset = 228, get, set, ref, mut)] f057: u32,
+	#[field(offset = 232, get, set, ref, mut)] f058: u32,
+	#[field(offset = 236, get, set, ref, mut)] f059: u32,
+	#[field(offset = 240, get, set, ref, mut)] f060: u32,
+	#[field(offset = 244, get, set, ref, mut)] f061: u32,
+	#[field(offset = 248, get, set, ref, mut)] f062: u32,
+	#[field(offset = 252, get, set, ref, mut)] f063: u32,
+	#[field(offset = 256, get, set, ref, mut)] f064: u32,
+	#[field(offset = 260, get, set, ref, mut)] f065: u32,
+	#[field(offset = 264, get, set, ref, mut)] f066: u32,
+	#[field(offset = 268, get, set, ref, mut)] f067: u32,
+	#[field(offset = 272, get, set, ref, mut)] f068: u32,
+	#[field(offset = 276, get, set, ref, mut)] f069: u32,
+	#[field(offset = 280, get, set, ref, mut)] f070: u32,
+	#[field(offset = 284, get, set, ref, mut)] f071: u32,
+	#[field(offset = 288, get, set, ref, mut)] f072: u32,
+	#[field(offset = 292, get, set, ref, mut)] f073: u32,
+	#[field(offset = 296, get, set, ref, mut)] f074: u32,
+	#[field(offset = 300, get, set, ref, mut)] f075: u32,
+	#[field(offset = 304, get, set, ref, mut)] f076: u32,
+	#[field(offset = 308, get, set, ref, mut)] f077: u32,
+	#[field(offset = 312, get, set, ref, mut)] f078: u32,
+	#[field(offset = 316, get, set, ref, mut)] f079: u32,
+	#[field(offset = 320, get, set, ref, mut)] f080: u32,
+	#[field(offset = 324, get, set, ref, mut)] f081: u32,
+	#[field(offset = 328, get, set, ref, mut)] f082: u32,
+	#[field(offset = 332, get, set, ref, mut)] f083: u32,
+	#[field(offset = 336, get, set, ref, mut)] f084: u32,
+	#[field(offset = 340, get, set, ref, mut)] f085: u32,
+	#[field(offset = 344, get, set, ref, mut)] f086: u32,
+	#[field(offset = 348, get, set, ref, mut)] f087: u32,
+	#[field(offset = 352, get, set, ref, mut)] f088: u32,
+	#[field(offset = 356, get, set, ref, mut)] f089: u32,
+	#[field(offset = 360, get, set, ref, mut)] f090: u32,
+	#[field(offset = 364, get, set, ref, mut)] f091: u32,
+	#[field(offset = 368, get, set, ref, mut)] f092: u32,
+	#[field(offset = 372, get, set, ref, mut)] f093: u32,
+	#[field(offset = 376, get, set, ref, mut)] f094: u32,
+	#[field(offset = 380, get, set, ref, mut)] f095: u32,
+	#[field(offset = 384, get, set, ref, mut)] f096: u32,
+	#[field(offset = 388, get, set, ref, mut)] f097: u32,
+	#[field(offset = 392, get, set, ref, mut)] f098: u32,
+	#[field(offset = 396, get, set, ref, mut)] f099: u32,
+	#[field(offset = 400, get, set, ref, mut)] f100: u32,
+	#[field(offset = 404, get, set, ref, mut)] f101: u32,
+	#[field(offset = 408, get, set, ref, mut)] f102: u32,
+	#[field(offset = 412, get, set, ref, mut)] f103: u32,
+	#[field(offset = 416, get, set, ref, mut)] f104: u32,
+	#[field(offset = 420, get, set, ref, mut)] f105: u32,
+	#[field(offset = 424, get, set, ref, mut)] f106: u32,
+	#[field(offset = 428, get, set, ref, mut)] f107: u32,
+	#[field(offset = 432, get, set, ref, mut)] f108: u32,
+	#[field(offset = 436, get, set, ref, mut)] f109: u32,
+	#[field(offset = 440, get, set, ref, mut)] f110: u32,
+	#[field(offset = 444, get, set, ref, mut)] f111: u32,
+	#[field(offset = 448, get, set, ref, mut)] f112: u32,
+	#[field(offset = 452, get, set, ref, mut)] f113: u32,
+	#[field(offset = 456, get, set, ref, mut)] f114: u32,
+	#[field(offset = 460, get, set, ref, mut)] f115: u32,
+	#[field(offset = 464, get, set, ref, mut)] f116: u32,
+	#[field(offset = 468, get, set, ref, mut)] f117: u32,
+	#[field(offset = 472, get, set, ref, mut)] f118: u32,
+	#[field(offset = 476, get, set, ref, mut)] f119: u32,
+	#[field(offset = 480, get, set, ref, mut)] f120: u32,
+	#[field(offset = 484, get, set, ref, mut)] f121: u32,
+	#[field(offset = 488, get, set, ref, mut)] f122: u32,
+	#[field(offset = 492, get, set, ref, mut)] f123: u32,
+	#[field(offset = 496, get, set, ref, mut)] f124: u32,
+	#[field(offset = 500, get, set, ref, mut)] f125: u32,
+	#[field(offset = 504, get, set, ref, mut)] f126: u32,
+	#[field(offset = 508, get, set, ref, mut)] f127: u32,
+	#[field(offset = 512, get, set, ref, mut)] f128: u32,
+	#[field(offset = 516, get, set, ref, mut)] f129: u32,
+	#[field(offset = 520, get, set, ref, mut)] f130: u32,
+	#[field(offset = 524, get, set, ref, mut)] f131: u32,
+	#[field(offset = 528, get, set, ref, mut)] f132: u32,
+	#[field(offset = 532, get, set, ref, mut)] f133: u32,
+	#[field(offset = 536, get, set, ref, mut)] f134: u32,
+	#[field(offset = 540, get, set, ref, mut)] f135: u32,
+	#[field(offset = 544, get, set, ref, mut)] f136: u32,
+	#[field(offset = 548, get, set, ref, mut)] f137: u32,
+	#[field(offset = 552, get, set, ref, mut)] f138: u32,
+	#[field(offset = 556, get, set, ref, mut)] f139: u32,
+	#[field(offset = 560, get, set, ref, mut)] f140: u32,
+	#[field(offset = 564, get, set, ref, mut)] f141: u32,
+	#[field(offset = 568, get, set, ref, mut)] f142: u32,
+	#[field(offset = 572, get, set, ref, mut)] f143: u32,
+	#[field(offset = 576, get, set, ref, mut)] f144: u32,
+	#[field(offset = 580, get, set, ref, mut)] f145: u32,
+	#[field(offset = 584, get, set, ref, mut)] f146: u32,
+	#[field(offset = 588, get, set, ref, mut)] f147: u32,
+	#[field(offset = 592, get, set, ref, mut)] f148: u32,
+	#[field(offset = 596, get, set, ref, mut)] f149: u32,
+	#[field(offset = 600, get, set, ref, mut)] f150: u32,
+	#[field(offset = 604, get, set, ref, mut)] f151: u32,
+	#[field(offset = 608, get, set, ref, mut)] f152: u32,
+	#[field(offset = 612, get, set, ref, mut)] f153: u32,
+	#[field(offset = 616, get, set, ref, mut)] f154: u32,
+	#[field(offset = 620, get, set, ref, mut)] f155: u32,
+	#[field(offset = 624, get, set, ref, mut)] f156: u32,
+	#[field(offset = 628, get, set, ref, mut)] f157: u32,
+	#[field(offset = 632, get, set, ref, mut)] f158: u32,
+	#[field(offset = 636, get, set, ref, mut)] f159: u32,
+	#[field(offset = 640, get, set, ref, mut)] f160: u32,
+	#[field(offset = 644, get, set, ref, mut)] f161: u32,
+	#[field(offset = 648, get, set, ref, mut)] f162: u32,
+	#[field(offset = 652, get, set, ref, mut)] f163: u32,
+	#[field(offset = 656, get, set, ref, mut)] f164: u32,
+	#[field(offset = 660, get, set, ref, mut)] f165: u32,
+	#[field(offset = 664, get, set, ref, mut)] f166: u32,
+	#[field(offset = 668, get, set, ref, mut)] f167: u32,
+	#[field(offset = 672, get, set, ref, mut)] f168: u32,
+	#[field(offset = 676, get, set, ref, mut)] f169: u32,
+	#[field(offset = 680, get, set, ref, mut)] f170: u32,
+	#[field(offset = 684, get, set, ref, mut)] f171: u32,
+	#[field(offset = 688, get, set, ref, mut)] f172: u32,
+	#[field(offset = 692, get, set, ref, mut)] f173: u32,
+	#[field(offset = 696, get, set, ref, mut)] f174: u32,
+	#[field(offset = 700, get, set, ref, mut)] f175: u32,
+	#[field(offset = 704, get, set, ref, mut)] f176: u32,
+	#[field(offset = 708, get, set, ref, mut)] f177: u32,
+	#[field(offset = 712, get, set, ref, mut)] f178: u32,
+	#[field(offset = 716, get, set, ref, mut)] f179: u32,
+	#[field(offset = 720, get, set, ref, mut)] f180: u32,
+	#[field(offset = 724, get, set, ref, mut)] f181: u32,
+	#[field(offset = 728, get, set, ref, mut)] f182: u32,
+	#[field(offset = 732, get, set, ref, mut)] f183: u32,
+	#[field(offset = 736, get, set, ref, mut)] f184: u32,
+	#[field(offset = 740, get, set, ref, mut)] f185: u32,
+	#[field(offset = 744, get, set, ref, mut)] f186: u32,
+	#[field(offset = 748, get, set, ref, mut)] f187: u32,
+	#[field(offset = 752, get, set, ref, mut)] f188: u32,
+	#[field(offset = 756, get, set, ref, mut)] f189: u32,
+	#[field(offset = 760, get, set, ref, mut)] f190: u32,
+	#[field(offset = 764, get, set, ref, mut)] f191: u32,
+	#[field(offset = 768, get, set, ref, mut)] f192: u32,
+	#[field(offset = 772, get, set, ref, mut)] f193: u32,
+	#[field(offset = 776, get, set, ref, mut)] f194: u32,
+	#[field(offset = 780, get, set, ref, mut)] f195: u32,
+	#[field(offset = 784, get, set, ref, mut)] f196: u32,
+	#[field(offset = 788, get, set, ref, mut)] f197: u32,
+	#[field(offset = 792, get, set, ref, mut)] f198: u32,
+	#[field(offset = 796, get, set, ref, mut)] f199: u32,
+	#[field(offset = 800, get, set, ref, mut)] f200: u32,
+	#[field(offset = 804, get, set, ref, mut)] f201: u32,
+	#[field(offset = 808, get, set, ref, mut)] f202: u32,
+	#[field(offset = 812, get, set, ref, mut)] f203: u32,
+	#[field(offset = 816, get, set, ref, mut)] f204: u32,
+	#[field(offset = 820, get, set, ref, mut)] f205: u32,
+	#[field(offset = 824, get, set, ref, mut)] f206: u32,
+	#[field(offset = 828, get, set, ref, mut)] f207: u32,
+	#[field(offset = 832, get, set, ref, mut)] f208: u32,
+	#[field(offset = 836, get, set, ref, mut)] f209: u32,
+	#[field(offset = 840, get, set, ref, mut)] f210: u32,
+	#[field(offset = 844, get, set, ref, mut)] f211: u32,
+	#[field(offset = 848, get, set, ref, mut)] f212: u32,
+	#[field(offset = 852, get, set, ref, mut)] f213: u32,
+	#[field(offset = 856, get, set, ref, mut)] f214: u32,
+	#[field(offset = 860, get, set, ref, mut)] f215: u32,
+	#[field(offset = 864, get, set, ref, mut)] f216: u32,
+	#[field(offset = 868, get, set, ref, mut)] f217: u32,
+	#[field(offset = 872, get, set, ref, mut)] f218: u32,
+	#[field(offset = 876, get, set, ref, mut)] f219: u32,
+	#[field(offset = 880, get, set, ref, mut)] f220: u32,
+	#[field(offset = 884, get, set, ref, mut)] f221: u32,
+	#[field(offset = 888, get, set, ref, mut)] f222: u32,
+	#[field(offset = 892, get, set, ref, mut)] f223: u32,
+	#[field(offset = 896, get, set, ref, mut)] f224: u32,
+	#[field(offset = 900, get, set, ref, mut)] f225: u32,
+	#[field(offset = 904, get, set, ref, mut)] f226: u32,
+	#[field(offset = 908, get, set, ref, mut)] f227: u32,
+	#[field(offset = 912, get, set, ref, mut)] f228: u32,
+	#[field(offset = 916, get, set, ref, mut)] f229: u32,
+	#[field(offset = 920, get, set, ref, mut)] f230: u32,
+	#[field(offset = 924, get, set, ref, mut)] f231: u32,
+	#[field(offset = 928, get, set, ref, mut)] f232: u32,
+	#[field(offset = 932, get, set, ref, mut)] f233: u32,
+	#[field(offset = 936, get, set, ref, mut)] f234: u32,
+	#[field(offset = 940, get, set, ref, mut)] f235: u32,
+	#[field(offset = 944, get, set, ref, mut)] f236: u32,
+	#[field(offset = 948, get, set, ref, mut)] f237: u32,
+	#[field(offset = 952, get, set, ref, mut)] f238: u32,
+	#[field(offset = 956, get, set, ref, mut)] f239: u32,
+	#[field(offset = 960, get, set, ref, mut)] f240: u32,
+	#[field(offset = 964, get, set, ref, mut)] f241: u32,
+	#[field(offset = 968, get, set, ref, mut)] f242: u32,
+	#[field(offset = 972, get, set, ref, mut)] f243: u32,
+	#[field(offset = 976, get, set, ref, mut)] f244: u32,
+	#[field(offset = 980, get, set, ref, mut)] f245: u32,
+	#[field(offset = 984, get, set, ref, mut)] f246: u32,
+	#[field(offset = 988, get, set, ref, mut)] f247: u32,
+	#[field(offset = 992, get, set, ref, mut)] f248: u32,
+	#[field(offset = 996, get, set, ref, mut)] f249: u32,
+	#[field(offset = 1000, get, set, ref, mut)] f250: u32,
+	#[field(offset = 1004, get, set, ref, mut)] f251: u32,
+	#[field(offset = 1008, get, set, ref, mut)] f252: u32,
+	#[field(offset = 1012, get, set, ref, mut)] f253: u32,
+	#[field(offset = 1016, get, set, ref, mut)] f254: u32,
+	#[field(offset = 1020, get, set, ref, mut)] f255: u32,
+	#[field(offset = 1024, get, set, ref, mut)] f256: u32,
+	#[field(offset = 1028, get, set, ref, mut)] f257: u32,
+	#[field(offset = 1032, get, set, ref, mut)] f258: u32,
+	#[field(offset = 1036, get, set, ref, mut)] f259: u32,
+	#[field(offset = 1040, get, set, ref, mut)] f260: u32,
+	#[field(offset = 1044, get, set, ref, mut)] f261: u32,
+	#[field(offset = 1048, get, set, ref, mut)] f262: u32,
+	#[field(offset = 1052, get, set, ref, mut)] f263: u32,
+	#[field(offset = 1056, get, set, ref, mut)] f264: u32,
+	#[field(offset = 1060, get, set, ref, mut)] f265: u32,
+	#[field(offset = 1064, get, set, ref, mut)] f266: u32,
+	#[field(offset = 1068, get, set, ref, mut)] f267: u32,
+	#[field(offset = 1072, get, set, ref, mut)] f268: u32,
+	#[field(offset = 1076, get, set, ref, mut)] f269: u32,
+	#[field(offset = 1080, get, set, ref, mut)] f270: u32,
+	#[field(offset = 1084, get, set, ref, mut)] f271: u32,
+	#[field(offset = 1088, get, set, ref, mut)] f272: u32,
+	#[field(offset = 1092, get, set, ref, mut)] f273: u32,
+	#[field(offset = 1096, get, set, ref, mut)] f274: u32,
+	#[field(offset = 1100, get, set, ref, mut)] f275: u32,
+	#[field(offset = 1104, get, set, ref, mut)] f276: u32,
+	#[field(offset = 1108, get, set, ref, mut)] f277: u32,
+	#[field(offset = 1112, get, set, ref, mut)] f278: u32,
+	#[field(offset = 1116, get, set, ref, mut)] f279: u32,
+	#[field(offset = 1120, get, set, ref, mut)] f280: u32,
+	#[field(offset = 1124, get, set, ref, mut)] f281: u32,
+	#[field(offset = 1128, get, set, ref, mut)] f282: u32,
+	#[field(offset = 1132, get, set, ref, mut)] f283: u32,
+	#[field(offset = 1136, get, set, ref, mut)] f284: u32,
+	#[field(offset = 1140, get, set, ref, mut)] f285: u32,
+	#[field(offset = 1144, get, set, ref, mut)] f286: u32,
+	#[field(offset = 1148, get, set, ref, mut)] f287: u32,
+	#[field(offset = 1152, get, set, ref, mut)] f288: u32,
+	#[field(offset = 1156, get, set, ref, mut)] f289: u32,
+	#[field(offset = 1160, get, set, ref, mut)] f290: u32,
+	#[field(offset = 1164, get, set, ref, mut)] f291: u32,
+	#[field(offset = 1168, get, set, ref, mut)] f292: u32,
+	#[field(offset = 1172, get, set, ref, mut)] f293: u32,
+	#[field(offset = 1176, get, set, ref, mut)] f294: u32,
+	#[field(offset = 1180, get, set, ref, mut)] f295: u32,
+	#[field(offset = 1184, get, set, ref, mut)] f296: u32,
+	#[field(offset = 1188, get, set, ref, mut)] f297: u32,
+	#[field(offset = 1192, get, set, ref, mut)] f298: u32,
+	#[field(offset = 1196, get, set, ref, mut)] f299: u32,
+	#[field(offset = 1200, get, set, ref, mut)] f300: u32,
+	#[field(offset = 1204, get, set, ref, mut)] f301: u32,
+	#[field(offset = 1208, get, set, ref, mut)] f302: u32,
+	#[field(offset = 1212, get, set, ref, mut)] f303: u32,
+	#[field(offset = 1216, get, set, ref, mut)] f304: u32,
+	#[field(offset = 1220, get, set, ref, mut)] f305: u32,
+	#[field(offset = 1224, get, set, ref, mut)] f306: u32,
+	#[field(offset = 1228, get, set, ref, mut)] f307: u32,
+	#[field(offset = 1232, get, set, ref, mut)] f308: u32,
+	#[field(offset = 1236, get, set, ref, mut)] f309: u32,
+	#[field(offset = 1240, get, set, ref, mut)] f310: u32,
+	#[field(offset = 1244, get, set, ref, mut)] f311: u32,
+	#[field(offset = 1248, get, set, ref, mut)] f312: u32,
+	#[field(offset = 1252, get, set, ref, mut)] f313: u32,
+	#[field(offset = 1256, get, set, ref, mut)] f314: u32,
+	#[field(offset = 1260, get, set, ref, mut)] f315: u32,
+	#[field(offset = 1264, get, set, ref, mut)] f316: u32,
+	#[field(offset = 1268, get, set, ref, mut)] f317: u32,
+	#[field(offset = 1272, get, set, ref, mut)] f318: u32,
+	#[field(offset = 1276, get, set, ref, mut)] f319: u32,
+	#[field(offset = 1280, get, set, ref, mut)] f320: u32,
+	#[field(offset = 1284, get, set, ref, mut)] f321: u32,
+	#[field(offset = 1288, get, set, ref, mut)] f322: u32,
+	#[field(offset = 1292, get, set, ref, mut)] f323: u32,
+	#[field(offset = 1296, get, set, ref, mut)] f324: u32,
+	#[field(offset = 1300, get, set, ref, mut)] f325: u32,
+	#[field(offset = 1304, get, set, ref, mut)] f326: u32,
+	#[field(offset = 1308, get, set, ref, mut)] f327: u32,
+	#[field(offset = 1312, get, set, ref, mut)] f328: u32,
+	#[field(offset = 1316, get, set, ref, mut)] f329: u32,
+	#[field(offset = 1320, get, set, ref, mut)] f330: u32,
+	#[field(offset = 1324, get, set, ref, mut)] f331: u32,
+	#[field(offset = 1328, get, set, ref, mut)] f332: u32,
+	#[field(offset = 1332, get, set, ref, mut)] f333: u32,
+	#[field(offset = 1336, get, set, ref, mut)] f334: u32,
+	#[field(offset = 1340, get, set, ref, mut)] f335: u32,
+	#[field(offset = 1344, get, set, ref, mut)] f336: u32,
+	#[field(offset = 1348, get, set, ref, mut)] f337: u32,
+	#[field(offset = 1352, get, set, ref, mut)] f338: u32,
+	#[field(offset = 1356, get, set, ref, mut)] f339: u32,
+	#[field(offset = 1360, get, set, ref, mut)] f340: u32,
+	#[field(offset = 1364, get, set, ref, mut)] f341: u32,
+	#[field(offset = 1368, get, set, ref, mut)] f342: u32,
+	#[field(offset = 1372, get, set, ref, mut)] f343: u32,
+	#[field(offset = 1376, get, set, ref, mut)] f344: u32,
+	#[field(offset = 1380, get, set, ref, mut)] f345: u32,
+	#[field(offset = 1384, get, set, ref, mut)] f346: u32,
+	#[field(offset = 1388, get, set, ref, mut)] f347: u32,
+	#[field(offset = 1392, get, set, ref, mut)] f348: u32,
+	#[field(offset = 1396, get, set, ref, mut)] f349: u32,
+	#[field(offset = 1400, get, set, ref, mut)] f350: u32,
+	#[field(offset = 1404, get, set, ref, mut)] f351: u32,
+	#[field(offset = 1408, get, set, ref, mut)] f352: u32,
+	#[field(offset = 1412, get, set, ref, mut)] f353: u32,
+	#[field(offset = 1416, get, set, ref, mut)] f354: u32,
+	#[field(offset = 1420, get, set, ref, mut)] f355: u32,
+	#[field(offset = 1424, get, set, ref, mut)] f356: u32,
+	#[field(offset = 1428, get, set, ref, mut)] f357: u32,
+	#[field(offset = 1432, get, set, ref, mut)] f358: u32,
+	#[field(offset = 1436, get, set, ref, mut)] f359: u32,
+	#[field(offset = 1440, get, set, ref, mut)] f360: u32,
+	#[field(offset = 1444, get, set, ref, mut)] f361: u32,
+	#[field(offset = 1448, get, set, ref, mut)] f362: u32,
+	#[field(offset = 1452, get, set, ref, mut)] f363: u32,
+	#[field(offset = 1456, get, set, ref, mut)] f364: u32,
+	#[field(offset = 1460, get, set, ref, mut)] f365: u32,
+	#[field(offset = 1464, get, set, ref, mut)] f366: u32,
+	#[field(offset = 1468, get, set, ref, mut)] f367: u32,
+	#[field(offset = 1472, get, set, ref, mut)] f368: u32,
+	#[field(offset = 1476, get, set, ref, mut)] f369: u32,
+	#[field(offset = 1480, get, set, ref, mut)] f370: u32,
+	#[field(offset = 1484, get, set, ref, mut)] f371: u32,
+	#[field(offset = 1488, get, set, ref, mut)] f372: u32,
+	#[field(offset = 1492, get, set, ref, mut)] f373: u32,
+	#[field(offset = 1496, get, set, ref, mut)] f374: u32,
+	#[field(offset = 1500, get, set, ref, mut)] f375: u32,
+	#[field(offset = 1504, get, set, ref, mut)] f376: u32,
+	#[field(offset = 1508, get, set, ref, mut)] f377: u32,
+	#[field(offset = 1512, get, set, ref, mut)] f378: u32,
+	#[field(offset = 1516, get, set, ref, mut)] f379: u32,
+	#[field(offset = 1520, get, set, ref, mut)] f380: u32,
+	#[field(offset = 1524, get, set, ref, mut)] f381: u32,
+	#[field(offset = 1528, get, set, ref, mut)] f382: u32,
+	#[field(offset = 1532, get, set, ref, mut)] f383: u32,
+	#[field(offset = 1536, get, set, ref, mut)] f384: u32,
+	#[field(offset = 1540, get, set, ref, mut)] f385: u32,
+	#[field(offset = 1544, get, set, ref, mut)] f386: u32,
+	#[field(offset = 1548, get, set, ref, mut)] f387: u32,
+	#[field(offset = 1552, get, set, ref, mut)] f388: u32,
+	#[field(offset = 1556, get, set, ref, mut)] f389: u32,
+	#[field(offset = 1560, get, set, ref, mut)] f390: u32,
+	#[field(offset = 1564, get, set, ref, mut)] f391: u32,
+	#[field(offset = 1568, get, set, ref, mut)] f392: u32,
+	#[field(offset = 1572, get, set, ref, mut)] f393: u32,
+	#[field(offset = 1576, get, set, ref, mut)] f394: u32,
+	#[field(offset = 1580, get, set, ref, mut)] f395: u32,
+	#[field(offset = 1584, get, set, ref, mut)] f396: u32,
+	#[field(offset = 1588, get, set, ref, mut)] f397: u32,
+	#[field(offset = 1592, get, set, ref, mut)] f398: u32,
+	#[field(offset = 1596, get, set, ref, mut)] f399: u32,
+	#[field(offset = 1600, get, set, ref, mut)] f400: u32,
+	#[field(offset = 1604, get, set, ref, mut)] f401: u32,
+	#[field(offset = 1608, get, set, ref, mut)] f402: u32,
+	#[field(offset = 1612, get, set, ref, mut)] f403: u32,
+	#[field(offset = 1616, get, set, ref, mut)] f404: u32,
+	#[field(offset = 1620, get, set, ref, mut)] f405: u32,
+	#[field(offset = 1624, get, set, ref, mut)] f406: u32,
+	#[field(offset = 1628, get, set, ref, mut)] f407: u32,
+	#[field(offset = 1632, get, set, ref, mut)] f408: u32,
+	#[field(offset = 1636, get, set, ref, mut)] f409: u32,
+	#[field(offset = 1640, get, set, ref, mut)] f410: u32,
+	#[field(offset = 1644, get, set, ref, mut)] f411: u32,
+	#[field(offset = 1648, get, set, ref, mut)] f412: u32,
+	#[field(offset = 1652, get, set, ref, mut)] f413: u32,
+	#[field(offset = 1656, get, set, ref, mut)] f414: u32,
+	#[field(offset = 1660, get, set, ref, mut)] f415: u32,
+	#[field(offset = 1664, get, set, ref, mut)] f416: u32,
+	#[field(offset = 1668, get, set, ref, mut)] f417: u32,
+	#[field(offset = 1672, get, set, ref, mut)] f418: u32,
+	#[field(offset = 1676, get, set, ref, mut)] f419: u32,
+	#[field(offset = 1680, get, set, ref, mut)] f420: u32,
+	#[field(offset = 1684, get, set, ref, mut)] f421: u32,
+	#[field(offset = 1688, get, set, ref, mut)] f422: u32,
+	#[field(offset = 1692, get, set, ref, mut)] f423: u32,
+	#[field(offset = 1696, get, set, ref, mut)] f424: u32,
+	#[field(offset = 1700, get, set, ref, mut)] f425: u32,
+	#[field(offset = 1704, get, set, ref, mut)] f426: u32,
+	#[field(offset = 1708, get, set, ref, mut)] f427: u32,
+	#[field(offset = 1712, get, set, ref, mut)] f428: u32,
+	#[field(offset = 1716, get, set, ref, mut)] f429: u32,
+	#[field(offset = 1720, get, set, ref, mut)] f430: u32,
+	#[field(offset = 1724, get, set, ref, mut)] f431: u32,
+	#[field(offset = 1728, get, set, ref, mut)] f432: u32,
+	#[field(offset = 1732, get, set, ref, mut)] f433: u32,
+	#[field(offset = 1736, get, set, ref, mut)] f434: u32,
+	#[field(offset = 1740, get, set, ref, mut)] f435: u32,
+	#[field(offset = 1744, get, set, ref, mut)] f436: u32,
+	#[field(offset = 1748, get, set, ref, mut)] f437: u32,
+	#[field(offset = 1752, get, set, ref, mut)] f438: u32,
+	#[field(offset = 1756, get, set, ref, mut)] f439: u32,
+	#[field(offset = 1760, get, set, ref, mut)] f440: u32,
+	#[field(offset = 1764, get, set, ref, mut)] f441: u32,
+	#[field(offset = 1768, get, set, ref, mut)] f442: u32,
+	#[field(offset = 1772, get, set, ref, mut)] f443: u32,
+	#[field(offset = 1776, get, set, ref, mut)] f444: u32,
+	#[field(offset = 1780, get, set, ref, mut)] f445: u32,
+	#[field(offset = 1784, get, set, ref, mut)] f446: u32,
+	#[field(offset = 1788, get, set, ref, mut)] f447: u32,
+	#[field(offset = 1792, get, set, ref, mut)] f448: u32,
+	#[field(offset = 1796, get, set, ref, mut)] f449: u32,
+	#[field(offset = 1800, get, set, ref, mut)] f450: u32,
+	#[field(offset = 1804, get, set, ref, mut)] f451: u32,
+	#[field(offset = 1808, get, set, ref, mut)] f452: u32,
+	#[field(offset = 1812, get, set, ref, mut)] f453: u32,
+	#[field(offset = 1816, get, set, ref, mut)] f454: u32,
+	#[field(offset = 1820, get, set, ref, mut)] f455: u32,
+	#[field(offset = 1824, get, set, ref, mut)] f456: u32,
+	#[field(offset = 1828, get, set, ref, mut)] f457: u32,
+	#[field(offset = 1832, get, set, ref, mut)] f458: u32,
+	#[field(offset = 1836, get, set, ref, mut)] f459: u32,
+	#[field(offset = 1840, get, set, ref, mut)] f460: u32,
+	#[field(offset = 1844, get, set, ref, mut)] f461: u32,
+	#[field(offset = 1848, get, set, ref, mut)] f462: u32,
+	#[field(offset = 1852, get, set, ref, mut)] f463: u32,
+	#[field(offset = 1856, get, set, ref, mut)] f464: u32,
+	#[field(offset = 1860, get, set, ref, mut)] f465: u32,
+	#[field(offset = 1864, get, set, ref, mut)] f466: u32,
+	#[field(offset = 1868, get, set, ref, mut)] f467: u32,
+	#[field(offset = 1872, get, set, ref, mut)] f468: u32,
+	#[field(offset = 1876, get, set, ref, mut)] f469: u32,
+	#[field(offset = 1880, get, set, ref, mut)] f470: u32,
+	#[field(offset = 1884, get, set, ref, mut)] f471: u32,
+	#[field(offset = 1888, get, set, ref, mut)] f472: u32,
+	#[field(offset = 1892, get, set, ref, mut)] f473: u32,
+	#[field(offset = 1896, get, set, ref, mut)] f474: u32,
+	#[field(offset = 1900, get, set, ref, mut)] f475: u32,
+	#[field(offset = 1904, get, set, ref, mut)] f476: u32,
+	#[field(offset = 1908, get, set, ref, mut)] f477: u32,
+	#[field(offset = 1912, get, set, ref, mut)] f478: u32,
+	#[field(offset = 1916, get, set, ref, mut)] f479: u32,
+	#[field(offset = 1920, get, set, ref, mut)] f480: u32,
+	#[field(offset = 1924, get, set, ref, mut)] f481: u32,
+	#[field(offset = 1928, get, set, ref, mut)] f482: u32,
+	#[field(offset = 1932, get, set, ref, mut)] f483: u32,
+	#[field(offset = 1936, get, set, ref, mut)] f484: u32,
+	#[field(offset = 1940, get, set, ref, mut)] f485: u32,
+	#[field(offset = 1944, get, set, ref, mut)] f486: u32,
+	#[field(offset = 1948, get, set, ref, mut)] f487: u32,
+	#[field(offset = 1952, get, set, ref, mut)] f488: u32,
+	#[field(offset = 1956, get, set, ref, mut)] f489: u32,
+	#[field(offset = 1960, get, set, ref, mut)] f490: u32,
+	#[field(offset = 1964, get, set, ref, mut)] f491: u32,
+	#[field(offset = 1968, get, set, ref, mut)] f492: u32,
+	#[field(offset = 1972, get, set, ref, mut)] f493: u32,
+	#[field(offset = 1976, get, set, ref, mut)] f494: u32,
+	#[field(offset = 1980, get, set, ref, mut)] f495: u32,
+	#[field(offset = 1984, get, set, ref, mut)] f496: u32,
+	#[field(offset = 1988, get, set, ref, mut)] f497: u32,
+	#[field(offset = 1992, get, set, ref, mut)] f498: u32,
+	#[field(offset = 1996, get, set, ref, mut)] f499: u32,
+	#[field(offset = 2000, get, set, ref, mut)] f500: u32,
+	#[field(offset = 2004, get, set, ref, mut)] f501: u32,
+	#[field(offset = 2008, get, set, ref, mut)] f502: u32,
+	#[field(offset = 2012, get, set, ref, mut)] f503: u32,
+	#[field(offset = 2016, get, set, ref, mut)] f504: u32,
+	#[field(offset = 2020, get, set, ref, mut)] f505: u32,
+	#[field(offset = 2024, get, set, ref, mut)] f506: u32,
+	#[field(offset = 2028, get, set, ref, mut)] f507: u32,
+	#[field(offset = 2032, get, set, ref, mut)] f508: u32,
+	#[field(offset = 2036, get, set, ref, mut)] f509: u32,
+	#[field(offset = 2040, get, set, ref, mut)] f510: u32,
+	#[field(offset = 2044, get, set, ref, mut)] f511: u32,
+	#[field(offset = 2048, get, set, ref, mut)] f512: u32,
+	#[field(offset = 2052, get, set, ref, mut)] f513: u32,
+	#[field(offset = 2056, get, set, ref, mut)] f514: u32,
+	#[field(offset = 2060, get, set, ref, mut)] f515: u32,
+	#[field(offset = 2064, get, set, ref, mut)] f516: u32,
+	#[field(offset = 2068, get, set, ref, mut)] f517: u32,
+	#[field(offset = 2072, get, set, ref, mut)] f518: u32,
+	#[field(offset = 2076, get, set, ref, mut)] f519: u32,
+	#[field(offset = 2080, get, set, ref, mut)] f520: u32,
+	#[field(offset = 2084, get, set, ref, mut)] f521: u32,
+	#[field(offset = 2088, get, set, ref, mut)] f522: u32,
+	#[field(offset = 2092, get, set, ref, mut)] f523: u32,
+	#[field(offset = 2096, get, set, ref, mut)] f524: u32,
+	#[field(offset = 2100, get, set, ref, mut)] f525: u32,
+	#[field(offset = 2104, get, set, ref, mut)] f526: u32,
+	#[field(offset = 2108, get, set, ref, mut)] f527: u32,
+	#[field(offset = 2112, get, set, ref, mut)] f528: u32,
+	#[field(offset = 2116, get, set, ref, mut)] f529: u32,
+	#[field(offset = 2120, get, set, ref, mut)] f530: u32,
+	#[field(offset = 2124, get, set, ref, mut)] f531: u32,
+	#[field(offset = 2128, get, set, ref, mut)] f532: u32,
+	#[field(offset = 2132, get, set, ref, mut)] f533: u32,
+	#[field(offset = 2136, get, set, ref, mut)] f534: u32,
+	#[field(offset = 2140, get, set, ref, mut)] f535: u32,
+	#[field(offset = 2144, get, set, ref, mut)] f536: u32,
+	#[field(offset = 2148, get, set, ref, mut)] f537: u32,
+	#[field(offset = 2152, get, set, ref, mut)] f538: u32,
+	#[field(offset = 2156, get, set, ref, mut)] f539: u32,
+	#[field(offset = 2160, get, set, ref, mut)] f540: u32,
+	#[field(offset = 2164, get, set, ref, mut)] f541: u32,
+	#[field(offset = 2168, get, set, ref, mut)] f542: u32,
+	#[field(offset = 2172, get, set, ref, mut)] f543: u32,
+	#[field(offset = 2176, get, set, ref, mut)] f544: u32,
+	#[field(offset = 2180, get, set, ref, mut)] f545: u32,
+	#[field(offset = 2184, get, set, ref, mut)] f546: u32,
+	#[field(offset = 2188, get, set, ref, mut)] f547: u32,
+	#[field(offset = 2192, get, set, ref, mut)] f548: u32,
+	#[field(offset = 2196, get, set, ref, mut)] f549: u32,
+	#[field(offset = 2200, get, set, ref, mut)] f550: u32,
+	#[field(offset = 2204, get, set, ref, mut)] f551: u32,
+	#[field(offset = 2208, get, set, ref, mut)] f552: u32,
+	#[field(offset = 2212, get, set, ref, mut)] f553: u32,
+	#[field(offset = 2216, get, set, ref, mut)] f554: u32,
+	#[field(offset = 2220, get, set, ref, mut)] f555: u32,
+	#[field(offset = 2224, get, set, ref, mut)] f556: u32,
+	#[field(offset = 2228, get, set, ref, mut)] f557: u32,
+	#[field(offset = 2232, get, set, ref, mut)] f558: u32,
+	#[field(offset = 2236, get, set, ref, mut)] f559: u32,
+	#[field(offset = 2240, get, set, ref, mut)] f560: u32,
+	#[field(offset = 2244, get, set, ref, mut)] f561: u32,
+	#[field(offset = 2248, get, set, ref, mut)] f562: u32,
+	#[field(offset = 2252, get, set, ref, mut)] f563: u32,
+	#[field(offset = 2256, get, set, ref, mut)] f564: u32,
+	#[field(offset = 2260, get, set, ref, mut)] f565: u32,
+	#[field(offset = 2264, get, set, ref, mut)] f566: u32,
+	#[field(offset = 2268, get, set, ref, mut)] f567: u32,
+	#[field(offset = 2272, get, set, ref, mut)] f568: u32,
+	#[field(offset = 2276, get, set, ref, mut)] f569: u32,
+	#[field(offset = 2280, get, set, ref, mut)] f570: u32,
+	#[field(offset = 2284, get, set, ref, mut)] f571: u32,
+	#[field(offset = 2288, get, set, ref, mut)] f572: u32,
+	#[field(offset = 2292, get, set, ref, mut)] f573: u32,
+	#[field(offset = 2296, get, set, ref, mut)] f574: u32,
+	#[field(offset = 2300, get, set, ref, mut)] f575: u32,
+	#[field(offset = 2304, get, set, ref, mut)] f576: u32,
+	#[field(offset = 2308, get, set, ref, mut)] f577: u32,
+	#[field(offset = 2312, get, set, ref, mut)] f578: u32,
+	#[field(offset = 2316, get, set, ref, mut)] f579: u32,
+	#[field(offset = 2320, get, set, ref, mut)] f580: u32,
+	#[field(offset = 2324, get, set, ref, mut)] f581: u32,
+	#[field(offset = 2328, get, set, ref, mut)] f582: u32,
+	#[field(offset = 2332, get, set, ref, mut)] f583: u32,
+	#[field(offset = 2336, get, set, ref, mut)] f584: u32,
+	#[field(offset = 2340, get, set, ref, mut)] f585: u32,
+	#[field(offset = 2344, get, set, ref, mut)] f586: u32,
+	#[field(offset = 2348, get, set, ref, mut)] f587: u32,
+	#[field(offset = 2352, get, set, ref, mut)] f588: u32,
+	#[field(offset = 2356, get, set, ref, mut)] f589: u32,
+	#[field(offset = 2360, get, set, ref, mut)] f590: u32,
+	#[field(offset = 2364, get, set, ref, mut)] f591: u32,
+	#[field(offset = 2368, get, set, ref, mut)] f592: u32,
+	#[field(offset = 2372, get, set, ref, mut)] f593: u32,
+	#[field(offset = 2376, get, set, ref, mut)] f594: u32,
+	#[field(offset = 2380, get, set, ref, mut)] f595: u32,
+	#[field(offset = 2384, get, set, ref, mut)] f596: u32,
+	#[field(offset = 2388, get, set, ref, mut)] f597: u32,
+	#[field(offset = 2392, get, set, ref, mut)] f598: u32,
+	#[field(offset = 2396, get, set, ref, mut)] f599: u32,
+	#[field(offset = 2400, get, set, ref, mut)] f600: u32,
+	#[field(offset = 2404, get, set, ref, mut)] f601: u32,
+	#[field(offset = 2408, get, set, ref, mut)] f602: u32,
+	#[field(offset = 2412, get, set, ref, mut)] f603: u32,
+	#[field(offset = 2416, get, set, ref, mut)] f604: u32,
+	#[field(offset = 2420, get, set, ref, mut)] f605: u32,
+	#[field(offset = 2424, get, set, ref, mut)] f606: u32,
+	#[field(offset = 2428, get, set, ref, mut)] f607: u32,
+	#[field(offset = 2432, get, set, ref, mut)] f608: u32,
+	#[field(offset = 2436, get, set, ref, mut)] f609: u32,
+	#[field(offset = 2440, get, set, ref, mut)] f610: u32,
+	#[field(offset = 2444, get, set, ref, mut)] f611: u32,
+	#[field(offset = 2448, get, set, ref, mut)] f612: u32,
+	#[field(offset = 2452, get, set, ref, mut)] f613: u32,
+	#[field(offset = 2456, get, set, ref, mut)] f614: u32,
+	#[field(offset = 2460, get, set, ref, mut)] f615: u32,
+	#[field(offset = 2464, get, set, ref, mut)] f616: u32,
+	#[field(offset = 2468, get, set, ref, mut)] f617: u32,
+	#[field(offset = 2472, get, set, ref, mut)] f618: u32,
+	#[field(offset = 2476, get, set, ref, mut)] f619: u32,
+	#[field(offset = 2480, get, set, ref, mut)] f620: u32,
+	#[field(offset = 2484, get, set, ref, mut)] f621: u32,
+	#[field(offset = 2488, get, set, ref, mut)] f622: u32,
+	#[field(offset = 2492, get, set, ref, mut)] f623: u32,
+	#[field(offset = 2496, get, set, ref, mut)] f624: u32,
+	#[field(offset = 2500, get, set, ref, mut)] f625: u32,
+	#[field(offset = 2504, get, set, ref, mut)] f626: u32,
+	#[field(offset = 2508, get, set, ref, mut)] f627: u32,
+	#[field(offset = 2512, get, set, ref, mut)] f628: u32,
+	#[field(offset = 2516, get, set, ref, mut)] f629: u32,
+	#[field(offset = 2520, get, set, ref, mut)] f630: u32,
+	#[field(offset = 2524, get, set, ref, mut)] f631: u32,
+	#[field(offset = 2528, get, set, ref, mut)] f632: u32,
+	#[field(offset = 2532, get, set, ref, mut)] f633: u32,
+	#[field(offset = 2536, get, set, ref, mut)] f634: u32,
+	#[field(offset = 2540, get, set, ref, mut)] f635: u32,
+	#[field(offset = 2544, get, set, ref, mut)] f636: u32,
+	#[field(offset = 2548, get, set, ref, mut)] f637: u32,
+	#[field(offset = 2552, get, set, ref, mut)] f638: u32,
+	#[field(offset = 2556, get, set, ref, mut)] f639: u32,
+	#[field(offset = 2560, get, set, ref, mut)] f640: u32,
+	#[field(offset = 2564, get, set, ref, mut)] f641: u32,
+	#[field(offset = 2568, get, set, ref, mut)] f642: u32,
+	#[field(offset = 2572, get, set, ref, mut)] f643: u32,
+	#[field(offset = 2576, get, set, ref, mut)] f644: u32,
+	#[field(offset = 2580, get, set, ref, mut)] f645: u32,
+	#[field(offset = 2584, get, set, ref, mut)] f646: u32,
+	#[field(offset = 2588, get, set, ref, mut)] f647: u32,
+	#[field(offset = 2592, get, set, ref, mut)] f648: u32,
+	#[field(offset = 2596, get, set, ref, mut)] f649: u32,
+	#[field(offset = 2600, get, set, ref, mut)] f650: u32,
+	#[field(offset = 2604, get, set, ref, mut)] f651: u32,
+	#[field(offset = 2608, get, set, ref, mut)] f652: u32,
+	#[field(offset = 2612, get, set, ref, mut)] f653: u32,
+	#[field(offset = 2616, get, set, ref, mut)] f654: u32,
+	#[field(offset = 2620, get, set, ref, mut)] f655: u32,
+	#[field(offset = 2624, get, set, ref, mut)] f656: u32,
+	#[field(offset = 2628, get, set, ref, mut)] f657: u32,
+	#[field(offset = 2632, get, set, ref, mut)] f658: u32,
+	#[field(offset = 2636, get, set, ref, mut)] f659: u32,
+	#[field(offset = 2640, get, set, ref, mut)] f660: u32,
+	#[field(offset = 2644, get, set, ref, mut)] f661: u32,
+	#[field(offset = 2648, get, set, ref, mut)] f662: u32,
+	#[field(offset = 2652, get, set, ref, mut)] f663: u32,
+	#[field(offset = 2656, get, set, ref, mut)] f664: u32,
+	#[field(offset = 2660, get, set, ref, mut)] f665: u32,
+	#[field(offset = 2664, get, set, ref, mut)] f666: u32,
+	#[field(offset = 2668, get, set, ref, mut)] f667: u32,
+	#[field(offset = 2672, get, set, ref, mut)] f668: u32,
+	#[field(offset = 2676, get, set, ref, mut)] f669: u32,
+	#[field(offset = 2680, get, set, ref, mut)] f670: u32,
+	#[field(offset = 2684, get, set, ref, mut)] f671: u32,
+	#[field(offset = 2688, get, set, ref, mut)] f672: u32,
+	#[field(offset = 2692, get, set, ref, mut)] f673: u32,
+	#[field(offset = 2696, get, set, ref, mut)] f674: u32,
+	#[field(offset = 2700, get, set, ref, mut)] f675: u32,
+	#[field(offset = 2704, get, set, ref, mut)] f676: u32,
+	#[field(offset = 2708, get, set, ref, mut)] f677: u32,
+	#[field(offset = 2712, get, set, ref, mut)] f678: u32,
+	#[field(offset = 2716, get, set, ref, mut)] f679: u32,
+	#[field(offset = 2720, get, set, ref, mut)] f680: u32,
+	#[field(offset = 2724, get, set, ref, mut)] f681: u32,
+	#[field(offset = 2728, get, set, ref, mut)] f682: u32,
+	#[field(offset = 2732, get, set, ref, mut)] f683: u32,
+	#[field(offset = 2736, get, set, ref, mut)] f684: u32,
+	#[field(offset = 2740, get, set, ref, mut)] f685: u32,
+	#[field(offset = 2744, get, set, ref, mut)] f686: u32,
+	#[field(offset = 2748, get, set, ref, mut)] f687: u32,
+	#[field(offset = 2752, get, set, ref, mut)] f688: u32,
+	#[field(offset = 2756, get, set, ref, mut)] f689: u32,
+	#[field(offset = 2760, get, set, ref, mut)] f690: u32,
+	#[field(offset = 2764, get, set, ref, mut)] f691: u32,
+	#[field(offset = 2768, get, set, ref, mut)] f692: u32,
+	#[field(offset = 2772, get, set, ref, mut)] f693: u32,
+	#[field(offset = 2776, get, set, ref, mut)] f694: u32,
+	#[field(offset = 2780, get, set, ref, mut)] f695: u32,
+	#[field(offset = 2784, get, set, ref, mut)] f696: u32,
+	#[field(offset = 2788, get, set, ref, mut)] f697: u32,
+	#[field(offset = 2792, get, set, ref, mut)] f698: u32,
+	#[field(offset = 2796, get, set, ref, mut)] f699: u32,
+	#[field(offset = 2800, get, set, ref, mut)] f700: u32,
+	#[field(offset = 2804, get, set, ref, mut)] f701: u32,
+	#[field(offset = 2808, get, set, ref, mut)] f702: u32,
+	#[field(offset = 2812, get, set, ref, mut)] f703: u32,
+	#[field(offset = 2816, get, set, ref, mut)] f704: u32,
+	#[field(offset = 2820, get, set, ref, mut)] f705: u32,
+	#[field(offset = 2824, get, set, ref, mut)] f706: u32,
+	#[field(offset = 2828, get, set, ref, mut)] f707: u32,
+	#[field(offset = 2832, get, set, ref, mut)] f708: u32,
+	#[field(offset = 2836, get, set, ref, mut)] f709: u32,
+	#[field(offset = 2840, get, set, ref, mut)] f710: u32,
+	#[field(offset = 2844, get, set, ref, mut)] f711: u32,
+	#[field(offset = 2848, get, set, ref, mut)] f712: u32,
+	#[field(offset = 2852, get, set, ref, mut)] f713: u32,
+	#[field(offset = 2856, get, set, ref, mut)] f714: u32,
+	#[field(offset = 2860, get, set, ref, mut)] f715: u32,
+	#[field(offset = 2864, get, set, ref, mut)] f716: u32,
+	#[field(offset = 2868, get, set, ref, mut)] f717: u32,
+	#[field(offset = 2872, get, set, ref, mut)] f718: u32,
+	#[field(offset = 2876, get, set, ref, mut)] f719: u32,
+	#[field(offset = 2880, get, set, ref, mut)] f720: u32,
+	#[field(offset = 2884, get, set, ref, mut)] f721: u32,
+	#[field(offset = 2888, get, set, ref, mut)] f722: u32,
+	#[field(offset = 2892, get, set, ref, mut)] f723: u32,
+	#[field(offset = 2896, get, set, ref, mut)] f724: u32,
+	#[field(offset = 2900, get, set, ref, mut)] f725: u32,
+	#[field(offset = 2904, get, set, ref, mut)] f726: u32,
+	#[field(offset = 2908, get, set, ref, mut)] f727: u32,
+	#[field(offset = 2912, get, set, ref, mut)] f728: u32,
+	#[field(offset = 2916, get, set, ref, mut)] f729: u32,
+	#[field(offset = 2920, get, set, ref, mut)] f730: u32,
+	#[field(offset = 2924, get, set, ref, mut)] f731: u32,
+	#[field(offset = 2928, get, set, ref, mut)] f732: u32,
+	#[field(offset = 2932, get, set, ref, mut)] f733: u32,
+	#[field(offset = 2936, get, set, ref, mut)] f734: u32,
+	#[field(offset = 2940, get, set, ref, mut)] f735: u32,
+	#[field(offset = 2944, get, set, ref, mut)] f736: u32,
+	#[field(offset = 2948, get, set, ref, mut)] f737: u32,
+	#[field(offset = 2952, get, set, ref, mut)] f738: u32,
+	#[field(offset = 2956, get, set, ref, mut)] f739: u32,
+	#[field(offset = 2960, get, set, ref, mut)] f740: u32,
+	#[field(offset = 2964, get, set, ref, mut)] f741: u32,
+	#[field(offset = 2968, get, set, ref, mut)] f742: u32,
+	#[field(offset = 2972, get, set, ref, mut)] f743: u32,
+	#[field(offset = 2976, get, set, ref, mut)] f744: u32,
+	#[field(offset = 2980, get, set, ref, mut)] f745: u32,
+	#[field(offset = 2984, get, set, ref, mut)] f746: u32,
+	#[field(offset = 2988, get, set, ref, mut)] f747: u32,
+	#[field(offset = 2992, get, set, ref, mut)] f748: u32,
+	#[field(offset = 2996, get, set, ref, mut)] f749: u32,
+	#[field(offset = 3000, get, set, ref, mut)] f750: u32,
+	#[field(offset = 3004, get, set, ref, mut)] f751: u32,
+	#[field(offset = 3008, get, set, ref, mut)] f752: u32,
+	#[field(offset = 3012, get, set, ref, mut)] f753: u32,
+	#[field(offset = 3016, get, set, ref, mut)] f754: u32,
+	#[field(offset = 3020, get, set, ref, mut)] f755: u32,
+	#[field(offset = 3024, get, set, ref, mut)] f756: u32,
+	#[field(offset = 3028, get, set, ref, mut)] f757: u32,
+	#[field(offset = 3032, get, set, ref, mut)] f758: u32,
+	#[field(offset = 3036, get, set, ref, mut)] f759: u32,
+	#[field(offset = 3040, get, set, ref, mut)] f760: u32,
+	#[field(offset = 3044, get, set, ref, mut)] f761: u32,
+	#[field(offset = 3048, get, set, ref, mut)] f762: u32,
+	#[field(offset = 3052, get, set, ref, mut)] f763: u32,
+	#[field(offset = 3056, get, set, ref, mut)] f764: u32,
+	#[field(offset = 3060, get, set, ref, mut)] f765: u32,
+	#[field(offset = 3064, get, set, ref, mut)] f766: u32,
+	#[field(offset = 3068, get, set, ref, mut)] f767: u32,
+	#[field(offset = 3072, get, set, ref, mut)] f768: u32,
+	#[field(offset = 3076, get, set, ref, mut)] f769: u32,
+	#[field(offset = 3080, get, set, ref, mut)] f770: u32,
+	#[field(offset = 3084, get, set, ref, mut)] f771: u32,
+	#[field(offset = 3088, get, set, ref, mut)] f772: u32,
+	#[field(offset = 3092, get, set, ref, mut)] f773: u32,
+	#[field(offset = 3096, get, set, ref, mut)] f774: u32,
+	#[field(offset = 3100, get, set, ref, mut)] f775: u32,
+	#[field(offset = 3104, get, set, ref, mut)] f776: u32,
+	#[field(offset = 3108, get, set, ref, mut)] f777: u32,
+	#[field(offset = 3112, get, set, ref, mut)] f778: u32,
+	#[field(offset = 3116, get, set, ref, mut)] f779: u32,
+	#[field(offset = 3120, get, set, ref, mut)] f780: u32,
+	#[field(offset = 3124, get, set, ref, mut)] f781: u32,
+	#[field(offset = 3128, get, set, ref, mut)] f782: u32,
+	#[field(offset = 3132, get, set, ref, mut)] f783: u32,
+	#[field(offset = 3136, get, set, ref, mut)] f784: u32,
+	#[field(offset = 3140, get, set, ref, mut)] f785: u32,
+	#[field(offset = 3144, get, set, ref, mut)] f786: u32,
+	#[field(offset = 3148, get, set, ref, mut)] f787: u32,
+	#[field(offset = 3152, get, set, ref, mut)] f788: u32,
+	#[field(offset = 3156, get, set, ref, mut)] f789: u32,
+	#[field(offset = 3160, get, set, ref, mut)] f790: u32,
+	#[field(offset = 3164, get, set, ref, mut)] f791: u32,
+	#[field(offset = 3168, get, set, ref, mut)] f792: u32,
+	#[field(offset = 3172, get, set, ref, mut)] f793: u32,
+	#[field(offset = 3176, get, set, ref, mut)] f794: u32,
+	#[field(offset = 3180, get, set, ref, mut)] f795: u32,
+	#[field(offset = 3184, get, set, ref, mut)] f796: u32,
+	#[field(offset = 3188, get, set, ref, mut)] f797: u32,
+	#[field(offset = 3192, get, set, ref, mut)] f798: u32,
+	#[field(offset = 3196, get, set, ref, mut)] f799: u32,
+	#[field(offset = 3200, get, set, ref, mut)] f800: u32,
+	#[field(offset = 3204, get, set, ref, mut)] f801: u32,
+	#[field(offset = 3208, get, set, ref, mut)] f802: u32,
+	#[field(offset = 3212, get, set, ref, mut)] f803: u32,
+	#[field(offset = 3216, get, set, ref, mut)] f804: u32,
+	#[field(offset = 3220, get, set, ref, mut)] f805: u32,
+	#[field(offset = 3224, get, set, ref, mut)] f806: u32,
+	#[field(offset = 3228, get, set, ref, mut)] f807: u32,
+	#[field(offset = 3232, get, set, ref, mut)] f808: u32,
+	#[field(offset = 3236, get, set, ref, mut)] f809: u32,
+	#[field(offset = 3240, get, set, ref, mut)] f810: u32,
+	#[field(offset = 3244, get, set, ref, mut)] f811: u32,
+	#[field(offset = 3248, get, set, ref, mut)] f812: u32,
+	#[field(offset = 3252, get, set, ref, mut)] f813: u32,
+	#[field(offset = 3256, get, set, ref, mut)] f814: u32,
+	#[field(offset = 3260, get, set, ref, mut)] f815: u32,
+	#[field(offset = 3264, get, set, ref, mut)] f816: u32,
+	#[field(offset = 3268, get, set, ref, mut)] f817: u32,
+	#[field(offset = 3272, get, set, ref, mut)] f818: u32,
+	#[field(offset = 3276, get, set, ref, mut)] f819: u32,
+	#[field(offset = 3280, get, set, ref, mut)] f820: u32,
+	#[field(offset = 3284, get, set, ref, mut)] f821: u32,
+	#[field(offset = 3288, get, set, ref, mut)] f822: u32,
+	#[field(offset = 3292, get, set, ref, mut)] f823: u32,
+	#[field(offset = 3296, get, set, ref, mut)] f824: u32,
+	#[field(offset = 3300, get, set, ref, mut)] f825: u32,
+	#[field(offset = 3304, get, set, ref, mut)] f826: u32,
+	#[field(offset = 3308, get, set, ref, mut)] f827: u32,
+	#[field(offset = 3312, get, set, ref, mut)] f828: u32,
+	#[field(offset = 3316, get, set, ref, mut)] f829: u32,
+	#[field(offset = 3320, get, set, ref, mut)] f830: u32,
+	#[field(offset = 3324, get, set, ref, mut)] f831: u32,
+	#[field(offset = 3328, get, set, ref, mut)] f832: u32,
+	#[field(offset = 3332, get, set, ref, mut)] f833: u32,
+	#[field(offset = 3336, get, set, ref, mut)] f834: u32,
+	#[field(offset = 3340, get, set, ref, mut)] f835: u32,
+	#[field(offset = 3344, get, set, ref, mut)] f836: u32,
+	#[field(offset = 3348, get, set, ref, mut)] f837: u32,
+	#[field(offset = 3352, get, set, ref, mut)] f838: u32,
+	#[field(offset = 3356, get, set, ref, mut)] f839: u32,
+	#[field(offset = 3360, get, set, ref, mut)] f840: u32,
+	#[field(offset = 3364, get, set, ref, mut)] f841: u32,
+	#[field(offset = 3368, get, set, ref, mut)] f842: u32,
+	#[field(offset = 3372, get, set, ref, mut)] f843: u32,
+	#[field(offset = 3376, get, set, ref, mut)] f844: u32,
+	#[field(offset = 3380, get, set, ref, mut)] f845: u32,
+	#[field(offset = 3384, get, set, ref, mut)] f846: u32,
+	#[field(offset = 3388, get, set, ref, mut)] f847: u32,
+	#[field(offset = 3392, get, set, ref, mut)] f848: u32,
+	#[field(offset = 3396, get, set, ref, mut)] f849: u32,
+	#[field(offset = 3400, get, set, ref, mut)] f850: u32,
+	#[field(offset = 3404, get, set, ref, mut)] f851: u32,
+	#[field(offset = 3408, get, set, ref, mut)] f852: u32,
+	#[field(offset = 3412, get, set, ref, mut)] f853: u32,
+	#[field(offset = 3416, get, set, ref, mut)] f854: u32,
+	#[field(offset = 3420, get, set, ref, mut)] f855: u32,
+	#[field(offset = 3424, get, set, ref, mut)] f856: u32,
+	#[field(offset = 3428, get, set, ref, mut)] f857: u32,
+	#[field(offset = 3432, get, set, ref, mut)] f858: u32,
+	#[field(offset = 3436, get, set, ref, mut)] f859: u32,
+	#[field(offset = 3440, get, set, ref, mut)] f860: u32,
+	#[field(offset = 3444, get, set, ref, mut)] f861: u32,
+	#[field(offset = 3448, get, set, ref, mut)] f862: u32,
+	#[field(offset = 3452, get, set, ref, mut)] f863: u32,
+	#[field(offset = 3456, get, set, ref, mut)] f864: u32,
+	#[field(offset = 3460, get, set, ref, mut)] f865: u32,
+	#[field(offset = 3464, get, set, ref, mut)] f866: u32,
+	#[field(offset = 3468, get, set, ref, mut)] f867: u32,
+	#[field(offset = 3472, get, set, ref, mut)] f868: u32,
+	#[field(offset = 3476, get, set, ref, mut)] f869: u32,
+	#[field(offset = 3480, get, set, ref, mut)] f870: u32,
+	#[field(offset = 3484, get, set, ref, mut)] f871: u32,
+	#[field(offset = 3488, get, set, ref, mut)] f872: u32,
+	#[field(offset = 3492, get, set, ref, mut)] f873: u32,
+	#[field(offset = 3496, get, set, ref, mut)] f874: u32,
+	#[field(offset = 3500, get, set, ref, mut)] f875: u32,
+	#[field(offset = 3504, get, set, ref, mut)] f876: u32,
+	#[field(offset = 3508, get, set, ref, mut)] f877: u32,
+	#[field(offset = 3512, get, set, ref, mut)] f878: u32,
+	#[field(offset = 3516, get, set, ref, mut)] f879: u32,
+	#[field(offset = 3520, get, set, ref, mut)] f880: u32,
+	#[field(offset = 3524, get, set, ref, mut)] f881: u32,
+	#[field(offset = 3528, get, set, ref, mut)] f882: u32,
+	#[field(offset = 3532, get, set, ref, mut)] f883: u32,
+	#[field(offset = 3536, get, set, ref, mut)] f884: u32,
+	#[field(offset = 3540, get, set, ref, mut)] f885: u32,
+	#[field(offset = 3544, get, set, ref, mut)] f886: u32,
+	#[field(offset = 3548, get, set, ref, mut)] f887: u32,
+	#[field(offset = 3552, get, set, ref, mut)] f888: u32,
+	#[field(offset = 3556, get, set, ref, mut)] f889: u32,
+	#[field(offset = 3560, get, set, ref, mut)] f890: u32,
+	#[field(offset = 3564, get, set, ref, mut)] f891: u32,
+	#[field(offset = 3568, get, set, ref, mut)] f892: u32,
+	#[field(offset = 3572, get, set, ref, mut)] f893: u32,
+	#[field(offset = 3576, get, set, ref, mut)] f894: u32,
+	#[field(offset = 3580, get, set, ref, mut)] f895: u32,
+	#[field(offset = 3584, get, set, ref, mut)] f896: u32,
+	#[field(offset = 3588, get, set, ref, mut)] f897: u32,
+	#[field(offset = 3592, get, set, ref, mut)] f898: u32,
+	#[field(offset = 3596, get, set, ref, mut)] f899: u32,
+	#[field(offset = 3600, get, set, ref, mut)] f900: u32,
+	#[field(offset = 3604, get, set, ref, mut)] f901: u32,
+	#[field(offset = 3608, get, set, ref, mut)] f902: u32,
+	#[field(offset = 3612, get, set, ref, mut)] f903: u32,
+	#[field(offset = 3616, get, set, ref, mut)] f904: u32,
+	#[field(offset = 3620, get, set, ref, mut)] f905: u32,
+	#[field(offset = 3624, get, set, ref, mut)] f906: u32,
+	#[field(offset = 3628, get, set, ref, mut)] f907: u32,
+	#[field(offset = 3632, get, set, ref, mut)] f908: u32,
+	#[field(offset = 3636, get, set, ref, mut)] f909: u32,
+	#[field(offset = 3640, get, set, ref, mut)] f910: u32,
+	#[field(offset = 3644, get, set, ref, mut)] f911: u32,
+	#[field(offset = 3648, get, set, ref, mut)] f912: u32,
+	#[field(offset = 3652, get, set, ref, mut)] f913: u32,
+	#[field(offset = 3656, get, set, ref, mut)] f914: u32,
+	#[field(offset = 3660, get, set, ref, mut)] f915: u32,
+	#[field(offset = 3664, get, set, ref, mut)] f916: u32,
+	#[field(offset = 3668, get, set, ref, mut)] f917: u32,
+	#[field(offset = 3672, get, set, ref, mut)] f918: u32,
+	#[field(offset = 3676, get, set, ref, mut)] f919: u32,
+	#[field(offset = 3680, get, set, ref, mut)] f920: u32,
+	#[field(offset = 3684, get, set, ref, mut)] f921: u32,
+	#[field(offset = 3688, get, set, ref, mut)] f922: u32,
+	#[field(offset = 3692, get, set, ref, mut)] f923: u32,
+	#[field(offset = 3696, get, set, ref, mut)] f924: u32,
+	#[field(offset = 3700, get, set, ref, mut)] f925: u32,
+	#[field(offset = 3704, get, set, ref, mut)] f926: u32,
+	#[field(offset = 3708, get, set, ref, mut)] f927: u32,
+	#[field(offset = 3712, get, set, ref, mut)] f928: u32,
+	#[field(offset = 3716, get, set, ref, mut)] f929: u32,
+	#[field(offset = 3720, get, set, ref, mut)] f930: u32,
+	#[field(offset = 3724, get, set, ref, mut)] f931: u32,
+	#[field(offset = 3728, get, set, ref, mut)] f932: u32,
+	#[field(offset = 3732, get, set, ref, mut)] f933: u32,
+	#[field(offset = 3736, get, set, ref, mut)] f934: u32,
+	#[field(offset = 3740, get, set, ref, mut)] f935: u32,
+	#[field(offset = 3744, get, set, ref, mut)] f936: u32,
+	#[field(offset = 3748, get, set, ref, mut)] f937: u32,
+	#[field(offset = 3752, get, set, ref, mut)] f938: u32,
+	#[field(offset = 3756, get, set, ref, mut)] f939: u32,
+	#[field(offset = 3760, get, set, ref, mut)] f940: u32,
+	#[field(offset = 3764, get, set, ref, mut)] f941: u32,
+	#[field(offset = 3768, get, set, ref, mut)] f942: u32,
+	#[field(offset = 3772, get, set, ref, mut)] f943: u32,
+	#[field(offset = 3776, get, set, ref, mut)] f944: u32,
+	#[field(offset = 3780, get, set, ref, mut)] f945: u32,
+	#[field(offset = 3784, get, set, ref, mut)] f946: u32,
+	#[field(offset = 3788, get, set, ref, mut)] f947: u32,
+	#[field(offset = 3792, get, set, ref, mut)] f948: u32,
+	#[field(offset = 3796, get, set, ref, mut)] f949: u32,
+	#[field(offset = 3800, get, set, ref, mut)] f950: u32,
+	#[field(offset = 3804, get, set, ref, mut)] f951: u32,
+	#[field(offset = 3808, get, set, ref, mut)] f952: u32,
+	#[field(offset = 3812, get, set, ref, mut)] f953: u32,
+	#[field(offset = 3816, get, set, ref, mut)] f954: u32,
+	#[field(offset = 3820, get, set, ref, mut)] f955: u32,
+	#[field(offset = 3824, get, set, ref, mut)] f956: u32,
+	#[field(offset = 3828, get, set, ref, mut)] f957: u32,
+	#[field(offset = 3832, get, set, ref, mut)] f958: u32,
+	#[field(offset = 3836, get, set, ref, mut)] f959: u32,
+	#[field(offset = 3840, get, set, ref, mut)] f960: u32,
+	#[field(offset = 3844, get, set, ref, mut)] f961: u32,
+	#[field(offset = 3848, get, set, ref, mut)] f962: u32,
+	#[field(offset = 3852, get, set, ref, mut)] f963: u32,
+	#[field(offset = 3856, get, set, ref, mut)] f964: u32,
+	#[field(offset = 3860, get, set, ref, mut)] f965: u32,
+	#[field(offset = 3864, get, set, ref, mut)] f966: u32,
+	#[field(offset = 3868, get, set, ref, mut)] f967: u32,
+	#[field(offset = 3872, get, set, ref, mut)] f968: u32,
+	#[field(offset = 3876, get, set, ref, mut)] f969: u32,
+	#[field(offset = 3880, get, set, ref, mut)] f970: u32,
+	#[field(offset = 3884, get, set, ref, mut)] f971: u32,
+	#[field(offset = 3888, get, set, ref, mut)] f972: u32,
+	#[field(offset = 3892, get, set, ref, mut)] f973: u32,
+	#[field(offset = 3896, get, set, ref, mut)] f974: u32,
+	#[field(offset = 3900, get, set, ref, mut)] f975: u32,
+	#[field(offset = 3904, get, set, ref, mut)] f976: u32,
+	#[field(offset = 3908, get, set, ref, mut)] f977: u32,
+	#[field(offset = 3912, get, set, ref, mut)] f978: u32,
+	#[field(offset = 3916, get, set, ref, mut)] f979: u32,
+	#[field(offset = 3920, get, set, ref, mut)] f980: u32,
+	#[field(offset = 3924, get, set, ref, mut)] f981: u32,
+	#[field(offset = 3928, get, set, ref, mut)] f982: u32,
+	#[field(offset = 3932, get, set, ref, mut)] f983: u32,
+	#[field(offset = 3936, get, set, ref, mut)] f984: u32,
+	#[field(offset = 3940, get, set, ref, mut)] f985: u32,
+	#[field(offset = 3944, get, set, ref, mut)] f986: u32,
+	#[field(offset = 3948, get, set, ref, mut)] f987: u32,
+	#[field(offset = 3952, get, set, ref, mut)] f988: u32,
+	#[field(offset = 3956, get, set, ref, mut)] f989: u32,
+	#[field(offset = 3960, get, set, ref, mut)] f990: u32,
+	#[field(offset = 3964, get, set, ref, mut)] f991: u32,
+	#[field(offset = 3968, get, set, ref, mut)] f992: u32,
+	#[field(offset = 3972, get, set, ref, mut)] f993: u32,
+	#[field(offset = 3976, get, set, ref, mut)] f994: u32,
+	#[field(offset = 3980, get, set, ref, mut)] f995: u32,
+	#[field(offset = 3984, get, set, ref, mut)] f996: u32,
+	#[field(offset = 3988, get, set, ref, mut)] f997: u32,
+	#[field(offset = 3992, get, set, ref, mut)] f998: u32,
+	#[field(offset = 3996, get, set, ref, mut)] f999: u32,
+}
+
+#[test]
+fn large() {
+	let mut large = Large::default();
+	large.set_f000(1).set_f250(2).set_f999(3);
+	*large.f123_mut() = 4;
+	assert_eq!((large.f000(), large.f250(), large.f999(), *large.f123_ref()), (1, 2, 3, 4));
+	assert_eq!(&large.0[1000..1004], &2u32.to_ne_bytes());
+}
@@ -0,0 +1,59 @@
+mod v1 {
+	#[struct_layout::explicit(size = 16, align = 4, layout_hash)]
+	pub struct Record {
+		#[field(offset = 0)]
+		pub id: u32,
+		#[field(offset = 4)]
+		pub pos: [f32; 2],
+	}
+}
+
+// Identical declaration in another module
+mod v1_copy {
+	#[struct_layout::explicit(size = 16, align = 4, layout_hash)]
+	pub struct Record {
+		#[field(offset = 0)]
+		pub id: u32,
+		#[field(offset = 4)]
+		pub pos: [f32; 2],
+	}
+}
+
+// The position moved by four bytes
+mod v2 {
+	#[struct_layout::explicit(size = 16, align = 4, layout_hash)]
+	pub struct Record {
+		#[field(offset = 0)]
+		pub id: u32,
+		#[field(offset = 8)]
+		pub pos: [f32; 2],
+	}
+}
+
+// Same offsets written differently
+mod v1_hex {
+	#[struct_layout::explicit(size = 0x10, align = 4, layout_hash)]
+	pub struct Record {
+		#[field(offset = 0x0)]
+		pub id: u32,
+		#[field(offset = 0x4)]
+		pub pos: [f32; 2],
+	}
+}
+
+#[test]
+fn identical() {
+	assert_eq!(v1::Record::LAYOUT_HASH, v1_copy::Record::LAYOUT_HASH);
+	assert_eq!(v1::Record::LAYOUT_HASH, v1_hex::Record::LAYOUT_HASH);
+}
+
+#[test]
+fn changed_offset() {
+	assert_ne!(v1::Record::LAYOUT_HASH, v2::Record::LAYOUT_HASH);
+}
+
+#[test]
+fn usable_in_consts() {
+	const KEY: u64 = v1::Record::LAYOUT_HASH ^ 1;
+	assert_eq!(KEY ^ 1, v1::Record::LAYOUT_HASH);
+}
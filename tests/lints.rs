@@ -0,0 +1,101 @@
+//! Generated code must pass strict lint policies of the user crate.
+#![deny(missing_docs, unsafe_op_in_unsafe_fn, clippy::pedantic)]
+
+use std::convert::TryFrom;
+
+/// Kind of the entity.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[repr(u8)]
+pub enum Kind {
+	/// Unknown kind.
+	#[default]
+	Unknown = 0,
+	/// A player.
+	Player = 1,
+}
+impl TryFrom<u8> for Kind {
+	type Error = u8;
+	fn try_from(value: u8) -> Result<Kind, u8> {
+		match value {
+			0 => Ok(Kind::Unknown),
+			1 => Ok(Kind::Player),
+			_ => Err(value),
+		}
+	}
+}
+
+/// Entity record.
+#[struct_layout::explicit(size = 64, align = 8, escape_hatch, define_trait(EntityFields), mockable(EntityAccess), modify)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Entity {
+	/// Identifier.
+	#[field(offset = 0, get, set, ref, mut)]
+	pub id: u32,
+	/// Kind.
+	#[field(offset = 4, get, set, enum(u8))]
+	pub kind: Kind,
+	/// Position.
+	#[field(offset = 8, get, set)]
+	pub position: [f32; 3],
+	/// Health.
+	#[field(offset = 20, get, set, clamp = 0..=100, clamped)]
+	pub health: u16,
+	/// Targets.
+	#[field(offset = 24, count = 2, stride = 8, get, set, ref, mut)]
+	pub targets: u64,
+	/// Tiles.
+	#[field(offset = 40, dims(2, 4), get, set)]
+	pub tiles: u8,
+	/// Name.
+	#[field(offset = 48, get, set, str)]
+	pub name: [u8; 16],
+}
+
+/// Record with most of the optional arguments.
+#[struct_layout::explicit(size = 32, align = 8, consts_mod = record_layout, cast_to(Words), copy_from(Entity), parts, field_enum, tracked, visit, slice_ext, export_c(prefix = "lints_"), grouped, portable = false)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Record {
+	/// Identifier.
+	#[field(offset = 0, get, set)]
+	#[group(header)]
+	pub id: u32,
+	/// Health.
+	#[field(offset = 20, get, set)]
+	#[group(header)]
+	pub health: u16,
+}
+
+/// Record as words.
+#[struct_layout::explicit(size = 32, align = 8, check(struct_layout::Pod, impl))]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Words {
+	/// First word.
+	#[field(offset = 0, get, set)]
+	pub first: u64,
+	/// Located at runtime.
+	#[field(dynamic, get)]
+	pub extra: u8,
+}
+
+#[test]
+fn strict_lints() {
+	let mut entity = Entity::default();
+	entity.set_id(1).set_kind(Kind::Player).set_position([1.0, 2.0, 3.0]);
+	entity.set_targets(1, 7);
+	assert_eq!(entity.id(), 1);
+	assert_eq!(entity.kind(), Some(Kind::Player));
+	assert_eq!(entity.targets(1), 7);
+	assert_eq!(unsafe { entity.read_at::<u32>(0) }, 1);
+}
+
+#[test]
+fn strict_lints_arguments() {
+	let mut record = Record::default();
+	record.copy_fields_from(&Entity::default());
+	record.set_header((3, 4));
+	assert_eq!(record.into_parts().0, 3);
+	assert_eq!(record.as_words().first() & 0xffff_ffff, 3);
+	assert_eq!(record_layout::HEALTH_OFFSET, 20);
+	assert_eq!(RecordField::Id.offset(), 0);
+	assert_eq!(Words::default().extra(&WordsOffsets::new(24)), 0);
+}
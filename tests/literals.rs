@@ -0,0 +1,48 @@
+// Offsets as printed by reverse engineering tools
+const HEADER_SIZE: usize = 0x10;
+
+#[struct_layout::explicit(size = HEADER_SIZE * 2, align = 0b100)]
+#[derive(Copy, Clone, Default)]
+struct Entity {
+	#[field(offset = 0x1C)]
+	health: i32,
+	#[field(offset = HEADER_SIZE + 4)]
+	flags: u32,
+	#[field(offset = 0x0_8)]
+	kind: u16,
+	#[field(offset = 1_2)]
+	team: u8,
+}
+
+#[test]
+fn expressions() {
+	assert_eq!(std::mem::size_of::<Entity>(), 0x20);
+	assert_eq!(std::mem::align_of::<Entity>(), 4);
+	let mut entity = Entity::default();
+	entity.set_health(-100).set_flags(0xdead_beef).set_kind(7).set_team(2);
+	assert_eq!((entity.health(), entity.flags(), entity.kind(), entity.team()), (-100, 0xdead_beef, 7, 2));
+	let bytes = unsafe { std::mem::transmute::<Entity, [u8; 0x20]>(entity) };
+	assert_eq!(&bytes[0x1C..0x20], &(-100i32).to_ne_bytes());
+	assert_eq!(&bytes[0x14..0x18], &0xdead_beefu32.to_ne_bytes());
+	assert_eq!(&bytes[8..10], &7u16.to_ne_bytes());
+	assert_eq!(bytes[12], 2);
+}
+
+#[struct_layout::explicit(size = 0x10_00 + 0x20, align = 8)]
+struct Page {
+	#[field(offset = 0x10_00)]
+	tag: u64,
+	#[field(offset = 0x10_08, count = 0o4)]
+	words: u32,
+}
+
+#[test]
+fn underscored() {
+	let mut page: Page = unsafe { std::mem::zeroed() };
+	page.set_tag(0x1122_3344_5566_7788);
+	page.set_words(3, 9);
+	let bytes = unsafe { std::mem::transmute::<Page, [u8; 0x10_20]>(page) };
+	assert_eq!(&bytes[0x1000..0x1008], &0x1122_3344_5566_7788u64.to_ne_bytes());
+	assert_eq!(&bytes[0x1014..0x1018], &9u32.to_ne_bytes());
+	assert!(bytes[..0x1000].iter().all(|&byte| byte == 0));
+}
@@ -0,0 +1,75 @@
+
+// Two layouts declared in one function body
+#[test]
+fn function_body() {
+	#[struct_layout::explicit(size = 16, align = 4, consts_mod = header_layout, field_enum, tracked, mirror, define_trait(HeaderFields), coverage, visit, byte_order)]
+	#[derive(Copy, Clone, Debug, Default, LowerHex)]
+	pub struct Header {
+		#[field(offset = 0, get, set, ref, mut)]
+		pub id: u32,
+		#[field(offset = 4, count = 2)]
+		pub pair: u16,
+		#[field(offset = 8, dims(2, 2))]
+		pub grid: u8,
+		#[field(offset = 12)]
+		pub flag: bool,
+	}
+	#[struct_layout::explicit(size = 8, align = 4, consts_mod = footer_layout, field_enum, tracked, mirror, impl_trait(HeaderFields), coverage, visit, byte_order)]
+	#[derive(Copy, Clone, Debug, Default, LowerHex)]
+	pub struct Footer {
+		#[field(offset = 0, get, set)]
+		pub id: u32,
+		#[field(offset = 4)]
+		pub flag: bool,
+	}
+
+	let mut header = Header::default();
+	header.set_id(1).set_flag(true);
+	*header.id_mut() += 1;
+	header.set_pair(1, 0x1234);
+	header.set_grid_at(1, 0, 9);
+	assert_eq!(*header.id_ref(), 2);
+	assert_eq!(header.pair(1), 0x1234);
+	assert_eq!(header.grid_at(1, 0), 9);
+	assert_eq!(format!("{:x}", header).len(), 32);
+	assert!(format!("{:?}", header).starts_with("Header"));
+	assert_eq!(header_layout::PAIR_OFFSET, 4);
+	assert_eq!(header_layout::SIZE, 16);
+	assert_eq!(HeaderField::Flag.offset(), 12);
+	assert_eq!(Header::GAPS.len(), 1);
+	assert_eq!(Header::GAPS[0], 13..16);
+	assert_eq!(HeaderValues::from(&header).id, 2);
+	let mut tracked = HeaderTracked::new(header);
+	tracked.set_flag(false);
+	assert_eq!(tracked.dirty().collect::<Vec<_>>(), [HeaderField::Flag]);
+
+	let mut footer = Footer::default();
+	HeaderFields::set_id(&mut footer, 7);
+	footer.set_flag(true);
+	assert_eq!(HeaderFields::id(&footer), 7);
+	assert_eq!(footer_layout::FLAG_OFFSET, 4);
+	assert_eq!(FooterField::ALL.len(), 2);
+	assert_eq!(Footer::COVERAGE.len(), 1);
+	assert_eq!(Footer::COVERAGE[0], 0..5);
+	assert!(FooterValues::from(&footer).flag);
+	assert_eq!(footer.to_be().to_be().id(), 7);
+}
+
+mod tests {
+	#[test]
+	fn implicit_in_test_module() {
+		#[struct_layout::implicit(align = 4, consts_mod = entry_layout, companion_vis = pub(crate))]
+		#[derive(Copy, Clone, Debug, Default)]
+		pub(super) struct Entry {
+			#[field(get, set)]
+			pub(crate) kind: u8,
+			#[field(get, set)]
+			pub(crate) value: u32,
+		}
+		let mut entry = Entry::default();
+		entry.set_kind(1).set_value(2);
+		assert_eq!((entry.kind(), entry.value()), (1, 2));
+		assert_eq!(entry_layout::VALUE_OFFSET, 4);
+		assert_eq!(entry_layout::SIZE, 8);
+	}
+}
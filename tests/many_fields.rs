@@ -0,0 +1,144 @@
+
+#[struct_layout::explicit(size = 160, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Many {
+	#[field(offset = 0, get, set, ref, mut)]
+	f00: u32,
+	#[field(offset = 4, get, set, ref, mut)]
+	f01: u32,
+	#[field(offset = 8, get, set, ref, mut)]
+	f02: u32,
+	#[field(offset = 12, get, set, ref, mut)]
+	f03: u32,
+	#[field(offset = 16, get, set, ref, mut)]
+	f04: u32,
+	#[field(offset = 20, get, set, ref, mut)]
+	f05: u32,
+	#[field(offset = 24, get, set, ref, mut)]
+	f06: u32,
+	#[field(offset = 28, get, set, ref, mut)]
+	f07: u32,
+	#[field(offset = 32, get, set, ref, mut)]
+	f08: u32,
+	#[field(offset = 36, get, set, ref, mut)]
+	f09: u32,
+	#[field(offset = 40, get, set, ref, mut)]
+	f10: u32,
+	#[field(offset = 44, get, set, ref, mut)]
+	f11: u32,
+	#[field(offset = 48, get, set, ref, mut)]
+	f12: u32,
+	#[field(offset = 52, get, set, ref, mut)]
+	f13: u32,
+	#[field(offset = 56, get, set, ref, mut)]
+	f14: u32,
+	#[field(offset = 60, get, set, ref, mut)]
+	f15: u32,
+	#[field(offset = 64, get, set, ref, mut)]
+	f16: u32,
+	#[field(offset = 68, get, set, ref, mut)]
+	f17: u32,
+	#[field(offset = 72, get, set, ref, mut)]
+	f18: u32,
+	#[field(offset = 76, get, set, ref, mut)]
+	f19: u32,
+	#[field(offset = 80, get, set, ref, mut)]
+	f20: u32,
+	#[field(offset = 84, get, set, ref, mut)]
+	f21: u32,
+	#[field(offset = 88, get, set, ref, mut)]
+	f22: u32,
+	#[field(offset = 92, get, set, ref, mut)]
+	f23: u32,
+	#[field(offset = 96, get, set, ref, mut)]
+	f24: u32,
+	#[field(offset = 100, get, set, ref, mut)]
+	f25: u32,
+	#[field(offset = 104, get, set, ref, mut)]
+	f26: u32,
+	#[field(offset = 108, get, set, ref, mut)]
+	f27: u32,
+	#[field(offset = 112, get, set, ref, mut)]
+	f28: u32,
+	#[field(offset = 116, get, set, ref, mut)]
+	f29: u32,
+	#[field(offset = 120, get, set, ref, mut)]
+	f30: u32,
+	#[field(offset = 124, get, set, ref, mut)]
+	f31: u32,
+	#[field(offset = 128, get, set, ref, mut)]
+	f32: u32,
+	#[field(offset = 132, get, set, ref, mut)]
+	f33: u32,
+	#[field(offset = 136, get, set, ref, mut)]
+	f34: u32,
+	#[field(offset = 140, get, set, ref, mut)]
+	f35: u32,
+	#[field(offset = 144, get, set, ref, mut)]
+	f36: u32,
+	#[field(offset = 148, get, set, ref, mut)]
+	f37: u32,
+	#[field(offset = 152, get, set, ref, mut)]
+	f38: u32,
+	#[field(offset = 156, get, set, ref, mut)]
+	f39: u32,
+}
+
+#[test]
+fn many_fields() {
+	let mut many = Many::default();
+
+	many.set_f00(1);
+	many.set_f01(4);
+	many.set_f02(7);
+	many.set_f03(10);
+	many.set_f04(13);
+	many.set_f05(16);
+	many.set_f06(19);
+	many.set_f07(22);
+	many.set_f08(25);
+	many.set_f09(28);
+	many.set_f10(31);
+	many.set_f11(34);
+	many.set_f12(37);
+	many.set_f13(40);
+	many.set_f14(43);
+	many.set_f15(46);
+	many.set_f16(49);
+	many.set_f17(52);
+	many.set_f18(55);
+	many.set_f19(58);
+	many.set_f20(61);
+	many.set_f21(64);
+	many.set_f22(67);
+	many.set_f23(70);
+	many.set_f24(73);
+	many.set_f25(76);
+	many.set_f26(79);
+	many.set_f27(82);
+	many.set_f28(85);
+	many.set_f29(88);
+	many.set_f30(91);
+	many.set_f31(94);
+	many.set_f32(97);
+	many.set_f33(100);
+	many.set_f34(103);
+	many.set_f35(106);
+	many.set_f36(109);
+	many.set_f37(112);
+	many.set_f38(115);
+	many.set_f39(118);
+	*many.f39_mut() += 1;
+	assert_eq!(many.f00(), 1);
+	assert_eq!(many.f17(), 52);
+	assert_eq!(*many.f21_ref(), 64);
+	assert_eq!(many.f39(), 119);
+	for i in 0..40 {
+		let bytes = [many.0[i * 4], many.0[i * 4 + 1], many.0[i * 4 + 2], many.0[i * 4 + 3]];
+		assert_eq!(u32::from_ne_bytes(bytes), if i == 39 { 119 } else { i as u32 * 3 + 1 });
+	}
+
+	// The accessors share the offset constant of their field
+	assert_eq!(Many::__OFFSET_F00, 0);
+	assert_eq!(Many::__OFFSET_F39, 156);
+}
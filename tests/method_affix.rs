@@ -0,0 +1,140 @@
+use std::ops::Deref;
+
+#[struct_layout::explicit(size = 8, align = 4, method_suffix = "_raw", parts)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Base {
+	#[field(offset = 0)]
+	size: u32,
+	#[field(offset = 4)]
+	len: u32,
+}
+
+// The derived layout dereferences to its base, the suffix keeps the accessors apart from the methods of the wrapper
+#[derive(Copy, Clone, Debug, Default)]
+struct Derived {
+	base: Base,
+}
+impl Derived {
+	fn size(&self) -> usize {
+		self.size_raw() as usize * 4
+	}
+	fn len(&self) -> usize {
+		self.len_raw() as usize
+	}
+}
+impl Deref for Derived {
+	type Target = Base;
+	fn deref(&self) -> &Base {
+		&self.base
+	}
+}
+
+#[test]
+fn deref_base() {
+	let mut derived = Derived::default();
+	derived.base.set_size_raw(3).set_len_raw(5);
+	assert_eq!(derived.size(), 12);
+	assert_eq!(derived.len(), 5);
+	assert_eq!(*derived.size_raw_ref(), 3);
+	*derived.base.len_raw_mut() = 6;
+	assert_eq!(derived.len(), 6);
+	assert_eq!(Base::from_parts(derived.into_parts()).size_raw(), 3);
+}
+
+#[struct_layout::explicit(size = 8, align = 4, check(::struct_layout::Pod, impl))]
+#[derive(Copy, Clone, Debug, Default)]
+struct Move {
+	#[field(offset = 0)]
+	x: f32,
+	#[field(offset = 4)]
+	y: f32,
+}
+
+#[struct_layout::explicit(size = 16, align = 4, method_prefix = "raw_", mirror(derive(Copy, Clone, Debug, PartialEq)), tracked, field_enum)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Message {
+	#[field(offset = 0)]
+	kind: u8,
+	#[field(offset = 2)]
+	payload_len: u16,
+	#[field(offset = 4, checksum(sum, range = 0..4, auto))]
+	sum: u32,
+	#[field(offset = 8, count = 2)]
+	ids: u16,
+	#[field(offset = 12)]
+	ready: bool,
+}
+
+#[struct_layout::explicit(size = 12, align = 4, method_suffix = "_raw")]
+#[derive(Copy, Clone, Debug, Default)]
+struct Player {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4, flatten)]
+	pos: Move,
+}
+
+#[struct_layout::explicit(size = 40, align = 4, method_suffix = "_raw", check(::struct_layout::Pod))]
+#[derive(Copy, Clone, Debug, Default)]
+struct Envelope {
+	#[field(offset = 0)]
+	kind: u8,
+	#[variant(kind = 1, offset = 4)]
+	moved: Move,
+	#[field(offset = 12)]
+	payload_len: u16,
+	#[field(offset = 16, tail(len = payload_len))]
+	payload: u8,
+}
+
+#[test]
+fn prefix() {
+	let mut message = Message::default();
+	message.set_raw_kind(3).set_raw_payload_len(0x0101).set_raw_ready(true);
+	message.set_raw_ids(1, 7);
+	assert_eq!(message.raw_kind(), 3);
+	assert_eq!(message.raw_sum(), 5);
+	assert!(message.verify_raw_sum());
+	assert_eq!(message.raw_ids(1), 7);
+	assert_eq!(Message::raw_ids_len(), 2);
+	assert!(message.raw_ready());
+
+	let values = MessageValues::from(&message);
+	assert_eq!((values.kind, values.payload_len, values.ready), (3, 0x0101, true));
+	assert_eq!(Message::from(&values).raw_payload_len(), 0x0101);
+	assert!(message == values);
+	assert!(matches!(message.read_dyn(MessageField::Kind), MessageValue::Kind(3)));
+
+	let mut tracked = MessageTracked::new(Message::default());
+	tracked.set_raw_kind(9);
+	let mut target = Message::default();
+	tracked.flush_into(&mut target);
+	assert_eq!(target.raw_kind(), 9);
+
+	let debug = format!("{:?}", message);
+	assert!(debug.contains("kind: 3"), "{}", debug);
+}
+
+#[test]
+fn flatten() {
+	let mut player = Player::default();
+	player.set_id_raw(2).set_x(1.5);
+	assert_eq!(player.id_raw(), 2);
+	assert_eq!(player.pos_raw().x(), 1.5);
+	// The flattened accessors keep the names of the inner structure
+	assert_eq!(player.x(), 1.5);
+}
+
+#[test]
+fn suffix_cross_references() {
+	let mut envelope = Envelope::default();
+	assert!(envelope.moved_raw().is_none());
+	let mut moved = Move::default();
+	moved.set_y(2.0);
+	envelope.set_moved_raw(moved);
+	assert_eq!(envelope.kind_raw(), 1);
+	assert_eq!(envelope.moved_raw().map(|moved| moved.y()), Some(2.0));
+	envelope.set_payload_len_raw(3);
+	envelope.payload_raw_by_mut().copy_from_slice(&[1, 2, 3]);
+	assert_eq!(envelope.payload_raw_by(), &[1, 2, 3]);
+}
@@ -0,0 +1,84 @@
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+enum Mode {
+	#[default]
+	Off = 0,
+	On = 1,
+}
+
+impl std::convert::TryFrom<u8> for Mode {
+	type Error = u8;
+	fn try_from(value: u8) -> Result<Mode, u8> {
+		match value {
+			0 => Ok(Mode::Off),
+			1 => Ok(Mode::On),
+			_ => Err(value),
+		}
+	}
+}
+
+#[struct_layout::explicit(size = 16, align = 4, mirror(derive(Copy, Clone, Debug, Default, PartialEq)))]
+#[derive(Copy, Clone, Debug, Default)]
+struct Foo {
+	#[field(offset = 1, get, set)]
+	unaligned: u16,
+	#[field(offset = 4)]
+	int: i32,
+	#[field(offset = 8, enum(u8))]
+	mode: Mode,
+	#[field(offset = 12, get)]
+	readonly: u32,
+}
+
+#[test]
+fn mirror_round_trip() {
+	let mut foo = Foo::default();
+	foo.set_unaligned(0x1234).set_int(-5).set_mode(Mode::On);
+
+	let values = FooValues::from(&foo);
+	assert_eq!(values, FooValues { unaligned: 0x1234, int: -5, mode: 1 });
+
+	let values = FooValues { int: 42, ..values };
+	let bar = Foo::from(&values);
+	assert_eq!(bar.int(), 42);
+	assert_eq!(bar.mode(), Some(Mode::On));
+
+	let foo_bytes = unsafe { std::mem::transmute::<Foo, [u8; 16]>(foo) };
+	let bar_bytes = unsafe { std::mem::transmute::<Foo, [u8; 16]>(bar) };
+	assert_eq!(&foo_bytes[1..3], &bar_bytes[1..3]);
+	assert_eq!(&bar_bytes[4..8], &42i32.to_ne_bytes());
+	assert_eq!(foo_bytes[8], bar_bytes[8]);
+}
+
+#[struct_layout::explicit(size = 8, align = 4, mirror(derive))]
+#[derive(Copy, Clone, Debug, Default)]
+struct Bar {
+	#[field(offset = 0)]
+	value: f32,
+}
+
+#[test]
+fn mirror_forwards_derives() {
+	let values = BarValues::default();
+	assert_eq!(format!("{:?}", values.clone()), "BarValues { value: 0.0 }");
+	let bar = Bar::from(&BarValues { value: 2.5 });
+	assert_eq!(bar.value(), 2.5);
+}
+
+#[test]
+fn compare_with_values() {
+	let mut foo = Foo::default();
+	foo.set_unaligned(7).set_int(42);
+	assert_eq!(foo, FooValues { unaligned: 7, int: 42, mode: 0 });
+	assert_eq!(FooValues { unaligned: 7, int: 42, mode: 0 }, foo);
+	assert_ne!(foo, FooValues { unaligned: 7, int: 41, mode: 0 });
+	assert_ne!(FooValues { unaligned: 7, int: 42, mode: 1 }, foo);
+
+	// Padding and fields without a setter are not compared
+	let mut bytes = unsafe { std::mem::transmute::<Foo, [u8; 16]>(foo) };
+	bytes[0] = 0xff;
+	bytes[3] = 0xff;
+	bytes[12] = 0xff;
+	let padded = unsafe { std::mem::transmute::<[u8; 16], Foo>(bytes) };
+	assert_eq!(padded, FooValues { unaligned: 7, int: 42, mode: 0 });
+}
@@ -0,0 +1,31 @@
+
+#[struct_layout::explicit(size = 8, align = 4, modify)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Control {
+	#[field(offset = 0, modify)]
+	flags: u32,
+	#[field(offset = 4, get, set)]
+	divider: u16,
+	#[field(offset = 6, stored = u16, modify)]
+	level: i16,
+}
+
+#[test]
+fn modify_field() {
+	let mut reg = Control::default();
+	reg.set_flags(0b0101);
+	reg.flags_modify(|flags| flags | 0b1000).flags_modify(|flags| flags & !1);
+	assert_eq!(reg.flags(), 0b1100);
+	reg.level_modify(|level| level - 5);
+	assert_eq!(reg.level(), -5);
+}
+
+#[test]
+fn modify_all() {
+	let mut reg = Control::default();
+	reg.modify_all(|r| {
+		r.set_flags(1).set_divider(3);
+	});
+	assert_eq!(reg.flags(), 1);
+	assert_eq!(reg.divider(), 3);
+}
@@ -0,0 +1,192 @@
+
+#[struct_layout::explicit(size = 12, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Entity {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4)]
+	health: f32,
+}
+
+#[struct_layout::explicit(size = 0x80, align = 8)]
+struct World {
+	#[field(offset = 0)]
+	count: u32,
+	#[field(offset = 0x10, count = 4, stride = 0x18)]
+	entities: Entity,
+	#[field(offset = 0x70, count = 3, get, set)]
+	flags: u16,
+}
+
+#[test]
+fn strided_records() {
+	let mut world: World = unsafe { std::mem::zeroed() };
+	assert_eq!(World::entities_len(), 4);
+	for i in 0..4 {
+		world.entities_mut(i).set_id(i as u32 + 1).set_health(i as f32 * 10.0);
+	}
+	world.set_count(4);
+	assert_eq!(world.entities_ref(2).id(), 3);
+	assert_eq!(world.entities(3).health(), 30.0);
+	let ids: Vec<u32> = world.entities_iter().map(|entity| entity.id()).collect();
+	assert_eq!(ids, [1, 2, 3, 4]);
+
+	assert_eq!(world.count(), 4);
+
+	// Element 1 lives at 0x10 + 0x18
+	let bytes = unsafe { std::mem::transmute::<World, [u8; 0x80]>(world) };
+	assert_eq!(bytes[0x28], 2);
+}
+
+#[test]
+fn default_stride() {
+	let mut world: World = unsafe { std::mem::zeroed() };
+	world.set_flags(2, 0xabcd);
+	assert_eq!(world.flags(2), 0xabcd);
+	assert_eq!(world.flags(1), 0);
+	let bytes = unsafe { std::mem::transmute::<World, [u8; 0x80]>(world) };
+	assert_eq!(&bytes[0x74..0x76], &[0xcd, 0xab]);
+}
+
+#[struct_layout::explicit(size = 0xa0, align = 8)]
+struct Entries {
+	#[field(offset = 0x20, count = 16, stride = 8, get, set, ref, mut)]
+	entries: u64,
+}
+
+#[test]
+fn indexed_accessors() {
+	let mut table: Entries = unsafe { std::mem::zeroed() };
+	table.set_entries(0, 1).set_entries(15, u64::MAX);
+	*table.entries_mut(7) = 0x0102030405060708;
+	assert_eq!(table.entries(0), 1);
+	assert_eq!(*table.entries_ref(7), 0x0102030405060708);
+	assert_eq!(table.entries(15), u64::MAX);
+	assert_eq!(table.entries_iter().filter(|&&value| value != 0).count(), 3);
+	// The last element ends exactly at the end of the structure
+	let bytes = unsafe { std::mem::transmute::<Entries, [u8; 0xa0]>(table) };
+	assert_eq!(&bytes[0x98..], &[0xff; 8]);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn strided_out_of_range() {
+	let world: World = unsafe { std::mem::zeroed() };
+	world.entities_ref(4);
+}
+
+#[struct_layout::explicit(size = 8, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Header {
+	#[field(offset = 0)]
+	magic: u32,
+	#[field(offset = 4)]
+	version: u16,
+}
+
+#[struct_layout::explicit(size = 0x20, align = 8)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Record {
+	#[field(offset = 8, nested)]
+	header: Header,
+	#[field(offset = 0x10)]
+	value: f64,
+}
+
+#[test]
+fn nested_layout() {
+	assert_eq!(Header::SIZE, 8);
+	assert_eq!(Record::ALIGN, 8);
+
+	let mut record = Record::default();
+	record.header_mut().set_magic(0x11223344).set_version(3);
+	record.set_value(1.5);
+	assert_eq!(record.header().magic(), 0x11223344);
+	assert_eq!(record.header_ref().version(), 3);
+
+	let bytes = unsafe { std::mem::transmute::<Record, [u8; 0x20]>(record) };
+	assert_eq!(&bytes[8..12], &0x11223344u32.to_ne_bytes());
+	assert_eq!(&bytes[12..14], &3u16.to_ne_bytes());
+}
+
+#[struct_layout::explicit(size = 0x48, align = 8)]
+#[derive(Copy, Clone, Default)]
+struct Table {
+	#[field(offset = 0)]
+	len: u32,
+	#[field(offset = 8, nested)]
+	headers: [Header; 8],
+}
+
+#[test]
+fn nested_array() {
+	let mut table = Table::default();
+	assert_eq!(Table::headers_len(), 8);
+	table.headers_mut()[2].set_magic(0x55667788);
+	let mut header = Header::default();
+	header.set_version(7);
+	table.set_headers_at(5, header).set_len(8);
+	assert_eq!(table.headers_ref()[2].magic(), 0x55667788);
+	assert_eq!(table.headers_at(5).version(), 7);
+	assert_eq!(table.headers_get(8).map(|header| header.magic()), None);
+	assert_eq!(table.headers().iter().filter(|header| header.magic() != 0 || header.version() != 0).count(), 2);
+
+	let bytes = unsafe { std::mem::transmute::<Table, [u8; 0x48]>(table) };
+	assert_eq!(&bytes[0x18..0x1c], &0x55667788u32.to_ne_bytes());
+	assert_eq!(&bytes[0x34..0x36], &7u16.to_ne_bytes());
+}
+
+#[struct_layout::explicit(size = 12, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Transform {
+	#[field(offset = 0)]
+	pos_x: f32,
+	#[field(offset = 4)]
+	pos_y: f32,
+	#[field(offset = 8, get)]
+	scale: f32,
+}
+
+#[struct_layout::explicit(size = 0x40, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Player {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 0x20, flatten)]
+	transform: Transform,
+}
+
+#[test]
+fn flattened_accessors() {
+	let mut player = Player::default();
+	player.set_pos_x(1.0).set_pos_y(-2.0);
+	assert_eq!(player.pos_x(), 1.0);
+	assert_eq!(player.transform().pos_y(), -2.0);
+	assert_eq!(player.scale(), 0.0);
+
+	let bytes = unsafe { std::mem::transmute::<Player, [u8; 0x40]>(player) };
+	assert_eq!(&bytes[0x20..0x24], &1.0f32.to_ne_bytes());
+	assert_eq!(&bytes[0x24..0x28], &(-2.0f32).to_ne_bytes());
+}
+
+#[struct_layout::explicit(size = 0x20, align = 4, base(Entity))]
+#[derive(Copy, Clone, Default)]
+struct Hero {
+	#[field(offset = 0x10)]
+	level: u32,
+}
+
+#[test]
+fn base_deref() {
+	let mut hero = Hero::default();
+	hero.set_id(7).set_health(100.0);
+	hero.set_level(3);
+	let hero_ref: &Hero = &hero;
+	assert_eq!(hero_ref.id(), 7);
+	assert_eq!(hero_ref.base().health(), 100.0);
+	assert_eq!(hero_ref.level(), 3);
+
+	let bytes = unsafe { std::mem::transmute::<Hero, [u8; 0x20]>(hero) };
+	assert_eq!(&bytes[0..4], &7u32.to_ne_bytes());
+	assert_eq!(&bytes[0x10..0x14], &3u32.to_ne_bytes());
+}
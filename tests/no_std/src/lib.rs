@@ -0,0 +1,85 @@
+/*!
+Compiles the generated code in a `#![no_std]` crate without `alloc`.
+*/
+
+#![no_std]
+
+#[struct_layout::explicit(size = 32, align = 4, byte_order, slice_ext, mirror, visit, handles, field_enum, consts_mod = packet_layout, export_json)]
+#[derive(Copy, Clone, Debug, Default, LowerHex, UpperHex)]
+pub struct Packet {
+	#[field(offset = 0)]
+	pub id: u32,
+	#[field(offset = 4, get, set)]
+	pub alive: bool,
+	#[field(offset = 6, get, set, enum(u8))]
+	pub kind: Kind,
+	#[field(offset = 8, stored = u32)]
+	pub scale: f32,
+	#[field(offset = 12, count = 2)]
+	pub ports: u16,
+	#[field(offset = 16, get, set, ref, mut, str, bytes_array, zero, copy_into, copy_from, size = 8)]
+	pub name: [u8; 8],
+	#[field(offset = 24, get, set, endian = both)]
+	pub length: u32,
+	#[field(offset = 28, wstr, opaque)]
+	pub label: [u16; 2],
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[repr(u8)]
+pub enum Kind {
+	#[default]
+	Empty = 0,
+	Full = 1,
+}
+impl core::convert::TryFrom<u8> for Kind {
+	type Error = ();
+	fn try_from(value: u8) -> Result<Kind, ()> {
+		match value {
+			0 => Ok(Kind::Empty),
+			1 => Ok(Kind::Full),
+			_ => Err(()),
+		}
+	}
+}
+
+#[struct_layout::implicit(align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Pair {
+	pub a: u16,
+	pub b: u32,
+}
+
+/// Exercises the accessors without std.
+pub fn exercise(bytes: &mut [u8]) -> u32 {
+	let mut packet = Packet::default();
+	packet.set_id(1).set_alive(true).set_kind(Kind::Full).set_scale(1.5).set_length_be(2);
+	packet.set_ports(1, 7);
+	*packet.id_mut() += *packet.id_ref();
+	packet.name_mut()[0] = b'a';
+	let _ = packet.name_str();
+	let _ = packet.name_to_bytes();
+	let _ = packet.label_units();
+	let _ = packet.to_be();
+	let _ = Pair::default().b();
+	let _ = bytes.set_packet_id(packet.id());
+	packet.id() + packet.ports(1) as u32 + bytes.packet_id().unwrap_or(0)
+}
+
+#[cfg(test)]
+extern crate std;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn accessors() {
+		let mut bytes = [0u8; 8];
+		assert_eq!(exercise(&mut bytes), 2 + 7 + 2);
+		let mut packet = Packet::default();
+		packet.set_length_be(0x01020304);
+		assert_eq!(&packet.0[24..28], &[1, 2, 3, 4]);
+		assert_eq!(std::format!("{:x}", Packet::default()).len(), 64);
+	}
+}
@@ -0,0 +1,39 @@
+#[struct_layout::explicit(size = 16, align = 4, const_fn, bytes)]
+#[derive(Copy, Clone, Default)]
+pub struct Foo {
+	#[field(offset = 4)]
+	pub int: i32,
+	#[field(offset = 8, get)]
+	flags: u16,
+	#[field(offset = 10, pad = 6)]
+	_reserved: (),
+}
+
+// Usable in const expressions, eg. for pointer arithmetic on foreign memory
+const INT_END: usize = Foo::INT_OFFSET + std::mem::size_of::<i32>();
+const TABLE: [usize; 4] = [Foo::INT_OFFSET, Foo::FLAGS_OFFSET, Foo::SIZE, Foo::ALIGN];
+
+#[test]
+fn constants() {
+	assert_eq!(TABLE, [4, 8, 16, 4]);
+	assert_eq!(INT_END, 8);
+	assert_eq!(Foo::SIZE, <Foo as struct_layout::ExplicitLayout>::SIZE);
+}
+
+#[test]
+fn pointer_arithmetic() {
+	let mut foo = Foo::default();
+	foo.set_int(-5);
+	let ptr = &foo as *const Foo as *const u8;
+	let int = unsafe { std::ptr::read_unaligned(ptr.add(Foo::INT_OFFSET) as *const i32) };
+	assert_eq!(int, -5);
+}
+
+const HEADER: Foo = Foo::from_bytes([0, 0, 0, 0, 7, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0]);
+
+#[test]
+fn const_getters() {
+	const INT: i32 = HEADER.int();
+	const FLAGS: u16 = const { HEADER.flags() };
+	assert_eq!((INT, FLAGS), (i32::from_ne_bytes([7, 0, 0, 0]), u16::from_ne_bytes([3, 0])));
+}
@@ -0,0 +1,37 @@
+// Register block documented in 32-bit words
+#[struct_layout::explicit(size = 0x100, align = 4, offset_unit = 4, field_enum, consts_mod = regs, doc_layout)]
+#[derive(Copy, Clone, Default)]
+struct Regs {
+	#[field(offset = 0)]
+	ctrl: u32,
+	#[field(offset = 1)]
+	status: u32,
+	#[field(offset = 0x10, count = 4)]
+	data: u32,
+	#[field(offset = 0x3f, unit = 1)]
+	straggler: u8,
+	#[field(offset = WORDS - 1)]
+	last: u32,
+	#[field(offset = 0x41, unit = 2)]
+	half: u16,
+}
+const WORDS: usize = 0x40;
+
+#[test]
+fn byte_offsets() {
+	assert_eq!(regs::CTRL_OFFSET, 0);
+	assert_eq!(regs::STATUS_OFFSET, 4);
+	assert_eq!(regs::DATA_OFFSET, 0x40);
+	assert_eq!(regs::STRAGGLER_OFFSET, 0x3f);
+	assert_eq!(RegsField::Ctrl.offset(), 0);
+	assert_eq!(RegsField::Status.offset(), 4);
+	assert_eq!(RegsField::Straggler.offset(), 0x3f);
+	assert_eq!(RegsField::Last.offset(), 0xfc);
+	assert_eq!(RegsField::Half.offset(), 0x82);
+	let mut regs = Regs::default();
+	regs.set_status(0x1234).set_data(3, 9).set_last(1);
+	let bytes = unsafe { &*(&regs as *const Regs as *const [u8; 0x100]) };
+	assert_eq!(bytes[4..8], 0x1234u32.to_ne_bytes());
+	assert_eq!(bytes[0x4c..0x50], 9u32.to_ne_bytes());
+	assert_eq!(bytes[0xfc..0x100], 1u32.to_ne_bytes());
+}
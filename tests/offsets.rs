@@ -0,0 +1,42 @@
+
+#[struct_layout::explicit(size = 0x200, align = 8, offsets = "tests/offsets/player.toml")]
+#[derive(Copy, Clone, Debug, Default)]
+struct Player {
+	#[field(offset = extern)]
+	health: i32,
+	#[field(offset = extern, get, set)]
+	armor: u16,
+	#[field(offset = extern, str)]
+	name: [u8; 16],
+	#[field(offset = 0)]
+	id: u32,
+}
+
+#[struct_layout::explicit(size = 0x40, align = 4, offsets = "tests/offsets/player.json")]
+#[derive(Copy, Clone, Debug, Default)]
+struct PlayerV2 {
+	#[field(offset = extern)]
+	health: i32,
+	#[field(offset = extern)]
+	armor: u16,
+}
+
+#[test]
+fn offsets_from_toml() {
+	let mut player = Player::default();
+	player.set_health(100).set_armor(5).set_id(1);
+	player.set_name_str("hero").unwrap();
+	let bytes = unsafe { std::mem::transmute::<Player, [u8; 0x200]>(player) };
+	assert_eq!(&bytes[0x10..0x14], &100i32.to_ne_bytes());
+	assert_eq!(&bytes[20..22], &5u16.to_ne_bytes());
+	assert_eq!(&bytes[0x100..0x104], b"hero");
+}
+
+#[test]
+fn offsets_from_json() {
+	let mut player = PlayerV2::default();
+	player.set_health(-1).set_armor(7);
+	let bytes = unsafe { std::mem::transmute::<PlayerV2, [u8; 0x40]>(player) };
+	assert_eq!(&bytes[32..36], &(-1i32).to_ne_bytes());
+	assert_eq!(&bytes[0x24..0x26], &7u16.to_ne_bytes());
+}
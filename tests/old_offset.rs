@@ -0,0 +1,27 @@
+#![allow(deprecated)]
+
+#[struct_layout::explicit(size = 0x60, align = 8)]
+#[derive(Copy, Clone, Default)]
+struct Entity {
+	#[field(offset = 0x58, old_offset = 0x50)]
+	health: i32,
+	#[field(offset = 0x5c, get, old_offset = 0x54)]
+	alive: bool,
+}
+
+fn bytes(entity: &Entity) -> &[u8; 0x60] {
+	unsafe { &*(entity as *const Entity as *const [u8; 0x60]) }
+}
+
+#[test]
+fn different_bytes() {
+	let mut entity = Entity::default();
+	entity.set_health(7);
+	entity.set_health_legacy(-2);
+	assert_eq!(entity.health(), 7);
+	assert_eq!(entity.health_legacy(), -2);
+	assert_eq!(bytes(&entity)[0x58..0x5c], 7i32.to_ne_bytes());
+	assert_eq!(bytes(&entity)[0x50..0x54], (-2i32).to_ne_bytes());
+	assert!(!entity.alive());
+	assert!(!entity.alive_legacy());
+}
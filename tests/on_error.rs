@@ -0,0 +1,68 @@
+use struct_layout::AccessError;
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Panics {
+	#[field(offset = 0, get, set)]
+	values: [u16; 4],
+	#[field(offset = 8, count = 2, stride = 4, get, set)]
+	ids: u16,
+}
+
+#[struct_layout::explicit(size = 16, align = 4, on_error = option)]
+#[derive(Copy, Clone, Default)]
+struct Options {
+	#[field(offset = 0, get, set)]
+	values: [u16; 4],
+	#[field(offset = 8, dims(2, 2), get, set)]
+	grid: u16,
+}
+
+#[struct_layout::explicit(size = 16, align = 4, on_error = result)]
+#[derive(Copy, Clone, Default)]
+struct Results {
+	#[field(offset = 0, get, set)]
+	values: [u16; 4],
+	#[field(offset = 8, count = 2, stride = 4, get, set, ref, mut)]
+	ids: u16,
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 4 but the index is 4 in field `Panics::values`")]
+fn panic_names_field() {
+	Panics::default().values_at(4);
+}
+
+#[test]
+#[should_panic(expected = "in field `Panics::ids`")]
+fn panic_strided() {
+	Panics::default().set_ids(2, 1);
+}
+
+#[test]
+fn option() {
+	let mut options = Options::default();
+	assert!(options.set_values_at(3, 7).is_some());
+	assert_eq!(options.values_at(3), Some(7));
+	assert_eq!(options.values_at(4), None);
+	assert!(options.set_values_at(4, 1).is_none());
+	assert_eq!(options.values_get(3), Some(7));
+	assert!(options.set_grid_at(1, 0, 5).is_some());
+	assert_eq!(options.grid_at(1, 0), Some(5));
+	assert_eq!(options.grid_at(0, 2), None);
+	assert_eq!(options.grid_row(1), Some([5, 0]));
+	assert_eq!(options.grid_row(2), None);
+}
+
+#[test]
+fn result() {
+	let mut results = Results::default();
+	assert!(results.set_ids(1, 9).is_ok());
+	assert_eq!(results.ids(1), Ok(9));
+	assert_eq!(results.ids(2), Err(AccessError::OutOfBounds { index: 2, len: 2 }));
+	assert_eq!(results.ids_ref(1).copied(), Ok(9));
+	*results.ids_mut(0).unwrap() = 3;
+	assert_eq!(results.ids_iter().copied().collect::<Vec<_>>(), [3, 9]);
+	assert_eq!(results.values_at(5), Err(AccessError::OutOfBounds { index: 5, len: 4 }));
+	assert_eq!(results.values_at(5).unwrap_err().to_string(), "index out of bounds: the len is 4 but the index is 5");
+}
@@ -0,0 +1,61 @@
+// Integration tests are built with `cfg(test)`, the fields under `cfg(not(test))` are absent
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Player {
+	#[field(offset = 0)]
+	score: i32,
+	#[field(offset = optional(cfg(test) = 4))]
+	dlc_score: i32,
+	#[field(offset = optional(cfg(not(test)) = 8), get, set)]
+	legacy: u32,
+	#[field(offset = optional(cfg(not(test)) = 8, cfg(test) = 12), get, set, stubs)]
+	bonus: u16,
+	#[field(offset = optional(cfg(not(test)) = 14), get, set, ref, mut, stubs)]
+	rank: u16,
+}
+
+#[test]
+fn present() {
+	let mut player = Player::default();
+	player.set_score(1).set_dlc_score(2);
+	*player.dlc_score_mut() += 1;
+	assert_eq!(player.dlc_score(), 3);
+	assert_eq!(*player.dlc_score_ref(), 3);
+	assert_eq!(&player.0[4..8], &3i32.to_ne_bytes());
+	assert_eq!(player.score(), 1);
+}
+
+#[test]
+fn present_stubs() {
+	let mut player = Player::default();
+	assert_eq!(player.bonus(), Some(0));
+	assert!(player.set_bonus(7));
+	assert_eq!(player.bonus(), Some(7));
+	assert_eq!(&player.0[12..14], &7u16.to_ne_bytes());
+	assert_eq!(Player::__OFFSET_BONUS, 12);
+}
+
+#[test]
+fn absent_stubs() {
+	let mut player = Player::default();
+	assert_eq!(player.rank(), None);
+	assert!(!player.set_rank(3));
+	assert_eq!(player.rank_ref(), None);
+	assert_eq!(player.rank_mut(), None);
+	assert_eq!(player.0, [0; 16]);
+}
+
+#[test]
+fn clear_padding() {
+	let mut player = Player::default();
+	player.set_dlc_score(-1).set_bonus(0xffff);
+	player.0[8] = 0xaa;
+	player.0[14] = 0xaa;
+	player.clear_padding();
+	assert_eq!(player.dlc_score(), -1);
+	assert_eq!(player.bonus(), Some(0xffff));
+	// The bytes of absent fields are padding
+	assert_eq!(player.0[8], 0);
+	assert_eq!(player.0[14], 0);
+}
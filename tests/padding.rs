@@ -0,0 +1,90 @@
+#[struct_layout::explicit(size = 32, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Packet {
+	#[field(offset = 0)]
+	kind: u8,
+	#[field(offset = 4)]
+	len: u32,
+	#[field(offset = 12, count = 2, stride = 6)]
+	values: u16,
+	#[field(offset = 24)]
+	tag: [u8; 4],
+}
+
+#[test]
+fn clear_padding() {
+	let mut packet = Packet::default();
+	assert_eq!(packet.0, [0u8; 32]);
+	packet.set_kind(1).set_len(0x01020304).set_values(0, 7).set_values(1, 8).set_tag(*b"abcd");
+	let fields = packet.0;
+
+	// Poke the padding between and after the fields
+	for &i in &[1, 3, 8, 14, 17, 20, 28, 31] {
+		packet.0[i] = 0xff;
+	}
+	packet.clear_padding();
+	assert_eq!(packet.0, fields);
+	assert_eq!(packet.kind(), 1);
+	assert_eq!(packet.len(), 0x01020304);
+	assert_eq!((packet.values(0), packet.values(1)), (7, 8));
+	assert_eq!(&packet.tag(), b"abcd");
+}
+
+#[struct_layout::explicit(size = 24, align = 4, parts)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Reserved {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4, pad = 8)]
+	_reserved: (),
+	#[field(offset = 12)]
+	value: u32,
+	#[field(offset = 16, pad = 8)]
+	_reserved: (),
+}
+
+#[test]
+fn anonymous_pads() {
+	let mut reserved = Reserved::from_parts((3, 4));
+	assert_eq!(reserved.into_parts(), (3, 4));
+	assert_eq!(Reserved::__OFFSET___PAD0, 4);
+	assert_eq!(Reserved::__OFFSET___PAD1, 16);
+
+	// Pad regions are not covered by a field
+	for byte in &mut reserved.0[4..12] {
+		*byte = 0xff;
+	}
+	reserved.0[20] = 0xff;
+	reserved.clear_padding();
+	assert_eq!(&reserved.0[4..12], &[0; 8]);
+	assert_eq!(reserved.0[20], 0);
+	assert_eq!(format!("{:?}", reserved), "Reserved { id: 3, value: 4 }");
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Token([u8; 8]);
+
+#[struct_layout::explicit(size = 24, align = 4)]
+#[derive(Copy, Clone)]
+struct Session {
+	#[field(offset = 0, get, set, zero)]
+	id: u32,
+	#[field(offset = 4, get, set, zero)]
+	token: Token,
+	#[field(offset = 12, get, set, zero)]
+	expires: u64,
+}
+
+#[test]
+fn zero_field() {
+	let mut session = Session([0xaa; 24]);
+	session.set_id(7).set_token(Token(*b"secret!!")).set_expires(u64::MAX);
+	session.zero_token();
+	assert_eq!(&session.0[4..12], &[0; 8]);
+	assert_eq!(session.id(), 7);
+	assert_eq!(session.expires(), u64::MAX);
+	assert_eq!(&session.0[20..24], &[0xaa; 4]);
+	session.zero_id().zero_expires();
+	assert_eq!(&session.0[..20], &[0; 20]);
+	assert_eq!(&session.0[20..24], &[0xaa; 4]);
+}
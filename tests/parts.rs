@@ -0,0 +1,31 @@
+
+#[struct_layout::explicit(size = 16, align = 4, parts)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Sample {
+	#[field(offset = 0)]
+	kind: u16,
+	#[field(offset = 4)]
+	value: i32,
+	#[field(offset = 8, get, set)]
+	scale: f32,
+	#[field(offset = 13)]
+	flags: u8,
+}
+
+#[test]
+fn round_trip() {
+	let mut sample = Sample::default();
+	sample.set_kind(3).set_value(-9).set_scale(0.5).set_flags(0x80);
+	let parts = sample.into_parts();
+	assert_eq!(parts, (3, -9, 0.5, 0x80));
+
+	let other = Sample::from_parts(parts);
+	assert_eq!(other.0, sample.0);
+}
+
+#[test]
+fn from_parts_map() {
+	let samples: Vec<Sample> = [(1, 2, 3.0, 4), (5, 6, 7.0, 8)].iter().cloned().map(Sample::from_parts).collect();
+	assert_eq!(samples[1].value(), 6);
+	assert_eq!(samples[0].into_parts(), (1, 2, 3.0, 4));
+}
@@ -0,0 +1,68 @@
+// Exercises the raw pointer accessors, CI runs it with `cargo miri test` and `-Zmiri-strict-provenance`
+
+#[struct_layout::explicit(size = 16, align = 4, check(::struct_layout::Pod, impl))]
+#[derive(Copy, Clone, Default)]
+struct Inner {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 5, get, set)]
+	unaligned: u32,
+}
+
+#[struct_layout::explicit(size = 64, align = 8, slice_ext, check(::struct_layout::Pod))]
+#[derive(Default)]
+struct Outer {
+	#[field(offset = 0)]
+	aligned: u64,
+	#[field(offset = 9, get, set)]
+	unaligned: u64,
+	#[field(offset = 20)]
+	array: [u16; 4],
+	#[field(offset = 32, nested)]
+	inner: Inner,
+	#[field(offset = 48, count = 3, stride = 4)]
+	strided: u32,
+}
+
+#[test]
+fn aligned_fields() {
+	let mut outer = Outer::default();
+	outer.set_aligned(1);
+	assert_eq!(outer.aligned(), 1);
+	*outer.aligned_mut() += 1;
+	assert_eq!(*outer.aligned_ref(), 2);
+
+	outer.set_array_at(3, 7);
+	assert_eq!(outer.array_at(3), 7);
+	outer.array_mut()[0] = 5;
+	assert_eq!(outer.array_ref(), &[5, 0, 0, 7]);
+
+	outer.set_strided(2, 9);
+	*outer.strided_mut(1) = 8;
+	assert_eq!(outer.strided_iter().copied().collect::<Vec<_>>(), [0, 8, 9]);
+}
+
+#[test]
+fn unaligned_fields() {
+	let mut outer = Outer::default();
+	outer.set_unaligned(u64::MAX - 1);
+	assert_eq!(outer.unaligned(), u64::MAX - 1);
+	assert_eq!(outer.aligned(), 0);
+}
+
+#[test]
+fn nested_fields() {
+	let mut outer = Outer::default();
+	outer.inner_mut().set_id(3).set_unaligned(4);
+	assert_eq!(outer.inner_ref().id(), 3);
+	assert_eq!(outer.inner().unaligned(), 4);
+}
+
+#[test]
+fn byte_slice_accessors() {
+	let mut bytes = [0u8; 70];
+	let buffer = &mut bytes[1..];
+	assert_eq!(buffer.set_outer_unaligned(42), Some(()));
+	assert_eq!(buffer.outer_unaligned(), Some(42));
+	assert_eq!(buffer[..16].outer_array(), None);
+}
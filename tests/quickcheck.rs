@@ -0,0 +1,52 @@
+#![cfg(feature = "quickcheck")]
+
+use quickcheck::{Arbitrary, Gen};
+
+// Runs the property and returns the shrunk counterexample if it fails, which the runner of quickcheck only prints
+fn quickcheck<A: Arbitrary>(prop: fn(A) -> bool) -> Option<A> {
+	let mut g = Gen::new(100);
+	for _ in 0..100 {
+		let mut value = A::arbitrary(&mut g);
+		if prop(value.clone()) {
+			continue;
+		}
+		while let Some(smaller) = value.shrink().find(|candidate| !prop(candidate.clone())) {
+			value = smaller;
+		}
+		return Some(value);
+	}
+	None
+}
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Debug, QuickcheckArbitrary)]
+struct Packet {
+	#[field(offset = 0, get, set)]
+	id: u32,
+	#[field(offset = 4, get, set)]
+	urgent: bool,
+	#[field(offset = 8, get, set)]
+	length: u32,
+	#[field(offset = 12, get)]
+	hidden: u32,
+}
+
+#[test]
+fn generated_fields() {
+	let result = quickcheck(|packet: Packet| {
+		// The padding and the fields without a setter stay zeroed
+		packet.hidden() == 0 && packet.0[5..8] == [0, 0, 0] && packet.0[4] <= 1
+	});
+	assert!(result.is_none());
+}
+
+#[test]
+fn shrunk_counterexample() {
+	let result = quickcheck(|packet: Packet| packet.length() < 1000 || !packet.urgent());
+	let packet = result.unwrap();
+	assert_eq!(packet.id(), 0);
+	assert!(packet.urgent());
+	assert!(packet.length() >= 1000 && packet.length() < 2000);
+	assert_eq!(packet.hidden(), 0);
+	assert_eq!(packet.0[5..8], [0, 0, 0]);
+}
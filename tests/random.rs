@@ -0,0 +1,82 @@
+#![cfg(feature = "rand")]
+
+use rand::distributions::{Distribution, Standard};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::convert::TryFrom;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+enum Kind {
+	Circle = 1,
+	Square = 2,
+	Line = 7,
+}
+impl TryFrom<u8> for Kind {
+	type Error = ();
+	fn try_from(value: u8) -> Result<Kind, ()> {
+		match value {
+			1 => Ok(Kind::Circle),
+			2 => Ok(Kind::Square),
+			7 => Ok(Kind::Line),
+			_ => Err(()),
+		}
+	}
+}
+impl Distribution<Kind> for Standard {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Kind {
+		[Kind::Circle, Kind::Square, Kind::Line][rng.gen_range(0..3)]
+	}
+}
+
+#[struct_layout::explicit(size = 32, align = 4, random)]
+#[derive(Copy, Clone, Debug)]
+struct Shape {
+	#[field(offset = 0, get, set)]
+	id: u32,
+	#[field(offset = 4, get, set, enum(u8))]
+	kind: Kind,
+	#[field(offset = 5, get, set)]
+	visible: bool,
+	#[field(offset = 8, get, set)]
+	scale: f32,
+	#[field(offset = 12, count = 3, get, set)]
+	points: u16,
+	#[field(offset = 20, get)]
+	hidden: u32,
+}
+
+#[test]
+fn deterministic() {
+	let a = Shape::random(&mut StdRng::seed_from_u64(42));
+	let b = Shape::random(&mut StdRng::seed_from_u64(42));
+	let c = Shape::random(&mut StdRng::seed_from_u64(1000));
+	assert_eq!(a.0, b.0);
+	assert_ne!(a.0, c.0);
+}
+
+#[test]
+fn constrained_fields() {
+	let mut rng = StdRng::seed_from_u64(7);
+	for _ in 0..100 {
+		let shape = Shape::random(&mut rng);
+		assert!(shape.kind().is_some());
+		assert!(shape.0[5] <= 1);
+		assert!(shape.scale() >= 0.0 && shape.scale() < 1.0);
+		// Fields without a setter and the padding stay zeroed
+		assert_eq!(shape.hidden(), 0);
+		assert_eq!(&shape.0[6..8], &[0, 0]);
+		assert_eq!(&shape.0[18..20], &[0, 0]);
+		assert_eq!(&shape.0[24..32], &[0; 8]);
+	}
+}
+
+#[test]
+fn fill_random() {
+	let mut shape = Shape::random(&mut StdRng::seed_from_u64(1));
+	shape.0[30] = 0xaa;
+	let before = shape.0;
+	shape.fill_random(&mut StdRng::seed_from_u64(2));
+	assert_ne!(shape.0, before);
+	assert_eq!(shape.0[30], 0xaa);
+}
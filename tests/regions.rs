@@ -0,0 +1,56 @@
+
+#[struct_layout::explicit(size = 32, align = 4)]
+struct Packet {
+	#[field(offset = 0)]
+	packet_len: u16,
+	#[field(offset = 0x10, tail(len = packet_len))]
+	payload: u8,
+}
+
+#[test]
+fn tail_empty() {
+	let packet: Packet = unsafe { std::mem::zeroed() };
+	assert_eq!(packet.payload(0), &[]);
+	assert_eq!(packet.payload_by(), &[]);
+}
+
+#[test]
+fn tail_fill_to_size() {
+	let mut packet: Packet = unsafe { std::mem::zeroed() };
+	packet.payload_mut(16).copy_from_slice(b"0123456789abcdef");
+	packet.set_packet_len(4);
+	assert_eq!(packet.payload_by(), b"0123");
+	assert_eq!(packet.payload(16), b"0123456789abcdef");
+	packet.payload_by_mut()[0] = b'x';
+	assert_eq!(packet.payload(4), b"x123");
+}
+
+#[test]
+fn tail_overflow() {
+	let mut packet: Packet = unsafe { std::mem::zeroed() };
+	assert_eq!(packet.payload_get(17), None);
+	assert_eq!(packet.payload_get(usize::MAX), None);
+	assert!(packet.payload_get_mut(17).is_none());
+	packet.set_packet_len(17);
+	assert!(std::panic::catch_unwind(|| packet.payload_by().len()).is_err());
+}
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Mystery {
+	#[field(offset = 0)]
+	known: u32,
+	#[field(offset = 4, len = 10)]
+	mystery: (),
+}
+
+#[test]
+fn byte_region() {
+	let mut mystery = Mystery::default();
+	mystery.set_mystery(b"0123456789");
+	assert_eq!(mystery.mystery(), b"0123456789");
+	mystery.mystery_mut()[9] = b'x';
+	assert_eq!(mystery.mystery(), b"012345678x");
+	assert_eq!(mystery.known(), 0);
+	assert_eq!(format!("{:?}", mystery), "Mystery { known: 0 }");
+}
@@ -0,0 +1,66 @@
+use struct_layout::{MemoryRead, MemoryWrite};
+
+#[derive(Debug, Eq, PartialEq)]
+struct OutOfBounds(u64);
+
+// Remote memory backed by a local buffer
+struct Memory(Vec<u8>);
+
+impl MemoryRead for Memory {
+	type Error = OutOfBounds;
+	fn read(&self, addr: u64, buf: &mut [u8]) -> Result<(), OutOfBounds> {
+		let start = addr as usize;
+		let bytes = self.0.get(start..start + buf.len()).ok_or(OutOfBounds(addr))?;
+		buf.copy_from_slice(bytes);
+		Ok(())
+	}
+}
+impl MemoryWrite for Memory {
+	type Error = OutOfBounds;
+	fn write(&mut self, addr: u64, buf: &[u8]) -> Result<(), OutOfBounds> {
+		let start = addr as usize;
+		let bytes = self.0.get_mut(start..start + buf.len()).ok_or(OutOfBounds(addr))?;
+		bytes.copy_from_slice(buf);
+		Ok(())
+	}
+}
+
+#[struct_layout::explicit(size = 0x100, align = 8, remote)]
+struct Player {
+	#[field(offset = 0x10)]
+	health: i32,
+	#[field(offset = 0x18, stored = u32)]
+	speed: f32,
+	#[field(offset = 0x20, get)]
+	id: u64,
+}
+
+#[test]
+fn read_write_remote() {
+	let mut memory = Memory(vec![0; 0x1000]);
+	let base = 0x200;
+	memory.0[0x210..0x214].copy_from_slice(&42i32.to_ne_bytes());
+	memory.0[0x220..0x228].copy_from_slice(&7u64.to_ne_bytes());
+
+	assert_eq!(Player::read_health(&memory, base), Ok(42));
+	assert_eq!(Player::read_id(&memory, base), Ok(7));
+
+	Player::write_health(&mut memory, base, -5).unwrap();
+	Player::write_speed(&mut memory, base, 1.5).unwrap();
+	assert_eq!(Player::read_health(&memory, base), Ok(-5));
+	assert_eq!(Player::read_speed(&memory, base), Ok(1.5));
+	assert_eq!(&memory.0[0x218..0x21c], &1.5f32.to_bits().to_ne_bytes());
+
+	// Same offsets as the local accessors
+	let mut player: Player = unsafe { std::mem::zeroed() };
+	player.0.copy_from_slice(&memory.0[0x200..0x300]);
+	assert_eq!(player.health(), -5);
+	assert_eq!(player.speed(), 1.5);
+}
+
+#[test]
+fn remote_errors() {
+	let mut memory = Memory(vec![0; 0x100]);
+	assert_eq!(Player::read_health(&memory, 0xf0), Err(OutOfBounds(0x100)));
+	assert_eq!(Player::write_speed(&mut memory, 0xf0, 1.0), Err(OutOfBounds(0x108)));
+}
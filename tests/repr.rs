@@ -0,0 +1,33 @@
+
+#[struct_layout::explicit(size = 48, align = 64, repr = manual)]
+#[derive(Copy, Clone, Default)]
+#[repr(C, align(64))]
+struct CacheLine {
+	#[field(offset = 0, get, set)]
+	head: u32,
+	#[field(offset = 32, get, set)]
+	tail: u32,
+}
+
+#[test]
+fn manual_repr() {
+	assert_eq!(std::mem::align_of::<CacheLine>(), 64);
+	assert_eq!(std::mem::size_of::<CacheLine>(), 64);
+	let mut line = CacheLine::default();
+	line.set_head(1).set_tail(2);
+	assert_eq!((line.head(), line.tail()), (1, 2));
+}
+
+#[struct_layout::explicit(size = 8, align = 4, repr = manual, bytes)]
+#[cfg_attr(target_endian = "little", repr(C, align(4)))]
+#[cfg_attr(target_endian = "big", repr(C, align(4)))]
+struct Conditional {
+	#[field(offset = 4)]
+	value: u32,
+}
+
+#[test]
+fn conditional_repr() {
+	assert_eq!(std::mem::align_of::<Conditional>(), 4);
+	assert_eq!(Conditional::from_bytes([0; 8]).value(), 0);
+}
@@ -0,0 +1,64 @@
+use std::marker::PhantomData;
+
+// Inherent constants take precedence over the trait constants when their bounds are met
+trait Fallback {
+	const SEND: bool = false;
+	const SYNC: bool = false;
+}
+struct Probe<T>(PhantomData<T>);
+impl<T> Fallback for Probe<T> {}
+impl<T: Send> Probe<T> {
+	const SEND: bool = true;
+}
+struct ProbeSync<T>(PhantomData<T>);
+impl<T> Fallback for ProbeSync<T> {}
+impl<T: Sync> ProbeSync<T> {
+	const SYNC: bool = true;
+}
+
+macro_rules! is_send {
+	($ty:ty) => { <Probe<$ty>>::SEND };
+}
+macro_rules! is_sync {
+	($ty:ty) => { <ProbeSync<$ty>>::SYNC };
+}
+
+#[struct_layout::explicit(size = 16, align = 8)]
+#[derive(Default)]
+struct Plain {
+	#[field(offset = 0)]
+	ptr: u64,
+}
+
+#[struct_layout::explicit(size = 16, align = 8, send = false, sync = false)]
+#[derive(Default)]
+struct Remote {
+	#[field(offset = 0)]
+	ptr: u64,
+}
+
+#[struct_layout::explicit(size = 16, align = 8, send = false)]
+struct Local {
+	#[field(offset = 0)]
+	ptr: u64,
+}
+
+#[struct_layout::explicit(size = 16, align = 8, sync = false)]
+struct Shared {
+	#[field(offset = 0)]
+	ptr: u64,
+}
+
+const _: () = assert!(is_send!(Plain) && is_sync!(Plain));
+const _: () = assert!(!is_send!(Remote) && !is_sync!(Remote));
+const _: () = assert!(!is_send!(Local) && is_sync!(Local));
+const _: () = assert!(is_send!(Shared) && !is_sync!(Shared));
+
+#[test]
+fn marker_member() {
+	// The marker does not take up any space
+	assert_eq!(std::mem::size_of::<Remote>(), 16);
+	let mut remote = Remote::default();
+	remote.set_ptr(0x1000);
+	assert_eq!(remote.ptr(), 0x1000);
+}
@@ -0,0 +1,49 @@
+
+const NO_TARGET: u32 = u32::MAX;
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Unit {
+	#[field(offset = 0, get, set)]
+	id: u32,
+	#[field(offset = 4, sentinel = NO_TARGET)]
+	target_id: u32,
+	#[field(offset = 8, sentinel = 0)]
+	owner: u16,
+	#[field(offset = 12, sentinel = -(1 << 4))]
+	slot: i8,
+}
+
+#[test]
+fn some() {
+	let mut unit = Unit::default();
+	unit.set_target_id(Some(7)).set_owner(Some(3));
+	assert_eq!(unit.target_id(), Some(7));
+	assert_eq!(unit.owner(), Some(3));
+	assert_eq!(unit.target_id_raw(), 7);
+}
+
+#[test]
+fn none() {
+	// Zeroed bytes are the sentinel of the owner only
+	let mut unit = Unit([0; 16]);
+	assert_eq!(unit.owner(), None);
+	assert_eq!(unit.target_id(), Some(0));
+	unit.set_target_id(None).set_slot(None);
+	assert_eq!(unit.target_id(), None);
+	assert_eq!(unit.target_id_raw(), u32::MAX);
+	assert_eq!(unit.slot_raw(), -16);
+	// The default of the fields is None
+	assert_eq!(unit.0, Unit::default().0);
+	assert_eq!(format!("{:?}", unit), "Unit { id: 0, target_id: None, owner: None, slot: None }");
+}
+
+#[test]
+fn raw_sentinel() {
+	let mut unit = Unit::default();
+	unit.set_target_id_raw(u32::MAX).set_slot_raw(-16);
+	assert_eq!(unit.target_id(), None);
+	assert_eq!(unit.slot(), None);
+	unit.set_slot_raw(-15);
+	assert_eq!(unit.slot(), Some(-15));
+}
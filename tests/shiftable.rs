@@ -0,0 +1,51 @@
+#[struct_layout::explicit(size = 32, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Player {
+	#[field(offset = 0, get, set, shiftable)]
+	health: i32,
+	#[field(offset = 4, get, shiftable)]
+	alive: bool,
+	#[field(offset = 8, checksum(sum, range = 0..8, auto))]
+	sum: u32,
+	#[field(offset = 12)]
+	mode: u32,
+}
+
+fn bytes_mut(player: &mut Player) -> &mut [u8; 32] {
+	unsafe { &mut *(player as *mut Player as *mut [u8; 32]) }
+}
+
+#[test]
+fn zero_delta() {
+	let mut player = Player::default();
+	assert_eq!(player.set_health_at(0, -5).map(|player| player.health()), Some(-5));
+	assert_eq!(player.health_at(0), Some(-5));
+	assert_eq!(player.alive_at(0), Some(false));
+}
+
+#[test]
+fn shifted() {
+	let mut player = Player::default();
+	player.set_health(1);
+	bytes_mut(&mut player)[16..20].copy_from_slice(&77i32.to_ne_bytes());
+	bytes_mut(&mut player)[20] = 1;
+	assert_eq!(player.health_at(0x10), Some(77));
+	assert_eq!(player.alive_at(0x10), Some(true));
+	assert_eq!(player.health(), 1);
+
+	player.set_health_at(0x10, -3).unwrap();
+	assert_eq!(bytes_mut(&mut player)[16..20], (-3i32).to_ne_bytes());
+	assert_eq!(player.health(), 1);
+	assert!(player.verify_sum());
+}
+
+#[test]
+fn out_of_bounds() {
+	let mut player = Player::default();
+	assert_eq!(player.health_at(28), Some(0));
+	assert_eq!(player.health_at(29), None);
+	assert_eq!(player.alive_at(27), Some(false));
+	assert_eq!(player.alive_at(28), None);
+	assert!(player.set_health_at(usize::MAX, 1).is_none());
+	assert_eq!(player.health(), 0);
+}
@@ -0,0 +1,63 @@
+use struct_layout::explicit;
+
+#[explicit(size = 16, align = 4, slice_ext)]
+struct PacketHeader {
+	#[field(offset = 0)]
+	int: i32,
+	#[field(offset = 5, get, set)]
+	unaligned: u16,
+	#[field(offset = 12, stored = u32)]
+	scale: f32,
+}
+
+#[test]
+fn read_longer_buffer() {
+	let mut buffer = [0u8; 20].to_vec();
+	buffer[0..4].copy_from_slice(&(-3i32).to_ne_bytes());
+	buffer[5..7].copy_from_slice(&0x1234u16.to_ne_bytes());
+	assert_eq!(buffer.packet_header_int(), Some(-3));
+	assert_eq!(buffer.packet_header_unaligned(), Some(0x1234));
+
+	assert_eq!(buffer.set_packet_header_scale(1.5), Some(()));
+	assert_eq!(&buffer[12..16], &1.5f32.to_bits().to_ne_bytes());
+	assert_eq!(buffer[4..].packet_header_scale(), Some(0.0));
+}
+
+#[test]
+fn read_shorter_buffer() {
+	let mut buffer = [0u8; 8].to_vec();
+	assert_eq!(buffer.packet_header_int(), Some(0));
+	assert_eq!(buffer.packet_header_unaligned(), Some(0));
+	assert_eq!(buffer.packet_header_scale(), None);
+	assert_eq!(buffer.set_packet_header_scale(1.0), None);
+	assert_eq!(buffer[..6].packet_header_unaligned(), None);
+	assert_eq!(buffer, [0; 8]);
+}
+
+fn int_ref(buffer: &[u8]) -> &i32 {
+	// The reference borrows the buffer, not the temporary subslice
+	let start = buffer.as_ptr().align_offset(4);
+	let view = &buffer[start..];
+	view.packet_header_int_ref().unwrap()
+}
+
+#[test]
+fn references_outlive_view() {
+	let mut buffer = vec![0u8; 24];
+	let start = buffer.as_ptr().align_offset(4);
+	buffer[start..start + 4].copy_from_slice(&7i32.to_ne_bytes());
+	let int = int_ref(&buffer);
+	assert_eq!(*int, 7);
+
+	*buffer[start..].packet_header_int_mut().unwrap() = -1;
+	assert_eq!(&buffer[start..start + 4], &(-1i32).to_ne_bytes());
+}
+
+#[test]
+fn references_check_alignment() {
+	let buffer = [0u8; 24];
+	let start = buffer.as_ptr().align_offset(4);
+	assert!(buffer[start..].packet_header_int_ref().is_some());
+	assert!(buffer[start + 1..].packet_header_int_ref().is_none());
+	assert!(buffer[start..start + 3].packet_header_int_ref().is_none());
+}
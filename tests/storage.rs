@@ -0,0 +1,50 @@
+use std::mem;
+
+#[struct_layout::explicit(size = 64, align = 8, storage = u64)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Reserved {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 5, get, set)]
+	flags: u16,
+	#[field(offset = 13, get, set)]
+	value: u64,
+	#[field(offset = 24)]
+	name: [u8; 7],
+	#[field(offset = 40, checksum(crc32, range = 0..40))]
+	crc: u32,
+}
+
+#[test]
+fn layout() {
+	assert_eq!(mem::size_of::<Reserved>(), 64);
+	assert_eq!(mem::align_of::<Reserved>(), 8);
+	let reserved = Reserved::default();
+	let _: [u64; 8] = reserved.0;
+}
+
+#[test]
+fn odd_offsets() {
+	let mut reserved = Reserved::default();
+	reserved.set_id(1).set_flags(0x0203).set_value(0x0405060708090a0b).set_name(*b"reserve");
+	assert_eq!(reserved.id(), 1);
+	assert_eq!(reserved.flags(), 0x0203);
+	assert_eq!(reserved.value(), 0x0405060708090a0b);
+	assert_eq!(&reserved.name(), b"reserve");
+	let bytes: [u8; 64] = unsafe { mem::transmute(reserved.0) };
+	assert_eq!(&bytes[5..7], &0x0203u16.to_ne_bytes());
+	assert_eq!(&bytes[13..21], &0x0405060708090a0bu64.to_ne_bytes());
+}
+
+#[test]
+fn byte_views() {
+	let mut reserved = Reserved::default();
+	reserved.set_id(7).update_crc();
+	assert!(reserved.verify_crc());
+	reserved.0[7] = u64::MAX;
+	reserved.clear_padding();
+	let bytes: [u8; 64] = unsafe { mem::transmute(reserved.0) };
+	assert_eq!(&bytes[56..64], &[0; 8]);
+	assert_eq!(reserved.id(), 7);
+	assert!(reserved.verify_crc());
+}
@@ -0,0 +1,178 @@
+
+#[struct_layout::explicit(size = 24, align = 8)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Stored {
+	#[field(offset = 0, stored = u32)]
+	speed: f32,
+	#[field(offset = 4, stored = f32)]
+	bits: u32,
+	#[field(offset = 8, stored = u16)]
+	signed: i16,
+	#[field(offset = 16, stored = u64)]
+	ptr: *const i32,
+}
+
+#[test]
+fn float_from_bits() {
+	let mut stored: Stored = unsafe { std::mem::zeroed() };
+	stored.set_bits(0x3fc00000);
+	assert_eq!(stored.bits(), 0x3fc00000);
+	assert_eq!(unsafe { std::mem::transmute::<Stored, [f32; 6]>(stored) }[1], 1.5);
+
+	stored.set_speed(2.5);
+	assert_eq!(stored.speed(), 2.5);
+	assert_eq!(unsafe { std::mem::transmute::<Stored, [u32; 6]>(stored) }[0], 0x40200000);
+}
+
+#[test]
+fn int_casts() {
+	let mut stored: Stored = unsafe { std::mem::zeroed() };
+	stored.set_signed(-2);
+	assert_eq!(stored.signed(), -2);
+	assert_eq!(unsafe { std::mem::transmute::<Stored, [u16; 12]>(stored) }[4], 0xfffe);
+
+	let value = 42;
+	stored.set_ptr(&value);
+	assert_eq!(unsafe { *stored.ptr() }, 42);
+}
+
+use std::convert::TryFrom;
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[repr(u8)]
+enum GameState {
+	#[default]
+	Menu = 1,
+	Running = 2,
+}
+impl TryFrom<u8> for GameState {
+	type Error = u8;
+	fn try_from(value: u8) -> Result<GameState, u8> {
+		match value {
+			1 => Ok(GameState::Menu),
+			2 => Ok(GameState::Running),
+			_ => Err(value),
+		}
+	}
+}
+impl From<GameState> for u8 {
+	fn from(state: GameState) -> u8 {
+		state as u8
+	}
+}
+
+#[struct_layout::explicit(size = 8, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Game {
+	#[field(offset = 4, stored = u8, convert = TryFrom)]
+	state: GameState,
+}
+
+#[test]
+fn try_from_conversion() {
+	let mut game = Game::default();
+	assert_eq!(game.state(), Ok(GameState::Menu));
+	game.set_state(GameState::Running);
+	assert_eq!(game.state_raw(), 2);
+	assert_eq!(game.state(), Ok(GameState::Running));
+	assert_eq!(format!("{:?}", game), "Game { state: Running }");
+
+	game.set_state_raw(7);
+	assert_eq!(game.state(), Err(7));
+	assert_eq!(format!("{:?}", game), "Game { state: 7 }");
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Meters(u32);
+impl From<u32> for Meters {
+	fn from(value: u32) -> Meters { Meters(value) }
+}
+impl From<Meters> for u32 {
+	fn from(value: Meters) -> u32 { value.0 }
+}
+
+#[struct_layout::explicit(size = 8, align = 4)]
+struct Distance {
+	#[field(offset = 1, stored = u32, convert = From)]
+	meters: Meters,
+}
+
+#[test]
+fn from_conversion() {
+	let mut distance: Distance = unsafe { std::mem::zeroed() };
+	distance.set_meters(Meters(1200));
+	assert_eq!(distance.meters(), Meters(1200));
+}
+
+#[struct_layout::explicit(size = 8, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Level {
+	#[field(offset = 3, enum(u8))]
+	state: GameState,
+}
+
+#[test]
+fn enum_field() {
+	let mut level = Level::default();
+	assert_eq!(level.state(), Some(GameState::Menu));
+	level.set_state(GameState::Running);
+	assert_eq!(level.state_raw(), 2);
+	assert_eq!(level.state(), Some(GameState::Running));
+	assert_eq!(format!("{:?}", level), "Level { state: Running }");
+
+	level.set_state_raw(0xff);
+	assert_eq!(level.state(), None);
+	assert_eq!(format!("{:?}", level), "Level { state: 255 }");
+}
+
+#[cfg(target_pointer_width = "64")]
+#[struct_layout::explicit(size = 8, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Slot {
+	#[field(offset = 0, narrow = u16)]
+	index: u32,
+	#[field(offset = 4, stored = i32, narrow = i8)]
+	delta: i32,
+}
+
+#[test]
+fn narrowing() {
+	let mut slot = Slot::default();
+	slot.set_index(0xfffe).set_delta(-3);
+	assert_eq!(slot.index(), Ok(0xfffe));
+	assert_eq!(slot.delta(), Ok(-3));
+	assert_eq!(format!("{:?}", slot), "Slot { index: 65534, delta: -3 }");
+
+	slot.set_index_raw(0x12345).set_delta_raw(1000);
+	assert!(slot.index().is_err());
+	assert!(slot.delta().is_err());
+	assert_eq!(slot.index_raw(), 0x12345);
+	assert_eq!(format!("{:?}", slot), "Slot { index: 74565, delta: 1000 }");
+}
+
+#[struct_layout::explicit(size = 32, align = 8)]
+struct Pointers {
+	#[field(offset = 0, size = 8)]
+	addr: usize,
+	#[field(offset = 8, size = 8)]
+	ptr: *const u8,
+}
+
+#[struct_layout::explicit(size = 32, align = 8, portable = false)]
+struct Native {
+	#[field(offset = 0)]
+	addr: usize,
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn pointer_sized() {
+	let mut pointers: Pointers = unsafe { std::mem::zeroed() };
+	pointers.set_addr(0x1000).set_ptr(std::ptr::null());
+	assert_eq!(pointers.addr(), 0x1000);
+	assert!(pointers.ptr().is_null());
+
+	let mut native: Native = unsafe { std::mem::zeroed() };
+	native.set_addr(usize::MAX);
+	assert_eq!(native.addr(), usize::MAX);
+}
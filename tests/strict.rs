@@ -0,0 +1,74 @@
+#[struct_layout::explicit(size = 32, align = 8, strict)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Record {
+	#[field(offset = 0, bits = 0..4, get, set)]
+	kind: u16,
+	#[field(offset = 0, bits = 4..16, get, set)]
+	len: u16,
+	#[field(offset = 2, pad = 2)]
+	reserved: (),
+	// Interleaved strided arrays
+	#[field(offset = 4, count = 3, stride = 4, get, set)]
+	lo: u16,
+	#[field(offset = 6, count = 3, stride = 4, get, set)]
+	hi: u16,
+	#[field(offset = 16, get, set)]
+	wide: u64,
+	// A view of the bytes of `wide`
+	#[field(offset = 16, overlaps, get, set)]
+	wide_lo: u32,
+	#[field(offset = 24, count = 8, get)]
+	rest: u8,
+}
+
+#[test]
+fn strict_layout() {
+	let mut record = Record::default();
+	record.set_kind(3).set_len(0x123).set_wide(0x1122334455667788);
+	record.set_lo(1, 0xaaaa).set_hi(1, 0xbbbb);
+	assert_eq!((record.kind(), record.len()), (3, 0x123));
+	assert_eq!((record.lo(0), record.lo(1), record.hi(1), record.hi(2)), (0, 0xaaaa, 0xbbbb, 0));
+	assert_eq!(record.wide_lo(), 0x1122334455667788u64 as u32);
+	assert_eq!(record.rest(7), 0);
+}
+
+// Overlapping fields are accepted without the strict argument
+#[struct_layout::explicit(size = 24, align = 8)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Clobbered {
+	#[field(offset = 12, get, set)]
+	a: i64,
+	#[field(offset = 16, get, set)]
+	b: u32,
+}
+
+#[test]
+fn intentional_overlap() {
+	let mut value = Clobbered::default();
+	value.set_b(7).set_a(-1);
+	assert_eq!(value.b(), u32::MAX);
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
+struct Pair {
+	x: u32,
+	y: u32,
+}
+
+#[struct_layout::explicit(size = 16, align = 4, strict)]
+struct Msg {
+	#[field(offset = 0, get, set)]
+	kind: u8,
+	#[variant(kind = 1, offset = 4)]
+	pair: Pair,
+	#[variant(kind = 2, offset = 4)]
+	value: u32,
+}
+
+#[test]
+fn variants() {
+	let mut msg: Msg = unsafe { std::mem::zeroed() };
+	msg.set_value(5);
+	assert_eq!((msg.kind(), msg.value().copied(), msg.pair().is_none()), (2, Some(5), true));
+}
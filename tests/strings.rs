@@ -0,0 +1,158 @@
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Named {
+	#[field(offset = 0, str)]
+	name: [u8; 8],
+	#[field(offset = 8)]
+	id: u32,
+}
+
+#[test]
+fn str_short() {
+	let mut named = Named::default();
+	named.set_id(5);
+	assert_eq!(named.name_str(), Ok(""));
+	named.set_name_str("hello").unwrap();
+	assert_eq!(named.name_str(), Ok("hello"));
+	assert_eq!(named.name(), *b"hello\0\0\0");
+	assert_eq!(named.id(), 5);
+	assert_eq!(format!("{:?}", named), "Named { name: \"hello\", id: 5 }");
+}
+
+#[test]
+fn str_exact_fit() {
+	let mut named = Named::default();
+	named.set_name_str("12345678").unwrap();
+	assert_eq!(named.name_str(), Ok("12345678"));
+	assert_eq!(named.id(), 0);
+
+	// Shorter strings zero the remainder
+	named.set_name_str("abc").unwrap();
+	assert_eq!(named.name(), *b"abc\0\0\0\0\0");
+}
+
+#[test]
+fn str_embedded_nul() {
+	let mut named = Named::default();
+	named.set_name_str("ab\0cd").unwrap();
+	assert_eq!(named.name_str(), Ok("ab"));
+}
+
+#[test]
+fn str_too_long() {
+	let mut named = Named::default();
+	named.set_name_str("abc").unwrap();
+	assert_eq!(named.set_name_str("123456789").err(), Some(struct_layout::TooLong));
+	assert_eq!(named.name_str(), Ok("abc"));
+}
+
+#[test]
+fn str_invalid_utf8() {
+	let mut named = Named::default();
+	named.set_name([0xff, b'a', 0, 0, 0, 0, 0, 0]);
+	assert!(named.name_str().is_err());
+	assert_eq!(format!("{:?}", named), "Named { name: [255, 97, 0, 0, 0, 0, 0, 0], id: 0 }");
+}
+
+#[struct_layout::explicit(size = 8, align = 1)]
+struct CNamed {
+	#[field(offset = 2, cstr)]
+	name: [u8; 4],
+}
+
+#[test]
+fn cstr_roundtrip() {
+	use std::ffi::CStr;
+	let mut named: CNamed = unsafe { std::mem::zeroed() };
+	named.set_name_cstr(CStr::from_bytes_with_nul(b"abc\0").unwrap()).unwrap();
+	assert_eq!(named.name_cstr().unwrap().to_bytes(), b"abc");
+	assert_eq!(named.name(), *b"abc\0");
+
+	named.set_name_cstr(CStr::from_bytes_with_nul(b"a\0").unwrap()).unwrap();
+	assert_eq!(named.name(), *b"a\0\0\0");
+
+	// The terminator must fit as well
+	let too_long = CStr::from_bytes_with_nul(b"abcd\0").unwrap();
+	assert_eq!(named.set_name_cstr(too_long).err(), Some(struct_layout::TooLong));
+}
+
+#[test]
+fn cstr_missing_terminator() {
+	let mut named: CNamed = unsafe { std::mem::zeroed() };
+	named.set_name(*b"abcd");
+	assert!(named.name_cstr().is_err());
+}
+
+#[struct_layout::explicit(size = 16, align = 2)]
+struct Wide {
+	#[field(offset = 2, wstr)]
+	title: [u16; 4],
+	#[field(offset = 10, wstr(truncate))]
+	short: [u16; 3],
+}
+
+#[test]
+fn wstr_ascii() {
+	let mut wide: Wide = unsafe { std::mem::zeroed() };
+	wide.set_title_str("abc").unwrap();
+	assert_eq!(wide.title(), [97, 98, 99, 0]);
+	assert_eq!(wide.title_units(), &[97, 98, 99]);
+	assert_eq!(wide.title_string(), "abc");
+
+	// Exact fit has no terminator
+	wide.set_title_str("abcd").unwrap();
+	assert_eq!(wide.title_string(), "abcd");
+	assert_eq!(wide.set_title_str("abcde").err(), Some(struct_layout::TooLong));
+	assert_eq!(wide.title_string(), "abcd");
+}
+
+#[test]
+fn wstr_surrogate_pair() {
+	let mut wide: Wide = unsafe { std::mem::zeroed() };
+	wide.set_title_str("a\u{1F600}").unwrap();
+	assert_eq!(wide.title(), [0x61, 0xD83D, 0xDE00, 0]);
+	assert_eq!(wide.title_string(), "a\u{1F600}");
+
+	// Truncation does not split the surrogate pair
+	wide.set_short_str("ab\u{1F600}").unwrap();
+	assert_eq!(wide.short(), [0x61, 0x62, 0]);
+	assert_eq!(wide.short_string(), "ab");
+}
+
+#[struct_layout::explicit(size = 12, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Labeled {
+	#[field(offset = 0, pstr(cap = 6))]
+	label: [u8; 8],
+	#[field(offset = 8)]
+	id: u32,
+}
+
+#[test]
+fn pstr_empty() {
+	let mut labeled = Labeled::default();
+	assert_eq!(labeled.label_str(), Ok(""));
+	labeled.set_label_str("").unwrap();
+	assert_eq!(labeled.label(), [0; 8]);
+}
+
+#[test]
+fn pstr_max_length() {
+	let mut labeled = Labeled::default();
+	labeled.set_label_str("abcdef").unwrap();
+	assert_eq!(labeled.label(), *b"\x06abcdef\0");
+	assert_eq!(labeled.label_str(), Ok("abcdef"));
+	assert_eq!(format!("{:?}", labeled), "Labeled { label: \"abcdef\", id: 0 }");
+	assert_eq!(labeled.set_label_str("abcdefg").err(), Some(struct_layout::TooLong));
+
+	labeled.set_label_str("xy").unwrap();
+	assert_eq!(labeled.label(), *b"\x02xy\0\0\0\0\0");
+}
+
+#[test]
+fn pstr_corrupted_length() {
+	let mut labeled = Labeled::default();
+	labeled.set_label(*b"\x07abcdefg");
+	assert_eq!(labeled.label_str(), Err(struct_layout::StrError::Length(7)));
+}
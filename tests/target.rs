@@ -0,0 +1,24 @@
+#[struct_layout::explicit(
+	size(cfg(target_pointer_width = "64") = 0x148, cfg(target_pointer_width = "32") = 0x120),
+	align(cfg(target_pointer_width = "64") = 8, cfg(target_pointer_width = "32") = 4),
+)]
+#[derive(Default)]
+struct Entity {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 0x100)]
+	health: i32,
+}
+
+#[test]
+fn target_size() {
+	let (size, align) = if cfg!(target_pointer_width = "64") { (0x148, 8) } else { (0x120, 4) };
+	assert_eq!(Entity::SIZE, size);
+	assert_eq!(Entity::ALIGN, align);
+	assert_eq!(std::mem::size_of::<Entity>(), size);
+	assert_eq!(std::mem::align_of::<Entity>(), align);
+
+	let mut entity = Entity::default();
+	entity.set_id(3).set_health(100);
+	assert_eq!((entity.id(), entity.health()), (3, 100));
+}
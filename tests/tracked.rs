@@ -0,0 +1,39 @@
+
+#[struct_layout::explicit(size = 20, align = 4, tracked)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Entity {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4)]
+	x: f32,
+	#[field(offset = 8)]
+	y: f32,
+	#[field(offset = 12)]
+	health: i32,
+	#[field(offset = 16)]
+	flags: u32,
+}
+
+#[test]
+fn flush_dirty() {
+	let mut tracked = EntityTracked::new(Entity::default());
+	tracked.set_x(1.5).set_health(-7);
+	assert_eq!(tracked.dirty().collect::<Vec<_>>(), [EntityField::X, EntityField::Health]);
+	assert_eq!(tracked.x(), 1.5);
+
+	let mut target = Entity::default();
+	target.set_id(3).set_y(2.0);
+	tracked.flush_into(&mut target);
+	assert_eq!((target.id(), target.x(), target.y(), target.health(), target.flags()), (3, 1.5, 2.0, -7, 0));
+	assert_eq!(tracked.dirty().count(), 0);
+}
+
+#[test]
+fn clear_dirty() {
+	let mut tracked = EntityTracked::new(Entity::default());
+	tracked.set_flags(1);
+	assert!(tracked.is_dirty(EntityField::Flags));
+	tracked.clear_dirty();
+	assert!(!tracked.is_dirty(EntityField::Flags));
+	assert_eq!(tracked.into_inner().flags(), 1);
+}
@@ -0,0 +1,130 @@
+
+#[struct_layout::explicit(size = 16, align = 4, define_trait(PlayerFields))]
+#[derive(Copy, Clone, Debug, Default)]
+struct PlayerV1 {
+	#[field(offset = 0)]
+	health: i32,
+	#[field(offset = 4)]
+	armor: u16,
+}
+
+#[struct_layout::explicit(size = 32, align = 8, impl_trait(PlayerFields))]
+#[derive(Copy, Clone, Debug, Default)]
+struct PlayerV2 {
+	#[field(offset = 0x10)]
+	health: i32,
+	#[field(offset = 6, get, set)]
+	armor: u16,
+}
+
+fn heal<P: PlayerFields>(player: &mut P) {
+	let health = player.health();
+	player.set_health(health + 10);
+}
+
+#[test]
+fn shared_trait() {
+	let mut v1 = PlayerV1::default();
+	let mut v2 = PlayerV2::default();
+	v1.set_health(5);
+	v2.set_health(7);
+	heal(&mut v1);
+	heal(&mut v2);
+	assert_eq!(v1.health(), 15);
+	assert_eq!(v2.health(), 17);
+
+	let players: [&mut dyn PlayerFields; 2] = [&mut v1, &mut v2];
+	for player in players {
+		player.set_armor(3);
+	}
+	assert_eq!(v1.armor(), 3);
+	assert_eq!(v2.armor(), 3);
+}
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Debug, Default)]
+struct Tagged {
+	#[field(offset = 0)]
+	marker: std::marker::PhantomData<u32>,
+	#[field(offset = 4)]
+	id: u32,
+	#[field(offset = 8)]
+	scale: f32,
+}
+
+#[test]
+fn derive_default() {
+	let tagged = Tagged::default();
+	assert_eq!(tagged.marker(), std::marker::PhantomData);
+	assert_eq!(tagged.id(), 0);
+	assert_eq!(tagged.scale(), 0.0);
+	assert_eq!(tagged.0, [0u8; 16]);
+}
+
+#[struct_layout::explicit(size = 32, align = 4, mockable(UnitAccess))]
+#[derive(Copy, Clone, Debug, Default)]
+struct Unit {
+	#[field(offset = 0, get, set, ref, mut)]
+	health: i32,
+	#[field(offset = 4, get, set)]
+	ammo: [u16; 2],
+	#[field(offset = 8, count = 2, stride = 4, get, set)]
+	targets: u32,
+	#[field(offset = 16, dims(2, 2), get, set)]
+	tiles: u8,
+}
+
+// Hand-rolled fake with plain fields
+#[derive(Default)]
+struct FakeUnit {
+	health: i32,
+	ammo: [u16; 2],
+	targets: [u32; 2],
+	tiles: [[u8; 2]; 2],
+}
+impl UnitAccess for FakeUnit {
+	fn health(&self) -> i32 { self.health }
+	fn set_health(&mut self, value: i32) { self.health = value; }
+	fn health_ref(&self) -> &i32 { &self.health }
+	fn health_mut(&mut self) -> &mut i32 { &mut self.health }
+	fn ammo(&self) -> [u16; 2] { self.ammo }
+	fn set_ammo(&mut self, value: [u16; 2]) { self.ammo = value; }
+	fn ammo_at(&self, index: usize) -> u16 { self.ammo[index] }
+	fn set_ammo_at(&mut self, index: usize, value: u16) { self.ammo[index] = value; }
+	fn targets(&self, index: usize) -> u32 { self.targets[index] }
+	fn set_targets(&mut self, index: usize, value: u32) { self.targets[index] = value; }
+	fn tiles_at(&self, row: usize, col: usize) -> u8 { self.tiles[row][col] }
+	fn set_tiles_at(&mut self, row: usize, col: usize, value: u8) { self.tiles[row][col] = value; }
+}
+
+fn fire(unit: &mut impl UnitAccess) {
+	let ammo = unit.ammo_at(1);
+	unit.set_ammo_at(1, ammo - 1);
+	unit.set_targets(0, 42);
+	*unit.health_mut() -= 1;
+	unit.set_tiles_at(1, 0, 9);
+}
+
+#[test]
+fn mockable() {
+	let mut unit = Unit::default();
+	unit.set_ammo([0, 5]).set_health(10);
+	fire(&mut unit);
+	assert_eq!(unit.ammo(), [0, 4]);
+	assert_eq!(unit.targets(0), 42);
+	assert_eq!(unit.health(), 9);
+	assert_eq!(unit.tiles_at(1, 0), 9);
+
+	let mut fake = FakeUnit { health: 10, ammo: [0, 5], ..FakeUnit::default() };
+	fire(&mut fake);
+	assert_eq!(fake.ammo, [0, 4]);
+	assert_eq!(fake.targets, [42, 0]);
+	assert_eq!(fake.health, 9);
+	assert_eq!(fake.tiles, [[0, 0], [9, 0]]);
+
+	// The trait is object safe
+	let units: [&dyn UnitAccess; 2] = [&unit, &fake];
+	for unit in units {
+		assert_eq!(*unit.health_ref(), 9);
+	}
+}
@@ -0,0 +1,41 @@
+
+#[struct_layout::explicit(size = 4, align = 4, transparent)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Handle {
+	#[field(offset = 0, get, set)]
+	raw: u32,
+}
+
+#[struct_layout::explicit(size = 8, align = 8, transparent)]
+#[derive(Copy, Clone, Default)]
+struct Position {
+	#[field(offset = 0)]
+	xy: [f32; 2],
+}
+
+fn close(raw: u32) -> u32 {
+	raw + 1
+}
+
+#[test]
+fn conversions() {
+	let handle = Handle::from(0x1234);
+	assert_eq!(handle.raw(), 0x1234);
+	assert_eq!(u32::from(handle), 0x1234);
+	let raw: u32 = handle.into();
+	assert_eq!(raw, 0x1234);
+	assert_eq!(close(handle.into()), 0x1235);
+	let handle: Handle = 7.into();
+	assert_eq!(handle.raw(), 7);
+}
+
+#[test]
+fn deref() {
+	let handle = Handle::from(0xff);
+	assert_eq!(*handle, 0xff);
+	assert_eq!(handle.count_ones(), 8);
+	let position = Position::from([1.0, 2.0]);
+	assert_eq!(position[1], 2.0);
+	assert_eq!(position.len(), 2);
+	assert_eq!(<[f32; 2]>::from(position), [1.0, 2.0]);
+}
@@ -0,0 +1,61 @@
+use struct_layout::{Unaligned, UnalignedMut};
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Packed {
+	#[field(offset = 1, get, set, unaligned_ref)]
+	value: u32,
+	#[field(offset = 7, get, set, unaligned_ref)]
+	scale: f64,
+}
+
+fn bump(mut value: UnalignedMut<u32>) {
+	let old = value.get();
+	value.set(old + 1);
+}
+
+fn read(value: Unaligned<f64>) -> f64 {
+	value.get()
+}
+
+#[test]
+fn unaligned_handles() {
+	let mut packed = Packed::default();
+	packed.set_value(41).set_scale(0.25);
+	bump(packed.value_unaligned_mut());
+	assert_eq!(packed.value(), 42);
+	assert_eq!(read(packed.scale_unaligned()), 0.25);
+
+	let mut scale = packed.scale_unaligned_mut();
+	scale.set(2.5);
+	assert_eq!(format!("{:?}", scale.as_unaligned()), "2.5");
+	assert_eq!(packed.scale(), 2.5);
+}
+
+#[struct_layout::explicit(size = 23, align = 1, packed)]
+#[derive(Copy, Clone, Default)]
+struct Wire {
+	#[field(offset = 0)]
+	kind: u8,
+	#[field(offset = 1)]
+	length: u32,
+	#[field(offset = 5)]
+	timestamp: u64,
+	#[field(offset = 13)]
+	value: f64,
+	#[field(offset = 21, get, set, ref)]
+	flags: [u8; 2],
+}
+
+#[test]
+fn packed_wire_format() {
+	assert_eq!(std::mem::size_of::<Wire>(), 23);
+	assert_eq!(std::mem::align_of::<Wire>(), 1);
+	let mut wire = Wire::default();
+	wire.set_kind(1).set_length(0x01020304).set_timestamp(u64::MAX - 1).set_value(0.5).set_flags([6, 7]);
+	assert_eq!(&wire.0[1..5], &0x01020304u32.to_ne_bytes());
+	assert_eq!(&wire.0[5..13], &(u64::MAX - 1).to_ne_bytes());
+	assert_eq!(wire.value(), 0.5);
+	// Byte aligned fields may still hand out references
+	assert_eq!(wire.flags_ref(), &[6, 7]);
+}
@@ -0,0 +1,39 @@
+
+#[struct_layout::union_layout(size = 8, align = 8)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Register {
+	#[field(get, set)]
+	full: u64,
+	#[field(ref, mut)]
+	bytes: [u8; 8],
+	#[field(offset = 0)]
+	lo: u32,
+	#[field(offset = 4)]
+	hi: u32,
+	double: f64,
+}
+
+#[test]
+fn overlapping_views() {
+	let mut reg = Register::default();
+	reg.set_full(0x1122334455667788);
+	if cfg!(target_endian = "little") {
+		assert_eq!(reg.lo(), 0x55667788);
+		assert_eq!(reg.hi(), 0x11223344);
+	}
+	else {
+		assert_eq!(reg.lo(), 0x11223344);
+		assert_eq!(reg.hi(), 0x55667788);
+	}
+	assert_eq!(reg.bytes_ref(), &0x1122334455667788u64.to_ne_bytes());
+
+	reg.set_double(1.0);
+	assert_eq!(reg.full(), 1.0f64.to_bits());
+}
+
+#[test]
+fn debug_bytes() {
+	let mut reg = Register::default();
+	reg.bytes_mut()[0] = 1;
+	assert_eq!(format!("{:?}", reg), "Register([1, 0, 0, 0, 0, 0, 0, 0])");
+}
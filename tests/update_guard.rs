@@ -0,0 +1,36 @@
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Position {
+	#[field(offset = 0)]
+	x: f32,
+	#[field(offset = 4)]
+	y: f32,
+	#[field(offset = 8)]
+	z: f32,
+	#[field(offset = 12, get, set, seq)]
+	seq: u32,
+}
+
+#[test]
+fn once_per_scope() {
+	let mut pos = Position::default();
+	{
+		let mut guard = pos.update_guarded();
+		guard.set_x(1.0).set_y(2.0).set_z(3.0);
+		guard.set_x(4.0);
+		// The writes are visible through the guard before the sequence changes
+		assert_eq!((guard.x(), guard.seq()), (4.0, 0));
+	}
+	assert_eq!((pos.x(), pos.y(), pos.z(), pos.seq()), (4.0, 2.0, 3.0, 1));
+
+	drop(pos.update_guarded());
+	assert_eq!(pos.seq(), 2);
+}
+
+#[test]
+fn wraps_around() {
+	let mut pos = Position::default();
+	pos.set_seq(u32::MAX);
+	pos.update_guarded().set_z(-1.0);
+	assert_eq!((pos.z(), pos.seq()), (-1.0, 0));
+}
@@ -0,0 +1,127 @@
+use struct_layout::InvalidValue;
+
+fn is_percentage(value: u8) -> bool {
+	value <= 100
+}
+
+mod rules {
+	pub fn nonzero(value: u32) -> Result<(), &'static str> {
+		if value != 0 { Ok(()) } else { Err("zero") }
+	}
+}
+
+#[struct_layout::explicit(size = 8, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Stats {
+	#[field(offset = 0, validate = rules::nonzero)]
+	id: u32,
+	#[field(offset = 4, get, set, validate = is_percentage)]
+	health: u8,
+	#[field(offset = 5, get, set)]
+	armor: u8,
+}
+
+#[test]
+fn accept() {
+	let mut stats = Stats::default();
+	assert!(stats.try_set_health(100).is_ok());
+	assert_eq!(stats.health(), 100);
+	stats.try_set_id(7).unwrap().try_set_health(0).unwrap().set_armor(3);
+	assert_eq!(stats.id(), 7);
+	assert_eq!(stats.health(), 0);
+	assert_eq!(stats.armor(), 3);
+}
+
+#[test]
+fn reject() {
+	let mut stats = Stats::default();
+	stats.set_health(50);
+	assert_eq!(stats.try_set_health(101).err(), Some(InvalidValue));
+	assert_eq!(stats.health(), 50);
+	assert!(stats.try_set_id(0).is_err());
+	assert_eq!(stats.id(), 0);
+}
+
+#[test]
+fn unchecked_setter() {
+	let mut stats = Stats::default();
+	stats.set_health(200);
+	assert_eq!(stats.health(), 200);
+}
+
+#[struct_layout::explicit(size = 4, align = 4, strict_validate)]
+#[derive(Copy, Clone, Default)]
+struct Strict {
+	#[field(offset = 0, get, set, modify, validate = is_percentage)]
+	health: u8,
+	#[field(offset = 1, get, set)]
+	armor: u8,
+}
+
+#[test]
+fn strict_accept() {
+	let mut strict = Strict::default();
+	strict.set_health(100).set_armor(200);
+	assert_eq!(strict.health(), 100);
+	assert_eq!(strict.armor(), 200);
+	assert!(strict.try_set_health(101).is_err());
+	assert_eq!(strict.health(), 100);
+}
+
+#[test]
+#[should_panic(expected = "invalid value for field `health`")]
+fn strict_reject() {
+	let mut strict = Strict::default();
+	strict.set_health(101);
+}
+
+#[test]
+#[should_panic]
+fn strict_modify() {
+	let mut strict = Strict::default();
+	strict.set_health(100).health_modify(|health| health + 1);
+}
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Default)]
+struct Clamped {
+	#[field(offset = 0, get, set, modify, clamp = 0..=100)]
+	health: i32,
+	#[field(offset = 4, get, set, clamp = -1.0..=1.0)]
+	speed: f32,
+	#[field(offset = 8, get, set, clamped, clamp = 10..=20)]
+	level: u8,
+}
+
+#[test]
+fn clamp() {
+	let mut clamped = Clamped::default();
+	clamped.set_health(-5);
+	assert_eq!(clamped.health(), 0);
+	clamped.set_health(42);
+	assert_eq!(clamped.health(), 42);
+	clamped.set_health(1000);
+	assert_eq!(clamped.health(), 100);
+	clamped.health_modify(|health| health + 1);
+	assert_eq!(clamped.health(), 100);
+	clamped.set_speed(-3.5);
+	assert_eq!(clamped.speed(), -1.0);
+	clamped.set_speed(0.25);
+	assert_eq!(clamped.speed(), 0.25);
+	clamped.set_speed(2.0);
+	assert_eq!(clamped.speed(), 1.0);
+}
+
+#[test]
+fn clamped_setter() {
+	let mut clamped = Clamped::default();
+	clamped.set_level_clamped(5);
+	assert_eq!(clamped.level(), 10);
+	clamped.set_level_clamped(15);
+	assert_eq!(clamped.level(), 15);
+	clamped.set_level_clamped(200);
+	assert_eq!(clamped.level(), 20);
+	// The raw setter is kept
+	clamped.set_level(200);
+	assert_eq!(clamped.level(), 200);
+}
@@ -0,0 +1,88 @@
+use std::convert::TryFrom;
+use std::mem;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+enum Kind {
+	#[default]
+	Idle = 0,
+	Walk = 1,
+	Run = 2,
+}
+impl TryFrom<u8> for Kind {
+	type Error = u8;
+	fn try_from(value: u8) -> Result<Kind, u8> {
+		match value {
+			0 => Ok(Kind::Idle),
+			1 => Ok(Kind::Walk),
+			2 => Ok(Kind::Run),
+			_ => Err(value),
+		}
+	}
+}
+impl From<Kind> for u8 {
+	fn from(kind: Kind) -> u8 {
+		kind as u8
+	}
+}
+
+fn is_percentage(value: u8) -> bool {
+	value <= 100
+}
+
+#[struct_layout::explicit(size = 8, align = 4, validate_all)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Unit {
+	#[field(offset = 0, enum(u8))]
+	kind: Kind,
+	#[field(offset = 1)]
+	alive: bool,
+	#[field(offset = 2, validate = is_percentage)]
+	health: u8,
+	#[field(offset = 3, clamp = 1..=9)]
+	level: u8,
+	#[field(offset = 4)]
+	gold: u32,
+}
+
+fn records() -> Vec<u8> {
+	let mut buf = Vec::new();
+	for (kind, level) in [(Kind::Idle, 1), (Kind::Walk, 5), (Kind::Run, 9)] {
+		let mut unit = Unit::default();
+		unit.set_kind(kind).set_alive(true).set_health(100).set_level(level).set_gold(1234);
+		buf.extend_from_slice(&unsafe { mem::transmute::<Unit, [u8; 8]>(unit) });
+	}
+	buf
+}
+
+#[test]
+fn clean() {
+	assert_eq!(Unit::validate_all(&records()), Ok(3));
+	assert_eq!(Unit::validate_all(&[]), Ok(0));
+}
+
+#[test]
+fn corrupted() {
+	let mut buf = records();
+	buf[8] = 7;
+	let error = Unit::validate_all(&buf).unwrap_err();
+	assert_eq!(error, UnitValidateError::Field { record: 1, field: "kind", bytes: &[7] });
+	assert_eq!(error.to_string(), "record 1 has an invalid value in field `kind`: [07]");
+
+	let mut buf = records();
+	buf[17] = 2;
+	assert_eq!(Unit::validate_all(&buf), Err(UnitValidateError::Field { record: 2, field: "alive", bytes: &[2] }));
+	buf[2] = 101;
+	assert_eq!(Unit::validate_all(&buf), Err(UnitValidateError::Field { record: 0, field: "health", bytes: &[101] }));
+	buf[2] = 50;
+	buf[11] = 0;
+	assert_eq!(Unit::validate_all(&buf), Err(UnitValidateError::Field { record: 1, field: "level", bytes: &[0] }));
+}
+
+#[test]
+fn ragged() {
+	let buf = records();
+	let error = Unit::validate_all(&buf[..20]).unwrap_err();
+	assert_eq!(error, UnitValidateError::Length(20));
+	assert_eq!(error.to_string(), "the length 20 is not a multiple of the size 8 of `Unit`");
+}
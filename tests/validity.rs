@@ -0,0 +1,62 @@
+
+#[struct_layout::explicit(size = 16, align = 4)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Entity {
+	#[field(offset = 0)]
+	alive: bool,
+	#[field(offset = 1, strict)]
+	visible: bool,
+	#[field(offset = 2, get, ref, assume_valid)]
+	flag: bool,
+	#[field(offset = 4)]
+	letter: char,
+	#[field(offset = 8, strict)]
+	symbol: char,
+}
+
+#[test]
+fn canonical_values() {
+	let mut entity = Entity::default();
+	entity.set_alive(true).set_visible(true).set_letter('λ').set_symbol('x');
+	assert_eq!(&entity.0[0..2], &[1, 1]);
+	assert_eq!(&entity.0[4..8], &('λ' as u32).to_ne_bytes());
+	assert!(entity.alive());
+	assert_eq!(entity.visible(), Some(true));
+	assert_eq!(entity.letter(), 'λ');
+	assert_eq!(entity.symbol(), Some('x'));
+	assert!(!*entity.flag_ref());
+}
+
+#[test]
+fn invalid_bool() {
+	let mut entity = Entity::default();
+	entity.0[0] = 2;
+	entity.0[1] = 0xff;
+	assert!(entity.alive());
+	assert_eq!(entity.alive_raw(), 2);
+	assert_eq!(entity.visible(), None);
+	assert_eq!(entity.visible_raw(), 0xff);
+	// The setter writes the canonical representation
+	entity.set_alive(true).set_visible(false);
+	assert_eq!(&entity.0[0..2], &[1, 0]);
+	entity.set_visible_raw(1);
+	assert_eq!(entity.visible(), Some(true));
+}
+
+#[test]
+fn invalid_char() {
+	let mut entity = Entity::default();
+	entity.0[4..8].copy_from_slice(&0xd800u32.to_ne_bytes());
+	entity.0[8..12].copy_from_slice(&0x110000u32.to_ne_bytes());
+	assert_eq!(entity.letter(), char::REPLACEMENT_CHARACTER);
+	assert_eq!(entity.letter_raw(), 0xd800);
+	assert_eq!(entity.symbol(), None);
+	assert_eq!(entity.symbol_raw(), 0x110000);
+}
+
+#[test]
+fn debug_invalid() {
+	let mut entity = Entity::default();
+	entity.0[1] = 7;
+	assert!(format!("{:?}", entity).contains("visible: 7"));
+}
@@ -0,0 +1,59 @@
+
+#[struct_layout::explicit(size = 12, align = 4, check(::struct_layout::Pod, impl))]
+#[derive(Copy, Clone, Debug, Default)]
+struct MoveMsg {
+	#[field(offset = 0)]
+	x: f32,
+	#[field(offset = 4)]
+	y: f32,
+}
+
+#[struct_layout::explicit(size = 16, align = 1, check(::struct_layout::Pod, impl))]
+#[derive(Copy, Clone, Debug, Default)]
+struct ChatMsg {
+	#[field(offset = 0)]
+	text: [u8; 16],
+}
+
+#[struct_layout::explicit(size = 64, align = 4, check(::struct_layout::Pod))]
+#[derive(Copy, Clone, Debug, Default)]
+struct Msg {
+	#[field(offset = 0)]
+	kind: u8,
+	#[variant(kind = 1, offset = 4)]
+	move_data: MoveMsg,
+	#[variant(kind = 2)]
+	chat_data: ChatMsg,
+}
+
+#[test]
+fn move_variant() {
+	let mut msg = Msg::default();
+	assert!(msg.move_data().is_none());
+	let mut data = MoveMsg::default();
+	data.set_x(1.0).set_y(2.0);
+	msg.set_move_data(data);
+	assert_eq!(msg.kind(), 1);
+	assert_eq!(msg.move_data().map(|data| data.y()), Some(2.0));
+	assert!(msg.chat_data().is_none());
+	msg.move_data_mut().unwrap().set_x(3.0);
+
+	let bytes = unsafe { std::mem::transmute::<Msg, [u8; 64]>(msg) };
+	assert_eq!(&bytes[4..8], &3.0f32.to_ne_bytes());
+}
+
+#[test]
+fn chat_variant() {
+	let mut msg = Msg::default();
+	let mut data = ChatMsg::default();
+	data.set_text(*b"hello world\0\0\0\0\0");
+	msg.set_chat_data(data);
+	assert_eq!(msg.kind(), 2);
+	assert!(msg.move_data().is_none());
+	assert!(msg.move_data_mut().is_none());
+	assert_eq!(&msg.chat_data().unwrap().text()[..5], b"hello");
+
+	// The payload starts right after the discriminant
+	let bytes = unsafe { std::mem::transmute::<Msg, [u8; 64]>(msg) };
+	assert_eq!(&bytes[1..6], b"hello");
+}
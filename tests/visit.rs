@@ -0,0 +1,94 @@
+use std::fmt;
+use struct_layout::{FieldVisitor, FieldVisitorMut};
+
+#[struct_layout::explicit(size = 8, align = 4, visit)]
+#[derive(Copy, Clone)]
+struct Inner {
+	#[field(offset = 0)]
+	id: u32,
+}
+
+#[struct_layout::explicit(size = 48, align = 8, visit)]
+struct Foo {
+	#[field(offset = 0)]
+	int: i16,
+	#[field(offset = 4)]
+	uint: u32,
+	#[field(offset = 8)]
+	float: f32,
+	#[field(offset = 12)]
+	flag: bool,
+	#[field(offset = 16)]
+	name: [u8; 4],
+	#[field(offset = 20, get)]
+	letter: char,
+	#[field(offset = 24, nested)]
+	inner: Inner,
+	#[field(offset = 32, get, set)]
+	big: u128,
+}
+
+#[derive(Default)]
+struct Names(Vec<String>);
+
+impl FieldVisitor for Names {
+	fn visit_debug(&mut self, name: &str, value: &dyn fmt::Debug) {
+		self.0.push(format!("{}={:?}", name, value));
+	}
+	fn visit_nested(&mut self, name: &str, visit: &mut dyn FnMut(&mut Self)) {
+		self.0.push(format!("{}.", name));
+		visit(self)
+	}
+}
+
+// Increments every number by one
+struct Increment(usize);
+
+impl FieldVisitorMut for Increment {
+	fn visit_debug(&mut self, _name: &str, _value: &dyn fmt::Debug) {
+		self.0 += 1;
+	}
+	fn visit_int(&mut self, _name: &str, value: i64, set: &mut dyn FnMut(i64)) {
+		set(value + 1);
+	}
+	fn visit_uint(&mut self, _name: &str, value: u64, set: &mut dyn FnMut(u64)) {
+		set(value + 1);
+	}
+	fn visit_float(&mut self, _name: &str, value: f64, set: &mut dyn FnMut(f64)) {
+		set(value + 1.0);
+	}
+	fn visit_bytes(&mut self, _name: &str, value: &[u8], set: &mut dyn FnMut(&[u8])) {
+		let bytes: Vec<u8> = value.iter().map(|&byte| byte + 1).collect();
+		set(&bytes);
+	}
+}
+
+#[test]
+fn visit_fields() {
+	let mut foo: Foo = unsafe { std::mem::zeroed() };
+	foo.set_int(-2).set_uint(3).set_float(0.5).set_flag(true).set_name(*b"abcd").set_big(7);
+	foo.inner_mut().set_id(9);
+
+	let mut names = Names::default();
+	foo.visit_fields(&mut names);
+	assert_eq!(names.0, [
+		"int=-2", "uint=3", "float=0.5", "flag=true", "name=[97, 98, 99, 100]",
+		"letter='\\0'", "inner.", "id=9", "big=7",
+	]);
+}
+
+#[test]
+fn visit_fields_mut() {
+	let mut foo: Foo = unsafe { std::mem::zeroed() };
+	foo.set_int(-2).set_uint(3).set_float(0.5).set_name(*b"abcd");
+
+	let mut increment = Increment(0);
+	foo.visit_fields_mut(&mut increment);
+	assert_eq!(foo.int(), -1);
+	assert_eq!(foo.uint(), 4);
+	assert_eq!(foo.float(), 1.5);
+	assert_eq!(&foo.name(), b"bcde");
+	assert_eq!(foo.inner().id(), 1);
+	// The bool, the getter only char and the u128 are visited read only
+	assert_eq!(increment.0, 3);
+}
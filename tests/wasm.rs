@@ -0,0 +1,63 @@
+#![cfg(feature = "wasm_bindgen")]
+
+// The wasm_bindgen attributes only apply when targeting wasm, the exported accessors are regular methods otherwise
+// The expanded attributes are checked by `cargo check --target wasm32-unknown-unknown --features wasm_bindgen --test wasm`
+
+#[struct_layout::explicit(size = 32, align = 8, wasm_bindgen)]
+#[derive(Copy, Clone, Default)]
+pub struct Entity {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 4, get)]
+	alive: bool,
+	#[field(offset = 8)]
+	speed: f64,
+	#[field(offset = 16, get, set)]
+	tag: [u8; 4],
+	#[field(offset = 20, pad = 12)]
+	_reserved: (),
+}
+
+#[test]
+fn accessors() {
+	let mut entity = Entity::default();
+	entity.wasm_set_id(7);
+	entity.wasm_set_speed(1.5);
+	entity.wasm_set_tag(b"abcd");
+	assert_eq!(entity.id(), 7);
+	assert_eq!(entity.wasm_get_speed(), 1.5);
+	assert!(!entity.wasm_get_alive());
+	assert_eq!(entity.wasm_get_tag(), b"abcd".to_vec());
+	assert_eq!(entity.tag(), *b"abcd");
+}
+
+#[test]
+#[should_panic(expected = "field `tag` expects 4 bytes")]
+fn byte_array_length() {
+	Entity::default().wasm_set_tag(b"abc");
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Point {
+	pub x: f32,
+	pub y: f32,
+}
+
+#[struct_layout::explicit(size = 16, align = 4, wasm_bindgen(skip))]
+#[derive(Copy, Clone, Default)]
+pub struct Marker {
+	#[field(offset = 0)]
+	pos: Point,
+	#[field(offset = 8, get, set)]
+	kind: u8,
+	#[field(offset = 12, count = 2, get)]
+	flags: u16,
+}
+
+#[test]
+fn skip_incompatible() {
+	let mut marker = Marker::default();
+	marker.wasm_set_kind(3);
+	assert_eq!(marker.wasm_get_kind(), 3);
+	assert_eq!(marker.pos().x, 0.0);
+}
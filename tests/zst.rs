@@ -0,0 +1,53 @@
+use std::mem;
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+struct Marker;
+
+#[struct_layout::explicit(size = 16, align = 4, coverage)]
+#[derive(Copy, Clone, Debug, Default)]
+struct Tagged {
+	#[field(offset = 0)]
+	id: u32,
+	#[field(offset = 2)]
+	inside: (),
+	#[field(offset = 8, get, set)]
+	value: u32,
+	#[field(offset = 16)]
+	end: Marker,
+}
+
+#[test]
+fn accessors() {
+	let mut tagged = Tagged::default();
+	tagged.set_id(1).set_inside(()).set_end(Marker).set_value(2);
+	assert_eq!(tagged.inside(), ());
+	assert_eq!(tagged.end(), Marker);
+	assert_eq!(*tagged.end_ref(), Marker);
+	*tagged.end_mut() = Marker;
+	// Zero sized fields do not touch the bytes of the structure
+	let mut expected = [0u8; 16];
+	expected[0..4].copy_from_slice(&1u32.to_ne_bytes());
+	expected[8..12].copy_from_slice(&2u32.to_ne_bytes());
+	assert_eq!(tagged.0, expected);
+}
+
+#[test]
+fn end_of_structure() {
+	assert_eq!([Tagged::END_IS_ALIGNED], [true]);
+	let tagged = Tagged::default();
+	let addr = tagged.end_ref() as *const Marker as usize;
+	assert_eq!(addr, &tagged as *const Tagged as usize + mem::size_of::<Tagged>());
+}
+
+#[test]
+fn coverage() {
+	// Zero sized fields cover no bytes, even inside other fields
+	assert_eq!(Tagged::COVERAGE, &[0..4, 8..12]);
+	assert_eq!(Tagged::GAPS, &[4..8, 12..16]);
+}
+
+#[test]
+fn debug() {
+	let tagged = Tagged::default();
+	assert_eq!(format!("{:?}", tagged), "Tagged { id: 0, inside: (), value: 0, end: Marker }");
+}